@@ -2,16 +2,44 @@ use anyhow::Result;
 use serde_json::json;
 use std::time::{Duration, Instant};
 
-use crate::types::{AlertConfig, TelegramConfig, NodeHealthStatus};
+use crate::host_checks::{DiskUsage, StorageStatus, SystemMetrics, TimeSync};
+use crate::types::{
+    AlertConfig, ClusterHealthConfig, DiskSpaceAlertConfig, TelegramConfig, NodeHealthStatus,
+    IdentityBalanceAlertConfig, SkipRateAlertConfig, SystemMetricsAlertConfig, TimeSyncAlertConfig,
+    VoteAccountBalanceAlertConfig, VoteCreditsAlertConfig,
+};
+use crate::solana_rpc::{
+    lamports_to_sol, ClusterHealth, EpochReward, VoteAccountBalance, VoteCredits,
+};
 
 #[derive(Clone)]
 pub struct AlertManager {
     config: AlertConfig,
+    ack_condition: Option<String>, // Attach an Acknowledge button for this condition
 }
 
 impl AlertManager {
     pub fn new(config: AlertConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            ack_condition: None,
+        }
+    }
+
+    /// Copy of this manager whose Telegram messages carry an "Acknowledge"
+    /// button for `condition`. The button is only added when bot commands are
+    /// enabled, since nothing else would handle the callback.
+    pub fn with_ack(&self, condition: &str) -> Self {
+        let commands_enabled = self
+            .config
+            .telegram
+            .as_ref()
+            .map(|t| t.commands_enabled)
+            .unwrap_or(false);
+        Self {
+            config: self.config.clone(),
+            ack_condition: commands_enabled.then(|| condition.to_string()),
+        }
     }
 
     #[allow(dead_code)]
@@ -124,7 +152,8 @@ impl AlertManager {
             The following alert types are configured:\n\
             • Validator Delinquency Alerts\n\
             • Catchup Failure Alerts\n\
-            • Switch Result Alerts",
+            • Switch Result Alerts\n\
+            • Recovery Notifications",
             validators_text,
             self.config.delinquency_threshold_seconds
         );
@@ -182,6 +211,18 @@ impl AlertManager {
 
         self.send_telegram_message(telegram, &switch_failure_example).await?;
 
+        // Send example recovery notification
+        let recovery_example = format!(
+            "✅ *EXAMPLE: RECOVERED: Validator Voting* ✅\n\n\
+            *Validator:* `{}`\n\
+            *Outage Duration:* 95 seconds\n\n\
+            The previously alerted condition has cleared. No action required.\n\n\
+            ⚠️ *This is just an example alert*",
+            validators_info.first().map(|(id, _)| *id).unwrap_or("ExampleValidator")
+        );
+
+        self.send_telegram_message(telegram, &recovery_example).await?;
+
         Ok("Test messages sent successfully (including examples of all alert types)".to_string())
     }
 
@@ -191,14 +232,22 @@ impl AlertManager {
             telegram.bot_token
         );
 
-        let payload = json!({
+        let mut payload = json!({
             "chat_id": telegram.chat_id,
-            "text": message,
+            "text": crate::redact::redact_secrets(message),
             "parse_mode": "Markdown",
             "disable_web_page_preview": true
         });
+        if let Some(condition) = &self.ack_condition {
+            payload["reply_markup"] = json!({
+                "inline_keyboard": [[{
+                    "text": "👀 Acknowledge",
+                    "callback_data": format!("ack:{}", condition)
+                }]]
+            });
+        }
 
-        let client = reqwest::Client::new();
+        let client = crate::proxy::http_client_builder().build()?;
         let response = client.post(&url).json(&payload).send().await?;
 
         if !response.status().is_success() {
@@ -221,6 +270,18 @@ impl AlertManager {
             return Ok(());
         }
 
+        let annotation = if success {
+            format!("Validator switch: {} → {}", active_node, standby_node)
+        } else {
+            format!(
+                "Validator switch failed: {} → {} ({})",
+                active_node,
+                standby_node,
+                error.unwrap_or("Unknown error")
+            )
+        };
+        self.annotate(&annotation, &["switch"]).await;
+
         if let Some(telegram) = &self.config.telegram {
             let message = if success {
                 let time_str = if let Some(time) = total_time {
@@ -402,6 +463,17 @@ impl AlertManager {
             return Ok(());
         }
 
+        self.annotate(
+            &format!(
+                "Emergency failover: {} → {}{}",
+                active_node,
+                standby_node,
+                if standby_switch_success { "" } else { " (failed)" }
+            ),
+            &["failover"],
+        )
+        .await;
+
         if let Some(telegram) = &self.config.telegram {
             let primary_status = if primary_switch_success { "✅" } else { "❌" };
             let tower_status = if tower_copy_success { "✅" } else { "❌" };
@@ -490,51 +562,1307 @@ impl AlertManager {
 
         Ok(())
     }
-}
 
-// Helper to track alert cooldowns per validator
-pub struct AlertTracker {
-    last_alert_times: Vec<Option<Instant>>,
-    cooldown_seconds: u64,
-}
+    /// Both nodes of a validator failed the same way at once. Sent instead
+    /// of per-node alerts; auto-failover is held back in this case.
+    pub async fn send_common_cause_alert(
+        &self,
+        validator_identity: &str,
+        cause: CommonCause,
+        node_labels: &[&str],
+        details: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
 
-impl AlertTracker {
-    pub fn new(validator_count: usize) -> Self {
-        Self::with_cooldown(validator_count, 1800) // Default 30 minutes
+        if let Some(telegram) = &self.config.telegram {
+            let failover_note = if self.config.auto_failover_enabled {
+                "\n\nAuto-failover is suppressed: switching between two broken nodes would not help."
+            } else {
+                ""
+            };
+            let message = format!(
+                "🚨 *COMMON-CAUSE FAILURE* 🚨\n\n\
+                *Validator:* `{}`\n\
+                *Nodes:* {}\n\
+                *Correlation:* {}\n\
+                *Details:* {}\n\n\
+                Both nodes are affected, so this is likely site-wide (network, \
+                cluster or shared infrastructure) rather than a single node.{}",
+                validator_identity,
+                node_labels.join(", "),
+                cause.description(),
+                details,
+                failover_note
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
     }
-    
-    pub fn with_cooldown(validator_count: usize, cooldown_seconds: u64) -> Self {
-        Self {
-            last_alert_times: vec![None; validator_count],
-            cooldown_seconds,
+
+    /// The validator stopped voting while the cluster itself is halted or
+    /// degraded. Sent instead of the delinquency alert; auto-failover is held back.
+    pub async fn send_cluster_problem_alert(
+        &self,
+        validator_identity: &str,
+        problem: &str,
+        seconds_since_vote: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let failover_note = if self.config.auto_failover_enabled {
+                "\n\nAuto-failover is suppressed: switching nodes won't help while the cluster is down."
+            } else {
+                ""
+            };
+            let message = format!(
+                "🌐 *CLUSTER PROBLEM - NOT YOUR NODE* 🌐\n\n\
+                *Validator:* `{}`\n\
+                *No Vote For:* {} seconds\n\
+                *Cluster:* {}\n\n\
+                The whole cluster is halted or degraded, so the missing votes are \
+                most likely not caused by your node. Follow the cluster status \
+                channels before taking action.{}",
+                validator_identity, seconds_since_vote, problem, failover_note
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
         }
+
+        Ok(())
     }
 
-    pub fn should_send_alert(&mut self, validator_idx: usize) -> bool {
-        if validator_idx >= self.last_alert_times.len() {
-            return false;
+    /// The former active node did not stay on the unfunded identity after a switch
+    pub async fn send_demotion_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        reason: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
         }
 
-        match self.last_alert_times[validator_idx] {
-            Some(last_time) => {
-                if last_time.elapsed().as_secs() >= self.cooldown_seconds {
-                    self.last_alert_times[validator_idx] = Some(Instant::now());
-                    true
-                } else {
-                    false
-                }
-            }
-            None => {
-                self.last_alert_times[validator_idx] = Some(Instant::now());
-                true
-            }
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🚨 *POST-SWITCH DEMOTION CHECK FAILED* 🚨\n\n\
+                *Validator:* `{}`\n\
+                *Former Active Node:* {}\n\
+                *Problem:* {}\n\n\
+                ⚠️ *Action Required:* Confirm the node runs the unfunded identity to avoid duplicate voting",
+                validator_identity, node_label, reason
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
         }
+
+        Ok(())
     }
 
-    pub fn reset(&mut self, validator_idx: usize) {
-        if validator_idx < self.last_alert_times.len() {
-            self.last_alert_times[validator_idx] = None;
+    /// The switch finished but the new active node never voted
+    pub async fn send_vote_resumption_alert(
+        &self,
+        validator_identity: &str,
+        previous_active: &str,
+        new_active: &str,
+        reason: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        self.annotate(
+            &format!("No vote after switch: {} → {}", previous_active, new_active),
+            &["switch"],
+        )
+        .await;
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🚨🚨 *NO VOTES AFTER SWITCH* 🚨🚨\n\n\
+                *Validator:* `{}`\n\
+                *Previous Active:* {}\n\
+                *New Active:* {}\n\
+                *Problem:* {}\n\n\
+                ⚠️ *Action Required:* The validator may not be voting - check the new active node immediately",
+                validator_identity, previous_active, new_active, reason
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The validator skipped more of its leader slots this epoch than allowed
+    pub async fn send_skip_rate_alert(
+        &self,
+        validator_identity: &str,
+        epoch: Option<u64>,
+        leader_slots: u64,
+        blocks_produced: u64,
+        skip_rate: f64,
+        threshold_percent: f64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let epoch = epoch.map(|e| e.to_string()).unwrap_or_else(|| "current".to_string());
+            let message = format!(
+                "📉 *HIGH SKIP RATE* 📉\n\n\
+                *Validator:* `{}`\n\
+                *Epoch:* {}\n\
+                *Leader Slots:* {}\n\
+                *Produced:* {}\n\
+                *Skipped:* {} ({:.1}%, threshold {:.1}%)\n\n\
+                Votes may still land while blocks are missed - check the active node's \
+                performance and network",
+                validator_identity,
+                epoch,
+                leader_slots,
+                blocks_produced,
+                leader_slots.saturating_sub(blocks_produced),
+                skip_rate,
+                threshold_percent
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The validator earned fewer vote credits than allowed relative to the
+    /// best vote account this epoch
+    pub async fn send_vote_credits_alert(
+        &self,
+        validator_identity: &str,
+        credits: &VoteCredits,
+        efficiency: f64,
+        min_efficiency_percent: f64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🗳️ *LOW VOTE CREDITS* 🗳️\n\n\
+                *Validator:* `{}`\n\
+                *Epoch:* {}\n\
+                *Earned:* {} credits ({:.2} per slot)\n\
+                *Cluster Best:* {} credits\n\
+                *Efficiency:* {:.1}% (minimum {:.1}%)\n\n\
+                Votes are landing late or being missed - check vote latency and \
+                the active node's network",
+                validator_identity,
+                credits.epoch,
+                credits.earned,
+                credits.per_slot(),
+                credits.cluster_max,
+                efficiency,
+                min_efficiency_percent
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// A node's ledger or accounts filesystem is running out of space
+    pub async fn send_disk_space_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        low_disks: &[(&str, &DiskUsage)],
+        min_free_percent: u8,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let disks: Vec<String> = low_disks
+                .iter()
+                .map(|(kind, disk)| {
+                    format!(
+                        "*{}{}:* {} free ({}%) on {}",
+                        kind[..1].to_uppercase(),
+                        &kind[1..],
+                        crate::commands::switch::format_bytes(disk.free_bytes),
+                        disk.free_percent(),
+                        disk.mount
+                    )
+                })
+                .collect();
+            let message = format!(
+                "💾 *LOW DISK SPACE* 💾\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                {}\n\
+                *Minimum Free:* {}%\n\n\
+                ⚠️ *Action Required:* A full disk stops the validator - free up \
+                space or grow the filesystem",
+                validator_identity,
+                node_label,
+                disks.join("\n"),
+                min_free_percent
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The standby's newest snapshot is older than the configured maximum
+    pub async fn send_snapshot_age_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        age_seconds: u64,
+        max_age_minutes: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "📸 *STALE STANDBY SNAPSHOT* 📸\n\n\
+                *Validator:* `{}`\n\
+                *Standby Node:* {}\n\
+                *Newest Snapshot:* {} old (maximum {}m)\n\n\
+                After a restart the standby would boot from this snapshot and \
+                replay everything since - check that it still creates snapshots",
+                validator_identity,
+                node_label,
+                crate::commands::switch::format_wait(Duration::from_secs(age_seconds)),
+                max_age_minutes
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// CPU, memory or swap usage of a node stayed above its limit
+    pub async fn send_system_metrics_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        breaches: &[(&str, f64, f64)],
+        sustained_seconds: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let usage: Vec<String> = breaches
+                .iter()
+                .map(|(resource, used, limit)| format!("*{}:* {:.0}% (limit {:.0}%)", resource, used, limit))
+                .collect();
+            let message = format!(
+                "🔥 *HIGH RESOURCE USAGE* 🔥\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                {}\n\
+                *For:* over {}\n\n\
+                ⚠️ An overloaded node falls behind and stops voting - check what \
+                is using it",
+                validator_identity,
+                node_label,
+                usage.join("\n"),
+                crate::commands::switch::format_wait(Duration::from_secs(sustained_seconds))
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The funded identity's balance fell below the configured minimum
+    pub async fn send_low_identity_balance_alert(
+        &self,
+        validator_identity: &str,
+        balance_sol: f64,
+        min_sol: f64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "💸 *LOW IDENTITY BALANCE* 💸\n\n\
+                *Validator:* `{}`\n\
+                *Balance:* ◎{:.3} (minimum ◎{:.3})\n\n\
+                ⚠️ Vote fees are paid from the identity account - top it up before \
+                it runs dry and the validator stops voting",
+                validator_identity, balance_sol, min_sol
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The vote account is at or near its rent exemption minimum
+    pub async fn send_vote_account_low_balance_alert(
+        &self,
+        validator_identity: &str,
+        vote_pubkey: &str,
+        balance: &VoteAccountBalance,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🏦 *VOTE ACCOUNT LOW BALANCE* 🏦\n\n\
+                *Validator:* `{}`\n\
+                *Vote Account:* `{}`\n\
+                *Balance:* ◎{:.6} (rent exempt minimum ◎{:.6})\n\n\
+                ⚠️ Withdrawals are leaving the vote account at or below what keeps it \
+                rent exempt - check who withdrew from it",
+                validator_identity,
+                vote_pubkey,
+                lamports_to_sol(balance.lamports),
+                lamports_to_sol(balance.rent_exempt_lamports)
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
         }
+
+        Ok(())
+    }
+
+    /// Lamports left the vote account since the previous poll
+    pub async fn send_vote_account_drain_alert(
+        &self,
+        validator_identity: &str,
+        vote_pubkey: &str,
+        previous_lamports: u64,
+        lamports: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🚨 *VOTE ACCOUNT WITHDRAWAL* 🚨\n\n\
+                *Validator:* `{}`\n\
+                *Vote Account:* `{}`\n\
+                *Balance:* ◎{:.4} → ◎{:.4} (−◎{:.4})\n\n\
+                ⚠️ *Action Required:* If you didn't withdraw, the vote account's \
+                withdraw authority may be compromised - secure it immediately",
+                validator_identity,
+                vote_pubkey,
+                lamports_to_sol(previous_lamports),
+                lamports_to_sol(lamports),
+                lamports_to_sol(previous_lamports.saturating_sub(lamports))
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// A node's clock is not synchronized or its offset exceeds the limit
+    pub async fn send_time_sync_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        problem: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "⏰ *CLOCK OUT OF SYNC* ⏰\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                *Clock:* {}\n\n\
+                ⚠️ A drifting clock breaks vote timing and gossip - check chrony or \
+                timesyncd on the node",
+                validator_identity, node_label, problem
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The vote account's commission differs from what it was last seen at
+    pub async fn send_commission_change_alert(
+        &self,
+        validator_identity: &str,
+        vote_pubkey: &str,
+        previous_commission: u8,
+        commission: u8,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🚨 *COMMISSION CHANGED* 🚨\n\n\
+                *Validator:* `{}`\n\
+                *Vote Account:* `{}`\n\
+                *Commission:* {}% → {}%\n\n\
+                ⚠️ *Action Required:* If you didn't change it, the vote account's \
+                withdraw authority may be compromised - secure it immediately",
+                validator_identity, vote_pubkey, previous_commission, commission
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The validator process on a node started again while svs watched it
+    pub async fn send_validator_restart_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        previous_uptime_seconds: u64,
+        uptime_seconds: u64,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "🔄 *VALIDATOR RESTARTED* 🔄\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                *Uptime:* {} (was {})\n\n\
+                ⚠️ If nobody restarted it, check the validator logs - silent \
+                restarts are often a crash or the OOM killer",
+                validator_identity,
+                node_label,
+                crate::host_checks::format_uptime(uptime_seconds),
+                crate::host_checks::format_uptime(previous_uptime_seconds)
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// A line of a node's validator log matched a configured pattern
+    pub async fn send_log_pattern_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        pattern: &str,
+        snippet: &str,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "📜 *LOG PATTERN MATCHED* 📜\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                *Pattern:* `{}`\n\n\
+                ```\n{}\n```",
+                validator_identity,
+                node_label,
+                pattern.replace('`', "'"),
+                snippet.replace('`', "'")
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// A node runs an older release than the cluster's supermajority, when
+    /// `version_lag` is on
+    pub async fn send_version_lag_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        running: &str,
+        supermajority: &str,
+    ) -> Result<()> {
+        if !self.config.enabled || !self.config.version_lag {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format!(
+                "⚠️ *NODE VERSION BEHIND CLUSTER* ⚠️\n\n\
+                *Validator:* `{}`\n\
+                *Node:* {}\n\
+                *Running:* {}\n\
+                *Supermajority Runs:* {} or newer\n\n\
+                Two thirds of the stake on this client runs a newer release. \
+                Upgrade the node, ideally the standby first and then switch.",
+                validator_identity, node_label, running, supermajority
+            );
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// One step of a running switch or takeover, when `switch_progress` is on
+    pub async fn send_switch_progress(&self, validator_identity: &str, step: &str) -> Result<()> {
+        if !self.config.enabled || !self.config.switch_progress {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            self.send_telegram_message(telegram, &format_switch_progress(validator_identity, step))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sender for switch progress lines that are posted in order by a
+    /// background task. The task ends once every sender is dropped; None
+    /// when progress streaming is off.
+    pub fn relay_switch_progress(
+        &self,
+        validator_identity: &str,
+    ) -> Option<(
+        tokio::sync::mpsc::UnboundedSender<String>,
+        tokio::task::JoinHandle<()>,
+    )> {
+        if !self.config.enabled || !self.config.switch_progress || self.config.telegram.is_none() {
+            return None;
+        }
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let manager = self.clone();
+        let identity = validator_identity.to_string();
+        let relay = tokio::spawn(async move {
+            while let Some(step) = receiver.recv().await {
+                let _ = manager.send_switch_progress(&identity, &step).await;
+            }
+        });
+        Some((sender, relay))
+    }
+
+    /// Scheduled summary sent even when nothing alerts
+    pub async fn send_daily_digest(&self, uptime: Duration, entries: &[DigestEntry<'_>]) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format_daily_digest(uptime, entries);
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Weekly summary of rewards credited to each vote account
+    pub async fn send_rewards_summary(&self, entries: &[RewardsEntry<'_>]) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format_rewards_summary(entries);
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Simulated delinquency sent by `svs drill`, marked so nobody mistakes
+    /// it for a real outage
+    pub async fn send_drill_alert(
+        &self,
+        validator_identity: &str,
+        node_label: &str,
+        seconds_since_vote: u64,
+        decision: &FailoverDecision,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        self.annotate(&format!("Delinquency drill: {}", node_label), &["drill"])
+            .await;
+
+        if let Some(telegram) = &self.config.telegram {
+            let message = format_drill_alert(
+                validator_identity,
+                node_label,
+                seconds_since_vote,
+                self.config.delinquency_threshold_seconds,
+                decision,
+            );
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Mark an event on Grafana graphs. Failures are ignored so a Grafana
+    /// outage never blocks the Telegram notification.
+    async fn annotate(&self, text: &str, tags: &[&str]) {
+        if let Some(grafana) = &self.config.grafana {
+            let _ = crate::grafana::push_annotation(grafana, text, tags).await;
+        }
+    }
+
+    /// Notify that a previously alerted condition has cleared
+    pub async fn send_recovery_alert(
+        &self,
+        condition: &str,
+        validator_identity: &str,
+        node_label: Option<&str>,
+        outage_seconds: Option<u64>,
+    ) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if let Some(telegram) = &self.config.telegram {
+            let mut message = format!(
+                "✅ *RECOVERED: {}* ✅\n\n\
+                *Validator:* `{}`\n",
+                condition, validator_identity
+            );
+
+            if let Some(label) = node_label {
+                message.push_str(&format!("*Node:* {}\n", label));
+            }
+
+            if let Some(seconds) = outage_seconds {
+                message.push_str(&format!("*Outage Duration:* {} seconds\n", seconds));
+            }
+
+            message.push_str("\nThe previously alerted condition has cleared. No action required.");
+
+            self.send_telegram_message(telegram, &message).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Events counted for the daily digest, reset after each digest
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DigestCounters {
+    pub delinquency_events: u32,
+    pub ssh_failures: u32,
+    pub rpc_failures: u32,
+}
+
+/// One validator's line in the daily digest
+pub struct DigestEntry<'a> {
+    pub validator_identity: &'a str,
+    pub active_node: Option<&'a str>,
+    pub counters: &'a DigestCounters,
+}
+
+pub fn format_daily_digest(uptime: Duration, entries: &[DigestEntry<'_>]) -> String {
+    let hours = uptime.as_secs() / 3600;
+    let mut message = format!(
+        "📰 *DAILY DIGEST*\n\n\
+        *Monitoring Uptime:* {}d {}h {}m\n",
+        hours / 24,
+        hours % 24,
+        (uptime.as_secs() % 3600) / 60
+    );
+
+    for entry in entries {
+        let counters = entry.counters;
+        let healthy = *counters == DigestCounters::default();
+        message.push_str(&format!(
+            "\n{} *Validator:* `{}`\n\
+            *Active Node:* {}\n\
+            *Delinquency Events:* {}\n\
+            *SSH Failures:* {}\n\
+            *RPC Failures:* {}\n",
+            if healthy { "✅" } else { "⚠️" },
+            entry.validator_identity,
+            entry.active_node.unwrap_or("none detected"),
+            counters.delinquency_events,
+            counters.ssh_failures,
+            counters.rpc_failures
+        ));
+    }
+
+    message.push_str("\nCounts cover the period since the previous digest.");
+    message
+}
+
+/// One validator's rewards in the weekly summary
+pub struct RewardsEntry<'a> {
+    pub validator_identity: &'a str,
+    pub rewards: &'a [EpochReward],
+}
+
+pub fn format_rewards_summary(entries: &[RewardsEntry<'_>]) -> String {
+    let mut message = "💰 *WEEKLY REWARDS SUMMARY*\n".to_string();
+
+    for entry in entries {
+        message.push_str(&format!("\n*Validator:* `{}`\n", entry.validator_identity));
+        if entry.rewards.is_empty() {
+            message.push_str("No rewards credited in this period\n");
+            continue;
+        }
+
+        for reward in entry.rewards {
+            let commission = reward
+                .commission
+                .map(|c| format!(" at {}% commission", c))
+                .unwrap_or_default();
+            message.push_str(&format!(
+                "Epoch {}: ◎{:.4}{}\n",
+                reward.epoch,
+                lamports_to_sol(reward.amount_lamports),
+                commission
+            ));
+        }
+
+        let earned: u64 = entry.rewards.iter().map(|r| r.amount_lamports).sum();
+        let total_rewards: Option<u64> = entry
+            .rewards
+            .iter()
+            .map(|r| r.estimated_total_rewards_lamports())
+            .sum();
+        message.push_str(&format!("*Commission Earned:* ◎{:.4}\n", lamports_to_sol(earned)));
+        if let Some(total) = total_rewards {
+            message.push_str(&format!(
+                "*Est. Total Stake Rewards:* ◎{:.4}\n",
+                lamports_to_sol(total)
+            ));
+        }
+        if let Some(last) = entry.rewards.last() {
+            message.push_str(&format!(
+                "*Vote Account Balance:* ◎{:.4}\n",
+                lamports_to_sol(last.post_balance_lamports)
+            ));
+        }
+    }
+
+    message
+}
+
+/// Ping a dead-man's switch URL so an external monitor knows svs is alive
+pub async fn send_heartbeat(url: &str) -> Result<()> {
+    let client = crate::proxy::http_client_builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let response = client.get(url).send().await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("Heartbeat endpoint returned {}", response.status());
+    }
+
+    Ok(())
+}
+
+/// Slots behind before a node counts as lagging for common-cause detection
+pub const BEHIND_SLOTS_THRESHOLD: u64 = 100;
+
+/// A failure shared by both nodes of a validator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommonCause {
+    SshUnreachable,
+    BothBehind,
+}
+
+impl CommonCause {
+    pub fn description(&self) -> &'static str {
+        match self {
+            CommonCause::SshUnreachable => "SSH unreachable on both nodes",
+            CommonCause::BothBehind => "both nodes behind the cluster",
+        }
+    }
+}
+
+/// Slots behind from a catchup status such as `1234 slots behind`
+pub fn slots_behind(catchup_status: &str) -> Option<u64> {
+    catchup_status
+        .strip_suffix(" slots behind")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Check two nodes for a shared failure. SSH takes precedence since a node
+/// that can't be reached can't report its catchup state either.
+pub fn detect_common_cause(
+    ssh_down: [bool; 2],
+    catchup_status: [Option<&str>; 2],
+) -> Option<CommonCause> {
+    if ssh_down.iter().all(|down| *down) {
+        return Some(CommonCause::SshUnreachable);
+    }
+
+    let behind = catchup_status.iter().all(|status| {
+        status
+            .and_then(slots_behind)
+            .map(|slots| slots >= BEHIND_SLOTS_THRESHOLD)
+            .unwrap_or(false)
+    });
+    behind.then_some(CommonCause::BothBehind)
+}
+
+/// Why the cluster as a whole looks halted or degraded, or None when it is
+/// healthy and a stalled validator is the node's own problem
+pub fn cluster_problem(config: &ClusterHealthConfig, health: &ClusterHealth) -> Option<String> {
+    if health.block_age_seconds > config.max_block_age_seconds {
+        return Some(format!(
+            "cluster halted, newest confirmed block is {}s old",
+            health.block_age_seconds
+        ));
+    }
+    if health.delinquent_stake_percent > config.max_delinquent_stake_percent {
+        return Some(format!(
+            "cluster degraded, {:.1}% of stake is delinquent",
+            health.delinquent_stake_percent
+        ));
+    }
+    None
+}
+
+/// What auto-failover does about a delinquent validator
+#[derive(Debug, Clone, PartialEq)]
+pub enum FailoverDecision {
+    Disabled,
+    Suppressed(String),
+    Trigger,
+}
+
+impl FailoverDecision {
+    /// What the decision would have done, for drills run in shadow mode
+    pub fn shadow_outcome(&self) -> String {
+        match self {
+            FailoverDecision::Disabled => "nothing, auto-failover is disabled".to_string(),
+            FailoverDecision::Suppressed(reason) => format!("nothing, failover suppressed ({})", reason),
+            FailoverDecision::Trigger => "emergency failover to the standby node".to_string(),
+        }
+    }
+}
+
+/// Decide whether a delinquent validator fails over. RPC must be working,
+/// since it is what confirms on-chain that the validator is not voting (SSH
+/// may be down if the node is completely offline), and a shared failure on
+/// both nodes holds failover back.
+pub fn failover_decision(
+    config: &AlertConfig,
+    common_cause: Option<CommonCause>,
+    node_health: &NodeHealthStatus,
+) -> FailoverDecision {
+    if !(config.enabled && config.auto_failover_enabled) {
+        return FailoverDecision::Disabled;
+    }
+    if let Some(cause) = common_cause {
+        return FailoverDecision::Suppressed(cause.description().to_string());
+    }
+    if node_health.rpc_status.consecutive_failures > 0 {
+        return FailoverDecision::Suppressed(format!(
+            "SSH failures={}, RPC failures={}",
+            node_health.ssh_status.consecutive_failures,
+            node_health.rpc_status.consecutive_failures
+        ));
+    }
+    FailoverDecision::Trigger
+}
+
+/// RPC endpoints that must confirm a validator stopped voting before it fails
+/// over: the configured quorum, or a majority of the endpoints
+pub fn required_quorum(configured: Option<usize>, endpoints: usize) -> usize {
+    configured.unwrap_or(endpoints / 2 + 1)
+}
+
+/// Endpoints confirming that no vote landed after `stalled_slot`, from each
+/// endpoint's (last vote slot, current slot) or None when it didn't answer.
+/// An endpoint lagging the freshest one shows old votes too, so it can't
+/// confirm anything.
+pub fn count_no_vote_confirmations(stalled_slot: u64, observations: &[Option<(u64, u64)>]) -> usize {
+    let freshest_slot = match observations.iter().flatten().map(|(_, current)| *current).max() {
+        Some(slot) => slot,
+        None => return 0,
+    };
+    observations
+        .iter()
+        .flatten()
+        .filter(|(last_vote, current)| {
+            *last_vote <= stalled_slot
+                && freshest_slot.saturating_sub(*current) <= crate::rpc_selector::MAX_SLOT_LAG
+        })
+        .count()
+}
+
+/// The epoch's skip rate when it breaches the threshold, once enough leader
+/// slots have passed for the rate to mean something
+pub fn skip_rate_breach(
+    config: &SkipRateAlertConfig,
+    leader_slots: u64,
+    blocks_produced: u64,
+) -> Option<f64> {
+    if leader_slots < config.min_leader_slots.max(1) {
+        return None;
+    }
+    crate::solana_rpc::skip_rate_percent(leader_slots, blocks_produced)
+        .filter(|rate| *rate > config.threshold_percent)
+}
+
+/// Best credits in the epoch below which efficiency isn't judged; early in
+/// an epoch a few late votes would swing it (about 60 slots of voting)
+pub const MIN_CLUSTER_CREDITS: u64 = 1_000;
+
+/// The epoch's vote credit efficiency when it is below the minimum
+pub fn vote_credits_breach(config: &VoteCreditsAlertConfig, credits: &VoteCredits) -> Option<f64> {
+    if credits.cluster_max < MIN_CLUSTER_CREDITS {
+        return None;
+    }
+    credits
+        .efficiency_percent()
+        .filter(|efficiency| *efficiency < config.min_efficiency_percent)
+}
+
+/// Filesystems of a node with less free space than the minimum
+pub fn disk_space_breach<'a>(
+    config: &DiskSpaceAlertConfig,
+    storage: &'a StorageStatus,
+) -> Vec<(&'static str, &'a DiskUsage)> {
+    storage
+        .disks()
+        .into_iter()
+        .filter(|(_, disk)| disk.free_percent() < config.min_free_percent)
+        .collect()
+}
+
+/// Resources of a node above their limit as (resource, used %, limit %)
+pub fn system_metrics_breach(
+    config: &SystemMetricsAlertConfig,
+    metrics: &SystemMetrics,
+) -> Vec<(&'static str, f64, f64)> {
+    [
+        ("CPU", metrics.cpu_percent, config.max_cpu_percent),
+        ("Memory", metrics.memory_used_percent(), config.max_memory_percent),
+        ("Swap", metrics.swap_used_percent(), config.max_swap_percent),
+    ]
+    .into_iter()
+    .filter_map(|(resource, used, limit)| used.filter(|used| *used > limit).map(|used| (resource, used, limit)))
+    .collect()
+}
+
+/// The identity balance in SOL when it is below the configured minimum
+pub fn identity_balance_breach(config: &IdentityBalanceAlertConfig, lamports: u64) -> Option<f64> {
+    Some(lamports_to_sol(lamports)).filter(|balance| *balance < config.min_sol)
+}
+
+/// Whether the vote account is within `min_sol_above_rent` of, or below,
+/// its rent exemption minimum
+pub fn vote_account_balance_low(
+    config: &VoteAccountBalanceAlertConfig,
+    balance: &VoteAccountBalance,
+) -> bool {
+    !balance.is_rent_exempt()
+        || (config.min_sol_above_rent > 0.0
+            && lamports_to_sol(balance.excess_lamports()) < config.min_sol_above_rent)
+}
+
+/// Whether the balance is more than `max_drop_sol` below `baseline_lamports`.
+/// Rewards only ever add to a vote account, so a drop is a withdrawal.
+pub fn vote_account_drained(
+    config: &VoteAccountBalanceAlertConfig,
+    baseline_lamports: u64,
+    lamports: u64,
+) -> bool {
+    lamports < baseline_lamports
+        && lamports_to_sol(baseline_lamports - lamports) > config.max_drop_sol
+}
+
+/// What is wrong with a node's clock, if anything. A node whose sync state
+/// can't be read is not alerted on.
+pub fn time_sync_problem(config: &TimeSyncAlertConfig, sync: &TimeSync) -> Option<String> {
+    if sync.synchronized == Some(false) {
+        return Some("not synchronized".to_string());
+    }
+    sync.offset_ms
+        .filter(|offset| offset.abs() > config.max_offset_ms)
+        .map(|offset| format!("offset {:+.0} ms (limit {:.0} ms)", offset, config.max_offset_ms))
+}
+
+pub fn format_drill_alert(
+    validator_identity: &str,
+    node_label: &str,
+    seconds_since_vote: u64,
+    threshold_seconds: u64,
+    decision: &FailoverDecision,
+) -> String {
+    format!(
+        "🧪 *DRILL - NOT A REAL OUTAGE* 🧪\n\n\
+        🚨 *VALIDATOR DELINQUENCY ALERT* 🚨\n\n\
+        *Validator:* `{}`\n\
+        *Node:* {}\n\
+        *Time Since Last Vote:* {} seconds (simulated)\n\
+        *Threshold:* {} seconds\n\n\
+        *Auto-failover (shadow mode) would run:* {}\n\n\
+        The validator was not touched. Follow your delinquency runbook as practice.",
+        validator_identity,
+        node_label,
+        seconds_since_vote,
+        threshold_seconds,
+        decision.shadow_outcome()
+    )
+}
+
+/// Telegram line for one step of a running switch
+pub fn format_switch_progress(validator_identity: &str, step: &str) -> String {
+    format!("🔄 *Switch* `{}`\n{}", validator_identity, step.trim())
+}
+
+/// Temporary alert silences set with the Telegram /mute command
+#[derive(Debug, Clone, Default)]
+pub struct AlertMutes {
+    global_until: Option<Instant>,
+    validators: std::collections::HashMap<usize, Instant>,
+}
+
+impl AlertMutes {
+    /// Mute one validator, or every validator when `validator_idx` is None
+    pub fn mute(&mut self, validator_idx: Option<usize>, duration: Duration) {
+        let until = Instant::now() + duration;
+        match validator_idx {
+            Some(idx) => {
+                self.validators.insert(idx, until);
+            }
+            None => self.global_until = Some(until),
+        }
+    }
+
+    /// Lift the mute for one validator, or all mutes when `validator_idx` is None
+    pub fn unmute(&mut self, validator_idx: Option<usize>) {
+        match validator_idx {
+            Some(idx) => {
+                self.validators.remove(&idx);
+            }
+            None => {
+                self.global_until = None;
+                self.validators.clear();
+            }
+        }
+    }
+
+    pub fn is_muted(&self, validator_idx: usize) -> bool {
+        self.remaining(validator_idx).is_some()
+    }
+
+    /// Time left on the longest mute covering this validator
+    pub fn remaining(&self, validator_idx: usize) -> Option<Duration> {
+        let now = Instant::now();
+        [self.global_until, self.validators.get(&validator_idx).copied()]
+            .iter()
+            .flatten()
+            .filter(|until| **until > now)
+            .map(|until| *until - now)
+            .max()
+    }
+}
+
+/// Key identifying an ongoing alert condition, e.g. `rpc:0` or `ssh:1:0`.
+/// Also used as the payload of the Telegram Acknowledge button.
+pub fn condition_key(kind: &str, validator_idx: usize, node_idx: Option<usize>) -> String {
+    match node_idx {
+        Some(node) => format!("{}:{}:{}", kind, validator_idx, node),
+        None => format!("{}:{}", kind, validator_idx),
+    }
+}
+
+/// Maximum number of delivered alerts kept in the history
+const ALERT_HISTORY_LIMIT: usize = 100;
+
+/// A delivered alert and who acknowledged it
+#[derive(Debug, Clone)]
+pub struct AlertRecord {
+    pub condition: String,
+    pub title: String,
+    pub validator_identity: String,
+    pub sent_at: chrono::DateTime<chrono::Utc>,
+    pub acknowledged_by: Option<String>,
+    pub acknowledged_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Recent alerts plus the ongoing conditions acknowledged from Telegram.
+/// An acknowledged condition gets no repeat notifications until it clears.
+#[derive(Debug, Clone, Default)]
+pub struct AlertHistory {
+    records: std::collections::VecDeque<AlertRecord>,
+    acknowledged: std::collections::HashMap<String, String>,
+}
+
+impl AlertHistory {
+    pub fn record(&mut self, condition: &str, title: &str, validator_identity: &str) {
+        if self.records.len() >= ALERT_HISTORY_LIMIT {
+            self.records.pop_front();
+        }
+        self.records.push_back(AlertRecord {
+            condition: condition.to_string(),
+            title: title.to_string(),
+            validator_identity: validator_identity.to_string(),
+            sent_at: chrono::Utc::now(),
+            acknowledged_by: None,
+            acknowledged_at: None,
+            resolved_at: None,
+        });
+    }
+
+    /// Acknowledge an ongoing condition on behalf of `user`. Returns false
+    /// when no unacknowledged alert is open for it (already acknowledged or
+    /// cleared in the meantime).
+    pub fn acknowledge(&mut self, condition: &str, user: &str) -> bool {
+        if self.acknowledged.contains_key(condition) {
+            return false;
+        }
+        let now = chrono::Utc::now();
+        let mut found = false;
+        for record in self
+            .records
+            .iter_mut()
+            .filter(|r| {
+                r.condition == condition && r.resolved_at.is_none() && r.acknowledged_by.is_none()
+            })
+        {
+            record.acknowledged_by = Some(user.to_string());
+            record.acknowledged_at = Some(now);
+            found = true;
+        }
+        if found {
+            self.acknowledged.insert(condition.to_string(), user.to_string());
+        }
+        found
+    }
+
+    pub fn is_acknowledged(&self, condition: &str) -> bool {
+        self.acknowledged.contains_key(condition)
+    }
+
+    /// The condition is over; the next occurrence alerts normally again
+    pub fn resolve(&mut self, condition: &str) {
+        self.acknowledged.remove(condition);
+        let now = chrono::Utc::now();
+        for record in self
+            .records
+            .iter_mut()
+            .filter(|r| r.condition == condition && r.resolved_at.is_none())
+        {
+            record.resolved_at = Some(now);
+        }
+    }
+
+    /// Most recent alerts first
+    pub fn recent(&self, limit: usize) -> impl Iterator<Item = &AlertRecord> {
+        self.records.iter().rev().take(limit)
+    }
+}
+
+// Helper to track alert cooldowns per validator
+pub struct AlertTracker {
+    last_alert_times: Vec<Option<Instant>>,
+    suppressed: Vec<bool>, // Last granted alert was muted and never delivered
+    cooldown_seconds: u64,
+}
+
+impl AlertTracker {
+    pub fn new(validator_count: usize) -> Self {
+        Self::with_cooldown(validator_count, 1800) // Default 30 minutes
+    }
+    
+    pub fn with_cooldown(validator_count: usize, cooldown_seconds: u64) -> Self {
+        Self {
+            last_alert_times: vec![None; validator_count],
+            suppressed: vec![false; validator_count],
+            cooldown_seconds,
+        }
+    }
+
+    /// Whether `should_send_alert` would grant an alert now, without taking it
+    pub fn is_due(&self, validator_idx: usize) -> bool {
+        match self.last_alert_times.get(validator_idx) {
+            Some(Some(last_time)) => last_time.elapsed().as_secs() >= self.cooldown_seconds,
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    pub fn should_send_alert(&mut self, validator_idx: usize) -> bool {
+        if !self.is_due(validator_idx) {
+            return false;
+        }
+        self.last_alert_times[validator_idx] = Some(Instant::now());
+        true
+    }
+
+    pub fn reset(&mut self, validator_idx: usize) {
+        if validator_idx < self.last_alert_times.len() {
+            self.last_alert_times[validator_idx] = None;
+            self.suppressed[validator_idx] = false;
+        }
+    }
+
+    /// Record that the alert just granted was muted. The cooldown still runs
+    /// so anything tied to the alert stays rate limited.
+    pub fn suppress(&mut self, validator_idx: usize) {
+        if let Some(flag) = self.suppressed.get_mut(validator_idx) {
+            *flag = true;
+        }
+    }
+
+    /// Call once the validator is no longer muted: if the last alert was
+    /// suppressed the cooldown is cleared so the ongoing condition is
+    /// delivered on the next check instead of after the cooldown
+    pub fn resume(&mut self, validator_idx: usize) {
+        if self.suppressed.get(validator_idx).copied().unwrap_or(false) {
+            self.reset(validator_idx);
+        }
+    }
+
+    /// Reset the tracker and report whether an alert had been sent for this
+    /// condition, meaning a recovery notification is due
+    pub fn resolve(&mut self, validator_idx: usize) -> bool {
+        let was_alerted = self
+            .last_alert_times
+            .get(validator_idx)
+            .map(|t| t.is_some())
+            .unwrap_or(false);
+        let was_suppressed = self.suppressed.get(validator_idx).copied().unwrap_or(false);
+        self.reset(validator_idx);
+        was_alerted && !was_suppressed
     }
 }
 
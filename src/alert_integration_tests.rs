@@ -13,7 +13,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
-            
+            ..Default::default()
         };
 
         // Simulate the actual check from status_ui_v2.rs
@@ -81,7 +81,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
-            
+            ..Default::default()
         };
 
         let mut ssh_tracker = FailureTracker::new();
@@ -116,7 +116,7 @@ mod alert_integration_tests {
             rpc_failure_threshold_seconds: 1800, // 30 minutes
             telegram: None,
             auto_failover_enabled: false,
-            
+            ..Default::default()
         };
 
         // Validator state
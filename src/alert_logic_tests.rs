@@ -13,8 +13,12 @@ mod alert_logic_tests {
             telegram: Some(TelegramConfig {
                 bot_token: "test_token".to_string(),
                 chat_id: "test_chat".to_string(),
+                commands_enabled: false,
+                allowed_chat_ids: Vec::new(),
+                admin_ids: Vec::new(),
             }),
             auto_failover_enabled: false,
+            ..Default::default()
         }
     }
 
@@ -1,7 +1,20 @@
 #[cfg(test)]
 mod tests {
-    use crate::alert::{AlertTracker, ComprehensiveAlertTracker};
-    use crate::types::{AlertConfig, FailureTracker, NodeHealthStatus, TelegramConfig};
+    use crate::alert::{
+        condition_key, detect_common_cause, format_daily_digest, slots_behind, CommonCause, AlertHistory, AlertMutes, AlertTracker, ComprehensiveAlertTracker, DigestCounters,
+        DigestEntry, RewardsEntry, format_rewards_summary, failover_decision, format_drill_alert,
+        format_switch_progress, FailoverDecision, count_no_vote_confirmations, required_quorum,
+        skip_rate_breach, vote_credits_breach, cluster_problem, disk_space_breach,
+        system_metrics_breach, time_sync_problem, identity_balance_breach, vote_account_balance_low,
+        vote_account_drained,
+    };
+    use crate::host_checks::{DiskUsage, StorageStatus, SystemMetrics, TimeSync};
+    use crate::solana_rpc::{ClusterHealth, EpochReward, VoteAccountBalance, VoteCredits};
+    use crate::types::{
+        AlertConfig, ClusterHealthConfig, DiskSpaceAlertConfig, FailureTracker, IdentityBalanceAlertConfig,
+        NodeHealthStatus, SkipRateAlertConfig, SystemMetricsAlertConfig, TelegramConfig, TimeSyncAlertConfig,
+        VoteAccountBalanceAlertConfig, VoteCreditsAlertConfig,
+    };
     use std::time::{Duration, Instant};
     use tokio::time::sleep;
 
@@ -14,8 +27,12 @@ mod tests {
             telegram: Some(TelegramConfig {
                 bot_token: "test_token".to_string(),
                 chat_id: "test_chat".to_string(),
+                commands_enabled: false,
+                allowed_chat_ids: Vec::new(),
+                admin_ids: Vec::new(),
             }),
             auto_failover_enabled: false,
+            ..Default::default()
         }
     }
 
@@ -81,6 +98,37 @@ mod tests {
         assert!(tracker.should_send_alert(1));
     }
 
+    #[test]
+    fn test_alert_tracker_is_due_does_not_take_the_alert() {
+        let mut tracker = AlertTracker::new(1);
+
+        assert!(tracker.is_due(0));
+        assert!(tracker.is_due(0));
+        assert!(tracker.should_send_alert(0));
+        assert!(!tracker.is_due(0));
+
+        // Out of range index is never due
+        assert!(!tracker.is_due(3));
+    }
+
+    #[test]
+    fn test_no_vote_quorum() {
+        assert_eq!(required_quorum(None, 1), 1);
+        assert_eq!(required_quorum(None, 3), 2);
+        assert_eq!(required_quorum(None, 4), 3);
+        assert_eq!(required_quorum(Some(1), 3), 1);
+
+        // Two agree the vote is stuck at 1000; one sees a newer vote
+        let observations = [Some((1000, 1100)), Some((1000, 1098)), Some((1090, 1100))];
+        assert_eq!(count_no_vote_confirmations(1000, &observations), 2);
+
+        // A lagging endpoint and a failed one don't count
+        let observations = [Some((1000, 1100)), Some((1000, 900)), None];
+        assert_eq!(count_no_vote_confirmations(1000, &observations), 1);
+
+        assert_eq!(count_no_vote_confirmations(1000, &[None, None]), 0);
+    }
+
     #[test]
     fn test_alert_tracker_reset() {
         let mut tracker = AlertTracker::new(2);
@@ -94,6 +142,138 @@ mod tests {
         assert!(tracker.should_send_alert(0));
     }
 
+    #[test]
+    fn test_alert_tracker_resolve_reports_prior_alert() {
+        let mut tracker = AlertTracker::new(2);
+
+        // Nothing alerted yet, so no recovery is due
+        assert!(!tracker.resolve(0));
+
+        // After an alert, resolving reports it once and re-arms the tracker
+        assert!(tracker.should_send_alert(0));
+        assert!(tracker.resolve(0));
+        assert!(!tracker.resolve(0));
+        assert!(tracker.should_send_alert(0));
+
+        // Out of range index is ignored
+        assert!(!tracker.resolve(5));
+    }
+
+    #[test]
+    fn test_alert_tracker_suppressed_alert_resumes_after_unmute() {
+        let mut tracker = AlertTracker::new(1);
+
+        // Alert granted while muted: cooldown runs but nothing was delivered
+        assert!(tracker.should_send_alert(0));
+        tracker.suppress(0);
+        assert!(!tracker.should_send_alert(0));
+
+        // Unmuted: the ongoing condition is delivered on the next check
+        tracker.resume(0);
+        assert!(tracker.should_send_alert(0));
+
+        // A delivered alert keeps its cooldown across resume
+        tracker.resume(0);
+        assert!(!tracker.should_send_alert(0));
+    }
+
+    #[test]
+    fn test_alert_tracker_no_recovery_for_suppressed_alert() {
+        let mut tracker = AlertTracker::new(1);
+        assert!(tracker.should_send_alert(0));
+        tracker.suppress(0);
+        assert!(!tracker.resolve(0));
+    }
+
+    #[test]
+    fn test_format_daily_digest() {
+        let quiet = DigestCounters::default();
+        let noisy = DigestCounters {
+            delinquency_events: 1,
+            ssh_failures: 4,
+            rpc_failures: 0,
+        };
+        let entries = vec![
+            DigestEntry {
+                validator_identity: "IdentityA",
+                active_node: Some("node-a"),
+                counters: &quiet,
+            },
+            DigestEntry {
+                validator_identity: "IdentityB",
+                active_node: None,
+                counters: &noisy,
+            },
+        ];
+
+        let message = format_daily_digest(Duration::from_secs(26 * 3600 + 5 * 60), &entries);
+        assert!(message.contains("1d 2h 5m"));
+        assert!(message.contains("✅ *Validator:* `IdentityA`"));
+        assert!(message.contains("*Active Node:* node-a"));
+        assert!(message.contains("⚠️ *Validator:* `IdentityB`"));
+        assert!(message.contains("*Active Node:* none detected"));
+        assert!(message.contains("*SSH Failures:* 4"));
+    }
+
+    #[test]
+    fn test_format_rewards_summary() {
+        let rewards = vec![
+            EpochReward {
+                epoch: 699,
+                amount_lamports: 1_000_000_000,
+                post_balance_lamports: 3_000_000_000,
+                commission: Some(10),
+            },
+            EpochReward {
+                epoch: 700,
+                amount_lamports: 500_000_000,
+                post_balance_lamports: 3_500_000_000,
+                commission: Some(10),
+            },
+        ];
+        let entries = vec![
+            RewardsEntry {
+                validator_identity: "IdentityA",
+                rewards: &rewards,
+            },
+            RewardsEntry {
+                validator_identity: "IdentityB",
+                rewards: &[],
+            },
+        ];
+
+        let message = format_rewards_summary(&entries);
+        assert!(message.contains("Epoch 699: ◎1.0000 at 10% commission"));
+        assert!(message.contains("*Commission Earned:* ◎1.5000"));
+        assert!(message.contains("*Est. Total Stake Rewards:* ◎15.0000"));
+        assert!(message.contains("*Vote Account Balance:* ◎3.5000"));
+        assert!(message.contains("No rewards credited in this period"));
+    }
+
+    #[test]
+    fn test_alert_mutes() {
+        let mut mutes = AlertMutes::default();
+        assert!(!mutes.is_muted(0));
+
+        mutes.mute(Some(1), Duration::from_secs(60));
+        assert!(!mutes.is_muted(0));
+        assert!(mutes.is_muted(1));
+
+        mutes.mute(None, Duration::from_secs(120));
+        assert!(mutes.is_muted(0));
+        assert!(mutes.remaining(1).unwrap() > Duration::from_secs(60));
+
+        mutes.unmute(Some(1));
+        assert!(mutes.is_muted(1)); // Still covered by the global mute
+
+        mutes.unmute(None);
+        assert!(!mutes.is_muted(0));
+        assert!(!mutes.is_muted(1));
+
+        mutes.mute(Some(0), Duration::from_secs(0));
+        assert!(!mutes.is_muted(0));
+    }
+
     #[test]
     fn test_comprehensive_alert_tracker() {
         let tracker = ComprehensiveAlertTracker::new(2, 2);
@@ -252,4 +432,298 @@ mod tests {
         assert_eq!(trackers[1].consecutive_failures, 0);
         assert_eq!(trackers[2].consecutive_failures, 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_alert_acknowledgment() {
+        let mut history = AlertHistory::default();
+        let condition = condition_key("delinquency", 0, None);
+        assert_eq!(condition, "delinquency:0");
+        assert_eq!(condition_key("ssh", 1, Some(0)), "ssh:1:0");
+
+        // Nothing to acknowledge before an alert is sent
+        assert!(!history.acknowledge(&condition, "@alice"));
+
+        history.record(&condition, "Validator Delinquency", "Identity111");
+        history.record(&condition_key("rpc", 0, None), "RPC Connection", "Identity111");
+        assert!(history.acknowledge(&condition, "@alice"));
+        assert!(history.is_acknowledged(&condition));
+        assert!(!history.is_acknowledged("rpc:0"));
+
+        // A second press doesn't overwrite who acknowledged it
+        assert!(!history.acknowledge(&condition, "@bob"));
+        let record = history
+            .recent(10)
+            .find(|r| r.condition == condition)
+            .unwrap();
+        assert_eq!(record.acknowledged_by.as_deref(), Some("@alice"));
+
+        // Once the condition clears the next occurrence alerts again
+        history.resolve(&condition);
+        assert!(!history.is_acknowledged(&condition));
+        assert!(!history.acknowledge(&condition, "@bob"));
+        assert!(history.recent(10).all(|r| r.condition != condition || r.resolved_at.is_some()));
+    }
+
+    #[test]
+    fn test_common_cause_detection() {
+        assert_eq!(slots_behind("250 slots behind"), Some(250));
+        assert_eq!(slots_behind("Caught up"), None);
+
+        // One node down is a per-node problem
+        assert_eq!(detect_common_cause([true, false], [None, None]), None);
+        assert_eq!(
+            detect_common_cause([true, true], [None, None]),
+            Some(CommonCause::SshUnreachable)
+        );
+
+        assert_eq!(
+            detect_common_cause([false, false], [Some("500 slots behind"), Some("120 slots behind")]),
+            Some(CommonCause::BothBehind)
+        );
+        // Small lag on one node doesn't count
+        assert_eq!(
+            detect_common_cause([false, false], [Some("500 slots behind"), Some("3 slots behind")]),
+            None
+        );
+        assert_eq!(
+            detect_common_cause([false, false], [Some("500 slots behind"), Some("Caught up")]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_failover_decision() {
+        let mut config = create_test_alert_config();
+        let mut health = NodeHealthStatus {
+            ssh_status: FailureTracker::new(),
+            rpc_status: FailureTracker::new(),
+            is_voting: false,
+            last_vote_slot: None,
+            last_vote_time: None,
+        };
+        assert_eq!(failover_decision(&config, None, &health), FailoverDecision::Disabled);
+
+        config.auto_failover_enabled = true;
+        assert_eq!(failover_decision(&config, None, &health), FailoverDecision::Trigger);
+        assert!(matches!(
+            failover_decision(&config, Some(CommonCause::SshUnreachable), &health),
+            FailoverDecision::Suppressed(_)
+        ));
+
+        // Without RPC there is no on-chain proof the validator stopped voting
+        health.rpc_status.record_failure("timeout".to_string());
+        assert!(matches!(
+            failover_decision(&config, None, &health),
+            FailoverDecision::Suppressed(_)
+        ));
+    }
+
+    #[test]
+    fn test_cluster_problem() {
+        let config = ClusterHealthConfig::default();
+        let healthy = ClusterHealth {
+            block_age_seconds: 2,
+            delinquent_stake_percent: 1.5,
+        };
+        assert_eq!(cluster_problem(&config, &healthy), None);
+
+        let halted = ClusterHealth {
+            block_age_seconds: 120,
+            ..healthy
+        };
+        assert_eq!(
+            cluster_problem(&config, &halted).as_deref(),
+            Some("cluster halted, newest confirmed block is 120s old")
+        );
+
+        let degraded = ClusterHealth {
+            delinquent_stake_percent: 25.0,
+            ..healthy
+        };
+        assert_eq!(
+            cluster_problem(&config, &degraded).as_deref(),
+            Some("cluster degraded, 25.0% of stake is delinquent")
+        );
+    }
+
+    #[test]
+    fn test_disk_space_breach() {
+        let config = DiskSpaceAlertConfig { min_free_percent: 10 };
+        let disk = |mount: &str, used_percent| DiskUsage {
+            mount: mount.to_string(),
+            used_percent,
+            free_bytes: 1024,
+        };
+        let storage = StorageStatus {
+            tower_age_seconds: None,
+            ledger_disk: Some(disk("/mnt/ledger", 95)),
+            accounts_disk: Some(disk("/mnt/accounts", 60)),
+            ..StorageStatus::default()
+        };
+        let low = disk_space_breach(&config, &storage);
+        assert_eq!(low.len(), 1);
+        assert_eq!(low[0].0, "ledger");
+
+        // Exactly at the minimum is still fine
+        let storage = StorageStatus {
+            ledger_disk: Some(disk("/mnt/ledger", 90)),
+            ..storage
+        };
+        assert!(disk_space_breach(&config, &storage).is_empty());
+        assert!(disk_space_breach(&config, &StorageStatus::default()).is_empty());
+    }
+
+    #[test]
+    fn test_system_metrics_breach() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let config = SystemMetricsAlertConfig::default();
+        let metrics = SystemMetrics {
+            cpu_percent: Some(97.0),
+            memory_total_bytes: 256 * GB,
+            memory_available_bytes: 64 * GB,
+            swap_total_bytes: 8 * GB,
+            swap_free_bytes: 4 * GB,
+            ..SystemMetrics::default()
+        };
+        assert_eq!(
+            system_metrics_breach(&config, &metrics),
+            vec![("CPU", 97.0, 90.0), ("Swap", 50.0, 20.0)]
+        );
+
+        // Unmeasured CPU and a node without swap are not breaches
+        let metrics = SystemMetrics {
+            cpu_percent: None,
+            swap_total_bytes: 0,
+            swap_free_bytes: 0,
+            ..metrics
+        };
+        assert!(system_metrics_breach(&config, &metrics).is_empty());
+    }
+
+    #[test]
+    fn test_identity_balance_breach() {
+        let config = IdentityBalanceAlertConfig::default();
+        assert_eq!(identity_balance_breach(&config, 2_500_000_000), None);
+        assert_eq!(identity_balance_breach(&config, 1_000_000_000), None);
+        assert_eq!(identity_balance_breach(&config, 400_000_000), Some(0.4));
+        assert_eq!(identity_balance_breach(&config, 0), Some(0.0));
+    }
+
+    #[test]
+    fn test_vote_account_balance_alerts() {
+        let config = VoteAccountBalanceAlertConfig::default();
+        let balance = VoteAccountBalance {
+            lamports: 27_074_400,
+            rent_exempt_lamports: 27_074_400,
+        };
+        // Withdrawn down to the minimum is fine unless a margin is configured
+        assert!(!vote_account_balance_low(&config, &balance));
+        assert!(vote_account_balance_low(
+            &config,
+            &VoteAccountBalance { lamports: 0, ..balance }
+        ));
+        let margin = VoteAccountBalanceAlertConfig {
+            min_sol_above_rent: 0.5,
+            ..config.clone()
+        };
+        assert!(vote_account_balance_low(&margin, &balance));
+        assert!(!vote_account_balance_low(
+            &margin,
+            &VoteAccountBalance { lamports: 1_027_074_400, ..balance }
+        ));
+
+        assert!(vote_account_drained(&config, 5_000_000_000, 4_999_999_999));
+        assert!(!vote_account_drained(&config, 5_000_000_000, 5_300_000_000));
+        let tolerant = VoteAccountBalanceAlertConfig {
+            max_drop_sol: 1.0,
+            ..config
+        };
+        assert!(!vote_account_drained(&tolerant, 5_000_000_000, 4_500_000_000));
+        assert!(vote_account_drained(&tolerant, 5_000_000_000, 27_074_400));
+    }
+
+    #[test]
+    fn test_time_sync_problem() {
+        let config = TimeSyncAlertConfig::default();
+        let synced = TimeSync {
+            synchronized: Some(true),
+            offset_ms: Some(-0.4),
+            source: None,
+        };
+        assert_eq!(time_sync_problem(&config, &synced), None);
+
+        let drifted = TimeSync {
+            offset_ms: Some(-250.0),
+            ..synced.clone()
+        };
+        assert_eq!(
+            time_sync_problem(&config, &drifted).as_deref(),
+            Some("offset -250 ms (limit 100 ms)")
+        );
+
+        let unsynced = TimeSync {
+            synchronized: Some(false),
+            ..synced
+        };
+        assert_eq!(time_sync_problem(&config, &unsynced).as_deref(), Some("not synchronized"));
+        assert_eq!(time_sync_problem(&config, &TimeSync::default()), None);
+    }
+
+    #[test]
+    fn test_format_drill_alert() {
+        let message = format_drill_alert("Id111", "node-a", 30, 30, &FailoverDecision::Trigger);
+        assert!(message.starts_with("🧪 *DRILL - NOT A REAL OUTAGE* 🧪"));
+        assert!(message.contains("*Node:* node-a"));
+        assert!(message.contains("would run:* emergency failover to the standby node"));
+
+        let message = format_drill_alert("Id111", "node-a", 30, 30, &FailoverDecision::Disabled);
+        assert!(message.contains("nothing, auto-failover is disabled"));
+    }
+
+    #[test]
+    fn test_skip_rate_breach() {
+        let config = SkipRateAlertConfig {
+            threshold_percent: 10.0,
+            min_leader_slots: 20,
+        };
+        // Too few leader slots so far to judge
+        assert_eq!(skip_rate_breach(&config, 8, 4), None);
+        assert_eq!(skip_rate_breach(&config, 40, 37), None);
+        assert_eq!(skip_rate_breach(&config, 40, 36), None);
+        assert_eq!(skip_rate_breach(&config, 40, 30), Some(25.0));
+    }
+
+    #[test]
+    fn test_vote_credits_breach() {
+        let config = VoteCreditsAlertConfig {
+            min_efficiency_percent: 90.0,
+        };
+        let credits = VoteCredits {
+            epoch: 700,
+            slots_elapsed: 40,
+            earned: 400,
+            cluster_max: 640,
+        };
+        // Too early in the epoch to judge
+        assert_eq!(vote_credits_breach(&config, &credits), None);
+
+        let credits = VoteCredits {
+            slots_elapsed: 8_000,
+            earned: 115_000,
+            cluster_max: 125_000,
+            ..credits
+        };
+        assert_eq!(vote_credits_breach(&config, &credits), None);
+        let credits = VoteCredits {
+            earned: 100_000,
+            ..credits
+        };
+        assert_eq!(vote_credits_breach(&config, &credits), Some(80.0));
+    }
+
+    #[test]
+    fn test_format_switch_progress() {
+        let message = format_switch_progress("Id111", "   ✓ Completed in 120ms\n");
+        assert_eq!(message, "🔄 *Switch* `Id111`\n✓ Completed in 120ms");
+    }
+}
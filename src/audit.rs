@@ -0,0 +1,269 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use crate::commands::status_ui_v2::{LogLevel, LogMessage};
+
+/// `prev_hash` of the first entry in a log
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Last entry this process appended and the log's length after it, so the
+/// log is only read again when another process has extended it since
+static LAST_ENTRY: Mutex<Option<(PathBuf, u64, AuditEntry)>> = Mutex::new(None);
+
+/// Monitor log channel that write failures are reported to while the status
+/// UI or headless monitor runs; plain commands print them instead
+static LOG_SENDER: OnceLock<tokio::sync::mpsc::UnboundedSender<LogMessage>> = OnceLock::new();
+
+/// Report audit write failures through the monitor's log channel
+pub fn set_log_sender(sender: tokio::sync::mpsc::UnboundedSender<LogMessage>) {
+    let _ = LOG_SENDER.set(sender);
+}
+
+/// Fields covered by an entry's hash, in the order they are hashed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub seq: u64,
+    pub timestamp: String,
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+    pub detail: String,
+    pub prev_hash: String,
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    #[serde(flatten)]
+    pub record: AuditRecord,
+    pub hash: String,
+}
+
+/// SHA-256 over the record's JSON, which includes the previous entry's hash,
+/// so changing or dropping any entry breaks every hash after it
+pub fn record_hash(record: &AuditRecord) -> String {
+    let json = serde_json::to_string(record).unwrap_or_default();
+    format!("{:x}", Sha256::digest(json.as_bytes()))
+}
+
+/// Entry following `previous` in the chain
+pub fn next_entry(
+    previous: Option<&AuditEntry>,
+    timestamp: &str,
+    actor: &str,
+    action: &str,
+    target: &str,
+    detail: &str,
+) -> AuditEntry {
+    let record = AuditRecord {
+        seq: previous.map(|entry| entry.record.seq + 1).unwrap_or(1),
+        timestamp: timestamp.to_string(),
+        actor: actor.to_string(),
+        action: action.to_string(),
+        target: target.to_string(),
+        detail: detail.to_string(),
+        prev_hash: previous
+            .map(|entry| entry.hash.clone())
+            .unwrap_or_else(|| GENESIS_HASH.to_string()),
+    };
+    let hash = record_hash(&record);
+    AuditEntry { record, hash }
+}
+
+/// Check every line of a log. Returns the number of entries, or the first
+/// line (1-based) whose entry was altered, removed or reordered.
+pub fn verify(content: &str) -> std::result::Result<usize, String> {
+    let mut previous: Option<AuditEntry> = None;
+    let mut count = 0;
+
+    for (idx, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let entry: AuditEntry = serde_json::from_str(line)
+            .map_err(|e| format!("line {}: not an audit entry ({})", idx + 1, e))?;
+        let expected_prev = previous
+            .as_ref()
+            .map(|entry| entry.hash.as_str())
+            .unwrap_or(GENESIS_HASH);
+        let expected_seq = previous.as_ref().map(|entry| entry.record.seq + 1).unwrap_or(1);
+
+        if entry.record.prev_hash != expected_prev || entry.record.seq != expected_seq {
+            return Err(format!(
+                "line {}: chain broken, an entry before it was removed or reordered",
+                idx + 1
+            ));
+        }
+        if record_hash(&entry.record) != entry.hash {
+            return Err(format!("line {}: entry was modified after it was written", idx + 1));
+        }
+
+        previous = Some(entry);
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// `~/.solana-validator-switch/audit.log`
+pub fn audit_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".solana-validator-switch").join("audit.log"))
+}
+
+/// Append an entry under an exclusive file lock, so the monitor and a
+/// concurrent `svs switch` extend the same chain instead of forking it
+pub fn append(path: &Path, actor: &str, action: &str, target: &str, detail: &str) -> Result<AuditEntry> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| anyhow!("Failed to open audit log {}: {}", path.display(), e))?;
+    file.lock()?;
+
+    let len = file.metadata()?.len();
+    let cached = LAST_ENTRY.lock().ok().and_then(|last| match last.as_ref() {
+        Some((last_path, last_len, entry)) if last_path == path && *last_len == len => Some(entry.clone()),
+        _ => None,
+    });
+    let previous = match cached {
+        Some(entry) => Some(entry),
+        None => read_last_entry(&mut file)?,
+    };
+
+    let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let entry = next_entry(previous.as_ref(), &timestamp, actor, action, target, detail);
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+    file.flush()?;
+    if let Ok(mut last) = LAST_ENTRY.lock() {
+        *last = Some((path.to_path_buf(), file.metadata()?.len(), entry.clone()));
+    }
+    Ok(entry)
+}
+
+fn read_last_entry(file: &mut std::fs::File) -> Result<Option<AuditEntry>> {
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    match content.lines().rev().find(|line| !line.trim().is_empty()) {
+        Some(line) => Ok(Some(
+            serde_json::from_str::<AuditEntry>(line)
+                .map_err(|e| anyhow!("Last audit log entry is unreadable: {}", e))?,
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Record an operator action in the shared audit log. Failures are reported
+/// but never stop the action itself.
+pub fn record(actor: &str, action: &str, target: &str, detail: &str) {
+    let path = match audit_path() {
+        Some(path) => path,
+        None => return,
+    };
+    if let Err(e) = append(&path, actor, action, target, &crate::redact::redact_secrets(detail)) {
+        let message = format!("Failed to write audit log: {}", e);
+        match LOG_SENDER.get() {
+            Some(sender) => {
+                let _ = sender.send(LogMessage {
+                    host: "audit".to_string(),
+                    message,
+                    timestamp: Instant::now(),
+                    level: LogLevel::Error,
+                });
+            }
+            None => eprintln!("⚠️  {}", message),
+        }
+    }
+}
+
+/// `svs verify-audit`: check the hash chain of the audit log
+pub fn verify_audit_command(path: Option<&str>) -> Result<()> {
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => audit_path().ok_or_else(|| anyhow!("Could not determine home directory"))?,
+    };
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow!("Failed to read audit log {}: {}", path.display(), e))?;
+
+    match verify(&content) {
+        Ok(count) => {
+            println!("✅ {}: {} entries, hash chain intact", path.display(), count);
+            Ok(())
+        }
+        Err(reason) => Err(anyhow!("Audit log {} failed verification: {}", path.display(), reason)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> Vec<AuditEntry> {
+        let first = next_entry(None, "2026-01-01T00:00:00.000Z", "alice@ops", "switch_started", "Id111", "node-a → node-b");
+        let second = next_entry(Some(&first), "2026-01-01T00:00:05.000Z", "alice@ops", "switch_completed", "Id111", "");
+        let third = next_entry(Some(&second), "2026-01-01T01:00:00.000Z", "telegram @bob", "mute", "all validators", "30 minutes");
+        vec![first, second, third]
+    }
+
+    fn to_log(entries: &[AuditEntry]) -> String {
+        entries
+            .iter()
+            .map(|entry| serde_json::to_string(entry).unwrap() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn test_chain_verifies() {
+        let entries = chain();
+        assert_eq!(entries[0].record.prev_hash, GENESIS_HASH);
+        assert_eq!(entries[2].record.prev_hash, entries[1].hash);
+        assert_eq!(entries[2].record.seq, 3);
+        assert_eq!(verify(&to_log(&entries)), Ok(3));
+        assert_eq!(verify(""), Ok(0));
+    }
+
+    #[test]
+    fn test_tampering_detected() {
+        let mut edited = chain();
+        edited[1].record.actor = "mallory".to_string();
+        assert!(verify(&to_log(&edited)).unwrap_err().starts_with("line 2: entry was modified"));
+
+        let mut removed = chain();
+        removed.remove(1);
+        assert!(verify(&to_log(&removed)).unwrap_err().starts_with("line 2: chain broken"));
+
+        let mut truncated_head = chain();
+        truncated_head.remove(0);
+        assert!(verify(&to_log(&truncated_head)).unwrap_err().starts_with("line 1: chain broken"));
+
+        assert!(verify("not json\n").unwrap_err().starts_with("line 1: not an audit entry"));
+    }
+
+    #[test]
+    fn test_append_extends_chain() {
+        let dir = std::env::temp_dir().join(format!("svs-audit-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("audit.log");
+
+        let first = append(&path, "alice@ops", "switch_started", "Id111", "").unwrap();
+        let second = append(&path, "auto-failover", "emergency_takeover", "Id111", "").unwrap();
+        assert_eq!(second.record.prev_hash, first.hash);
+        assert_eq!(verify(&std::fs::read_to_string(&path).unwrap()), Ok(2));
+
+        // An entry from another process is chained onto, not the cached one
+        let other = next_entry(Some(&second), "2026-01-01T00:00:00.000Z", "bob@ops", "mute", "Id111", "");
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "{}", serde_json::to_string(&other).unwrap()).unwrap();
+        let fourth = append(&path, "alice@ops", "unmute", "Id111", "").unwrap();
+        assert_eq!(fourth.record.prev_hash, other.hash);
+        assert_eq!(verify(&std::fs::read_to_string(&path).unwrap()), Ok(4));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
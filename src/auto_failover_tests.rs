@@ -11,6 +11,7 @@ mod tests {
             rpc_failure_threshold_seconds: 1800,
             telegram: None,
             auto_failover_enabled: true,
+            ..Default::default()
         };
 
         assert!(alert_config.enabled);
@@ -26,6 +27,7 @@ mod tests {
             rpc_failure_threshold_seconds: 1800,
             telegram: None,
             auto_failover_enabled: false,
+            ..Default::default()
         };
 
         assert!(!alert_config.auto_failover_enabled);
@@ -0,0 +1,448 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_yaml::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::config::ConfigManager;
+
+const REDACTED: &str = "<redacted>";
+
+/// Keys whose values are always secrets, regardless of their content
+const SECRET_KEY_MARKERS: &[&str] = &["token", "password", "secret", "api_key", "apikey", "chat_id", "admin_ids"];
+
+/// Export the current configuration, optionally masking secrets and private paths
+pub fn config_export_command(redacted: bool, output: Option<&str>) -> Result<()> {
+    let config_manager = ConfigManager::new()?;
+    let config = config_manager.load()?;
+    let mut value = serde_yaml::to_value(&config)?;
+
+    if redacted {
+        Redactor::new().redact(&mut value, None);
+    }
+
+    let body = serde_yaml::to_string(&value)?;
+    let snapshot = format!("{}{}", export_header(redacted), body);
+
+    match output {
+        Some(path) => {
+            fs::write(path, &snapshot)
+                .map_err(|e| anyhow!("Failed to write config export to {}: {}", path, e))?;
+            println!("{}", format!("✅ Config exported to {}", path).green());
+            if !redacted {
+                println!(
+                    "{}",
+                    "⚠️  Export is NOT redacted - use --redacted before sharing".yellow()
+                );
+            }
+        }
+        None => {
+            print!("{}", snapshot);
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare two config files and print added, removed and changed settings
+pub fn config_diff_command(left: &str, right: &str, redacted: bool) -> Result<()> {
+    let mut left_value = load_yaml(left)?;
+    let mut right_value = load_yaml(right)?;
+
+    if redacted {
+        // A shared redactor keeps host placeholders consistent across both files
+        let mut redactor = Redactor::new();
+        redactor.redact(&mut left_value, None);
+        redactor.redact(&mut right_value, None);
+    }
+
+    let changes = diff_values(&left_value, &right_value);
+
+    println!(
+        "\n{} {} {} {}\n",
+        "🔍 Comparing".bright_cyan().bold(),
+        left.bright_white(),
+        "→".dimmed(),
+        right.bright_white()
+    );
+
+    if changes.is_empty() {
+        println!("{}", "✅ Configs are identical".green());
+        return Ok(());
+    }
+
+    for change in &changes {
+        match change {
+            ConfigChange::Added { path, value } => {
+                println!("{}", format!("  + {}: {}", path, value).green())
+            }
+            ConfigChange::Removed { path, value } => {
+                println!("{}", format!("  - {}: {}", path, value).red())
+            }
+            ConfigChange::Changed { path, from, to } => {
+                println!("{}", format!("  ~ {}: {} → {}", path, from, to).yellow())
+            }
+        }
+    }
+
+    println!("\n{} difference(s) found", changes.len());
+    Ok(())
+}
+
+fn export_header(redacted: bool) -> String {
+    let mut header = String::new();
+    header.push_str("# Solana Validator Switch configuration snapshot\n");
+    header.push_str(&format!(
+        "# Exported by svs v{} at {}\n",
+        env!("CARGO_PKG_VERSION"),
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    if redacted {
+        header.push_str("#\n");
+        header.push_str("# This snapshot is redacted for sharing:\n");
+        header.push_str("#   - tokens, passwords and chat IDs are replaced with <redacted>\n");
+        header.push_str("#   - hosts are replaced with stable placeholders (host-1, host-2, ...)\n");
+        header.push_str("#   - file paths keep only their file name (<redacted>/keypair.json)\n");
+        header.push_str("#   - RPC URLs keep only scheme and host\n");
+    } else {
+        header.push_str("#\n");
+        header.push_str("# WARNING: this snapshot is NOT redacted and may contain secrets\n");
+    }
+    header.push('\n');
+    header
+}
+
+fn load_yaml(path: &str) -> Result<Value> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read config file {}: {}", path, e))?;
+    serde_yaml::from_str(&content).map_err(|e| anyhow!("Failed to parse {}: {}", path, e))
+}
+
+/// Masks secrets and private details in a config value while keeping its structure
+pub(crate) struct Redactor {
+    hosts: HashMap<String, String>,
+}
+
+impl Redactor {
+    pub fn new() -> Self {
+        Self {
+            hosts: HashMap::new(),
+        }
+    }
+
+    pub fn redact(&mut self, value: &mut Value, key: Option<&str>) {
+        match value {
+            // Header names are free-form and their values usually credentials
+            Value::Mapping(map) if key == Some("headers") => {
+                for v in map.values_mut() {
+                    *v = Value::String(REDACTED.to_string());
+                }
+            }
+            Value::Mapping(map) => {
+                for (k, v) in map.iter_mut() {
+                    let child_key = k.as_str().map(|s| s.to_string());
+                    self.redact(v, child_key.as_deref());
+                }
+            }
+            Value::Sequence(seq) => {
+                for item in seq.iter_mut() {
+                    self.redact(item, key);
+                }
+            }
+            Value::String(s) => {
+                if let Some(masked) = self.redact_string(key, s) {
+                    *s = masked;
+                }
+            }
+            Value::Number(_) if key.map(is_secret_key).unwrap_or(false) => {
+                *value = Value::String(REDACTED.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn redact_string(&mut self, key: Option<&str>, value: &str) -> Option<String> {
+        let key = key.unwrap_or("").to_lowercase();
+
+        if is_secret_key(&key) {
+            return Some(REDACTED.to_string());
+        }
+
+        if key == "host" {
+            let next = self.hosts.len() + 1;
+            let placeholder = self
+                .hosts
+                .entry(value.to_string())
+                .or_insert_with(|| format!("host-{}", next));
+            return Some(placeholder.clone());
+        }
+
+        // RPC keys (`rpc`, `additionalRpcs`, `referenceRpc`) and lists of URLs
+        // carry provider API keys in the path or query
+        if key.contains("rpc") || key.ends_with("url") || is_url(value) {
+            return Some(redact_url(value));
+        }
+
+        if value.starts_with('/') || value.starts_with('~') {
+            return Some(redact_path(value));
+        }
+
+        None
+    }
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    // Pubkeys are public and needed to review a config, so never mask them
+    if key.contains("pubkey") {
+        return false;
+    }
+    SECRET_KEY_MARKERS.iter().any(|marker| key.contains(marker))
+}
+
+fn is_url(value: &str) -> bool {
+    url::Url::parse(value)
+        .map(|parsed| parsed.has_host())
+        .unwrap_or(false)
+}
+
+fn redact_path(path: &str) -> String {
+    match Path::new(path).file_name().and_then(|f| f.to_str()) {
+        Some(file_name) => format!("{}/{}", REDACTED, file_name),
+        None => REDACTED.to_string(),
+    }
+}
+
+fn redact_url(raw: &str) -> String {
+    match url::Url::parse(raw) {
+        Ok(parsed) => {
+            let host = parsed.host_str().unwrap_or("");
+            let has_private_parts = !parsed.username().is_empty()
+                || parsed.password().is_some()
+                || parsed.query().is_some()
+                || !parsed.path().trim_matches('/').is_empty();
+            let port = parsed.port().map(|p| format!(":{}", p)).unwrap_or_default();
+            if has_private_parts {
+                format!("{}://{}{}/{}", parsed.scheme(), host, port, REDACTED)
+            } else {
+                format!("{}://{}{}", parsed.scheme(), host, port)
+            }
+        }
+        Err(_) => REDACTED.to_string(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ConfigChange {
+    Added { path: String, value: String },
+    Removed { path: String, value: String },
+    Changed { path: String, from: String, to: String },
+}
+
+/// Flatten both values into dotted paths and compare them leaf by leaf
+pub(crate) fn diff_values(left: &Value, right: &Value) -> Vec<ConfigChange> {
+    let mut left_leaves = BTreeMap::new();
+    let mut right_leaves = BTreeMap::new();
+    flatten(left, String::new(), &mut left_leaves);
+    flatten(right, String::new(), &mut right_leaves);
+
+    let mut changes = Vec::new();
+
+    for (path, left_leaf) in &left_leaves {
+        match right_leaves.get(path) {
+            Some(right_leaf) if right_leaf != left_leaf => changes.push(ConfigChange::Changed {
+                path: path.clone(),
+                from: left_leaf.clone(),
+                to: right_leaf.clone(),
+            }),
+            Some(_) => {}
+            None => changes.push(ConfigChange::Removed {
+                path: path.clone(),
+                value: left_leaf.clone(),
+            }),
+        }
+    }
+
+    for (path, right_leaf) in &right_leaves {
+        if !left_leaves.contains_key(path) {
+            changes.push(ConfigChange::Added {
+                path: path.clone(),
+                value: right_leaf.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn flatten(value: &Value, prefix: String, leaves: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Mapping(map) => {
+            for (k, v) in map {
+                let key = match k {
+                    Value::String(s) => s.clone(),
+                    other => serde_yaml::to_string(other)
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                };
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(v, path, leaves);
+            }
+        }
+        Value::Sequence(seq) => {
+            for (idx, item) in seq.iter().enumerate() {
+                flatten(item, format!("{}[{}]", prefix, idx), leaves);
+            }
+        }
+        Value::Null => {
+            leaves.insert(prefix, "null".to_string());
+        }
+        Value::Bool(b) => {
+            leaves.insert(prefix, b.to_string());
+        }
+        Value::Number(n) => {
+            leaves.insert(prefix, n.to_string());
+        }
+        Value::String(s) => {
+            leaves.insert(prefix, s.clone());
+        }
+        Value::Tagged(tagged) => flatten(&tagged.value, prefix, leaves),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+version: "1.0.0"
+validators:
+  - votePubkey: Vote111
+    identityPubkey: Ident111
+    rpc: https://rpc.example.com/?api-key=abcdef
+    nodes:
+      - label: node-1
+        host: 10.0.0.1
+        port: 22
+        user: solana
+        sshKeyPath: /Users/me/.ssh/id_ed25519
+        paths:
+          fundedIdentity: /home/solana/keys/funded.json
+          unfundedIdentity: /home/solana/keys/unfunded.json
+          voteKeypair: /home/solana/keys/vote.json
+      - label: node-2
+        host: 10.0.0.2
+        port: 22
+        user: solana
+        paths:
+          fundedIdentity: /home/solana/keys/funded.json
+          unfundedIdentity: /home/solana/keys/unfunded.json
+          voteKeypair: /home/solana/keys/vote.json
+alert_config:
+  enabled: true
+  telegram:
+    bot_token: "123456:ABC"
+    chat_id: "-100123"
+rpc_headers:
+  - url: https://rpc.example.com
+    headers:
+      Authorization: Bearer xyz789
+"#;
+
+    #[test]
+    fn test_redaction_masks_secrets_and_keeps_structure() {
+        let mut value: Value = serde_yaml::from_str(SAMPLE).unwrap();
+        Redactor::new().redact(&mut value, None);
+
+        let output = serde_yaml::to_string(&value).unwrap();
+        assert!(!output.contains("123456:ABC"));
+        assert!(!output.contains("-100123"));
+        assert!(!output.contains("10.0.0.1"));
+        assert!(!output.contains("/home/solana"));
+        assert!(!output.contains("api-key"));
+        assert!(!output.contains("xyz789"));
+
+        // Structure and public data stay intact
+        assert!(output.contains("Vote111"));
+        assert!(output.contains("Ident111"));
+        assert!(output.contains("<redacted>/funded.json"));
+        assert!(output.contains("https://rpc.example.com/<redacted>"));
+
+        let config: crate::types::Config = serde_yaml::from_value(value).unwrap();
+        assert_eq!(config.validators[0].nodes[0].host, "host-1");
+        assert_eq!(config.validators[0].nodes[1].host, "host-2");
+        assert_eq!(config.validators[0].nodes[0].user, "solana");
+    }
+
+    #[test]
+    fn test_redaction_masks_every_rpc_url() {
+        let mut value: Value = serde_yaml::from_str(
+            r#"
+validators:
+  - votePubkey: Vote111
+    rpc: https://rpc.example.com/key-1
+    additionalRpcs:
+      - https://backup.example.com/key-2
+      - https://mainnet.example.com/?api-key=key-3
+    referenceRpc: https://ref.example.com/key-4
+    wsUrl: wss://rpc.example.com/key-5
+heartbeat:
+  url: https://hc-ping.com/key-6
+"#,
+        )
+        .unwrap();
+        Redactor::new().redact(&mut value, None);
+
+        let output = serde_yaml::to_string(&value).unwrap();
+        for key in ["key-1", "key-2", "key-3", "key-4", "key-5", "key-6"] {
+            assert!(!output.contains(key), "{} leaked", key);
+        }
+        assert_eq!(
+            value["validators"][0]["additionalRpcs"][0],
+            Value::from("https://backup.example.com/<redacted>")
+        );
+        assert_eq!(value["validators"][0]["votePubkey"], Value::from("Vote111"));
+    }
+
+    #[test]
+    fn test_redaction_host_placeholders_are_stable() {
+        let mut redactor = Redactor::new();
+        let mut a = Value::String("10.0.0.1".to_string());
+        let mut b = Value::String("10.0.0.1".to_string());
+        redactor.redact(&mut a, Some("host"));
+        redactor.redact(&mut b, Some("host"));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed() {
+        let left: Value = serde_yaml::from_str("a: 1\nb: x\nlist: [1, 2]").unwrap();
+        let right: Value = serde_yaml::from_str("a: 2\nc: y\nlist: [1]").unwrap();
+
+        let changes = diff_values(&left, &right);
+        assert!(changes.contains(&ConfigChange::Changed {
+            path: "a".to_string(),
+            from: "1".to_string(),
+            to: "2".to_string(),
+        }));
+        assert!(changes.contains(&ConfigChange::Removed {
+            path: "b".to_string(),
+            value: "x".to_string(),
+        }));
+        assert!(changes.contains(&ConfigChange::Removed {
+            path: "list[1]".to_string(),
+            value: "2".to_string(),
+        }));
+        assert!(changes.contains(&ConfigChange::Added {
+            path: "c".to_string(),
+            value: "y".to_string(),
+        }));
+        assert!(diff_values(&left, &left).is_empty());
+    }
+}
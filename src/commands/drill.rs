@@ -0,0 +1,132 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+
+use crate::alert::{detect_common_cause, failover_decision, AlertManager, FailoverDecision};
+use crate::types::{FailureTracker, NodeHealthStatus, NodeStatus};
+use crate::AppState;
+
+/// Send a simulated delinquency for one validator through the alert pipeline
+/// and run the auto-failover decision against live SSH and RPC health in
+/// shadow mode. Nothing is executed on the nodes beyond a reachability check.
+pub async fn drill_command(app_state: &AppState, validator: usize) -> Result<()> {
+    let validator_idx = validator
+        .checked_sub(1)
+        .ok_or_else(|| anyhow!("Validators are numbered from 1"))?;
+    let validator_status = app_state
+        .validator_statuses
+        .get(validator_idx)
+        .ok_or_else(|| anyhow!("Validator {} is not configured", validator))?;
+    let alert_config = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .ok_or_else(|| anyhow!("No alert configuration found, run `svs test-alert` for an example"))?;
+
+    let pair = &validator_status.validator_pair;
+    println!(
+        "{}",
+        "\n🧪 Delinquency Drill (simulated - the validator is not touched)\n"
+            .bright_blue()
+            .bold()
+    );
+    println!("  • Validator: {}", pair.identity_pubkey);
+
+    let active_node = validator_status
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == NodeStatus::Active)
+        .or_else(|| validator_status.nodes_with_status.first())
+        .ok_or_else(|| anyhow!("Validator {} has no nodes", validator))?;
+    println!("  • Simulated delinquent node: {}", active_node.node.label);
+
+    // Gather the same health inputs the monitor uses for its decision
+    let mut node_health = NodeHealthStatus {
+        ssh_status: FailureTracker::new(),
+        rpc_status: FailureTracker::new(),
+        is_voting: false,
+        last_vote_slot: None,
+        last_vote_time: None,
+    };
+
+    let rpc_url =
+        crate::rpc_selector::best_rpc(&app_state.rpc_selectors, validator_idx, &pair.rpc).await;
+    match crate::solana_rpc::fetch_vote_account_data(&rpc_url, &pair.vote_pubkey).await {
+        Ok(data) => {
+            node_health.rpc_status.record_success();
+            node_health.last_vote_slot = data.recent_votes.last().map(|v| v.slot);
+            println!("  • RPC: {}", "✅ Working".green());
+        }
+        Err(e) => {
+            node_health.rpc_status.record_failure(e.to_string());
+            println!("  • RPC: {}", format!("❌ {}", e).red());
+        }
+    }
+
+    let mut ssh_down = [false; 2];
+    for (node_idx, node) in validator_status.nodes_with_status.iter().take(2).enumerate() {
+        let reachable = match app_state.detected_ssh_keys.get(&node.node.host) {
+            Some(key) => app_state
+                .ssh_pool
+                .execute_command(&node.node, key, "true")
+                .await
+                .map_err(|e| e.to_string()),
+            None => Err("no SSH key detected".to_string()),
+        };
+        match reachable {
+            Ok(_) => println!("  • SSH {}: {}", node.node.label, "✅ Connected".green()),
+            Err(e) => {
+                ssh_down[node_idx] = true;
+                if node.node.label == active_node.node.label {
+                    node_health.ssh_status.record_failure(e.clone());
+                }
+                println!("  • SSH {}: {}", node.node.label, format!("❌ {}", e).red());
+            }
+        }
+    }
+
+    let common_cause = detect_common_cause(ssh_down, [None, None]);
+    let decision = failover_decision(alert_config, common_cause, &node_health);
+    let seconds_since_vote = alert_config.delinquency_threshold_seconds;
+
+    println!("\n{}", "📨 Alert Pipeline:".bright_blue());
+    if !alert_config.enabled {
+        println!("  {}", "⚠️  Alerts are disabled in configuration, nothing was sent".yellow());
+    } else {
+        let alert_manager = AlertManager::new(alert_config.clone());
+        match alert_manager
+            .send_drill_alert(
+                &pair.identity_pubkey,
+                &active_node.node.label,
+                seconds_since_vote,
+                &decision,
+            )
+            .await
+        {
+            Ok(()) => {
+                if alert_config.telegram.is_some() {
+                    println!("  • Telegram: {}", "✅ Drill alert delivered".green());
+                }
+                if alert_config.grafana.is_some() {
+                    println!("  • Grafana: drill annotation pushed");
+                }
+            }
+            Err(e) => println!("  • Telegram: {}", format!("❌ {}", e).red()),
+        }
+    }
+
+    println!("\n{}", "🤖 Auto-failover (shadow mode):".bright_blue());
+    let decision_line = format!("  Would run: {}", decision.shadow_outcome());
+    match decision {
+        FailoverDecision::Trigger => println!("{}", decision_line.bright_green()),
+        FailoverDecision::Suppressed(_) => println!("{}", decision_line.yellow()),
+        FailoverDecision::Disabled => println!("{}", decision_line),
+    }
+
+    println!(
+        "\n{}",
+        "✅ Drill complete - confirm the on-call responders received the alert"
+            .green()
+            .bold()
+    );
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use inquire::Confirm;
+
+use crate::commands::onboard::{
+    check_catchup, check_keypairs, check_standby_ready, check_tower, ChecklistItem,
+};
+use crate::history::SwitchRecord;
+use crate::types::NodeStatus;
+use crate::AppState;
+
+/// Why a failback can't start with the current roles, if anything
+pub fn failback_blocker(
+    record: &SwitchRecord,
+    active: Option<&str>,
+    standby: Option<&str>,
+) -> Option<String> {
+    if active == Some(record.from_node.as_str()) {
+        return Some(format!(
+            "{} is already active, nothing to fail back",
+            record.from_node
+        ));
+    }
+    if active != Some(record.to_node.as_str()) {
+        return Some(format!(
+            "Expected {} to be active, found {}",
+            record.to_node,
+            active.unwrap_or("no active node")
+        ));
+    }
+    if standby != Some(record.from_node.as_str()) {
+        return Some(format!(
+            "{} is not running as standby",
+            record.from_node
+        ));
+    }
+    None
+}
+
+/// Guided switch back to the node that was active before the last switch,
+/// offered only once that node passes its own preflight checks
+pub async fn failback_command(app_state: &mut AppState, validator: usize) -> Result<bool> {
+    let validator_idx = validator
+        .checked_sub(1)
+        .ok_or_else(|| anyhow!("Validators are numbered from 1"))?;
+    let validator_status = app_state
+        .validator_statuses
+        .get(validator_idx)
+        .ok_or_else(|| anyhow!("Validator {} is not configured", validator))?;
+    let identity = validator_status.validator_pair.identity_pubkey.clone();
+
+    println!("{}", "↩️  Failback".bright_cyan().bold());
+    println!("{}", "━".repeat(50).dimmed());
+
+    let record = match crate::history::last_successful_switch(&identity) {
+        Some(record) => record,
+        None => {
+            println!(
+                "{}",
+                "No switch recorded for this validator - nothing to fail back".yellow()
+            );
+            return Ok(false);
+        }
+    };
+
+    let when = chrono::DateTime::from_timestamp(record.timestamp, 0)
+        .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_default();
+    println!(
+        "Last {}: {} → {} ({})\n",
+        if record.emergency { "emergency takeover" } else { "switch" },
+        record.from_node.bright_white(),
+        record.to_node.bright_white(),
+        when
+    );
+
+    let active = validator_status
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == NodeStatus::Active);
+    let standby = validator_status
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == NodeStatus::Standby);
+
+    if let Some(reason) = failback_blocker(
+        &record,
+        active.map(|n| n.node.label.as_str()),
+        standby.map(|n| n.node.label.as_str()),
+    ) {
+        println!("{}", format!("🔒 {}", reason).red());
+        return Ok(false);
+    }
+    let (active, original) = match (active, standby) {
+        (Some(active), Some(standby)) => (active, standby),
+        _ => return Ok(false),
+    };
+
+    let items: Vec<ChecklistItem> = vec![
+        check_standby_ready(original),
+        check_catchup(original),
+        check_keypairs(app_state, &[active, original]).await,
+        check_tower(app_state, active).await,
+    ];
+
+    for item in &items {
+        let mark = if item.passed { "✅" } else { "❌" };
+        println!("{} {}", mark, item.title.bright_white().bold());
+        for detail in &item.details {
+            println!("     • {}", detail);
+        }
+    }
+    println!("{}", "━".repeat(50).dimmed());
+
+    if items.iter().any(|item| !item.passed) {
+        println!(
+            "{}",
+            format!(
+                "🔒 Failback locked - {} is not ready to take over again",
+                original.node.label
+            )
+            .red()
+            .bold()
+        );
+        return Ok(false);
+    }
+
+    let original_label = original.node.label.clone();
+    let proceed = Confirm::new(&format!("Switch back to {} now?", original_label))
+        .with_default(false)
+        .prompt()?;
+    if !proceed {
+        println!("{}", "Failback not started".dimmed());
+        return Ok(false);
+    }
+
+    let show_status = crate::commands::switch::switch_validator_with_progress(
+        false,
+        app_state,
+        validator_idx,
+        false,
+        false,
+        None,
+    )
+    .await?;
+    Ok(show_status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> SwitchRecord {
+        SwitchRecord {
+            timestamp: 0,
+            identity: "Id111".to_string(),
+            from_node: "primary".to_string(),
+            to_node: "backup".to_string(),
+            emergency: true,
+            success: true,
+            duration_ms: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_failback_blocker() {
+        assert_eq!(failback_blocker(&record(), Some("backup"), Some("primary")), None);
+
+        let already = failback_blocker(&record(), Some("primary"), Some("backup")).unwrap();
+        assert!(already.contains("already active"));
+
+        let no_active = failback_blocker(&record(), None, Some("primary")).unwrap();
+        assert!(no_active.contains("found no active node"));
+
+        let not_standby = failback_blocker(&record(), Some("backup"), None).unwrap();
+        assert!(not_standby.contains("primary is not running as standby"));
+    }
+}
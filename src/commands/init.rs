@@ -0,0 +1,267 @@
+use anyhow::Result;
+use colored::*;
+use inquire::{validator::Validation, Confirm, Text};
+use std::io::Write;
+
+use crate::commands::switch::{keypair_probe_command, probe_outcome};
+use crate::config::ConfigManager;
+use crate::ssh::AsyncSshPool;
+use crate::types::{
+    parse_ssh_address, Config, NodeConfig, NodePaths, NodeStatus, ValidatorPair, ValidatorType,
+};
+
+const DEFAULT_RPC: &str = "https://api.mainnet-beta.solana.com";
+
+/// SSH user assumed when the address doesn't name one
+const DEFAULT_SSH_USER: &str = "solana";
+
+/// Keypair paths suggested for a node logged into as `user`
+pub(crate) fn default_keypair_paths(user: &str) -> NodePaths {
+    let home = if user == "root" {
+        "/root".to_string()
+    } else {
+        format!("/home/{}", user)
+    };
+    NodePaths {
+        funded_identity: format!("{}/keypairs/funded-validator-keypair.json", home),
+        unfunded_identity: format!("{}/keypairs/unfunded-validator-keypair.json", home),
+        vote_keypair: format!("{}/keypairs/vote-account-keypair.json", home),
+        ..Default::default()
+    }
+}
+
+/// Node for `label` at `address` (`[user@]host[:port]`); paths are filled in later
+pub(crate) fn node_from_address(label: &str, address: &str) -> Result<NodeConfig> {
+    let mut node = NodeConfig {
+        label: label.to_string(),
+        host: address.trim().to_string(),
+        port: 22,
+        user: DEFAULT_SSH_USER.to_string(),
+        paths: default_keypair_paths(DEFAULT_SSH_USER),
+        ssh_key_path: None,
+        password_auth: false,
+        sudo: None,
+        commands: None,
+    };
+    node.apply_host_address()?;
+    node.paths = default_keypair_paths(&node.user);
+    Ok(node)
+}
+
+fn pubkey_prompt(name: &str, help: &str) -> Result<String> {
+    let required = format!("{} is required", name);
+    let pubkey = Text::new(&format!("{}:", name))
+        .with_help_message(help)
+        .with_validator(move |input: &str| {
+            let input = input.trim();
+            if input.is_empty() {
+                Ok(Validation::Invalid(required.clone().into()))
+            } else if input.len() < 32 || input.len() > 44 {
+                Ok(Validation::Invalid(
+                    "Should be a valid base58 public key (32-44 characters)".into(),
+                ))
+            } else {
+                Ok(Validation::Valid)
+            }
+        })
+        .prompt()?;
+    Ok(pubkey.trim().to_string())
+}
+
+/// Ask for one node's address, SSH key and keypair paths
+async fn prompt_node(index: usize, default_label: &str) -> Result<NodeConfig> {
+    println!("\n{} Node {}", "🖥️ ".bright_cyan(), index + 1);
+
+    let label = Text::new("Label:").with_default(default_label).prompt()?;
+    let address = Text::new("SSH address:")
+        .with_help_message("user@host or user@host:port; the user defaults to solana")
+        .with_validator(|input: &str| match parse_ssh_address(input.trim()) {
+            Ok(_) => Ok(Validation::Valid),
+            Err(e) => Ok(Validation::Invalid(e.to_string().into())),
+        })
+        .prompt()?;
+    let mut node = node_from_address(label.trim(), &address)?;
+
+    print!("  🔑 Detecting SSH key for {}@{}... ", node.user, node.host);
+    std::io::stdout().flush()?;
+    match crate::ssh_key_detector::detect_ssh_key(&node.host, node.port, &node.user).await {
+        Ok(key) => {
+            println!("{}", key.green());
+            node.ssh_key_path = Some(key);
+        }
+        Err(e) => {
+            println!("{}", "not found".yellow());
+            println!("     {}", e.to_string().dimmed());
+            let key = Text::new("SSH key path (empty to use the default key):").prompt()?;
+            if !key.trim().is_empty() {
+                node.ssh_key_path = Some(key.trim().to_string());
+            }
+        }
+    }
+
+    node.paths.funded_identity = Text::new("Funded identity keypair:")
+        .with_default(&node.paths.funded_identity)
+        .prompt()?;
+    node.paths.unfunded_identity = Text::new("Unfunded identity keypair:")
+        .with_default(&node.paths.unfunded_identity)
+        .prompt()?;
+    node.paths.vote_keypair = Text::new("Vote account keypair:")
+        .with_default(&node.paths.vote_keypair)
+        .prompt()?;
+
+    Ok(node)
+}
+
+/// Probe a node with the startup detection and print what was discovered.
+/// Returns whether the node runs the validator's funded identity.
+async fn probe_node(pool: &AsyncSshPool, validator_pair: &ValidatorPair, node: &NodeConfig) -> Result<bool> {
+    println!("\n{} {} ({})", "🔍".bright_cyan(), node.label.bold(), node.host);
+
+    let (status, validator_type, agave, fdctl, solana_cli, version, _, identity, ledger_path, _, _) =
+        crate::startup::detect_node_status_and_executable(node, validator_pair, pool).await?;
+
+    if status == NodeStatus::Unknown && validator_type == ValidatorType::Unknown && identity.is_none() {
+        println!("  {}", "❌ Could not connect or find a running validator".red());
+        return Ok(false);
+    }
+
+    let role = match status {
+        NodeStatus::Active => "active".green(),
+        NodeStatus::Standby => "standby".yellow(),
+        NodeStatus::Unknown => "unknown".red(),
+    };
+    let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "not detected".to_string());
+    println!("  Role:       {}", role);
+    println!("  Type:       {:?} {}", validator_type, show(&version));
+    println!("  Identity:   {}", show(&identity));
+    println!("  Ledger:     {}", show(&ledger_path));
+    println!("  Validator:  {}", show(&agave.or(fdctl)));
+    println!("  Solana CLI: {}", show(&solana_cli));
+
+    let ssh_key = node
+        .ssh_key_path
+        .as_deref()
+        .unwrap_or(crate::startup::DEFAULT_SSH_KEY);
+    for path in [
+        &node.paths.funded_identity,
+        &node.paths.unfunded_identity,
+        &node.paths.vote_keypair,
+    ] {
+        let outcome = pool
+            .execute_command(node, ssh_key, &keypair_probe_command(path))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|output| probe_outcome(&output));
+        match outcome {
+            Ok(()) => println!("  ✅ {}", path),
+            Err(reason) => println!("  ⚠️  {}", reason.yellow()),
+        }
+    }
+
+    Ok(status == NodeStatus::Active)
+}
+
+/// `svs init`: ask for the validator and its two nodes, probe them with the
+/// startup detection and write a ready-to-use config file
+pub async fn init_command() -> Result<()> {
+    let config_manager = ConfigManager::new()?;
+    let path = config_manager.get_config_path().clone();
+
+    println!("{}", "🧰 Configuration Wizard".bright_cyan().bold());
+    println!("{}", "━".repeat(50).dimmed());
+    println!(
+        "{}",
+        "Validator type, ledger path and executables are detected on each node.\n".dimmed()
+    );
+
+    if config_manager.exists()
+        && !Confirm::new(&format!("{} already exists. Overwrite it?", path.display()))
+            .with_default(false)
+            .prompt()?
+    {
+        println!("{}", "Kept the existing configuration.".yellow());
+        return Ok(());
+    }
+
+    let vote_pubkey = pubkey_prompt("Vote Pubkey", "Public key of the vote account")?;
+    let identity_pubkey = pubkey_prompt("Identity Pubkey", "Public key of the funded validator identity")?;
+    let rpc = Text::new("RPC endpoint:").with_default(DEFAULT_RPC).prompt()?;
+
+    let mut nodes = Vec::new();
+    for (index, label) in ["primary", "backup"].iter().enumerate() {
+        nodes.push(prompt_node(index, label).await?);
+    }
+
+    let validator_pair = ValidatorPair {
+        vote_pubkey,
+        identity_pubkey,
+        rpc: rpc.trim().to_string(),
+        additional_rpcs: Vec::new(),
+        ws_url: None,
+        reference_rpc: None,
+        nodes,
+    };
+
+    let pool = AsyncSshPool::new();
+    let mut active_nodes = 0;
+    for node in &validator_pair.nodes {
+        if probe_node(&pool, &validator_pair, node).await? {
+            active_nodes += 1;
+        }
+    }
+    pool.clear_all_sessions().await;
+
+    if active_nodes != 1 {
+        println!(
+            "\n{}",
+            format!(
+                "⚠️  {} node(s) run identity {}; check the identity pubkey before switching",
+                active_nodes, validator_pair.identity_pubkey
+            )
+            .yellow()
+        );
+    }
+
+    let mut config: Config = ConfigManager::create_default();
+    config.validators.push(validator_pair);
+
+    println!("\n{}", serde_yaml::to_string(&config)?.dimmed());
+    if !Confirm::new(&format!("Write this configuration to {}?", path.display()))
+        .with_default(true)
+        .prompt()?
+    {
+        println!("{}", "Nothing was written.".yellow());
+        return Ok(());
+    }
+
+    config_manager.save(&config)?;
+    println!("✅ Configuration written to {}", path.display().to_string().bright_cyan());
+    println!(
+        "{}",
+        "Run `svs` to start; alerts and other options are described in config.example.yaml".dimmed()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_from_address() {
+        let node = node_from_address("backup", "ubuntu@10.0.0.2:2222").unwrap();
+        assert_eq!((node.user.as_str(), node.host.as_str(), node.port), ("ubuntu", "10.0.0.2", 2222));
+        assert_eq!(
+            node.paths.funded_identity,
+            "/home/ubuntu/keypairs/funded-validator-keypair.json"
+        );
+
+        let node = node_from_address("primary", " 10.0.0.1 ").unwrap();
+        assert_eq!((node.user.as_str(), node.host.as_str(), node.port), ("solana", "10.0.0.1", 22));
+
+        let node = node_from_address("primary", "root@10.0.0.1").unwrap();
+        assert_eq!(node.paths.vote_keypair, "/root/keypairs/vote-account-keypair.json");
+
+        assert!(node_from_address("primary", "solana@:22").is_err());
+    }
+}
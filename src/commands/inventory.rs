@@ -0,0 +1,614 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_json::{json, Value as JsonValue};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::config::ConfigManager;
+use crate::types::{Config, NodeConfig, NodePaths, ValidatorPair};
+
+/// Terraform output holding the validator hosts
+const TERRAFORM_OUTPUT: &str = "svs_nodes";
+
+/// Default RPC for validator pairs created from an inventory
+const DEFAULT_RPC: &str = "https://api.mainnet-beta.solana.com";
+
+/// One host read from an Ansible inventory or Terraform output
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryHost {
+    pub label: String,
+    pub host: String,
+    pub port: Option<u16>,
+    pub user: Option<String>,
+    pub vars: BTreeMap<String, String>, // Remaining variables, incl. svs_* keys
+}
+
+impl InventoryHost {
+    fn var(&self, key: &str) -> Option<&str> {
+        self.vars.get(key).map(|v| v.as_str())
+    }
+}
+
+/// What an import did to the config
+#[derive(Debug, Clone, PartialEq)]
+pub enum InventoryChange {
+    Updated { label: String, field: &'static str, from: String, to: String },
+    AddedNode { label: String, identity: String },
+    Skipped { label: String, reason: String },
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn host_from_vars(label: &str, vars: BTreeMap<String, String>, host_keys: &[&str]) -> InventoryHost {
+    let mut vars = vars;
+    let host = host_keys
+        .iter()
+        .find_map(|key| vars.remove(*key))
+        .unwrap_or_else(|| label.to_string());
+    let port = ["ansible_port", "port"]
+        .iter()
+        .find_map(|key| vars.remove(*key))
+        .and_then(|p| p.parse().ok());
+    let user = ["ansible_user", "user"].iter().find_map(|key| vars.remove(*key));
+    InventoryHost {
+        label: label.to_string(),
+        host,
+        port,
+        user,
+        vars,
+    }
+}
+
+fn string_vars(map: Option<&serde_yaml::Mapping>) -> BTreeMap<String, String> {
+    map.map(|map| {
+        map.iter()
+            .filter_map(|(k, v)| Some((k.as_str()?.to_string(), scalar_to_string(v)?)))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Static YAML inventory: groups with `hosts`, `vars` and `children`. Group
+/// variables apply to every host below the group; host variables win.
+pub fn parse_ansible_yaml(content: &str) -> Result<Vec<InventoryHost>> {
+    fn walk(
+        group: &Value,
+        inherited: &BTreeMap<String, String>,
+        hosts: &mut BTreeMap<String, BTreeMap<String, String>>,
+    ) {
+        let mut vars = inherited.clone();
+        vars.extend(string_vars(group.get("vars").and_then(|v| v.as_mapping())));
+
+        if let Some(group_hosts) = group.get("hosts").and_then(|h| h.as_mapping()) {
+            for (name, host_vars) in group_hosts {
+                let name = match name.as_str() {
+                    Some(name) => name.to_string(),
+                    None => continue,
+                };
+                let entry = hosts.entry(name).or_default();
+                entry.extend(vars.clone());
+                entry.extend(string_vars(host_vars.as_mapping()));
+            }
+        }
+
+        if let Some(children) = group.get("children").and_then(|c| c.as_mapping()) {
+            for (_, child) in children {
+                walk(child, &vars, hosts);
+            }
+        }
+    }
+
+    let root: Value =
+        serde_yaml::from_str(content).map_err(|e| anyhow!("Invalid inventory YAML: {}", e))?;
+    let groups = root
+        .as_mapping()
+        .ok_or_else(|| anyhow!("Inventory must be a mapping of groups"))?;
+
+    let mut hosts = BTreeMap::new();
+    for (_, group) in groups {
+        walk(group, &BTreeMap::new(), &mut hosts);
+    }
+
+    Ok(hosts
+        .into_iter()
+        .map(|(label, vars)| host_from_vars(&label, vars, &["ansible_host"]))
+        .collect())
+}
+
+/// Output of `ansible-inventory --list`, which works for INI, YAML and
+/// dynamic inventories alike
+pub fn parse_ansible_json(content: &str) -> Result<Vec<InventoryHost>> {
+    let root: JsonValue =
+        serde_json::from_str(content).map_err(|e| anyhow!("Invalid inventory JSON: {}", e))?;
+    let mut hosts: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+
+    if let Some(groups) = root.as_object() {
+        for (name, group) in groups {
+            if name == "_meta" {
+                continue;
+            }
+            for host in group["hosts"].as_array().into_iter().flatten() {
+                if let Some(host) = host.as_str() {
+                    hosts.entry(host.to_string()).or_default();
+                }
+            }
+        }
+    }
+
+    if let Some(hostvars) = root["_meta"]["hostvars"].as_object() {
+        for (name, vars) in hostvars {
+            let entry = hosts.entry(name.clone()).or_default();
+            for (key, value) in vars.as_object().into_iter().flatten() {
+                let value = match value {
+                    JsonValue::String(s) => s.clone(),
+                    JsonValue::Number(n) => n.to_string(),
+                    JsonValue::Bool(b) => b.to_string(),
+                    _ => continue,
+                };
+                entry.insert(key.clone(), value);
+            }
+        }
+    }
+
+    Ok(hosts
+        .into_iter()
+        .map(|(label, vars)| host_from_vars(&label, vars, &["ansible_host"]))
+        .collect())
+}
+
+/// `terraform output -json`, reading the `svs_nodes` output: a map of label
+/// to an object with `host` (or `ip`/`public_ip`), optional `port`, `user`
+/// and svs_* fields
+pub fn parse_terraform_output(content: &str) -> Result<Vec<InventoryHost>> {
+    let root: JsonValue =
+        serde_json::from_str(content).map_err(|e| anyhow!("Invalid Terraform output JSON: {}", e))?;
+    let nodes = root[TERRAFORM_OUTPUT]["value"].as_object().ok_or_else(|| {
+        anyhow!(
+            "Terraform output has no '{}' map - add an output named {} (see docs)",
+            TERRAFORM_OUTPUT,
+            TERRAFORM_OUTPUT
+        )
+    })?;
+
+    Ok(nodes
+        .iter()
+        .map(|(label, attrs)| {
+            let vars = attrs
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter_map(|(key, value)| {
+                    let value = match value {
+                        JsonValue::String(s) => s.clone(),
+                        JsonValue::Number(n) => n.to_string(),
+                        _ => return None,
+                    };
+                    Some((key.clone(), value))
+                })
+                .collect();
+            host_from_vars(label, vars, &["host", "public_ip", "ip"])
+        })
+        .collect())
+}
+
+/// Parse an inventory file, detecting the format when none is given
+pub fn parse_inventory(content: &str, format: Option<&str>) -> Result<Vec<InventoryHost>> {
+    let format = match format {
+        Some(format) => format.to_string(),
+        None => match serde_json::from_str::<JsonValue>(content) {
+            Ok(json) if json.get(TERRAFORM_OUTPUT).is_some() => "terraform".to_string(),
+            Ok(_) => "ansible-json".to_string(),
+            Err(_) => "ansible".to_string(),
+        },
+    };
+
+    match format.as_str() {
+        "ansible" if content.trim_start().starts_with('{') => parse_ansible_json(content),
+        "ansible" => parse_ansible_yaml(content),
+        "ansible-json" => parse_ansible_json(content),
+        "terraform" => parse_terraform_output(content),
+        other => Err(anyhow!(
+            "Unknown inventory format '{}', use ansible or terraform",
+            other
+        )),
+    }
+}
+
+/// Bring the config in line with the inventory. Nodes are matched by label
+/// and get their host, port and user updated. Hosts carrying
+/// `svs_vote_pubkey`, `svs_identity_pubkey` and the three keypair paths are
+/// added as new nodes; other unknown hosts are skipped.
+pub fn sync_config(config: &mut Config, hosts: &[InventoryHost]) -> Vec<InventoryChange> {
+    let mut changes = Vec::new();
+
+    for host in hosts {
+        let existing = config
+            .validators
+            .iter_mut()
+            .flat_map(|pair| pair.nodes.iter_mut())
+            .find(|node| node.label == host.label);
+
+        if let Some(node) = existing {
+            let mut update = |field: &'static str, current: &mut String, new: String| {
+                if *current != new {
+                    changes.push(InventoryChange::Updated {
+                        label: host.label.clone(),
+                        field,
+                        from: std::mem::replace(current, new.clone()),
+                        to: new,
+                    });
+                }
+            };
+            update("host", &mut node.host, host.host.clone());
+            if let Some(user) = &host.user {
+                update("user", &mut node.user, user.clone());
+            }
+            if let Some(port) = host.port {
+                let mut current = node.port.to_string();
+                update("port", &mut current, port.to_string());
+                node.port = port;
+            }
+            continue;
+        }
+
+        match new_node(host) {
+            Ok((vote_pubkey, identity_pubkey, node)) => {
+                let pair = match config
+                    .validators
+                    .iter_mut()
+                    .find(|pair| pair.vote_pubkey == vote_pubkey)
+                {
+                    Some(pair) => pair,
+                    None => {
+                        config.validators.push(ValidatorPair {
+                            vote_pubkey: vote_pubkey.clone(),
+                            identity_pubkey: identity_pubkey.clone(),
+                            rpc: host.var("svs_rpc").unwrap_or(DEFAULT_RPC).to_string(),
+                            additional_rpcs: Vec::new(),
+                            ws_url: None,
+                            reference_rpc: None,
+                            nodes: Vec::new(),
+                        });
+                        config.validators.last_mut().expect("pair just added")
+                    }
+                };
+                if pair.nodes.len() >= 2 {
+                    changes.push(InventoryChange::Skipped {
+                        label: host.label.clone(),
+                        reason: format!("validator {} already has two nodes", vote_pubkey),
+                    });
+                    continue;
+                }
+                pair.nodes.push(node);
+                changes.push(InventoryChange::AddedNode {
+                    label: host.label.clone(),
+                    identity: identity_pubkey,
+                });
+            }
+            Err(reason) => changes.push(InventoryChange::Skipped {
+                label: host.label.clone(),
+                reason,
+            }),
+        }
+    }
+
+    changes
+}
+
+fn new_node(host: &InventoryHost) -> std::result::Result<(String, String, NodeConfig), String> {
+    let required = [
+        "svs_vote_pubkey",
+        "svs_identity_pubkey",
+        "svs_funded_identity",
+        "svs_unfunded_identity",
+        "svs_vote_keypair",
+    ];
+    let missing: Vec<&str> = required
+        .iter()
+        .filter(|key| host.var(key).is_none())
+        .copied()
+        .collect();
+    if missing.len() == required.len() {
+        return Err("not in config and has no svs_* variables".to_string());
+    }
+    if !missing.is_empty() {
+        return Err(format!("missing {}", missing.join(", ")));
+    }
+
+    let var = |key: &str| host.var(key).unwrap_or_default().to_string();
+    Ok((
+        var("svs_vote_pubkey"),
+        var("svs_identity_pubkey"),
+        NodeConfig {
+            label: host.label.clone(),
+            host: host.host.clone(),
+            port: host.port.unwrap_or(22),
+            user: host.user.clone().unwrap_or_else(|| "solana".to_string()),
+            paths: NodePaths {
+                funded_identity: var("svs_funded_identity"),
+                unfunded_identity: var("svs_unfunded_identity"),
+                vote_keypair: var("svs_vote_keypair"),
+                ..Default::default()
+            },
+            ssh_key_path: host.var("svs_ssh_key_path").map(|s| s.to_string()),
+            password_auth: false,
+            sudo: None,
+            commands: None,
+        },
+    ))
+}
+
+fn node_vars(pair: &ValidatorPair, node: &NodeConfig) -> BTreeMap<&'static str, JsonValue> {
+    BTreeMap::from([
+        ("svs_vote_pubkey", json!(pair.vote_pubkey)),
+        ("svs_identity_pubkey", json!(pair.identity_pubkey)),
+        ("svs_rpc", json!(pair.rpc)),
+        ("svs_funded_identity", json!(node.paths.funded_identity)),
+        ("svs_unfunded_identity", json!(node.paths.unfunded_identity)),
+        ("svs_vote_keypair", json!(node.paths.vote_keypair)),
+    ])
+}
+
+/// Ansible YAML inventory with every node in an `svs_validators` group
+pub fn render_ansible_inventory(config: &Config) -> Result<String> {
+    let mut hosts = serde_json::Map::new();
+    for pair in &config.validators {
+        for node in &pair.nodes {
+            let mut vars: serde_json::Map<String, JsonValue> = node_vars(pair, node)
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+            vars.insert("ansible_host".to_string(), json!(node.host));
+            vars.insert("ansible_port".to_string(), json!(node.port));
+            vars.insert("ansible_user".to_string(), json!(node.user));
+            hosts.insert(node.label.clone(), JsonValue::Object(vars));
+        }
+    }
+    let inventory = json!({ "all": { "children": { "svs_validators": { "hosts": hosts } } } });
+    Ok(serde_yaml::to_string(&inventory)?)
+}
+
+/// Terraform variables file (`.tfvars.json`) with an `svs_nodes` map
+pub fn render_terraform_vars(config: &Config) -> Result<String> {
+    let mut nodes = serde_json::Map::new();
+    for pair in &config.validators {
+        for node in &pair.nodes {
+            let mut attrs: serde_json::Map<String, JsonValue> = node_vars(pair, node)
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+            attrs.insert("host".to_string(), json!(node.host));
+            attrs.insert("port".to_string(), json!(node.port));
+            attrs.insert("user".to_string(), json!(node.user));
+            nodes.insert(node.label.clone(), JsonValue::Object(attrs));
+        }
+    }
+    Ok(serde_json::to_string_pretty(&json!({ TERRAFORM_OUTPUT: nodes }))? + "\n")
+}
+
+/// Read an inventory and show or apply the resulting config changes
+pub fn inventory_import_command(path: &str, format: Option<&str>, write: bool) -> Result<()> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read inventory {}: {}", path, e))?;
+    let hosts = parse_inventory(&content, format)?;
+
+    let config_manager = ConfigManager::new()?;
+    let mut config = if config_manager.exists() {
+        config_manager.load()?
+    } else {
+        ConfigManager::create_default()
+    };
+
+    let changes = sync_config(&mut config, &hosts);
+
+    println!(
+        "\n{} {} ({} hosts)\n",
+        "📥 Inventory".bright_cyan().bold(),
+        path.bright_white(),
+        hosts.len()
+    );
+
+    let mut applied = 0;
+    for change in &changes {
+        match change {
+            InventoryChange::Updated { label, field, from, to } => {
+                applied += 1;
+                println!("{}", format!("  ~ {}.{}: {} → {}", label, field, from, to).yellow())
+            }
+            InventoryChange::AddedNode { label, identity } => {
+                applied += 1;
+                println!("{}", format!("  + {} (validator {})", label, identity).green())
+            }
+            InventoryChange::Skipped { label, reason } => {
+                println!("{}", format!("  · {} skipped: {}", label, reason).dimmed())
+            }
+        }
+    }
+
+    if applied == 0 {
+        println!("{}", "✅ Config already matches the inventory".green());
+        return Ok(());
+    }
+
+    if !write {
+        println!(
+            "\n{} change(s) - run again with --write to update {}",
+            applied,
+            config_manager.get_config_path().display()
+        );
+        return Ok(());
+    }
+
+    let config_path = config_manager.get_config_path();
+    if config_path.exists() {
+        let backup = config_path.with_extension("yaml.bak");
+        fs::copy(config_path, &backup)?;
+        println!("\n💾 Previous config saved to {}", backup.display());
+    }
+    config_manager.save(&config)?;
+    println!(
+        "{}",
+        format!("✅ {} change(s) written to {}", applied, config_path.display()).green()
+    );
+    Ok(())
+}
+
+/// Print the configured nodes as an Ansible inventory or Terraform variables
+pub fn inventory_export_command(format: &str, output: Option<&str>) -> Result<()> {
+    let config = ConfigManager::new()?.load()?;
+    let rendered = match format {
+        "ansible" => render_ansible_inventory(&config)?,
+        "terraform" => render_terraform_vars(&config)?,
+        other => {
+            return Err(anyhow!(
+                "Unknown inventory format '{}', use ansible or terraform",
+                other
+            ))
+        }
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(path, &rendered)
+                .map_err(|e| anyhow!("Failed to write inventory to {}: {}", path, e))?;
+            println!("{}", format!("✅ Inventory written to {}", path).green());
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let mut config = ConfigManager::create_default();
+        config.validators.push(ValidatorPair {
+            vote_pubkey: "Vote111".to_string(),
+            identity_pubkey: "Ident111".to_string(),
+            rpc: DEFAULT_RPC.to_string(),
+            additional_rpcs: Vec::new(),
+            ws_url: None,
+            reference_rpc: None,
+            nodes: vec![NodeConfig {
+                label: "node-a".to_string(),
+                host: "10.0.0.1".to_string(),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/keys/funded.json".to_string(),
+                    unfunded_identity: "/keys/unfunded.json".to_string(),
+                    vote_keypair: "/keys/vote.json".to_string(),
+                    ..Default::default()
+                },
+                ssh_key_path: None,
+                password_auth: false,
+                sudo: None,
+                commands: None,
+            }],
+        });
+        config
+    }
+
+    #[test]
+    fn test_parse_ansible_yaml_applies_group_vars() {
+        let inventory = r#"
+all:
+  vars:
+    ansible_user: sol
+  children:
+    validators:
+      hosts:
+        node-a:
+          ansible_host: 10.0.0.9
+          ansible_port: 2222
+        node-b:
+          ansible_host: 10.0.0.2
+          ansible_user: admin
+"#;
+        let hosts = parse_inventory(inventory, None).unwrap();
+        assert_eq!(hosts.len(), 2);
+        assert_eq!(hosts[0].label, "node-a");
+        assert_eq!(hosts[0].host, "10.0.0.9");
+        assert_eq!(hosts[0].port, Some(2222));
+        assert_eq!(hosts[0].user.as_deref(), Some("sol"));
+        assert_eq!(hosts[1].user.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn test_parse_ansible_json_and_terraform() {
+        let ansible = r#"{"_meta":{"hostvars":{"node-a":{"ansible_host":"10.0.0.9"}}},
+            "validators":{"hosts":["node-a","node-b"]}}"#;
+        let hosts = parse_inventory(ansible, None).unwrap();
+        assert_eq!(hosts.len(), 2);
+        assert_eq!(hosts[0].host, "10.0.0.9");
+        assert_eq!(hosts[1].host, "node-b");
+
+        let terraform = r#"{"svs_nodes":{"sensitive":false,"type":"map","value":
+            {"node-a":{"public_ip":"1.2.3.4","user":"sol","port":22}}}}"#;
+        let hosts = parse_inventory(terraform, None).unwrap();
+        assert_eq!(hosts[0].host, "1.2.3.4");
+        assert_eq!(hosts[0].user.as_deref(), Some("sol"));
+        assert!(parse_inventory("{}", Some("terraform")).is_err());
+    }
+
+    #[test]
+    fn test_sync_config_updates_adds_and_skips() {
+        let mut config = config();
+        let mut new_vars = BTreeMap::new();
+        for (key, value) in [
+            ("svs_vote_pubkey", "Vote111"),
+            ("svs_identity_pubkey", "Ident111"),
+            ("svs_funded_identity", "/keys/funded.json"),
+            ("svs_unfunded_identity", "/keys/unfunded.json"),
+            ("svs_vote_keypair", "/keys/vote.json"),
+        ] {
+            new_vars.insert(key.to_string(), value.to_string());
+        }
+        let hosts = vec![
+            InventoryHost {
+                label: "node-a".to_string(),
+                host: "10.0.0.9".to_string(),
+                port: None,
+                user: Some("solana".to_string()),
+                vars: BTreeMap::new(),
+            },
+            InventoryHost {
+                label: "node-b".to_string(),
+                host: "10.0.0.2".to_string(),
+                port: None,
+                user: None,
+                vars: new_vars,
+            },
+            InventoryHost {
+                label: "monitoring".to_string(),
+                host: "10.0.0.50".to_string(),
+                port: None,
+                user: None,
+                vars: BTreeMap::new(),
+            },
+        ];
+
+        let changes = sync_config(&mut config, &hosts);
+        assert_eq!(changes.len(), 3);
+        assert!(matches!(&changes[0], InventoryChange::Updated { field: "host", to, .. } if to == "10.0.0.9"));
+        assert!(matches!(&changes[1], InventoryChange::AddedNode { label, .. } if label == "node-b"));
+        assert!(matches!(&changes[2], InventoryChange::Skipped { label, .. } if label == "monitoring"));
+        assert_eq!(config.validators.len(), 1);
+        assert_eq!(config.validators[0].nodes[1].user, "solana");
+
+        // Exported inventory round-trips into the same nodes
+        let exported = render_ansible_inventory(&config).unwrap();
+        let hosts = parse_inventory(&exported, None).unwrap();
+        assert!(sync_config(&mut config, &hosts).is_empty());
+    }
+}
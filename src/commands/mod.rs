@@ -1,9 +1,17 @@
+pub mod config;
+pub mod drill;
+pub mod failback;
 pub mod error_handler;
+pub mod init;
+pub mod inventory;
+pub mod onboard;
+pub mod service;
 pub mod status;
 pub mod status_ui_v2;
 pub mod switch;
 pub mod test_alert;
 
+pub use onboard::onboard_command;
 pub use status::status_command;
 pub use switch::switch_command;
 pub use test_alert::test_alert_command;
@@ -0,0 +1,325 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use inquire::Confirm;
+use std::io::Write;
+
+use crate::commands::switch::{keypair_probe_command, probe_outcome};
+use crate::types::{NodeStatus, NodeWithStatus, ValidatorType};
+use crate::AppState;
+
+/// Result of a single onboarding checklist item
+pub(crate) struct ChecklistItem {
+    pub(crate) title: &'static str,
+    pub(crate) explanation: &'static str,
+    pub(crate) passed: bool,
+    pub(crate) details: Vec<String>,
+}
+
+/// Guided first failover: walks through every prerequisite with explanations
+/// and only offers the real switch once all checks pass
+pub async fn onboard_command(app_state: &mut AppState) -> Result<bool> {
+    print!("\x1B[2J\x1B[1;1H");
+    std::io::stdout().flush()?;
+
+    println!("{}", "🧭 Guided First Switch".bright_cyan().bold());
+    println!("{}", "━".repeat(50).dimmed());
+    println!(
+        "{}",
+        "This walkthrough verifies every prerequisite for a safe failover.".dimmed()
+    );
+    println!(
+        "{}",
+        "The real switch is only unlocked once all checks pass.\n".dimmed()
+    );
+
+    let validator_status = app_state
+        .validator_statuses
+        .first()
+        .ok_or_else(|| anyhow!("No validators configured"))?;
+
+    let active = validator_status
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == NodeStatus::Active);
+    let standby = validator_status
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == NodeStatus::Standby);
+
+    let mut items = Vec::new();
+    items.push(check_roles(active, standby));
+
+    if let (Some(active), Some(standby)) = (active, standby) {
+        items.push(check_keypairs(app_state, &[active, standby]).await);
+        items.push(check_tower(app_state, active).await);
+        items.push(check_executables(&[active, standby]));
+        items.push(check_standby_ready(standby));
+        items.push(check_catchup(standby));
+    }
+
+    items.push(check_alerting(app_state));
+
+    let total = items.len();
+    for (idx, item) in items.iter().enumerate() {
+        println!(
+            "{} {}",
+            format!("[{}/{}]", idx + 1, total).dimmed(),
+            item.title.bright_white().bold()
+        );
+        println!("  {}", item.explanation.dimmed());
+
+        if item.passed {
+            println!("  {}", "✅ Passed".green());
+        } else {
+            println!("  {}", "❌ Failed".red());
+        }
+        for detail in &item.details {
+            println!("     • {}", detail);
+        }
+        println!();
+
+        if idx + 1 < total {
+            let proceed = Confirm::new("Continue to the next check?")
+                .with_default(true)
+                .prompt()?;
+            if !proceed {
+                println!("{}", "⏹️  Walkthrough stopped".yellow());
+                return Ok(false);
+            }
+            println!();
+        }
+    }
+
+    let failed: Vec<&ChecklistItem> = items.iter().filter(|i| !i.passed).collect();
+
+    println!("{}", "━".repeat(50).dimmed());
+    if !failed.is_empty() {
+        println!(
+            "{}",
+            format!("🔒 Switch locked - {} of {} checks failed:", failed.len(), total)
+                .red()
+                .bold()
+        );
+        for item in failed {
+            println!("  • {}", item.title);
+        }
+        println!(
+            "\n{}",
+            "Fix the items above and run the guided switch again.".yellow()
+        );
+        return Ok(false);
+    }
+
+    println!(
+        "{}",
+        "🔓 All checks passed - the switch is unlocked".green().bold()
+    );
+
+    let run_switch = Confirm::new("Proceed with the real switch now?")
+        .with_default(false)
+        .prompt()?;
+
+    if !run_switch {
+        println!("{}", "Switch not started".dimmed());
+        return Ok(false);
+    }
+
+    crate::commands::switch_command(false, false, false, 0, app_state).await
+}
+
+fn check_roles(
+    active: Option<&NodeWithStatus>,
+    standby: Option<&NodeWithStatus>,
+) -> ChecklistItem {
+    let mut details = Vec::new();
+    match active {
+        Some(node) => details.push(format!("Active: {} ({})", node.node.label, node.node.host)),
+        None => details.push("No node is currently running the funded identity".to_string()),
+    }
+    match standby {
+        Some(node) => details.push(format!("Standby: {} ({})", node.node.label, node.node.host)),
+        None => details.push("No node is currently running the unfunded identity".to_string()),
+    }
+
+    ChecklistItem {
+        title: "Active and standby nodes detected",
+        explanation: "A switch moves the funded identity from the active node to the standby node, so exactly one of each must be running.",
+        passed: active.is_some() && standby.is_some(),
+        details,
+    }
+}
+
+pub(crate) async fn check_keypairs(app_state: &AppState, nodes: &[&NodeWithStatus]) -> ChecklistItem {
+    let mut details = Vec::new();
+    let mut passed = true;
+
+    for node in nodes {
+        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+            Some(key) => key,
+            None => {
+                passed = false;
+                details.push(format!("{}: no SSH key detected", node.node.label));
+                continue;
+            }
+        };
+
+        let paths = [
+            ("funded identity", &node.node.paths.funded_identity),
+            ("unfunded identity", &node.node.paths.unfunded_identity),
+        ];
+
+        for (name, path) in paths {
+            let outcome = app_state
+                .ssh_pool
+                .execute_command(&node.node, ssh_key, &keypair_probe_command(path))
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|output| probe_outcome(&output));
+
+            match outcome {
+                Ok(()) => details.push(format!("{}: {} valid", node.node.label, name)),
+                Err(reason) => {
+                    passed = false;
+                    details.push(format!("{}: {} - {}", node.node.label, name, reason));
+                }
+            }
+        }
+    }
+
+    ChecklistItem {
+        title: "Identity keypairs present on both nodes",
+        explanation: "Each node needs both the funded and the unfunded identity keypair so it can take over or step down.",
+        passed,
+        details,
+    }
+}
+
+pub(crate) async fn check_tower(app_state: &AppState, active: &NodeWithStatus) -> ChecklistItem {
+    let mut details = Vec::new();
+
+    let passed = match (&active.tower_path, app_state.detected_ssh_keys.get(&active.node.host)) {
+        (Some(tower_path), Some(ssh_key)) => {
+            let command = format!("test -r '{}' && echo ok || echo missing", tower_path);
+            let readable = app_state
+                .ssh_pool
+                .execute_command(&active.node, ssh_key, &command)
+                .await
+                .map(|output| output.trim() == "ok")
+                .unwrap_or(false);
+            if readable {
+                details.push(format!("Tower file found: {}", tower_path));
+            } else {
+                details.push(format!("Tower file not readable: {}", tower_path));
+            }
+            readable
+        }
+        (None, _) => {
+            details.push("Tower path could not be determined from the ledger path".to_string());
+            false
+        }
+        (_, None) => {
+            details.push("No SSH key detected for the active node".to_string());
+            false
+        }
+    };
+
+    ChecklistItem {
+        title: "Tower file available on the active node",
+        explanation: "The tower file records recent votes. It is copied to the standby so the new active node never votes against its own lockouts.",
+        passed,
+        details,
+    }
+}
+
+fn check_executables(nodes: &[&NodeWithStatus]) -> ChecklistItem {
+    let mut details = Vec::new();
+    let mut passed = true;
+
+    for node in nodes {
+        let (name, executable) = match node.validator_type {
+            ValidatorType::Firedancer => ("fdctl", &node.fdctl_executable),
+            ValidatorType::Agave | ValidatorType::Jito => {
+                ("agave-validator", &node.agave_validator_executable)
+            }
+            ValidatorType::Unknown => {
+                passed = false;
+                details.push(format!("{}: validator client not detected", node.node.label));
+                continue;
+            }
+        };
+
+        match executable {
+            Some(path) => details.push(format!("{}: {} at {}", node.node.label, name, path)),
+            None => {
+                passed = false;
+                details.push(format!("{}: {} not found", node.node.label, name));
+            }
+        }
+    }
+
+    ChecklistItem {
+        title: "Validator executables detected",
+        explanation: "The identity is changed with `fdctl set-identity` or `agave-validator set-identity`, so the binary must be found on each node.",
+        passed,
+        details,
+    }
+}
+
+pub(crate) fn check_standby_ready(standby: &NodeWithStatus) -> ChecklistItem {
+    let passed = standby.swap_ready.unwrap_or(false);
+    let details = if passed {
+        vec![format!("{} reports ready for switching", standby.node.label)]
+    } else if standby.swap_issues.is_empty() {
+        vec!["Swap readiness could not be determined".to_string()]
+    } else {
+        standby.swap_issues.clone()
+    };
+
+    ChecklistItem {
+        title: "Standby node ready to receive the switch",
+        explanation: "Checks the standby ledger directory is writable and its keypairs are accessible for the tower copy and identity change.",
+        passed,
+        details,
+    }
+}
+
+pub(crate) fn check_catchup(standby: &NodeWithStatus) -> ChecklistItem {
+    let status = standby.sync_status.clone();
+    let passed = status
+        .as_deref()
+        .map(|s| s.contains("Caught up"))
+        .unwrap_or(false);
+
+    ChecklistItem {
+        title: "Standby node caught up",
+        explanation: "A standby that is behind the cluster would start voting late after the switch and the validator would be delinquent.",
+        passed,
+        details: vec![status.unwrap_or_else(|| "Sync status unknown".to_string())],
+    }
+}
+
+fn check_alerting(app_state: &AppState) -> ChecklistItem {
+    let mut details = Vec::new();
+    let passed = match &app_state.config.alert_config {
+        Some(config) if config.enabled && config.telegram.is_some() => {
+            details.push("Telegram alerts enabled".to_string());
+            details.push("Tip: run `svs test-alert` to confirm messages arrive".to_string());
+            true
+        }
+        Some(config) if config.enabled => {
+            details.push("Alerts enabled but no Telegram configuration".to_string());
+            false
+        }
+        _ => {
+            details.push("Alerts are not configured".to_string());
+            false
+        }
+    };
+
+    ChecklistItem {
+        title: "Alerting configured",
+        explanation: "Switch results and delinquency are reported through alerts, so you will know if the new active node stops voting.",
+        passed,
+        details,
+    }
+}
@@ -0,0 +1,375 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Seconds the service manager waits before restarting a stopped monitor
+const RESTART_DELAY_SECONDS: u32 = 10;
+
+/// Seconds without a watchdog ping before systemd restarts the monitor
+const WATCHDOG_SECONDS: u32 = 120;
+
+/// Everything the generated service definitions need
+#[derive(Debug, Clone)]
+pub struct ServiceSpec {
+    pub name: String,
+    pub executable: String,
+    pub user: String,
+    pub home: String,
+    pub path: String,
+}
+
+impl ServiceSpec {
+    /// Command line the service runs
+    fn exec_args(&self) -> [&str; 2] {
+        [self.executable.as_str(), "watch"]
+    }
+}
+
+/// systemd unit running `svs watch` as `user`, restarted whenever it exits
+pub fn systemd_unit(spec: &ServiceSpec) -> String {
+    format!(
+        "[Unit]
+Description=Solana Validator Switch monitor
+After=network-online.target
+Wants=network-online.target
+StartLimitIntervalSec=0
+
+[Service]
+Type=notify
+User={user}
+WorkingDirectory={home}
+ExecStart={exec}
+Restart=always
+RestartSec={delay}
+WatchdogSec={watchdog}
+Environment=HOME={home}
+Environment=PATH={path}
+Environment=NO_COLOR=1
+
+[Install]
+WantedBy=multi-user.target
+",
+        user = spec.user,
+        home = spec.home,
+        exec = spec.exec_args().join(" "),
+        delay = RESTART_DELAY_SECONDS,
+        watchdog = WATCHDOG_SECONDS,
+        path = spec.path,
+    )
+}
+
+/// launchd agent for the current user, kept alive and started at login
+pub fn launchd_plist(spec: &ServiceSpec) -> String {
+    let args: String = spec
+        .exec_args()
+        .iter()
+        .map(|arg| format!("        <string>{}</string>\n", xml_escape(arg)))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{label}</string>
+    <key>ProgramArguments</key>
+    <array>
+{args}    </array>
+    <key>WorkingDirectory</key>
+    <string>{home}</string>
+    <key>EnvironmentVariables</key>
+    <dict>
+        <key>HOME</key>
+        <string>{home}</string>
+        <key>PATH</key>
+        <string>{path}</string>
+        <key>NO_COLOR</key>
+        <string>1</string>
+    </dict>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>KeepAlive</key>
+    <true/>
+    <key>ThrottleInterval</key>
+    <integer>{delay}</integer>
+    <key>StandardOutPath</key>
+    <string>{home}/.solana-validator-switch/logs/{name}.log</string>
+    <key>StandardErrorPath</key>
+    <string>{home}/.solana-validator-switch/logs/{name}.log</string>
+</dict>
+</plist>
+"#,
+        label = launchd_label(&spec.name),
+        args = args,
+        home = xml_escape(&spec.home),
+        path = xml_escape(&spec.path),
+        delay = RESTART_DELAY_SECONDS,
+        name = spec.name,
+    )
+}
+
+/// Task Scheduler definition started at boot as `user` and restarted on
+/// failure. schtasks reads it as UTF-16, see `install_windows`.
+pub fn task_scheduler_xml(spec: &ServiceSpec) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <RegistrationInfo>
+    <Description>Solana Validator Switch monitor</Description>
+  </RegistrationInfo>
+  <Triggers>
+    <BootTrigger>
+      <Enabled>true</Enabled>
+    </BootTrigger>
+  </Triggers>
+  <Principals>
+    <Principal id="Author">
+      <UserId>{user}</UserId>
+      <LogonType>S4U</LogonType>
+      <RunLevel>LeastPrivilege</RunLevel>
+    </Principal>
+  </Principals>
+  <Settings>
+    <MultipleInstancesPolicy>IgnoreNew</MultipleInstancesPolicy>
+    <DisallowStartIfOnBatteries>false</DisallowStartIfOnBatteries>
+    <StopIfGoingOnBatteries>false</StopIfGoingOnBatteries>
+    <ExecutionTimeLimit>PT0S</ExecutionTimeLimit>
+    <RestartOnFailure>
+      <Interval>PT1M</Interval>
+      <Count>999</Count>
+    </RestartOnFailure>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>{exec}</Command>
+      <Arguments>watch</Arguments>
+      <WorkingDirectory>{home}</WorkingDirectory>
+    </Exec>
+  </Actions>
+</Task>
+"#,
+        user = xml_escape(&spec.user),
+        exec = xml_escape(&spec.executable),
+        home = xml_escape(&spec.home),
+    )
+}
+
+fn launchd_label(name: &str) -> String {
+    format!("com.solana-validator-switch.{}", name)
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// User the service runs as: the one who invoked sudo, else the current user
+fn default_user() -> String {
+    std::env::var("SUDO_USER")
+        .or_else(|_| std::env::var("USER"))
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "root".to_string())
+}
+
+/// Home directory of `user`, where svs finds its config
+fn home_for(user: &str) -> Result<String> {
+    let invoking_user = std::env::var("USER").or_else(|_| std::env::var("USERNAME"));
+    if std::env::var("SUDO_USER").is_err() && invoking_user.as_deref() == Ok(user) {
+        return dirs::home_dir()
+            .map(|home| home.display().to_string())
+            .ok_or_else(|| anyhow!("Could not find home directory"));
+    }
+    if user == "root" {
+        return Ok("/root".to_string());
+    }
+    if cfg!(target_os = "macos") {
+        Ok(format!("/Users/{}", user))
+    } else if cfg!(windows) {
+        Ok(format!(r"C:\Users\{}", user))
+    } else {
+        Ok(format!("/home/{}", user))
+    }
+}
+
+fn build_spec(name: &str, user: Option<String>) -> Result<ServiceSpec> {
+    let user = user.unwrap_or_else(default_user);
+    let home = home_for(&user)?;
+    let executable = std::env::current_exe()?.display().to_string();
+    let path = std::env::var("PATH")
+        .unwrap_or_else(|_| "/usr/local/bin:/usr/bin:/bin".to_string());
+
+    let config = Path::new(&home).join(".solana-validator-switch/config.yaml");
+    if !config.exists() {
+        // stderr, so `--print` output stays a clean unit file
+        eprintln!(
+            "{}",
+            format!(
+                "⚠️  No config at {} - the service will fail until one is created",
+                config.display()
+            )
+            .yellow()
+        );
+    }
+
+    Ok(ServiceSpec {
+        name: name.to_string(),
+        executable,
+        user,
+        home,
+        path,
+    })
+}
+
+/// Generate the service definition for this platform and install it, or
+/// just print it with `print_only`
+pub fn install_service_command(name: &str, user: Option<String>, print_only: bool) -> Result<()> {
+    let spec = build_spec(name, user)?;
+
+    let definition = if cfg!(target_os = "macos") {
+        launchd_plist(&spec)
+    } else if cfg!(windows) {
+        task_scheduler_xml(&spec)
+    } else {
+        systemd_unit(&spec)
+    };
+
+    if print_only {
+        print!("{}", definition);
+        return Ok(());
+    }
+
+    if cfg!(target_os = "macos") {
+        install_launchd(&spec, &definition)
+    } else if cfg!(windows) {
+        install_windows(&spec, &definition)
+    } else {
+        install_systemd(&spec, &definition)
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> Result<()> {
+    let status = Command::new(program).args(args).status()?;
+    if !status.success() {
+        return Err(anyhow!("{} {} failed with {}", program, args.join(" "), status));
+    }
+    Ok(())
+}
+
+fn install_systemd(spec: &ServiceSpec, unit: &str) -> Result<()> {
+    let unit_path = PathBuf::from(format!("/etc/systemd/system/{}.service", spec.name));
+    std::fs::write(&unit_path, unit).map_err(|e| {
+        anyhow!(
+            "Could not write {}: {} (run with sudo, or use --print to install it yourself)",
+            unit_path.display(),
+            e
+        )
+    })?;
+    println!("✅ Wrote {}", unit_path.display());
+
+    run("systemctl", &["daemon-reload"])?;
+    run("systemctl", &["enable", "--now", &spec.name])?;
+
+    println!(
+        "{}",
+        format!("🚀 {} is running as {}", spec.name, spec.user).bright_green()
+    );
+    println!("   Logs: journalctl -u {} -f", spec.name);
+    Ok(())
+}
+
+fn install_launchd(spec: &ServiceSpec, plist: &str) -> Result<()> {
+    let agents = Path::new(&spec.home).join("Library/LaunchAgents");
+    std::fs::create_dir_all(&agents)?;
+    std::fs::create_dir_all(Path::new(&spec.home).join(".solana-validator-switch/logs"))?;
+
+    let plist_path = agents.join(format!("{}.plist", launchd_label(&spec.name)));
+    std::fs::write(&plist_path, plist)?;
+    println!("✅ Wrote {}", plist_path.display());
+
+    let plist_path = plist_path.display().to_string();
+    // Reload if an older definition is already loaded
+    let _ = run("launchctl", &["unload", &plist_path]);
+    run("launchctl", &["load", "-w", &plist_path])?;
+
+    println!(
+        "{}",
+        format!("🚀 {} is running", launchd_label(&spec.name)).bright_green()
+    );
+    println!(
+        "   Logs: {}/.solana-validator-switch/logs/{}.log",
+        spec.home, spec.name
+    );
+    Ok(())
+}
+
+fn install_windows(spec: &ServiceSpec, xml: &str) -> Result<()> {
+    // schtasks expects the XML file in UTF-16 with a byte order mark
+    let xml_path = std::env::temp_dir().join(format!("{}-task.xml", spec.name));
+    let mut bytes = vec![0xFF, 0xFE];
+    bytes.extend(xml.encode_utf16().flat_map(|unit| unit.to_le_bytes()));
+    std::fs::write(&xml_path, bytes)?;
+
+    let xml_path_str = xml_path.display().to_string();
+    let result = run(
+        "schtasks",
+        &["/Create", "/TN", &spec.name, "/XML", &xml_path_str, "/F"],
+    )
+    .and_then(|_| run("schtasks", &["/Run", "/TN", &spec.name]));
+    let _ = std::fs::remove_file(&xml_path);
+    result.map_err(|e| anyhow!("{} (run from an elevated prompt)", e))?;
+
+    println!(
+        "{}",
+        format!("🚀 Scheduled task {} is running as {}", spec.name, spec.user).bright_green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> ServiceSpec {
+        ServiceSpec {
+            name: "svs".to_string(),
+            executable: "/usr/local/bin/svs".to_string(),
+            user: "sol".to_string(),
+            home: "/home/sol".to_string(),
+            path: "/usr/local/bin:/usr/bin:/bin".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_systemd_unit() {
+        let unit = systemd_unit(&spec());
+        assert!(unit.contains("User=sol\n"));
+        assert!(unit.contains("ExecStart=/usr/local/bin/svs watch\n"));
+        assert!(unit.contains("Restart=always\n"));
+        assert!(unit.contains("Type=notify\n"));
+        assert!(unit.contains("WatchdogSec=120\n"));
+        assert!(unit.contains("Environment=HOME=/home/sol\n"));
+        assert!(unit.contains("WantedBy=multi-user.target\n"));
+    }
+
+    #[test]
+    fn test_launchd_plist() {
+        let plist = launchd_plist(&spec());
+        assert!(plist.contains("<string>com.solana-validator-switch.svs</string>"));
+        assert!(plist.contains("<string>/usr/local/bin/svs</string>\n        <string>watch</string>"));
+        assert!(plist.contains("<key>KeepAlive</key>\n    <true/>"));
+    }
+
+    #[test]
+    fn test_task_scheduler_xml_escapes_values() {
+        let mut spec = spec();
+        spec.executable = r"C:\Program Files\svs & co\svs.exe".to_string();
+        let xml = task_scheduler_xml(&spec);
+        assert!(xml.contains(r"<Command>C:\Program Files\svs &amp; co\svs.exe</Command>"));
+        assert!(xml.contains("<Arguments>watch</Arguments>"));
+        assert!(xml.contains("<RestartOnFailure>"));
+    }
+}
@@ -26,6 +26,32 @@ pub async fn status_command(app_state: &AppState) -> Result<()> {
     crate::commands::status_ui_v2::show_enhanced_status_ui(app_state).await
 }
 
+/// Headless monitor for running under a service manager
+pub async fn watch_command(app_state: &AppState) -> Result<()> {
+    if app_state.config.validators.is_empty() {
+        println!(
+            "{}",
+            "⚠️ No validators configured. Run setup first.".yellow()
+        );
+        return Ok(());
+    }
+
+    crate::commands::status_ui_v2::run_headless_monitor(app_state).await
+}
+
+/// Status dashboard for shared NOC displays
+pub async fn kiosk_command(app_state: &AppState) -> Result<()> {
+    if app_state.config.validators.is_empty() {
+        println!(
+            "{}",
+            "⚠️ No validators configured. Run setup first.".yellow()
+        );
+        return Ok(());
+    }
+
+    crate::commands::status_ui_v2::show_kiosk_status_ui(app_state).await
+}
+
 #[allow(dead_code)]
 async fn show_comprehensive_status(app_state: &AppState) -> Result<()> {
     println!("\n{}", "📋 Validator Status".bright_cyan().bold());
@@ -429,15 +455,13 @@ fn display_simple_status_table_with_rpc(
     let node_0_swap = validator_status
         .nodes_with_status
         .get(0)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
+        .map(|n| n.swap_readiness_label())
+        .unwrap_or_else(|| "❓ Unknown".to_string());
     let node_1_swap = validator_status
         .nodes_with_status
         .get(1)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
+        .map(|n| n.swap_readiness_label())
+        .unwrap_or_else(|| "❓ Unknown".to_string());
 
     table.add_row(vec![
         Cell::new("Swap Ready")
@@ -1296,15 +1320,13 @@ fn display_simple_status_table(
     let node_0_swap = validator_status
         .nodes_with_status
         .get(0)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
+        .map(|n| n.swap_readiness_label())
+        .unwrap_or_else(|| "❓ Unknown".to_string());
     let node_1_swap = validator_status
         .nodes_with_status
         .get(1)
-        .and_then(|n| n.swap_ready)
-        .map(|ready| if ready { "✅ Ready" } else { "❌ Not Ready" })
-        .unwrap_or("❓ Unknown");
+        .map(|n| n.swap_readiness_label())
+        .unwrap_or_else(|| "❓ Unknown".to_string());
 
     table.add_row(vec![
         Cell::new("Swap Ready")
@@ -8,7 +8,7 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Terminal,
 };
@@ -18,16 +18,83 @@ use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tokio::time::interval;
 
-use crate::alert::{AlertManager, ComprehensiveAlertTracker};
-use crate::solana_rpc::{fetch_vote_account_data, ValidatorVoteData};
+use crate::host_checks::{
+    check_ledger_size, check_os_patch_status, check_process_uptime, check_storage_status,
+    check_system_metrics, check_time_sync, ledger_growth, LedgerGrowth, OsPatchStatus, ProcessUptime,
+    StorageStatus, SystemMetrics, TimeSync,
+};
+use crate::alert::{
+    condition_key, count_no_vote_confirmations, detect_common_cause, failover_decision,
+    required_quorum, send_heartbeat, skip_rate_breach, vote_credits_breach, AlertHistory,
+    AlertManager, AlertMutes, AlertTracker, CommonCause, ComprehensiveAlertTracker,
+    DigestCounters, DigestEntry, FailoverDecision, RewardsEntry,
+};
+use crate::log_tail::LogTail;
+use crate::rpc_selector::{best_rpc, probe_all};
+use crate::solana_rpc::{
+    fetch_balance, fetch_block_production, fetch_cluster_health, fetch_epoch_info,
+    fetch_epoch_rewards, fetch_recent_leader_slots, fetch_rent_exempt_minimum, fetch_stake_summary,
+    fetch_upcoming_leader_slots, fetch_vote_account_data, fetch_vote_credits,
+    fetch_vote_landing_stats, lamports_to_sol, leader_windows, EpochReward, LeaderSlotOutcome,
+    StakeSummary, ValidatorVoteData, VoteAccountBalance, VoteCredits, VoteLandingStats,
+    VOTE_ACCOUNT_SIZE,
+};
+use solana_sdk::epoch_info::EpochInfo;
 use crate::types::{FailureTracker, NodeHealthStatus};
+use crate::warnings::{collect_warnings, StickyWarnings};
 use crate::{ssh::AsyncSshPool, AppState};
 
+/// Completed epochs of vote account rewards kept for display (about a week)
+const REWARD_EPOCHS: u64 = 4;
+
+/// Recent leader slots shown per validator (four leader rotations)
+const RECENT_LEADER_SLOTS: usize = 16;
+
+/// How often node roles are re-read so switches done outside svs show up
+const ROLE_RECONCILE_SECONDS: u64 = 60;
+
+/// How often the SSH health task wakes up to check nodes that are due
+const SSH_HEALTH_TICK: Duration = Duration::from_secs(5);
+
+/// SSH health check interval for reachable nodes, and the first retry delay
+const SSH_HEALTH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Longest wait between retries of a node whose SSH keeps failing
+const SSH_RETRY_MAX_INTERVAL: Duration = Duration::from_secs(300);
+
+/// SSH health checks in flight at once across all nodes
+const SSH_HEALTH_CONCURRENCY: usize = 16;
+
 /// View states for the UI
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewState {
     Status,
     Switch,
+    Diagnostics,
+    Leaders,
+    Logs,
+}
+
+/// Upcoming leader slots as of a fetch; the countdown advances from there
+/// at the nominal slot time until the next fetch
+#[derive(Debug, Clone)]
+pub struct LeaderSchedule {
+    pub current_slot: u64,
+    pub fetched_at: Instant,
+    pub slots: Vec<u64>, // Ascending, through the end of the next epoch
+}
+
+impl LeaderSchedule {
+    fn estimated_slot(&self) -> u64 {
+        self.current_slot
+            + self.fetched_at.elapsed().as_millis() as u64 / crate::commands::switch::SLOT_DURATION_MS
+    }
+
+    /// Slots until the next leader slot that hasn't passed yet
+    fn next_leader_in(&self) -> Option<u64> {
+        let now = self.estimated_slot();
+        self.slots.iter().find(|slot| **slot >= now).map(|slot| slot - now)
+    }
 }
 
 /// Enhanced UI App state with async support
@@ -52,6 +119,38 @@ pub struct UiState {
     // Track when each validator's last vote slot changed
     pub last_vote_slot_times: Vec<Option<(u64, Instant)>>, // (slot, time when slot last changed)
 
+    // Vote landing rate sampled from recent vote transactions
+    pub vote_landing_stats: Vec<Option<VoteLandingStats>>,
+
+    // Inflation rewards credited to each vote account, oldest epoch first
+    pub epoch_rewards: Vec<Vec<EpochReward>>,
+
+    // Position in the current epoch, as seen by each validator's RPC
+    pub epoch_info: Vec<Option<EpochInfo>>,
+
+    // Leader slots and blocks produced by each validator this epoch
+    pub block_production: Vec<Option<(u64, u64)>>,
+    // Most recent finalized leader slots, oldest first
+    pub recent_leader_slots: Vec<Option<Vec<LeaderSlotOutcome>>>,
+
+    // Vote credits earned this epoch against the cluster's best
+    pub vote_credits: Vec<Option<VoteCredits>>,
+
+    // Funded identity balance of each validator in lamports
+    pub identity_balances: Vec<Option<u64>>,
+
+    // Vote account balance of each validator next to its rent exemption minimum
+    pub vote_account_balances: Vec<Option<VoteAccountBalance>>,
+
+    // Activated and pending stake of each vote account
+    pub stake: Vec<Option<StakeSummary>>,
+
+    // Upcoming leader slots of each validator identity
+    pub leader_schedules: Vec<Option<LeaderSchedule>>,
+
+    // Gossip version and activated stake of every voting node in the cluster
+    pub cluster_version_stake: Option<Vec<(String, u64)>>,
+
     // Catchup status for each node
     pub catchup_data: Vec<NodePairStatus>,
 
@@ -65,6 +164,44 @@ pub struct UiState {
 
     // SSH health status for each node
     pub ssh_health_data: Vec<NodePairSshStatus>,
+    // Wakes the SSH health task to retry failing nodes now
+    pub ssh_retry: Arc<tokio::sync::Notify>,
+
+    // Host-level checks (OS patches, ...) for each node
+    pub host_checks: Vec<NodePairHostChecks>,
+
+    // SSH pool internals for the diagnostics view
+    pub ssh_pool_diagnostics: Vec<crate::ssh::HostPoolDiagnostics>,
+
+    // Alert silences set from Telegram /mute
+    pub alert_mutes: AlertMutes,
+
+    // Delivered alerts and conditions acknowledged from Telegram
+    pub alert_history: AlertHistory,
+
+    // Event counts for the daily digest, per validator
+    pub digest_counters: Vec<DigestCounters>,
+    pub started_at: Instant,
+
+    // Kiosk mode: masked details, view navigation only
+    pub kiosk: bool,
+
+    // Validator pair chosen in the switch view
+    pub switch_selection: usize,
+    // Phrase typed so far when switch.typed_confirmation is on
+    pub confirm_input: String,
+
+    // Node chosen in the log view, the log streamed from it and its task
+    pub log_selection: usize,
+    pub log_tail: Option<LogTail>,
+    pub log_tail_task: Option<tokio::task::JoinHandle<()>>,
+
+    // Operator holding the switch lock per validator, when someone else is switching
+    pub switch_locks: Vec<Option<String>>,
+
+    // Unresolved warnings panel in the status view
+    pub sticky_warnings: StickyWarnings,
+    pub warnings_collapsed: bool,
 
     // Comprehensive health tracking for each validator
     pub validator_health: Vec<NodeHealthStatus>,
@@ -125,6 +262,8 @@ pub struct NodePairStatus {
 #[derive(Clone)]
 pub struct CatchupStatus {
     pub status: String,
+    // Last measured lag, 0 once caught up; kept while a refresh is running
+    pub slots_behind: Option<u64>,
     #[allow(dead_code)]
     pub last_updated: Instant,
     pub is_streaming: bool,
@@ -136,6 +275,22 @@ pub struct NodePairSshStatus {
     pub node_1: SshHealthStatus,
 }
 
+#[derive(Clone, Default)]
+pub struct NodePairHostChecks {
+    pub node_0: HostCheckStatus,
+    pub node_1: HostCheckStatus,
+}
+
+#[derive(Clone, Default)]
+pub struct HostCheckStatus {
+    pub os_patch: Option<OsPatchStatus>,
+    pub storage: Option<StorageStatus>,
+    pub ledger_growth: Option<LedgerGrowth>,
+    pub system: Option<SystemMetrics>,
+    pub process: Option<ProcessUptime>,
+    pub time: Option<TimeSync>,
+}
+
 #[derive(Clone)]
 pub struct SshHealthStatus {
     pub is_healthy: bool,
@@ -159,12 +314,24 @@ pub enum LogLevel {
     Error,
 }
 
+impl LogLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warning => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
 impl EnhancedStatusApp {
     pub async fn new(app_state: Arc<AppState>) -> Result<Self> {
         let ssh_pool = Arc::clone(&app_state.ssh_pool);
 
-        // Create unbounded channel for log messages
-        let (log_sender, _log_receiver) = tokio::sync::mpsc::unbounded_channel();
+        // Create unbounded channel for log messages, kept in the JSON log
+        // file and/or syslog when configured
+        let (log_sender, log_receiver) = tokio::sync::mpsc::unbounded_channel();
+        spawn_log_sinks(app_state.config.log_file.as_ref(), log_receiver, log_sender.downgrade())?;
 
         // Initialize UI state
         let mut initial_vote_data = Vec::new();
@@ -186,6 +353,7 @@ impl EnhancedStatusApp {
                     || validator_status.nodes_with_status[0].validator_type == crate::types::ValidatorType::Firedancer {
                     node_pair.node_0 = Some(CatchupStatus {
                         status: "⏳ Initializing...".to_string(),
+                        slots_behind: None,
                         last_updated: Instant::now(),
                         is_streaming: false,
                     });
@@ -194,6 +362,7 @@ impl EnhancedStatusApp {
                     || validator_status.nodes_with_status[1].validator_type == crate::types::ValidatorType::Firedancer {
                     node_pair.node_1 = Some(CatchupStatus {
                         status: "⏳ Initializing...".to_string(),
+                        slots_behind: None,
                         last_updated: Instant::now(),
                         is_streaming: false,
                     });
@@ -220,15 +389,38 @@ impl EnhancedStatusApp {
         // Initialize health tracking
         let mut initial_validator_health = Vec::new();
         let mut initial_rpc_trackers = Vec::new();
-        for _ in 0..app_state.validator_statuses.len() {
-            initial_validator_health.push(NodeHealthStatus {
+        for validator_status in &app_state.validator_statuses {
+            let mut health = NodeHealthStatus {
                 ssh_status: FailureTracker::new(),
                 rpc_status: FailureTracker::new(),
                 is_voting: true,
                 last_vote_slot: None,
                 last_vote_time: None,
-            });
-            initial_rpc_trackers.push(FailureTracker::new());
+            };
+            let mut rpc_tracker = FailureTracker::new();
+
+            // Outages still open when svs last stopped keep counting from
+            // when they began, so a restart doesn't reset alert thresholds
+            if let Some(store) = crate::history::store() {
+                let identity = &validator_status.validator_pair.identity_pubkey;
+                let node_0 = validator_status
+                    .nodes_with_status
+                    .first()
+                    .map(|n| n.node.label.as_str())
+                    .unwrap_or("");
+                let age = |since: i64| {
+                    Duration::from_secs((chrono::Utc::now().timestamp() - since).max(0) as u64)
+                };
+                if let Ok(Some(since)) = store.open_failure_since("ssh", identity, node_0) {
+                    health.ssh_status.resume_failure(age(since));
+                }
+                if let Ok(Some(since)) = store.open_failure_since("rpc", identity, "") {
+                    rpc_tracker.resume_failure(age(since));
+                }
+            }
+
+            initial_validator_health.push(health);
+            initial_rpc_trackers.push(rpc_tracker);
         }
 
         // Initialize field refresh states
@@ -244,10 +436,37 @@ impl EnhancedStatusApp {
             previous_last_slots: Vec::new(),
             increment_times: Vec::new(),
             last_vote_slot_times: vec![None; app_state.validator_statuses.len()],
+            vote_landing_stats: vec![None; app_state.validator_statuses.len()],
+            epoch_rewards: vec![Vec::new(); app_state.validator_statuses.len()],
+            epoch_info: vec![None; app_state.validator_statuses.len()],
+            block_production: vec![None; app_state.validator_statuses.len()],
+            recent_leader_slots: vec![None; app_state.validator_statuses.len()],
+            vote_credits: vec![None; app_state.validator_statuses.len()],
+            identity_balances: vec![None; app_state.validator_statuses.len()],
+            vote_account_balances: vec![None; app_state.validator_statuses.len()],
+            stake: vec![None; app_state.validator_statuses.len()],
+            leader_schedules: vec![None; app_state.validator_statuses.len()],
+            cluster_version_stake: None,
             catchup_data: initial_catchup_data,
             catchup_failure_counts: vec![(0, 0); app_state.validator_statuses.len()],
             last_catchup_alert_times: vec![(None, None); app_state.validator_statuses.len()],
             ssh_health_data: initial_ssh_health_data,
+            ssh_retry: Arc::new(tokio::sync::Notify::new()),
+            host_checks: vec![NodePairHostChecks::default(); app_state.validator_statuses.len()],
+            ssh_pool_diagnostics: Vec::new(),
+            alert_mutes: AlertMutes::default(),
+            alert_history: AlertHistory::default(),
+            digest_counters: vec![DigestCounters::default(); app_state.validator_statuses.len()],
+            started_at: Instant::now(),
+            kiosk: false,
+            switch_selection: 0,
+            confirm_input: String::new(),
+            log_selection: 0,
+            log_tail: None,
+            log_tail_task: None,
+            switch_locks: vec![None; app_state.validator_statuses.len()],
+            sticky_warnings: StickyWarnings::default(),
+            warnings_collapsed: false,
             validator_health: initial_validator_health,
             rpc_failure_tracker: initial_rpc_trackers,
             last_vote_refresh: Instant::now(),
@@ -270,12 +489,14 @@ impl EnhancedStatusApp {
         })
     }
     
-    /// Spawn continuous catchup streaming tasks for each node
+    /// Spawn continuous catchup streaming tasks for each node, or slot
+    /// polling tasks when catchup is measured over RPC
     fn spawn_catchup_streaming_tasks(&self) {
         let ui_state = Arc::clone(&self.ui_state);
         let app_state = Arc::clone(&self.app_state);
         let ssh_pool = Arc::clone(&self.ssh_pool);
         let log_sender = self.log_sender.clone();
+        let catchup_config = app_state.config.catchup.clone();
         
         // Spawn a streaming task for each node
         for (validator_idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
@@ -285,6 +506,24 @@ impl EnhancedStatusApp {
                 let ssh_pool = Arc::clone(&ssh_pool);
                 let log_sender = log_sender.clone();
                 let ssh_key = app_state.detected_ssh_keys.get(&node.node.host).cloned();
+
+                if let (Some(ssh_key), Some(config)) = (ssh_key.clone(), catchup_config.as_ref()) {
+                    if config.method == crate::types::CatchupMethod::Rpc {
+                        let app_state = Arc::clone(&app_state);
+                        tokio::spawn(async move {
+                            poll_rpc_catchup_for_node(
+                                ssh_pool,
+                                app_state,
+                                ssh_key,
+                                ui_state,
+                                validator_idx,
+                                node_idx,
+                                log_sender,
+                            ).await;
+                        });
+                        continue;
+                    }
+                }
                 
                 if let Some(ssh_key) = ssh_key {
                     tokio::spawn(async move {
@@ -303,11 +542,201 @@ impl EnhancedStatusApp {
         }
     }
 
+    /// Keep a WebSocket subscription to each vote account open that wakes
+    /// the vote refresh loop on every update. Polling continues regardless,
+    /// so a failed subscription only costs latency.
+    fn spawn_vote_subscription_tasks(&self, wakeup: Arc<tokio::sync::Notify>) {
+        let enabled = self
+            .app_state
+            .config
+            .vote_subscription
+            .as_ref()
+            .map(|config| config.enabled)
+            .unwrap_or(false);
+        if !enabled {
+            return;
+        }
+
+        for validator_status in &self.app_state.validator_statuses {
+            let validator_pair = &validator_status.validator_pair;
+            let ws_url = match validator_pair
+                .ws_url
+                .clone()
+                .or_else(|| crate::vote_subscription::websocket_url(&validator_pair.rpc))
+            {
+                Some(url) => url,
+                None => continue,
+            };
+            let vote_pubkey = validator_pair.vote_pubkey.clone();
+            let wakeup = Arc::clone(&wakeup);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                let mut attempt = 0;
+                loop {
+                    let connected_at = Instant::now();
+                    if let Err(e) =
+                        crate::vote_subscription::watch_vote_account(&ws_url, &vote_pubkey, &wakeup).await
+                    {
+                        let _ = log_sender.send(LogMessage {
+                            host: "websocket".to_string(),
+                            message: format!("Vote subscription unavailable, polling every 5s: {}", e),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Warning,
+                        });
+                    }
+                    // A subscription that stayed up a while starts the backoff over
+                    if connected_at.elapsed() > Duration::from_secs(60) {
+                        attempt = 0;
+                    }
+                    tokio::time::sleep(crate::vote_subscription::reconnect_delay(attempt)).await;
+                    attempt += 1;
+                }
+            });
+        }
+    }
+
+    /// Follow each node's validator log over SSH and alert on lines matching
+    /// a configured pattern, at most once per cooldown per node and pattern.
+    /// Only new lines are matched; a dropped stream reconnects with backoff.
+    fn spawn_log_pattern_tasks(&self) {
+        let config = match self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .and_then(|config| config.log_patterns.clone())
+        {
+            Some(config) if !config.patterns.is_empty() => config,
+            _ => return,
+        };
+        let patterns = match crate::log_tail::compile_patterns(&config.patterns) {
+            Ok(patterns) => Arc::new(patterns),
+            Err(e) => {
+                let _ = self.log_sender.send(LogMessage {
+                    host: "logs".to_string(),
+                    message: format!("Log pattern alerts disabled: {}", e),
+                    timestamp: Instant::now(),
+                    level: LogLevel::Error,
+                });
+                return;
+            }
+        };
+        let cooldown = Duration::from_secs(config.cooldown_minutes * 60);
+        let alert_manager = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .filter(|config| config.enabled)
+            .map(|config| AlertManager::new(config.clone()));
+
+        for (validator_idx, validator_status) in self.app_state.validator_statuses.iter().enumerate() {
+            let identity = validator_status.validator_pair.identity_pubkey.clone();
+            for node in validator_status.nodes_with_status.iter().take(2) {
+                let node = node.node.clone();
+                let ssh_key = match self.app_state.detected_ssh_keys.get(&node.host) {
+                    Some(key) => key.clone(),
+                    None => continue,
+                };
+                let ui_state = Arc::clone(&self.ui_state);
+                let ssh_pool = Arc::clone(&self.ssh_pool);
+                let log_sender = self.log_sender.clone();
+                let patterns = Arc::clone(&patterns);
+                let alert_manager = alert_manager.clone();
+                let identity = identity.clone();
+
+                tokio::spawn(async move {
+                    // When each pattern last alerted for this node
+                    let mut last_alert: std::collections::HashMap<String, Instant> =
+                        std::collections::HashMap::new();
+                    let command = crate::log_tail::tail_command(&node, 0);
+                    let mut failures = 0;
+                    loop {
+                        let (tx, mut rx) = tokio::sync::mpsc::channel(1024);
+                        let stream = ssh_pool.execute_command_streaming(&node, &ssh_key, &command, tx);
+                        let receive = async {
+                            let mut received = false;
+                            while let Some(line) = rx.recv().await {
+                                received = true;
+                                let pattern = match crate::log_tail::matching_pattern(&patterns, &line) {
+                                    Some(pattern) => pattern.as_str(),
+                                    None => continue,
+                                };
+                                let snippet = crate::log_tail::snippet(&line);
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.label.clone(),
+                                    message: format!("Log matched '{}': {}", pattern, snippet),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+
+                                if last_alert.get(pattern).is_some_and(|sent| sent.elapsed() < cooldown) {
+                                    continue;
+                                }
+                                last_alert.insert(pattern.to_string(), Instant::now());
+                                if ui_state.read().await.alert_mutes.is_muted(validator_idx) {
+                                    continue;
+                                }
+                                if let Some(alert_mgr) = alert_manager.as_ref() {
+                                    if alert_mgr
+                                        .send_log_pattern_alert(&identity, &node.label, pattern, &snippet)
+                                        .await
+                                        .is_ok()
+                                    {
+                                        crate::history::record_alert("Log Pattern", &identity);
+                                    }
+                                }
+                            }
+                            received
+                        };
+                        let (result, received) = tokio::join!(stream, receive);
+
+                        if let Err(e) = result {
+                            let _ = log_sender.send(LogMessage {
+                                host: node.label.clone(),
+                                message: format!("Log pattern watch interrupted: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                        // A stream that delivered lines starts the backoff over
+                        failures = if received { 1 } else { failures + 1 };
+                        tokio::time::sleep(crate::ssh::backoff_delay(
+                            Duration::from_secs(5),
+                            Duration::from_secs(300),
+                            failures,
+                            crate::ssh::jitter(),
+                        ))
+                        .await;
+                    }
+                });
+            }
+        }
+    }
+
     /// Spawn background tasks for data fetching
     pub fn spawn_background_tasks(&self) {
+        crate::audit::set_log_sender(self.log_sender.clone());
+
         // Spawn continuous catchup streaming tasks for each node
         self.spawn_catchup_streaming_tasks();
-        
+
+        // Follow each node's validator log for the configured alert patterns
+        self.spawn_log_pattern_tasks();
+
+        // Vote account updates pushed over WebSocket wake the refresh early
+        let vote_wakeup = Arc::new(tokio::sync::Notify::new());
+        self.spawn_vote_subscription_tasks(Arc::clone(&vote_wakeup));
+        let min_wakeup_gap = Duration::from_millis(
+            self.app_state
+                .config
+                .vote_subscription
+                .as_ref()
+                .map(|config| config.min_interval_ms)
+                .unwrap_or(1000),
+        );
+
         // Vote data refresh task
         let ui_state = Arc::clone(&self.ui_state);
         let app_state = Arc::clone(&self.app_state);
@@ -316,6 +745,7 @@ impl EnhancedStatusApp {
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(5));
+            let mut last_pass = Instant::now();
 
             // Initialize alert manager and tracker if alerts are configured
             let alert_manager = app_state
@@ -331,23 +761,240 @@ impl EnhancedStatusApp {
                 nodes_per_validator
             );
 
+            // Commission last seen per vote account; the first read is the baseline
+            let mut known_commission: Vec<Option<u8>> =
+                vec![None; app_state.validator_statuses.len()];
+
+            // Dead-man's switch - pinged from this loop so the pings stop if
+            // monitoring stalls, the process dies or the machine loses network
+            let heartbeat = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.heartbeat.clone());
+            let mut last_heartbeat: Option<Instant> = None;
+
+            // Validators currently past the delinquency threshold, so each
+            // episode is counted once in the daily digest
+            let mut delinquent = vec![false; app_state.validator_statuses.len()];
+
+            let local_rpc_fallback = app_state
+                .config
+                .local_rpc
+                .as_ref()
+                .map(|config| config.fallback)
+                .unwrap_or(true);
+            let local_rpc_port = app_state.config.local_rpc.as_ref().and_then(|config| config.port);
+
+            let mut balances = BalanceMonitor::new(&app_state);
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = vote_wakeup.notified() => {
+                        // Votes land every few hundred ms; don't poll RPC that often
+                        let since = last_pass.elapsed();
+                        if since < min_wakeup_gap {
+                            tokio::time::sleep(min_wakeup_gap - since).await;
+                        }
+                    }
+                }
+                last_pass = Instant::now();
+
+                if let Some(heartbeat) = heartbeat.as_ref() {
+                    let due = last_heartbeat
+                        .map(|t| t.elapsed().as_secs() >= heartbeat.interval_seconds)
+                        .unwrap_or(true);
+                    if due {
+                        last_heartbeat = Some(Instant::now());
+                        let url = heartbeat.url.clone();
+                        let log_sender = log_sender.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = send_heartbeat(&url).await {
+                                let _ = log_sender.send(LogMessage {
+                                    host: "heartbeat".to_string(),
+                                    message: format!("Failed to send heartbeat: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                            }
+                        });
+                    }
+                }
+
+                // Muted validators still track alert cooldowns but nothing is
+                // delivered; once unmuted, suppressed alerts are re-armed
+                let muted: Vec<bool> = {
+                    let state = ui_state.read().await;
+                    (0..app_state.validator_statuses.len())
+                        .map(|idx| state.alert_mutes.is_muted(idx))
+                        .collect()
+                };
+                for (idx, is_muted) in muted.iter().enumerate() {
+                    if !is_muted {
+                        alert_tracker.rpc_failure_tracker.resume(idx);
+                        alert_tracker.delinquency_tracker.resume(idx);
+                    }
+                }
 
-                // Fetch vote data for all validators
+                // Fetch vote data for all validators, with the external endpoint
+                // that served each so it can be vetted before acting on it
                 let mut new_vote_data = Vec::new();
+                let mut vote_sources: Vec<Option<String>> = Vec::new();
+                // Why node-local vote data can't back an alert or failover
+                let mut local_distrust: Vec<Option<String>> = Vec::new();
 
                 for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
                     let validator_pair = &validator_status.validator_pair;
 
-                    match fetch_vote_account_data(&validator_pair.rpc, &validator_pair.vote_pubkey)
-                        .await
-                    {
-                        Ok(data) => {
-                            // Update RPC success
+                    // Route the query to the currently healthiest endpoint
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+                    let query_start = Instant::now();
+
+                    let fetched =
+                        fetch_vote_account_data(&rpc_url, &validator_pair.vote_pubkey).await;
+                    if let Some(selector) = app_state.rpc_selectors.get(idx) {
+                        let mut selector = selector.write().await;
+                        match &fetched {
+                            Ok(data) => selector.record_success(
+                                &rpc_url,
+                                query_start.elapsed(),
+                                data.vote_account_info.current_slot,
+                            ),
+                            Err(_) => selector.record_failure(&rpc_url),
+                        }
+                    }
+
+                    // When the external RPC fails, read the vote account from a
+                    // node's own RPC over SSH so monitoring and the failover
+                    // decision don't hinge on third-party RPC alone
+                    let mut vote_source = Some(rpc_url.clone());
+                    let mut distrust = None;
+                    let fetched = match fetched {
+                        Err(e) if local_rpc_fallback => {
+                            let reference_slot = match app_state.rpc_selectors.get(idx) {
+                                Some(selector) => selector.read().await.freshest_slot(),
+                                None => None,
+                            };
+                            match fetch_vote_data_from_nodes(
+                                &app_state,
+                                validator_status,
+                                local_rpc_port,
+                                reference_slot,
+                            )
+                            .await
                             {
+                                Some((label, data, local_problem)) => {
+                                    vote_source = None;
+                                    distrust = local_problem;
+                                    let _ = log_sender.send(LogMessage {
+                                        host: format!("validator-{}", idx),
+                                        message: format!(
+                                            "RPC {} failed ({}), using local RPC on {}",
+                                            rpc_url, e, label
+                                        ),
+                                        timestamp: Instant::now(),
+                                        level: LogLevel::Warning,
+                                    });
+                                    Ok(data)
+                                }
+                                None => Err(e),
+                            }
+                        }
+                        other => other,
+                    };
+                    vote_sources.push(vote_source.clone());
+                    local_distrust.push(distrust);
+
+                    balances
+                        .check(&app_state, idx, &rpc_url, muted[idx], &sinks)
+                        .await;
+
+                    match fetched {
+                        Ok(mut data) => {
+                            // Data from an endpoint lagging the others shows an old vote
+                            if vote_source.is_some() {
+                                if let Some(selector) = app_state.rpc_selectors.get(idx) {
+                                    data.rpc_stale_slots = selector.read().await.stale_by(&rpc_url);
+                                }
+                                if let Some(lag) = data.rpc_stale_slots {
+                                    let _ = log_sender.send(LogMessage {
+                                        host: format!("validator-{}", idx),
+                                        message: format!("RPC {} is stale: {} slots behind", rpc_url, lag),
+                                        timestamp: Instant::now(),
+                                        level: LogLevel::Warning,
+                                    });
+                                }
+                            }
+
+                            // A commission change is often the first sign of a
+                            // compromised withdraw authority. Stale data could
+                            // show an older commission, so it isn't compared.
+                            let commission = data.vote_account_info.commission;
+                            if data.rpc_stale_slots.is_none() {
+                                if let Some(previous) = known_commission[idx]
+                                    .replace(commission)
+                                    .filter(|previous| *previous != commission)
+                                {
+                                    let _ = log_sender.send(LogMessage {
+                                        host: format!("validator-{}", idx),
+                                        message: format!(
+                                            "Vote account commission changed from {}% to {}%",
+                                            previous, commission
+                                        ),
+                                        timestamp: Instant::now(),
+                                        level: LogLevel::Error,
+                                    });
+                                    if !muted[idx] {
+                                        if let Some(alert_mgr) = alert_manager.as_ref() {
+                                            if alert_mgr
+                                                .send_commission_change_alert(
+                                                    &validator_pair.identity_pubkey,
+                                                    &validator_pair.vote_pubkey,
+                                                    previous,
+                                                    commission,
+                                                )
+                                                .await
+                                                .is_ok()
+                                            {
+                                                crate::history::record_alert(
+                                                    "Commission Change",
+                                                    &validator_pair.identity_pubkey,
+                                                );
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Update RPC success
+                            let rpc_outage_seconds = {
                                 let mut state = ui_state.write().await;
+                                let outage = state.rpc_failure_tracker[idx].seconds_since_first_failure();
                                 state.rpc_failure_tracker[idx].record_success();
+                                state.alert_history.resolve(&condition_key("rpc", idx, None));
+                                outage
+                            };
+                            if rpc_outage_seconds.is_some() {
+                                crate::history::record_failure("rpc", &validator_pair.identity_pubkey, "", None);
+                            }
+
+                            // Send recovery notice if an RPC failure alert was sent
+                            if alert_tracker.rpc_failure_tracker.resolve(idx) && !muted[idx] {
+                                if let Some(alert_mgr) = alert_manager.as_ref() {
+                                    let _ = alert_mgr.send_recovery_alert(
+                                        "RPC Connection",
+                                        &validator_pair.identity_pubkey,
+                                        None,
+                                        rpc_outage_seconds,
+                                    ).await;
+                                }
                             }
 
                             let _ = log_sender.send(LogMessage {
@@ -367,9 +1014,18 @@ impl EnhancedStatusApp {
                             let (should_alert_rpc, consecutive_failures, seconds_since_first) = {
                                 let mut state = ui_state.write().await;
                                 state.rpc_failure_tracker[idx].record_failure(e.to_string());
+                                state.digest_counters[idx].rpc_failures += 1;
                                 
                                 let tracker = &state.rpc_failure_tracker[idx];
                                 let consecutive = tracker.consecutive_failures;
+                                if consecutive == 1 {
+                                    crate::history::record_failure(
+                                        "rpc",
+                                        &validator_pair.identity_pubkey,
+                                        "",
+                                        Some(&e.to_string()),
+                                    );
+                                }
                                 let seconds = tracker.seconds_since_first_failure().unwrap_or(0);
                                 
                                 let config = app_state.config.alert_config.as_ref();
@@ -381,16 +1037,37 @@ impl EnhancedStatusApp {
                                 (should_alert, consecutive, seconds)
                             };
 
-                            // Send RPC failure alert if needed
-                            if should_alert_rpc {
+                            // Send RPC failure alert if needed, unless muted or already
+                            // acknowledged from Telegram
+                            let condition = condition_key("rpc", idx, None);
+                            if should_alert_rpc && muted[idx] {
+                                alert_tracker.rpc_failure_tracker.suppress(idx);
+                            } else if should_alert_rpc
+                                && !ui_state.read().await.alert_history.is_acknowledged(&condition)
+                            {
                                 if let Some(alert_mgr) = alert_manager.as_ref() {
-                                    let _ = alert_mgr.send_rpc_failure_alert(
-                                        &validator_pair.identity_pubkey,
-                                        &validator_pair.vote_pubkey,
-                                        consecutive_failures,
-                                        seconds_since_first,
-                                        &e.to_string()
-                                    ).await;
+                                    if alert_mgr
+                                        .with_ack(&condition)
+                                        .send_rpc_failure_alert(
+                                            &validator_pair.identity_pubkey,
+                                            &validator_pair.vote_pubkey,
+                                            consecutive_failures,
+                                            seconds_since_first,
+                                            &e.to_string(),
+                                        )
+                                        .await
+                                        .is_ok()
+                                    {
+                                        ui_state.write().await.alert_history.record(
+                                            &condition,
+                                            "RPC Connection",
+                                            &validator_pair.identity_pubkey,
+                                        );
+                                        crate::history::record_alert(
+                                            "RPC Connection",
+                                            &validator_pair.identity_pubkey,
+                                        );
+                                    }
                                 }
                             }
 
@@ -406,42 +1083,82 @@ impl EnhancedStatusApp {
                     }
                 }
 
-                // Update UI state
-                let mut state = ui_state.write().await;
+                // Snapshot the vote fields, which only this task writes, so the
+                // RPC checks and alerts below run without holding the UI lock
+                let (old_last_slots, old_increment_times, last_vote_slot_times) = {
+                    let state = ui_state.read().await;
+                    (
+                        state
+                            .vote_data
+                            .iter()
+                            .map(|v| v.as_ref().and_then(|d| d.recent_votes.last().map(|v| v.slot)))
+                            .collect::<Vec<Option<u64>>>(),
+                        state.increment_times.clone(),
+                        state.last_vote_slot_times.clone(),
+                    )
+                };
 
                 // Calculate increments and track slot changes
                 let mut new_increments = Vec::new();
                 let mut new_slot_times = Vec::new();
 
                 for (idx, new_data) in new_vote_data.iter().enumerate() {
+                    let tracked = last_vote_slot_times.get(idx).and_then(|&v| v);
                     if let Some(new) = new_data {
                         let new_last_slot = new.recent_votes.last().map(|v| v.slot);
 
                         // Check if this is a new slot
                         if let Some(new_slot) = new_last_slot {
                             // Check against our tracked slot time
-                            let should_update_slot_time = if let Some(tracked) =
-                                state.last_vote_slot_times.get(idx).and_then(|&v| v)
-                            {
-                                tracked.0 != new_slot // Slot has changed
-                            } else {
-                                true // No previous tracking
+                            let should_update_slot_time = match tracked {
+                                Some((slot, _)) => slot != new_slot, // Slot has changed
+                                None => true,                        // No previous tracking
                             };
 
                             if should_update_slot_time {
                                 new_slot_times.push(Some((new_slot, Instant::now())));
-                                // Reset alert tracker since slot is advancing
-                                alert_tracker.delinquency_tracker.reset(idx);
+                                delinquent[idx] = false;
+                                ui_state
+                                    .write()
+                                    .await
+                                    .alert_history
+                                    .resolve(&condition_key("delinquency", idx, None));
+                                // Reset alert tracker since slot is advancing, and notify
+                                // if a delinquency alert was sent for this validator
+                                if alert_tracker.delinquency_tracker.resolve(idx) && !muted[idx] {
+                                    if let Some(alert_mgr) = alert_manager.as_ref() {
+                                        let outage_seconds = tracked
+                                            .map(|(_, last_change)| last_change.elapsed().as_secs());
+
+                                        if alert_mgr
+                                            .send_recovery_alert(
+                                                "Validator Voting",
+                                                &app_state.validator_statuses[idx]
+                                                    .validator_pair
+                                                    .identity_pubkey,
+                                                None,
+                                                outage_seconds,
+                                            )
+                                            .await
+                                            .is_ok()
+                                        {
+                                            let _ = log_sender.send(LogMessage {
+                                                host: format!("validator-{}", idx),
+                                                message: format!("Voting recovered at slot {}", new_slot),
+                                                timestamp: Instant::now(),
+                                                level: LogLevel::Info,
+                                            });
+                                        }
+                                    }
+                                }
                             } else {
                                 // Slot hasn't changed, keep existing time
-                                new_slot_times
-                                    .push(state.last_vote_slot_times.get(idx).and_then(|&v| v));
+                                new_slot_times.push(tracked);
 
                                 // Check for delinquency
-                                if let (Some(alert_mgr), Some((_, last_change_time))) = (
-                                    alert_manager.as_ref(),
-                                    state.last_vote_slot_times.get(idx).and_then(|&v| v),
-                                ) {
+                                if let (Some(alert_mgr), Some((_, last_change_time))) =
+                                    (alert_manager.as_ref(), tracked)
+                                {
                                     let seconds_since_vote = last_change_time.elapsed().as_secs();
                                     let threshold = app_state
                                         .config
@@ -450,44 +1167,180 @@ impl EnhancedStatusApp {
                                         .map(|c| c.delinquency_threshold_seconds)
                                         .unwrap_or(30);
 
+                                    if seconds_since_vote >= threshold && !delinquent[idx] {
+                                        delinquent[idx] = true;
+                                        ui_state.write().await.digest_counters[idx].delinquency_events += 1;
+                                    }
+
+                                    // An unhealthy or lagging endpoint shows a stalled
+                                    // vote slot too, so vet it before alerting or failing over
+                                    let distrust = if seconds_since_vote >= threshold
+                                        && alert_tracker.delinquency_tracker.is_due(idx)
+                                    {
+                                        match vote_sources.get(idx).cloned().flatten() {
+                                            Some(url) => {
+                                                let references = app_state.validator_statuses[idx]
+                                                    .validator_pair
+                                                    .rpc_endpoints();
+                                                crate::solana_rpc::endpoint_distrust(&url, &references).await
+                                            }
+                                            None => local_distrust.get(idx).cloned().flatten(),
+                                        }
+                                    } else {
+                                        None
+                                    };
+                                    if let Some(reason) = distrust.as_ref() {
+                                        let _ = log_sender.send(LogMessage {
+                                            host: format!("validator-{}", idx),
+                                            message: format!(
+                                                "Vote data untrusted, not treating {}s without vote as delinquency: {}",
+                                                seconds_since_vote, reason
+                                            ),
+                                            timestamp: Instant::now(),
+                                            level: LogLevel::Warning,
+                                        });
+                                    }
+
                                     if seconds_since_vote >= threshold
+                                        && distrust.is_none()
                                         && alert_tracker.delinquency_tracker.should_send_alert(idx)
                                     {
+                                        let condition = condition_key("delinquency", idx, None);
+                                        let (validator_status, node_health, common_cause, acknowledged) = {
+                                            let state = ui_state.read().await;
+                                            // Both nodes failing the same way points at a shared
+                                            // cause; say so instead of blaming the active node
+                                            let common_cause = state.ssh_health_data.get(idx).and_then(|ssh| {
+                                                let catchup = state.catchup_data.get(idx);
+                                                detect_common_cause(
+                                                    [
+                                                        !ssh.node_0.is_healthy && ssh.node_0.failure_start.is_some(),
+                                                        !ssh.node_1.is_healthy && ssh.node_1.failure_start.is_some(),
+                                                    ],
+                                                    [
+                                                        catchup.and_then(|c| c.node_0.as_ref()).map(|c| c.status.as_str()),
+                                                        catchup.and_then(|c| c.node_1.as_ref()).map(|c| c.status.as_str()),
+                                                    ],
+                                                )
+                                            });
+                                            (
+                                                // Reconciled roles rather than those detected at startup
+                                                state.validator_statuses[idx].clone(),
+                                                state.validator_health[idx].clone(),
+                                                common_cause,
+                                                state.alert_history.is_acknowledged(&condition),
+                                            )
+                                        };
+
                                         // Find which node is active
-                                        let active_node = if let Some(node_with_status) = app_state
-                                            .validator_statuses[idx]
+                                        let active_node = if let Some(node_with_status) = validator_status
                                             .nodes_with_status
                                             .iter()
                                             .find(|n| n.status == crate::types::NodeStatus::Active)
                                         {
-                                            &node_with_status.node
+                                            node_with_status.node.clone()
                                         } else {
-                                            &app_state.validator_statuses[idx].nodes_with_status[0]
-                                                .node
+                                            validator_status.nodes_with_status[0].node.clone()
                                         };
 
-                                        let is_active = app_state.validator_statuses[idx]
+                                        let is_active = validator_status
                                             .nodes_with_status
                                             .iter()
                                             .any(|n| n.status == crate::types::NodeStatus::Active);
 
-                                        // Get current health status
-                                        let node_health = state.validator_health[idx].clone();
+                                        let node_labels: Vec<&str> = app_state.validator_statuses[idx]
+                                            .nodes_with_status
+                                            .iter()
+                                            .map(|n| n.node.label.as_str())
+                                            .collect();
+
+                                        // A halted or degraded cluster stalls every validator's
+                                        // votes; report that instead of blaming the node
+                                        let cluster_problem = match vote_sources.get(idx).cloned().flatten() {
+                                            Some(url) => match fetch_cluster_health(&url).await {
+                                                Ok(health) => {
+                                                    let cluster_config = app_state
+                                                        .config
+                                                        .alert_config
+                                                        .as_ref()
+                                                        .and_then(|c| c.cluster_health.clone())
+                                                        .unwrap_or_default();
+                                                    crate::alert::cluster_problem(&cluster_config, &health)
+                                                }
+                                                Err(e) => {
+                                                    let _ = log_sender.send(LogMessage {
+                                                        host: format!("validator-{}", idx),
+                                                        message: format!("Failed to check cluster health: {}", e),
+                                                        timestamp: Instant::now(),
+                                                        level: LogLevel::Warning,
+                                                    });
+                                                    None
+                                                }
+                                            },
+                                            None => None,
+                                        };
+                                        let alert_title = if cluster_problem.is_some() {
+                                            "Cluster Problem"
+                                        } else {
+                                            "Validator Delinquency"
+                                        };
                                         
                                         // Send alert with health status
-                                        if let Err(e) = alert_mgr
-                                            .send_delinquency_alert_with_health(
-                                                &app_state.validator_statuses[idx]
-                                                    .validator_pair
-                                                    .identity_pubkey,
-                                                &active_node.label,
-                                                is_active,
-                                                new_slot,
-                                                seconds_since_vote,
-                                                &node_health,
-                                            )
-                                            .await
-                                        {
+                                        let alert_mgr_ack = alert_mgr.with_ack(&condition);
+                                        if muted[idx] {
+                                            alert_tracker.delinquency_tracker.suppress(idx);
+                                            let _ = log_sender.send(LogMessage {
+                                                host: format!("validator-{}", idx),
+                                                message: format!("Delinquency alert muted: {} seconds without vote", seconds_since_vote),
+                                                timestamp: Instant::now(),
+                                                level: LogLevel::Warning,
+                                            });
+                                        } else if acknowledged {
+                                            let _ = log_sender.send(LogMessage {
+                                                host: format!("validator-{}", idx),
+                                                message: format!("Delinquency alert acknowledged, not repeating: {} seconds without vote", seconds_since_vote),
+                                                timestamp: Instant::now(),
+                                                level: LogLevel::Warning,
+                                            });
+                                        } else if let Err(e) = match (cluster_problem.as_deref(), common_cause) {
+                                            (Some(problem), _) => {
+                                                alert_mgr_ack
+                                                    .send_cluster_problem_alert(
+                                                        &app_state.validator_statuses[idx]
+                                                            .validator_pair
+                                                            .identity_pubkey,
+                                                        problem,
+                                                        seconds_since_vote,
+                                                    )
+                                                    .await
+                                            }
+                                            (None, Some(cause)) => {
+                                                alert_mgr_ack
+                                                    .send_common_cause_alert(
+                                                        &app_state.validator_statuses[idx]
+                                                            .validator_pair
+                                                            .identity_pubkey,
+                                                        cause,
+                                                        &node_labels,
+                                                        &format!("no vote for {} seconds (last vote slot {})", seconds_since_vote, new_slot),
+                                                    )
+                                                    .await
+                                            }
+                                            (None, None) => {
+                                                alert_mgr_ack
+                                                    .send_delinquency_alert_with_health(
+                                                        &app_state.validator_statuses[idx]
+                                                            .validator_pair
+                                                            .identity_pubkey,
+                                                        &active_node.label,
+                                                        is_active,
+                                                        new_slot,
+                                                        seconds_since_vote,
+                                                        &node_health,
+                                                    )
+                                                    .await
+                                            }
+                                        } {
                                             let _ = log_sender.send(LogMessage {
                                                 host: format!("validator-{}", idx),
                                                 message: format!(
@@ -498,9 +1351,22 @@ impl EnhancedStatusApp {
                                                 level: LogLevel::Error,
                                             });
                                         } else {
+                                            ui_state.write().await.alert_history.record(
+                                                &condition,
+                                                alert_title,
+                                                &app_state.validator_statuses[idx]
+                                                    .validator_pair
+                                                    .identity_pubkey,
+                                            );
+                                            crate::history::record_alert(
+                                                alert_title,
+                                                &app_state.validator_statuses[idx]
+                                                    .validator_pair
+                                                    .identity_pubkey,
+                                            );
                                             let _ = log_sender.send(LogMessage {
                                                 host: format!("validator-{}", idx),
-                                                message: format!("Delinquency alert sent: {} seconds without vote", seconds_since_vote),
+                                                message: format!("{} alert sent: {} seconds without vote", alert_title, seconds_since_vote),
                                                 timestamp: Instant::now(),
                                                 level: LogLevel::Warning,
                                             });
@@ -508,25 +1374,72 @@ impl EnhancedStatusApp {
                                         
                                         // Check if auto-failover is enabled
                                         if let Some(alert_config) = &app_state.config.alert_config {
-                                            if alert_config.enabled && alert_config.auto_failover_enabled {
-                                                // CRITICAL: Only trigger auto-failover if RPC is working
-                                                // We need RPC to verify on-chain that the validator is not voting
-                                                // SSH may be down if the node is completely offline
-                                                if node_health.rpc_status.consecutive_failures == 0 {
-                                                    
+                                            let mut decision = failover_decision(alert_config, common_cause, &node_health);
+
+                                            // Switching nodes can't fix a halted cluster
+                                            if let (Some(problem), FailoverDecision::Trigger) = (cluster_problem.as_ref(), &decision) {
+                                                decision = FailoverDecision::Suppressed(problem.clone());
+                                            }
+
+                                            // One endpoint's view is not enough to take over a
+                                            // validator; enough endpoints must each see no new vote
+                                            if decision == FailoverDecision::Trigger {
+                                                let validator_pair = &app_state.validator_statuses[idx].validator_pair;
+                                                let endpoints = validator_pair.rpc_endpoints();
+                                                let required = required_quorum(alert_config.auto_failover_quorum, endpoints.len());
+                                                // Separate tasks so the endpoints are queried in parallel
+                                                let observations: Vec<Option<(u64, u64)>> =
+                                                    futures::future::join_all(endpoints.iter().map(|url| {
+                                                        let url = url.clone();
+                                                        let vote_pubkey = validator_pair.vote_pubkey.clone();
+                                                        tokio::spawn(async move {
+                                                            fetch_vote_account_data(&url, &vote_pubkey).await
+                                                        })
+                                                    }))
+                                                    .await
+                                                    .into_iter()
+                                                    .map(|result| {
+                                                        let data = result.ok()?.ok()?;
+                                                        let last_vote = data.recent_votes.last().map(|v| v.slot).unwrap_or(0);
+                                                        Some((last_vote, data.vote_account_info.current_slot?))
+                                                    })
+                                                    .collect();
+                                                let confirmed = count_no_vote_confirmations(new_slot, &observations);
+                                                if confirmed < required {
+                                                    decision = FailoverDecision::Suppressed(format!(
+                                                        "{} of {} RPC endpoints confirm no votes, {} required",
+                                                        confirmed,
+                                                        endpoints.len(),
+                                                        required
+                                                    ));
+                                                }
+                                            }
+
+                                            match decision {
+                                                FailoverDecision::Disabled => {}
+                                                FailoverDecision::Suppressed(reason) => {
                                                     let _ = log_sender.send(LogMessage {
                                                         host: format!("validator-{}", idx),
-                                                        message: "🚨 AUTO-FAILOVER: Initiating emergency takeover".to_string(),
+                                                        message: format!("Auto-failover suppressed: {}", reason),
                                                         timestamp: Instant::now(),
-                                                        level: LogLevel::Error,
+                                                        level: LogLevel::Warning,
                                                     });
-                                                    
+                                                }
+                                                FailoverDecision::Trigger => {
+                                                    let _ = log_sender.send(LogMessage {
+                                                        host: format!("validator-{}", idx),
+                                                        message: "🚨 AUTO-FAILOVER: Initiating emergency takeover".to_string(),
+                                                        timestamp: Instant::now(),
+                                                        level: LogLevel::Error,
+                                                    });
+                                                    
                                                     // Spawn emergency failover task
-                                                    let validator_status = app_state.validator_statuses[idx].clone();
                                                     let alert_manager = alert_mgr.clone();
                                                     let ssh_pool = app_state.ssh_pool.clone();
                                                     let ssh_keys = app_state.detected_ssh_keys.clone();
                                                     let emergency_flag = emergency_takeover_flag.clone();
+                                                    let switch_config = app_state.config.switch.clone().unwrap_or_default();
+                                                    let statsd_config = app_state.config.statsd.clone();
                                                     
                                                     tokio::spawn(async move {
                                                         execute_emergency_failover(
@@ -535,19 +1448,10 @@ impl EnhancedStatusApp {
                                                             ssh_pool,
                                                             ssh_keys,
                                                             emergency_flag,
+                                                            switch_config,
+                                                            statsd_config,
                                                         ).await;
                                                     });
-                                                } else {
-                                                    let _ = log_sender.send(LogMessage {
-                                                        host: format!("validator-{}", idx),
-                                                        message: format!(
-                                                            "Auto-failover suppressed: SSH failures={}, RPC failures={}",
-                                                            node_health.ssh_status.consecutive_failures,
-                                                            node_health.rpc_status.consecutive_failures
-                                                        ),
-                                                        timestamp: Instant::now(),
-                                                        level: LogLevel::Warning,
-                                                    });
                                                 }
                                             }
                                         }
@@ -556,58 +1460,1684 @@ impl EnhancedStatusApp {
                             }
 
                             // Handle increment display (visual indicator)
-                            if let Some(old) = state.vote_data.get(idx).and_then(|v| v.as_ref()) {
-                                if let Some(old_last_slot) = old.recent_votes.last().map(|v| v.slot)
-                                {
-                                    if new_slot > old_last_slot {
-                                        new_increments.push(Some(Instant::now()));
-                                    } else {
-                                        // Keep existing increment if still valid
-                                        if let Some(existing) =
-                                            state.increment_times.get(idx).and_then(|&v| v)
-                                        {
-                                            if existing.elapsed().as_secs() < 2 {
-                                                new_increments.push(Some(existing));
-                                            } else {
-                                                new_increments.push(None);
-                                            }
+                            if let Some(old_last_slot) = old_last_slots.get(idx).and_then(|&v| v) {
+                                if new_slot > old_last_slot {
+                                    new_increments.push(Some(Instant::now()));
+                                } else {
+                                    // Keep existing increment if still valid
+                                    if let Some(existing) =
+                                        old_increment_times.get(idx).and_then(|&v| v)
+                                    {
+                                        if existing.elapsed().as_secs() < 2 {
+                                            new_increments.push(Some(existing));
                                         } else {
                                             new_increments.push(None);
                                         }
+                                    } else {
+                                        new_increments.push(None);
                                     }
-                                } else {
-                                    new_increments.push(None);
                                 }
                             } else {
                                 new_increments.push(None);
                             }
-                        } else {
-                            new_increments.push(None);
-                            new_slot_times.push(None);
+                        } else {
+                            new_increments.push(None);
+                            new_slot_times.push(None);
+                        }
+                    } else {
+                        // RPC failed - preserve existing slot time instead of setting to None
+                        new_increments.push(None);
+                        new_slot_times.push(tracked);
+                    }
+                }
+
+                // Apply the results in one short write
+                let mut state = ui_state.write().await;
+                state.previous_last_slots = old_last_slots;
+                state.vote_data = new_vote_data;
+                state.increment_times = new_increments;
+                state.last_vote_slot_times = new_slot_times;
+                state.last_vote_refresh = Instant::now();
+            }
+        });
+
+        // Liveness endpoint for container and orchestrator probes
+        if let Some(health_config) = self.app_state.config.health_endpoint.clone() {
+            let ui_state = Arc::clone(&self.ui_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                if let Err(e) = crate::health_endpoint::serve(health_config, ui_state).await {
+                    let _ = log_sender.send(LogMessage {
+                        host: "healthz".to_string(),
+                        message: e.to_string(),
+                        timestamp: Instant::now(),
+                        level: LogLevel::Error,
+                    });
+                }
+            });
+        }
+
+        // RPC endpoint probing task - keeps latency and freshness of every
+        // configured endpoint current so queries can pick the healthiest one
+        let app_state_for_probe = Arc::clone(&self.app_state);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(10));
+
+            loop {
+                interval.tick().await;
+                probe_all(&app_state_for_probe.rpc_selectors).await;
+            }
+        });
+
+        // SSH pool diagnostics task - snapshot for the diagnostics view and a
+        // Prometheus textfile so pool saturation can be graphed
+        let ui_state = Arc::clone(&self.ui_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(5));
+            let metrics_path = dirs::home_dir()
+                .map(|home| home.join(".solana-validator-switch").join("metrics"));
+
+            loop {
+                interval.tick().await;
+
+                let diagnostics = ssh_pool.diagnostics().await;
+
+                if let Some(dir) = metrics_path.as_ref() {
+                    if std::fs::create_dir_all(dir).is_ok() {
+                        let _ = std::fs::write(
+                            dir.join("ssh_pool.prom"),
+                            crate::ssh::render_pool_metrics(&diagnostics),
+                        );
+                    }
+                }
+
+                ui_state.write().await.ssh_pool_diagnostics = diagnostics;
+            }
+        });
+
+        // InfluxDB export task - node, validator and SSH pool metrics in line
+        // protocol for existing time-series dashboards
+        if let Some(influx_config) = self.app_state.config.influxdb.clone() {
+            let ui_state = Arc::clone(&self.ui_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                let mut interval = interval(Duration::from_secs(influx_config.interval_seconds.max(1)));
+                let mut failing = false;
+
+                loop {
+                    interval.tick().await;
+
+                    let body = {
+                        let state = ui_state.read().await;
+                        influx_lines(&state, chrono::Utc::now().timestamp())
+                    };
+                    match crate::influxdb::write(&influx_config, body).await {
+                        Ok(()) => failing = false,
+                        Err(e) if !failing => {
+                            // Logged once per outage, not on every write
+                            failing = true;
+                            let _ = log_sender.send(LogMessage {
+                                host: "influxdb".to_string(),
+                                message: format!("Failed to write metrics: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                        Err(_) => {}
+                    }
+                }
+            });
+        }
+
+        // statsd task - delinquency, catchup lag and SSH failure counters for
+        // Datadog and other statsd-based monitoring
+        if let Some(statsd_config) = self.app_state.config.statsd.clone() {
+            let ui_state = Arc::clone(&self.ui_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                let client = match crate::statsd::StatsdClient::new(&statsd_config) {
+                    Ok(client) => client,
+                    Err(e) => {
+                        let _ = log_sender.send(LogMessage {
+                            host: "statsd".to_string(),
+                            message: format!("Failed to open statsd socket: {}", e),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                        return;
+                    }
+                };
+                let mut interval = interval(Duration::from_secs(statsd_config.interval_seconds.max(1)));
+                let mut reported_ssh_failures: Vec<u32> = Vec::new();
+
+                loop {
+                    interval.tick().await;
+                    let state = ui_state.read().await;
+                    reported_ssh_failures.resize(state.validator_statuses.len(), 0);
+
+                    for (idx, validator_status) in state.validator_statuses.iter().enumerate() {
+                        let identity = validator_status.validator_pair.identity_pubkey.as_str();
+
+                        if let Some((_, last_change)) = state.last_vote_slot_times.get(idx).copied().flatten() {
+                            client.send(
+                                "delinquency_seconds",
+                                last_change.elapsed().as_secs() as f64,
+                                crate::statsd::Kind::Gauge,
+                                &[("validator", identity)],
+                            );
+                        }
+
+                        for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate() {
+                            let catchup = state.catchup_data.get(idx).and_then(|pair| {
+                                if node_idx == 0 { pair.node_0.as_ref() } else { pair.node_1.as_ref() }
+                            });
+                            let slots_behind = catchup.and_then(|c| {
+                                if c.status == "Caught up" {
+                                    Some(0)
+                                } else {
+                                    crate::alert::slots_behind(&c.status)
+                                }
+                            });
+                            if let Some(slots) = slots_behind {
+                                client.send(
+                                    "slots_behind",
+                                    slots as f64,
+                                    crate::statsd::Kind::Gauge,
+                                    &[("validator", identity), ("node", &node.node.label)],
+                                );
+                            }
+                        }
+
+                        // Counters restart when the daily digest is sent
+                        let failures = state
+                            .digest_counters
+                            .get(idx)
+                            .map(|counters| counters.ssh_failures)
+                            .unwrap_or(0);
+                        let new_failures = if failures >= reported_ssh_failures[idx] {
+                            failures - reported_ssh_failures[idx]
+                        } else {
+                            failures
+                        };
+                        reported_ssh_failures[idx] = failures;
+                        if new_failures > 0 {
+                            client.send(
+                                "ssh_failures",
+                                new_failures as f64,
+                                crate::statsd::Kind::Count,
+                                &[("validator", identity)],
+                            );
+                        }
+                    }
+                }
+            });
+        }
+
+        // Delegation program sampling task - records once a minute whether
+        // each validator is voting, for `svs sfdp-report`
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(crate::sfdp::SAMPLE_INTERVAL_SECONDS));
+            let mut last_prune: Option<Instant> = None;
+            let threshold = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .map(|c| c.delinquency_threshold_seconds)
+                .unwrap_or(30);
+
+            loop {
+                interval.tick().await;
+
+                let samples: Vec<crate::sfdp::SfdpSample> = {
+                    let state = ui_state.read().await;
+                    state
+                        .validator_statuses
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, validator_status)| {
+                            // No vote data means RPC is failing: unknown, not delinquent
+                            state.vote_data.get(idx)?.as_ref()?;
+                            let (_, last_change) = state.last_vote_slot_times.get(idx).copied()??;
+                            Some(crate::sfdp::SfdpSample {
+                                timestamp: chrono::Utc::now().timestamp(),
+                                identity: validator_status.validator_pair.identity_pubkey.clone(),
+                                voting: last_change.elapsed().as_secs() < threshold,
+                                version: validator_status
+                                    .nodes_with_status
+                                    .iter()
+                                    .find(|n| n.status == crate::types::NodeStatus::Active)
+                                    .and_then(|n| n.version.clone()),
+                            })
+                        })
+                        .collect()
+                };
+
+                if !samples.is_empty() {
+                    let _ = crate::sfdp::append_samples(&samples);
+                }
+
+                // The same minute's vote slots and catchup lag go to the
+                // history database
+                if let Some(store) = crate::history::store() {
+                    let now = chrono::Utc::now().timestamp();
+                    let (votes, catchups) = {
+                        let state = ui_state.read().await;
+                        let votes: Vec<(String, Option<u64>, bool)> = samples
+                            .iter()
+                            .map(|sample| {
+                                let last_vote_slot = state
+                                    .validator_statuses
+                                    .iter()
+                                    .position(|v| v.validator_pair.identity_pubkey == sample.identity)
+                                    .and_then(|idx| state.vote_data.get(idx)?.as_ref())
+                                    .and_then(|data| data.recent_votes.last().map(|v| v.slot));
+                                (sample.identity.clone(), last_vote_slot, sample.voting)
+                            })
+                            .collect();
+                        let mut catchups = Vec::new();
+                        for (idx, validator_status) in state.validator_statuses.iter().enumerate() {
+                            let pair = match state.catchup_data.get(idx) {
+                                Some(pair) => pair,
+                                None => continue,
+                            };
+                            for (node_idx, catchup) in [&pair.node_0, &pair.node_1].into_iter().enumerate() {
+                                if let (Some(catchup), Some(node)) =
+                                    (catchup, validator_status.nodes_with_status.get(node_idx))
+                                {
+                                    catchups.push((
+                                        validator_status.validator_pair.identity_pubkey.clone(),
+                                        node.node.label.clone(),
+                                        catchup.status.clone(),
+                                    ));
+                                }
+                            }
+                        }
+                        (votes, catchups)
+                    };
+                    for (identity, last_vote_slot, voting) in &votes {
+                        let _ = store.record_vote_sample(now, identity, *last_vote_slot, *voting);
+                    }
+                    for (identity, node, status) in &catchups {
+                        let _ = store.record_catchup(now, identity, node, status);
+                    }
+                }
+
+                let prune_due = match last_prune {
+                    Some(pruned_at) => pruned_at.elapsed() >= Duration::from_secs(86400),
+                    None => true,
+                };
+                if prune_due {
+                    let _ = crate::sfdp::prune_samples();
+                    if let Some(store) = crate::history::store() {
+                        let _ = store.prune(
+                            chrono::Utc::now().timestamp() - crate::history::RETENTION_DAYS * 86_400,
+                        );
+                    }
+                    last_prune = Some(Instant::now());
+                }
+            }
+        });
+
+        // Delegation program report files, if configured
+        if let Some(report_config) = self.app_state.config.sfdp_report.clone() {
+            let app_state = Arc::clone(&self.app_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                let mut interval =
+                    interval(Duration::from_secs(report_config.interval_hours.max(1) * 3600));
+
+                loop {
+                    interval.tick().await;
+
+                    if let Err(e) = crate::sfdp::write_periodic_reports(
+                        &app_state,
+                        &report_config.window_days,
+                        &report_config.format,
+                    )
+                    .await
+                    {
+                        let _ = log_sender.send(LogMessage {
+                            host: "sfdp".to_string(),
+                            message: format!("Failed to write SFDP report: {}", e),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                    }
+                }
+            });
+        }
+
+        // Host checks task - OS patch level changes slowly, so check rarely
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(1800));
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key,
+                            None => continue,
+                        };
+
+                        match check_os_patch_status(&ssh_pool, &node.node, ssh_key).await {
+                            Ok(os_patch) => {
+                                if os_patch.needs_reboot() {
+                                    let _ = log_sender.send(LogMessage {
+                                        host: node.node.label.clone(),
+                                        message: format!(
+                                            "Reboot pending (running kernel {})",
+                                            os_patch.running_kernel
+                                        ),
+                                        timestamp: Instant::now(),
+                                        level: LogLevel::Warning,
+                                    });
+                                }
+
+                                let mut state = ui_state.write().await;
+                                if let Some(pair) = state.host_checks.get_mut(idx) {
+                                    let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                    status.os_patch = Some(os_patch);
+                                }
+                            }
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("OS patch check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        // Storage check task - tower freshness and ledger disk usage
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            let disk_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.disk_space.clone());
+            // One slot per node (validator * 2 + node); space shrinks slowly,
+            // so once an hour is enough
+            let mut disk_tracker =
+                AlertTracker::with_cooldown(app_state.validator_statuses.len() * 2, 3600);
+            let snapshot_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.snapshot_age.clone());
+            let mut snapshot_tracker =
+                AlertTracker::with_cooldown(app_state.validator_statuses.len() * 2, 3600);
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
+            loop {
+                interval.tick().await;
+
+                let statuses = ui_state.read().await.validator_statuses.clone();
+                for (idx, validator_status) in statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key,
+                            None => continue,
+                        };
+
+                        match check_storage_status(
+                            &ssh_pool,
+                            &node.node,
+                            ssh_key,
+                            node.tower_path.as_deref(),
+                            node.ledger_path.as_deref(),
+                        )
+                        .await
+                        {
+                            Ok(storage) => {
+                                let muted = {
+                                    let mut state = ui_state.write().await;
+                                    if let Some(pair) = state.host_checks.get_mut(idx) {
+                                        let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                        status.storage = Some(storage.clone());
+                                    }
+                                    state.alert_mutes.is_muted(idx)
+                                };
+
+                                // Only the standby matters: it is what boots from its
+                                // snapshot when a switch or failover needs it
+                                if let Some(config) = snapshot_config.as_ref() {
+                                    let slot = idx * 2 + node_idx;
+                                    let subject = AlertSubject {
+                                        slot,
+                                        validator_idx: idx,
+                                        condition: condition_key("snapshot_age", idx, Some(node_idx)),
+                                        identity: &validator_status.validator_pair.identity_pubkey,
+                                        node_label: Some(&node.node.label),
+                                        muted,
+                                    };
+                                    let is_standby = node.status == crate::types::NodeStatus::Standby;
+
+                                    match storage.snapshot_age_seconds() {
+                                        Some(age) if is_standby => {
+                                            evaluate_alert(
+                                                &mut snapshot_tracker,
+                                                Some(age).filter(|age| *age > config.max_age_minutes * 60),
+                                                "Stale Snapshot",
+                                                &subject,
+                                                &sinks,
+                                                |age| {
+                                                    format!(
+                                                        "Newest snapshot is {} old, above {}m",
+                                                        crate::commands::switch::format_wait(Duration::from_secs(*age)),
+                                                        config.max_age_minutes
+                                                    )
+                                                },
+                                                |alert_mgr, age| async move {
+                                                    alert_mgr
+                                                        .send_snapshot_age_alert(
+                                                            &validator_status.validator_pair.identity_pubkey,
+                                                            &node.node.label,
+                                                            age,
+                                                            config.max_age_minutes,
+                                                        )
+                                                        .await
+                                                },
+                                            )
+                                            .await;
+                                        }
+                                        // A node that became active no longer needs a fresh snapshot
+                                        Some(_) => {
+                                            ui_state.write().await.alert_history.resolve(&subject.condition);
+                                            snapshot_tracker.resolve(slot);
+                                        }
+                                        None => {}
+                                    }
+                                }
+
+                                let config = match disk_config.as_ref() {
+                                    Some(config) => config,
+                                    None => continue,
+                                };
+                                // Nothing measured yet is not a recovery
+                                if storage.disks().is_empty() {
+                                    continue;
+                                }
+                                let subject = AlertSubject {
+                                    slot: idx * 2 + node_idx,
+                                    validator_idx: idx,
+                                    condition: condition_key("disk_space", idx, Some(node_idx)),
+                                    identity: &validator_status.validator_pair.identity_pubkey,
+                                    node_label: Some(&node.node.label),
+                                    muted,
+                                };
+                                let low_disks = crate::alert::disk_space_breach(config, &storage);
+                                evaluate_alert(
+                                    &mut disk_tracker,
+                                    Some(low_disks).filter(|low_disks| !low_disks.is_empty()),
+                                    "Low Disk Space",
+                                    &subject,
+                                    &sinks,
+                                    |low_disks| {
+                                        let summary: Vec<String> = low_disks
+                                            .iter()
+                                            .map(|(kind, disk)| format!("{} {}% free", kind, disk.free_percent()))
+                                            .collect();
+                                        format!(
+                                            "Low disk space: {} (minimum {}%)",
+                                            summary.join(", "),
+                                            config.min_free_percent
+                                        )
+                                    },
+                                    |alert_mgr, low_disks| async move {
+                                        alert_mgr
+                                            .send_disk_space_alert(
+                                                &validator_status.validator_pair.identity_pubkey,
+                                                &node.node.label,
+                                                &low_disks,
+                                                config.min_free_percent,
+                                            )
+                                            .await
+                                    },
+                                )
+                                .await;
+                            }
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("Storage check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        // System metrics task - CPU, memory and swap from /proc alongside the
+        // SSH health check, alerting only once a limit stays exceeded
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(SSH_HEALTH_INTERVAL);
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            let metrics_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.system_metrics.clone());
+            // One slot per node (validator * 2 + node), each remembering when
+            // its current breach started
+            let slots = app_state.validator_statuses.len() * 2;
+            let mut metrics_tracker = AlertTracker::with_cooldown(slots, 3600);
+            let mut breach_since: Vec<Option<Instant>> = vec![None; slots];
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
+            loop {
+                interval.tick().await;
+
+                let statuses = ui_state.read().await.validator_statuses.clone();
+                for (idx, validator_status) in statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key,
+                            None => continue,
+                        };
+
+                        let metrics = match check_system_metrics(&ssh_pool, &node.node, ssh_key).await {
+                            Ok(metrics) => metrics,
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("System metrics check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                continue;
+                            }
+                        };
+
+                        let muted = {
+                            let mut state = ui_state.write().await;
+                            if let Some(pair) = state.host_checks.get_mut(idx) {
+                                let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                status.system = Some(metrics.clone());
+                            }
+                            state.alert_mutes.is_muted(idx)
+                        };
+
+                        let config = match metrics_config.as_ref() {
+                            Some(config) => config,
+                            None => continue,
+                        };
+                        // A breach only counts once it has lasted the sustained period
+                        let slot = idx * 2 + node_idx;
+                        let breaches = crate::alert::system_metrics_breach(config, &metrics);
+                        if breaches.is_empty() {
+                            breach_since[slot] = None;
+                        } else {
+                            let since = *breach_since[slot].get_or_insert_with(Instant::now);
+                            if since.elapsed().as_secs() < config.sustained_seconds {
+                                continue;
+                            }
+                        }
+
+                        let subject = AlertSubject {
+                            slot,
+                            validator_idx: idx,
+                            condition: condition_key("system_metrics", idx, Some(node_idx)),
+                            identity: &validator_status.validator_pair.identity_pubkey,
+                            node_label: Some(&node.node.label),
+                            muted,
+                        };
+                        evaluate_alert(
+                            &mut metrics_tracker,
+                            Some(breaches).filter(|breaches| !breaches.is_empty()),
+                            "High Resource Usage",
+                            &subject,
+                            &sinks,
+                            |breaches| {
+                                let summary: Vec<String> = breaches
+                                    .iter()
+                                    .map(|(resource, used, _)| format!("{} {:.0}%", resource, used))
+                                    .collect();
+                                format!(
+                                    "High resource usage for over {}s: {}",
+                                    config.sustained_seconds,
+                                    summary.join(", ")
+                                )
+                            },
+                            |alert_mgr, breaches| async move {
+                                alert_mgr
+                                    .send_system_metrics_alert(
+                                        &validator_status.validator_pair.identity_pubkey,
+                                        &node.node.label,
+                                        &breaches,
+                                        config.sustained_seconds,
+                                    )
+                                    .await
+                            },
+                        )
+                        .await;
+                    }
+                }
+            }
+        });
+
+        // Time sync task - chrony or timesyncd state of every node, alerting
+        // when a clock is unsynchronized or drifts past the limit
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            let time_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.time_sync.clone());
+            // One slot per node (validator * 2 + node)
+            let mut time_tracker = AlertTracker::with_cooldown(app_state.validator_statuses.len() * 2, 3600);
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
+            loop {
+                interval.tick().await;
+
+                let statuses = ui_state.read().await.validator_statuses.clone();
+                for (idx, validator_status) in statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key,
+                            None => continue,
+                        };
+
+                        let sync = match check_time_sync(&ssh_pool, &node.node, ssh_key).await {
+                            Ok(sync) => sync,
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("Time sync check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                continue;
+                            }
+                        };
+
+                        let muted = {
+                            let mut state = ui_state.write().await;
+                            if let Some(pair) = state.host_checks.get_mut(idx) {
+                                let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                status.time = Some(sync.clone());
+                            }
+                            state.alert_mutes.is_muted(idx)
+                        };
+
+                        let config = match time_config.as_ref() {
+                            Some(config) => config,
+                            None => continue,
+                        };
+                        let subject = AlertSubject {
+                            slot: idx * 2 + node_idx,
+                            validator_idx: idx,
+                            condition: condition_key("time_sync", idx, Some(node_idx)),
+                            identity: &validator_status.validator_pair.identity_pubkey,
+                            node_label: Some(&node.node.label),
+                            muted,
+                        };
+                        evaluate_alert(
+                            &mut time_tracker,
+                            crate::alert::time_sync_problem(config, &sync),
+                            "Clock Out Of Sync",
+                            &subject,
+                            &sinks,
+                            |problem| format!("Clock out of sync: {}", problem),
+                            |alert_mgr, problem| async move {
+                                alert_mgr
+                                    .send_time_sync_alert(
+                                        &validator_status.validator_pair.identity_pubkey,
+                                        &node.node.label,
+                                        &problem,
+                                    )
+                                    .await
+                            },
+                        )
+                        .await;
+                    }
+                }
+            }
+        });
+
+        // Process uptime task - an uptime lower than the last one seen means
+        // the validator restarted, whether or not anyone noticed
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            // Last uptime seen per node (validator * 2 + node), kept while the
+            // process is down so its return counts as a restart
+            let mut last_uptime: Vec<Option<u64>> = vec![None; app_state.validator_statuses.len() * 2];
+
+            loop {
+                interval.tick().await;
+
+                let statuses = ui_state.read().await.validator_statuses.clone();
+                for (idx, validator_status) in statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key,
+                            None => continue,
+                        };
+
+                        let uptime = match check_process_uptime(&ssh_pool, &node.node, ssh_key).await {
+                            Ok(uptime) => uptime,
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("Process uptime check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                continue;
+                            }
+                        };
+
+                        let slot = idx * 2 + node_idx;
+                        let restarted_from = match (last_uptime[slot], uptime) {
+                            (Some(previous), Some(current)) if current < previous => Some(previous),
+                            _ => None,
+                        };
+                        if uptime.is_some() {
+                            last_uptime[slot] = uptime;
+                        }
+
+                        let muted = {
+                            let mut state = ui_state.write().await;
+                            if let Some(pair) = state.host_checks.get_mut(idx) {
+                                let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                                let process = status.process.get_or_insert_with(ProcessUptime::default);
+                                process.uptime_seconds = uptime;
+                                if restarted_from.is_some() {
+                                    process.restarts += 1;
+                                }
+                            }
+                            state.alert_mutes.is_muted(idx)
+                        };
+
+                        let (previous, current) = match (restarted_from, uptime) {
+                            (Some(previous), Some(current)) => (previous, current),
+                            _ => continue,
+                        };
+                        let identity = &validator_status.validator_pair.identity_pubkey;
+                        let _ = log_sender.send(LogMessage {
+                            host: node.node.label.clone(),
+                            message: format!(
+                                "Validator restarted: up {} after {}",
+                                crate::host_checks::format_uptime(current),
+                                crate::host_checks::format_uptime(previous)
+                            ),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                        if !muted {
+                            if let Some(alert_mgr) = alert_manager.as_ref() {
+                                if alert_mgr
+                                    .send_validator_restart_alert(identity, &node.node.label, previous, current)
+                                    .await
+                                    .is_ok()
+                                {
+                                    crate::history::record_alert("Validator Restart", identity);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        // Ledger size task - du walks the whole ledger, so sample hourly and
+        // judge growth over the last day of samples
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(3600));
+
+            loop {
+                interval.tick().await;
+
+                let statuses = ui_state.read().await.validator_statuses.clone();
+                for (idx, validator_status) in statuses.iter().enumerate() {
+                    let identity = &validator_status.validator_pair.identity_pubkey;
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let (ssh_key, ledger_path) = match (
+                            app_state.detected_ssh_keys.get(&node.node.host),
+                            node.ledger_path.as_deref(),
+                        ) {
+                            (Some(key), Some(path)) => (key, path),
+                            _ => continue,
+                        };
+
+                        let bytes = match check_ledger_size(&ssh_pool, &node.node, ssh_key, ledger_path).await {
+                            Ok(bytes) => bytes,
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: node.node.label.clone(),
+                                    message: format!("Ledger size check failed: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                continue;
+                            }
+                        };
+                        crate::history::record_ledger_size(identity, &node.node.label, bytes);
+
+                        let since = chrono::Utc::now().timestamp() - 86_400;
+                        let mut samples = crate::history::ledger_sizes_since(identity, &node.node.label, since);
+                        if samples.is_empty() {
+                            // No history store; the current size is all there is
+                            samples.push((chrono::Utc::now().timestamp(), bytes));
+                        }
+
+                        let mut state = ui_state.write().await;
+                        if let Some(pair) = state.host_checks.get_mut(idx) {
+                            let status = if node_idx == 0 { &mut pair.node_0 } else { &mut pair.node_1 };
+                            let free_bytes = status
+                                .storage
+                                .as_ref()
+                                .and_then(|s| s.ledger_disk.as_ref())
+                                .map(|disk| disk.free_bytes);
+                            status.ledger_growth = ledger_growth(&samples, free_bytes);
+                        }
+                    }
+                }
+            }
+        });
+
+        // Switch lock task - show when another operator is switching
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(15));
+
+            loop {
+                interval.tick().await;
+
+                let mut locks = Vec::new();
+                for validator_status in &app_state.validator_statuses {
+                    let mut holder = None;
+                    for node in validator_status.nodes_with_status.iter().take(2) {
+                        if let Some(ssh_key) = app_state.detected_ssh_keys.get(&node.node.host) {
+                            holder = crate::switch_lock::lock_holder(
+                                &ssh_pool,
+                                &node.node,
+                                ssh_key,
+                                &validator_status.validator_pair.identity_pubkey,
+                            )
+                            .await;
+                        }
+                        if holder.is_some() {
+                            break;
+                        }
+                    }
+                    locks.push(holder);
+                }
+                ui_state.write().await.switch_locks = locks;
+            }
+        });
+
+        // Role reconciliation task - pick up switches done outside svs
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let ssh_pool = Arc::clone(&self.ssh_pool);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(ROLE_RECONCILE_SECONDS));
+            let catchup_timeout_seconds = app_state.config.ssh.clone().unwrap_or_default().catchup_timeout_seconds;
+            interval.tick().await; // Roles were just detected at startup
+
+            loop {
+                interval.tick().await;
+
+                let validators = ui_state.read().await.validator_statuses.clone();
+                for (validator_idx, validator_status) in validators.into_iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+                            Some(key) => key.clone(),
+                            None => continue,
+                        };
+                        refresh_node_status_and_identity(
+                            validator_idx,
+                            node_idx,
+                            node.clone(),
+                            validator_status.validator_pair.clone(),
+                            Arc::clone(&ssh_pool),
+                            ssh_key,
+                            Arc::clone(&ui_state),
+                            true,
+                            catchup_timeout_seconds,
+                        )
+                        .await;
+                    }
+
+                    let state = ui_state.read().await;
+                    let current = match state.validator_statuses.get(validator_idx) {
+                        Some(current) => current,
+                        None => continue,
+                    };
+                    if let Some(message) =
+                        role_change_message(&validator_status.nodes_with_status, &current.nodes_with_status)
+                    {
+                        let _ = log_sender.send(LogMessage {
+                            host: format!("validator-{}", validator_idx),
+                            message,
+                            timestamp: Instant::now(),
+                            level: LogLevel::Warning,
+                        });
+                    }
+                }
+            }
+        });
+
+        // Sticky warnings task - keep the panel in step with the latest state
+        let ui_state = Arc::clone(&self.ui_state);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(5));
+
+            loop {
+                interval.tick().await;
+
+                let mut state = ui_state.write().await;
+                let mut active = collect_warnings(&state.validator_statuses, &state.host_checks);
+                for (idx, holder) in state.switch_locks.iter().enumerate() {
+                    if let Some(holder) = holder {
+                        active.push((
+                            format!("lock:{}", idx),
+                            format!("Validator {} switch in progress by {}", idx + 1, holder),
+                        ));
+                    }
+                }
+                state.sticky_warnings.sync(active);
+            }
+        });
+
+        // Vote landing rate task - sampling signatures is heavier, so refresh less often
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let validator_pair = &validator_status.validator_pair;
+
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    match fetch_vote_landing_stats(&rpc_url, &validator_pair.vote_pubkey, 200)
+                        .await
+                    {
+                        Ok(stats) => {
+                            let mut state = ui_state.write().await;
+                            if let Some(slot) = state.vote_landing_stats.get_mut(idx) {
+                                *slot = Some(stats);
+                            }
+                        }
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch vote landing stats: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                    }
+                }
+            }
+        });
+
+        // Epoch rewards task - rewards are credited once per epoch, so hourly is plenty
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(3600));
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let validator_pair = &validator_status.validator_pair;
+
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    match fetch_epoch_rewards(&rpc_url, &validator_pair.vote_pubkey, REWARD_EPOCHS)
+                        .await
+                    {
+                        Ok(rewards) => {
+                            let mut state = ui_state.write().await;
+                            if let Some(slot) = state.epoch_rewards.get_mut(idx) {
+                                *slot = rewards;
+                            }
+                        }
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch epoch rewards: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                    }
+                }
+            }
+        });
+
+        // Epoch progress task - often enough for the boundary countdown to stay close
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(30));
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let rpc_url = best_rpc(
+                        &app_state.rpc_selectors,
+                        idx,
+                        &validator_status.validator_pair.rpc,
+                    )
+                    .await;
+
+                    match fetch_epoch_info(&rpc_url).await {
+                        Ok(info) => {
+                            let mut state = ui_state.write().await;
+                            if let Some(slot) = state.epoch_info.get_mut(idx) {
+                                *slot = Some(info);
+                            }
+                        }
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch epoch info: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                    }
+                }
+            }
+        });
+
+        // Block production task - leader slots come a few at a time, so every
+        // couple of minutes catches a rising skip rate early enough
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(120));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            let skip_rate_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.skip_rate.clone());
+            // Low severity: the rate moves slowly, so once an hour is enough
+            let mut skip_rate_tracker =
+                AlertTracker::with_cooldown(app_state.validator_statuses.len(), 3600);
+            // Newest skipped slot already logged, so each miss is logged once
+            let mut last_logged_skip: Vec<u64> = vec![0; app_state.validator_statuses.len()];
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let validator_pair = &validator_status.validator_pair;
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    match fetch_recent_leader_slots(
+                        &rpc_url,
+                        &validator_pair.identity_pubkey,
+                        RECENT_LEADER_SLOTS,
+                    )
+                    .await
+                    {
+                        Ok(outcomes) => {
+                            let threshold = last_logged_skip[idx];
+                            for outcome in outcomes
+                                .iter()
+                                .filter(|o| !o.produced && o.slot > threshold)
+                            {
+                                crate::history::record_skipped_slot(
+                                    &validator_pair.identity_pubkey,
+                                    outcome.slot,
+                                );
+                                let _ = log_sender.send(LogMessage {
+                                    host: format!("validator-{}", idx),
+                                    message: format!("Skipped leader slot {}", outcome.slot),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                last_logged_skip[idx] = outcome.slot;
+                            }
+                            let mut state = ui_state.write().await;
+                            if let Some(slot) = state.recent_leader_slots.get_mut(idx) {
+                                *slot = Some(outcomes);
+                            }
+                        }
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch recent leader slots: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                    }
+
+                    let (leader_slots, blocks_produced) =
+                        match fetch_block_production(&rpc_url, &validator_pair.identity_pubkey)
+                            .await
+                        {
+                            Ok(production) => production,
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: format!("validator-{}", idx),
+                                    message: format!("Failed to fetch block production: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Warning,
+                                });
+                                continue;
+                            }
+                        };
+
+                    let (muted, epoch) = {
+                        let mut state = ui_state.write().await;
+                        if let Some(slot) = state.block_production.get_mut(idx) {
+                            *slot = Some((leader_slots, blocks_produced));
+                        }
+                        let epoch = state.epoch_info.get(idx).and_then(|e| e.as_ref()).map(|e| e.epoch);
+                        (state.alert_mutes.is_muted(idx), epoch)
+                    };
+
+                    let config = match skip_rate_config.as_ref() {
+                        Some(config) => config,
+                        None => continue,
+                    };
+                    // Only a rate over enough slots clears the alert, not
+                    // the empty count at the start of a new epoch
+                    let skip_rate = skip_rate_breach(config, leader_slots, blocks_produced);
+                    if skip_rate.is_none() && leader_slots < config.min_leader_slots {
+                        continue;
+                    }
+                    let subject = AlertSubject {
+                        slot: idx,
+                        validator_idx: idx,
+                        condition: condition_key("skip_rate", idx, None),
+                        identity: &validator_pair.identity_pubkey,
+                        node_label: None,
+                        muted,
+                    };
+                    evaluate_alert(
+                        &mut skip_rate_tracker,
+                        skip_rate,
+                        "Skip Rate",
+                        &subject,
+                        &sinks,
+                        |skip_rate| {
+                            format!(
+                                "Skip rate {:.1}% is above {:.1}% ({} of {} leader slots skipped)",
+                                skip_rate,
+                                config.threshold_percent,
+                                leader_slots.saturating_sub(blocks_produced),
+                                leader_slots
+                            )
+                        },
+                        |alert_mgr, skip_rate| async move {
+                            alert_mgr
+                                .send_skip_rate_alert(
+                                    &validator_pair.identity_pubkey,
+                                    epoch,
+                                    leader_slots,
+                                    blocks_produced,
+                                    skip_rate,
+                                    config.threshold_percent,
+                                )
+                                .await
+                        },
+                    )
+                    .await;
+                }
+            }
+        });
+
+        // Vote credits task - getVoteAccounts with every account is large, and
+        // efficiency over an epoch moves slowly
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(300));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled)
+                .map(|config| AlertManager::new(config.clone()));
+            let credits_config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.vote_credits.clone());
+            let mut credits_tracker =
+                AlertTracker::with_cooldown(app_state.validator_statuses.len(), 3600);
+            let sinks = AlertSinks {
+                ui_state: &ui_state,
+                alert_manager: alert_manager.as_ref(),
+                log_sender: &log_sender,
+            };
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let validator_pair = &validator_status.validator_pair;
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    let credits = match fetch_vote_credits(&rpc_url, &validator_pair.vote_pubkey).await {
+                        Ok(credits) => credits,
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch vote credits: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                            continue;
+                        }
+                    };
+
+                    let muted = {
+                        let mut state = ui_state.write().await;
+                        if let Some(slot) = state.vote_credits.get_mut(idx) {
+                            *slot = Some(credits);
+                        }
+                        state.alert_mutes.is_muted(idx)
+                    };
+
+                    let config = match credits_config.as_ref() {
+                        Some(config) => config,
+                        None => continue,
+                    };
+                    // Early in an epoch there is nothing to judge, so keep any alert open
+                    let efficiency = vote_credits_breach(config, &credits);
+                    if efficiency.is_none() && credits.cluster_max < crate::alert::MIN_CLUSTER_CREDITS {
+                        continue;
+                    }
+                    let subject = AlertSubject {
+                        slot: idx,
+                        validator_idx: idx,
+                        condition: condition_key("vote_credits", idx, None),
+                        identity: &validator_pair.identity_pubkey,
+                        node_label: None,
+                        muted,
+                    };
+                    evaluate_alert(
+                        &mut credits_tracker,
+                        efficiency,
+                        "Vote Credits",
+                        &subject,
+                        &sinks,
+                        |efficiency| {
+                            format!(
+                                "Vote credits at {:.1}% of the cluster best, below {:.1}%",
+                                efficiency, config.min_efficiency_percent
+                            )
+                        },
+                        |alert_mgr, efficiency| async move {
+                            alert_mgr
+                                .send_vote_credits_alert(
+                                    &validator_pair.identity_pubkey,
+                                    &credits,
+                                    efficiency,
+                                    config.min_efficiency_percent,
+                                )
+                                .await
+                        },
+                    )
+                    .await;
+                }
+            }
+        });
+
+        // Stake task - refreshed on every new epoch, and every half hour in
+        // between so a deactivation started mid-epoch shows up
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+            let mut last_fetch: Vec<Option<(u64, Instant)>> =
+                vec![None; app_state.validator_statuses.len()];
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let epoch = ui_state
+                        .read()
+                        .await
+                        .epoch_info
+                        .get(idx)
+                        .and_then(|e| e.as_ref())
+                        .map(|e| e.epoch);
+                    let due = match last_fetch[idx] {
+                        Some((fetched_epoch, at)) => {
+                            epoch.is_some_and(|epoch| epoch != fetched_epoch)
+                                || at.elapsed() >= Duration::from_secs(1800)
+                        }
+                        None => true,
+                    };
+                    if !due {
+                        continue;
+                    }
+
+                    let validator_pair = &validator_status.validator_pair;
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    match fetch_stake_summary(&rpc_url, &validator_pair.vote_pubkey).await {
+                        Ok(stake) => {
+                            last_fetch[idx] = Some((stake.epoch, Instant::now()));
+                            let mut state = ui_state.write().await;
+                            if let Some(slot) = state.stake.get_mut(idx) {
+                                *slot = Some(stake);
+                            }
+                        }
+                        Err(e) => {
+                            // Retry at the next half-hour mark, not every minute
+                            last_fetch[idx] = Some((epoch.unwrap_or(0), Instant::now()));
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch stake: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
+                        }
+                    }
+                }
+            }
+        });
+
+        // Leader schedule task - the schedule is fixed per epoch, this only
+        // re-anchors the countdown to the actual slot
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+
+            loop {
+                interval.tick().await;
+
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let validator_pair = &validator_status.validator_pair;
+                    let rpc_url =
+                        best_rpc(&app_state.rpc_selectors, idx, &validator_pair.rpc).await;
+
+                    match fetch_upcoming_leader_slots(&rpc_url, &validator_pair.identity_pubkey).await {
+                        Ok((current_slot, slots)) => {
+                            let mut state = ui_state.write().await;
+                            if let Some(schedule) = state.leader_schedules.get_mut(idx) {
+                                *schedule = Some(LeaderSchedule {
+                                    current_slot,
+                                    fetched_at: Instant::now(),
+                                    slots,
+                                });
+                            }
+                        }
+                        Err(e) => {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("Failed to fetch leader schedule: {}", e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Warning,
+                            });
                         }
-                    } else {
-                        // RPC failed - preserve existing slot time instead of setting to None
-                        new_increments.push(None);
-                        new_slot_times.push(
-                            state.last_vote_slot_times.get(idx).and_then(|&v| v)
-                        );
                     }
                 }
+            }
+        });
 
-                // Update previous slots
-                state.previous_last_slots = state
-                    .vote_data
-                    .iter()
-                    .map(|v| {
-                        v.as_ref()
-                            .and_then(|d| d.recent_votes.last().map(|v| v.slot))
-                    })
-                    .collect();
+        // Cluster version task - releases roll out over days, so half-hourly
+        // is plenty to notice a node falling below the supermajority
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+        let log_sender = self.log_sender.clone();
 
-                state.vote_data = new_vote_data;
-                state.increment_times = new_increments;
-                state.last_vote_slot_times = new_slot_times;
-                state.last_vote_refresh = Instant::now();
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(1800));
+
+            let alert_manager = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .filter(|config| config.enabled && config.version_lag)
+                .map(|config| AlertManager::new(config.clone()));
+            // Supermajority version each lagging (validator, node) was reported
+            // against, so every new release is reported once
+            let mut reported: std::collections::HashMap<(usize, usize), String> =
+                std::collections::HashMap::new();
+
+            loop {
+                interval.tick().await;
+
+                let rpc_url = match app_state.validator_statuses.first() {
+                    Some(status) => best_rpc(&app_state.rpc_selectors, 0, &status.validator_pair.rpc).await,
+                    None => continue,
+                };
+                let stake_by_version = match crate::solana_rpc::fetch_cluster_version_stake(&rpc_url).await {
+                    Ok(stake_by_version) => stake_by_version,
+                    Err(e) => {
+                        let _ = log_sender.send(LogMessage {
+                            host: "cluster".to_string(),
+                            message: format!("Failed to fetch cluster versions: {}", e),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Warning,
+                        });
+                        continue;
+                    }
+                };
+
+                let (nodes, muted) = {
+                    let mut state = ui_state.write().await;
+                    state.cluster_version_stake = Some(stake_by_version.clone());
+                    let nodes: Vec<(usize, usize, String, String, Option<String>)> = state
+                        .validator_statuses
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(idx, status)| {
+                            status.nodes_with_status.iter().enumerate().map(move |(node_idx, node)| {
+                                (
+                                    idx,
+                                    node_idx,
+                                    status.validator_pair.identity_pubkey.clone(),
+                                    node.node.label.clone(),
+                                    node.version.clone(),
+                                )
+                            })
+                        })
+                        .collect();
+                    let muted: Vec<bool> = (0..state.validator_statuses.len())
+                        .map(|idx| state.alert_mutes.is_muted(idx))
+                        .collect();
+                    (nodes, muted)
+                };
+
+                for (idx, node_idx, identity, label, version) in nodes {
+                    let version = match version {
+                        Some(version) => version,
+                        None => continue,
+                    };
+                    let supermajority = crate::sfdp::supermajority_version(&version, &stake_by_version);
+                    let lagging = supermajority
+                        .as_deref()
+                        .and_then(|target| crate::sfdp::version_older(&version, target))
+                        .unwrap_or(false);
+
+                    if !lagging {
+                        if reported.remove(&(idx, node_idx)).is_some() {
+                            let _ = log_sender.send(LogMessage {
+                                host: format!("validator-{}", idx),
+                                message: format!("{} is on the cluster's supermajority version again", label),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Info,
+                            });
+                        }
+                        continue;
+                    }
+                    let supermajority = supermajority.unwrap_or_default();
+                    if reported.get(&(idx, node_idx)) == Some(&supermajority) {
+                        continue;
+                    }
+
+                    let _ = log_sender.send(LogMessage {
+                        host: format!("validator-{}", idx),
+                        message: format!(
+                            "{} runs {}, below the {} two thirds of the cluster's stake runs",
+                            label, version, supermajority
+                        ),
+                        timestamp: Instant::now(),
+                        level: LogLevel::Warning,
+                    });
+
+                    if let Some(alert_mgr) = alert_manager.as_ref() {
+                        if muted.get(idx).copied().unwrap_or(false) {
+                            // Report again once unmuted
+                            continue;
+                        }
+                        match alert_mgr
+                            .send_version_lag_alert(&identity, &label, &version, &supermajority)
+                            .await
+                        {
+                            Ok(()) => crate::history::record_alert("Version Lag", &identity),
+                            Err(e) => {
+                                let _ = log_sender.send(LogMessage {
+                                    host: format!("validator-{}", idx),
+                                    message: format!("Failed to send version lag alert: {}", e),
+                                    timestamp: Instant::now(),
+                                    level: LogLevel::Error,
+                                });
+                                continue;
+                            }
+                        }
+                    }
+                    reported.insert((idx, node_idx), supermajority);
+                }
             }
         });
 
@@ -636,19 +3166,12 @@ impl EnhancedStatusApp {
                 {
                     let mut state = ui_state.write().await;
                     for catchup in &mut state.catchup_data {
-                        if catchup.node_0.is_some() {
-                            catchup.node_0 = Some(CatchupStatus {
-                                status: "Checking...".to_string(),
-                                last_updated: Instant::now(),
-                                is_streaming: false,
-                            });
-                        }
-                        if catchup.node_1.is_some() {
-                            catchup.node_1 = Some(CatchupStatus {
-                                status: "Checking...".to_string(),
-                                last_updated: Instant::now(),
-                                is_streaming: false,
-                            });
+                        for status in [&mut catchup.node_0, &mut catchup.node_1] {
+                            if let Some(status) = status.as_mut() {
+                                status.status = "Checking...".to_string();
+                                status.last_updated = Instant::now();
+                                status.is_streaming = false;
+                            }
                         }
                     }
                 }
@@ -838,7 +3361,25 @@ impl EnhancedStatusApp {
         let log_sender = self.log_sender.clone();
 
         tokio::spawn(async move {
-            let mut interval = interval(Duration::from_secs(30));
+            // Healthy nodes are checked every SSH_HEALTH_INTERVAL; failing ones
+            // back off, and 'r' in the TUI retries them right away
+            let mut interval = interval(SSH_HEALTH_TICK);
+            let health_check_timeout_seconds = app_state
+                .config
+                .ssh
+                .clone()
+                .unwrap_or_default()
+                .health_check_timeout_seconds;
+            let ssh_retry = Arc::clone(&ui_state.read().await.ssh_retry);
+            let mut backoffs: Vec<[crate::ssh::ReconnectBackoff; 2]> = app_state
+                .validator_statuses
+                .iter()
+                .map(|_| {
+                    std::array::from_fn(|_| {
+                        crate::ssh::ReconnectBackoff::new(SSH_HEALTH_INTERVAL, SSH_RETRY_MAX_INTERVAL)
+                    })
+                })
+                .collect();
 
             // Initialize alert manager and tracker if alerts are configured
             let alert_manager = app_state
@@ -855,12 +3396,59 @@ impl EnhancedStatusApp {
             );
 
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = ssh_retry.notified() => {
+                        backoffs.iter_mut().flatten().for_each(|backoff| backoff.retry_now());
+                    }
+                }
+
+                // Check SSH health for all nodes that are due, concurrently so a
+                // slow host only delays its own result
+                let now = Instant::now();
+                let mut due_checks = Vec::new();
+                for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate().take(2) {
+                        if !backoffs[idx][node_idx].is_due(now) {
+                            continue;
+                        }
+                        if let Some(ssh_key) = app_state.detected_ssh_keys.get(&node.node.host) {
+                            due_checks.push((idx, node_idx, node, ssh_key));
+                        }
+                    }
+                }
+                let semaphore = tokio::sync::Semaphore::new(SSH_HEALTH_CONCURRENCY);
+                let outcomes = futures::future::join_all(due_checks.into_iter().map(
+                    |(idx, node_idx, node, ssh_key)| {
+                        let semaphore = &semaphore;
+                        let ssh_pool = &ssh_pool;
+                        async move {
+                            let _permit = semaphore.acquire().await;
+                            let result = crate::commands::switch::with_step_timeout(
+                                "SSH health check",
+                                health_check_timeout_seconds,
+                                ssh_pool.execute_command(&node.node, ssh_key, "true"),
+                            )
+                            .await;
+                            (idx, node_idx, result)
+                        }
+                    },
+                ))
+                .await;
+                let mut check_results: Vec<[Option<Result<String>>; 2]> =
+                    app_state.validator_statuses.iter().map(|_| [None, None]).collect();
+                for (idx, node_idx, result) in outcomes {
+                    check_results[idx][node_idx] = Some(result);
+                }
 
-                // Check SSH health for all nodes
                 let mut new_ssh_health_data = Vec::new();
 
                 for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+                    let muted = ui_state.read().await.alert_mutes.is_muted(idx);
+                    if !muted {
+                        alert_tracker.ssh_failure_tracker[0].resume(idx);
+                    }
+
                     let mut node_pair = NodePairSshStatus {
                         node_0: SshHealthStatus {
                             is_healthy: false,
@@ -880,23 +3468,53 @@ impl EnhancedStatusApp {
                         state.ssh_health_data.get(idx).cloned()
                     };
 
+                    // Node 0 failure alert, held until node 1 is checked so a
+                    // shared outage is reported once
+                    let mut pending_ssh_alert = None;
+
                     // Check node 0
                     if validator_status.nodes_with_status.len() > 0 {
                         let node_0 = &validator_status.nodes_with_status[0];
-                        if let Some(ssh_key) = app_state.detected_ssh_keys.get(&node_0.node.host) {
-                            match ssh_pool
-                                .execute_command(&node_0.node, ssh_key, "true")
-                                .await
-                            {
+                        if !backoffs[idx][0].is_due(now) {
+                            // Not due yet, keep the last result
+                            if let Some(ref current) = current_state {
+                                node_pair.node_0 = current.node_0.clone();
+                            }
+                        } else if let Some(result) = check_results[idx][0].take() {
+                            match result {
                                 Ok(_) => {
+                                    backoffs[idx][0].record_success(Instant::now());
                                     node_pair.node_0.is_healthy = true;
                                     node_pair.node_0.last_success = Some(Instant::now());
                                     node_pair.node_0.failure_start = None;
                                     
                                     // Update health tracking
-                                    {
+                                    let ssh_outage_seconds = {
                                         let mut state = ui_state.write().await;
+                                        let outage = state.validator_health[idx].ssh_status.seconds_since_first_failure();
                                         state.validator_health[idx].ssh_status.record_success();
+                                        state.alert_history.resolve(&condition_key("ssh", idx, Some(0)));
+                                        outage
+                                    };
+                                    if ssh_outage_seconds.is_some() {
+                                        crate::history::record_failure(
+                                            "ssh",
+                                            &validator_status.validator_pair.identity_pubkey,
+                                            &node_0.node.label,
+                                            None,
+                                        );
+                                    }
+
+                                    // Send recovery notice if an SSH failure alert was sent
+                                    if alert_tracker.ssh_failure_tracker[0].resolve(idx) && !muted {
+                                        if let Some(alert_mgr) = alert_manager.as_ref() {
+                                            let _ = alert_mgr.send_recovery_alert(
+                                                "SSH Connection",
+                                                &validator_status.validator_pair.identity_pubkey,
+                                                Some(&node_0.node.label),
+                                                ssh_outage_seconds,
+                                            ).await;
+                                        }
                                     }
                                     
                                     let _ = log_sender.send(LogMessage {
@@ -907,6 +3525,7 @@ impl EnhancedStatusApp {
                                     });
                                 }
                                 Err(e) => {
+                                    let retry_in = backoffs[idx][0].record_failure(Instant::now(), crate::ssh::jitter());
                                     node_pair.node_0.is_healthy = false;
                                     // Preserve last_success from previous state
                                     if let Some(ref current) = current_state {
@@ -925,9 +3544,18 @@ impl EnhancedStatusApp {
                                     let (should_alert_ssh, consecutive_failures, seconds_since_first) = {
                                         let mut state = ui_state.write().await;
                                         state.validator_health[idx].ssh_status.record_failure(e.to_string());
+                                        state.digest_counters[idx].ssh_failures += 1;
                                         
                                         let tracker = &state.validator_health[idx].ssh_status;
                                         let consecutive = tracker.consecutive_failures;
+                                        if consecutive == 1 {
+                                            crate::history::record_failure(
+                                                "ssh",
+                                                &validator_status.validator_pair.identity_pubkey,
+                                                &node_0.node.label,
+                                                Some(&e.to_string()),
+                                            );
+                                        }
                                         let seconds = tracker.seconds_since_first_failure().unwrap_or(0);
                                         
                                         let config = app_state.config.alert_config.as_ref();
@@ -939,22 +3567,25 @@ impl EnhancedStatusApp {
                                         (should_alert, consecutive, seconds)
                                     };
                                     
-                                    // Send SSH failure alert if needed
-                                    if should_alert_ssh {
-                                        if let Some(alert_mgr) = alert_manager.as_ref() {
-                                            let _ = alert_mgr.send_ssh_failure_alert(
-                                                &validator_status.validator_pair.identity_pubkey,
-                                                &node_0.node.label,
-                                                consecutive_failures,
-                                                seconds_since_first,
-                                                &e.to_string()
-                                            ).await;
-                                        }
+                                    // Send SSH failure alert if needed, unless muted or
+                                    // already acknowledged from Telegram
+                                    let condition = condition_key("ssh", idx, Some(0));
+                                    if should_alert_ssh && muted {
+                                        alert_tracker.ssh_failure_tracker[0].suppress(idx);
+                                    } else if should_alert_ssh
+                                        && !ui_state.read().await.alert_history.is_acknowledged(&condition)
+                                    {
+                                        pending_ssh_alert =
+                                            Some((consecutive_failures, seconds_since_first, e.to_string()));
                                     }
                                     
                                     let _ = log_sender.send(LogMessage {
                                         host: node_0.node.label.clone(),
-                                        message: format!("SSH health check failed: {}", e),
+                                        message: format!(
+                                            "SSH health check failed: {} (retrying in {}s)",
+                                            e,
+                                            retry_in.as_secs()
+                                        ),
                                         timestamp: Instant::now(),
                                         level: LogLevel::Error,
                                     });
@@ -966,15 +3597,26 @@ impl EnhancedStatusApp {
                     // Check node 1
                     if validator_status.nodes_with_status.len() > 1 {
                         let node_1 = &validator_status.nodes_with_status[1];
-                        if let Some(ssh_key) = app_state.detected_ssh_keys.get(&node_1.node.host) {
-                            match ssh_pool
-                                .execute_command(&node_1.node, ssh_key, "true")
-                                .await
-                            {
+                        if !backoffs[idx][1].is_due(now) {
+                            // Not due yet, keep the last result
+                            if let Some(ref current) = current_state {
+                                node_pair.node_1 = current.node_1.clone();
+                            }
+                        } else if let Some(result) = check_results[idx][1].take() {
+                            match result {
                                 Ok(_) => {
+                                    backoffs[idx][1].record_success(Instant::now());
                                     node_pair.node_1.is_healthy = true;
                                     node_pair.node_1.last_success = Some(Instant::now());
                                     node_pair.node_1.failure_start = None;
+                                    if current_state.as_ref().is_some_and(|c| !c.node_1.is_healthy) {
+                                        crate::history::record_failure(
+                                            "ssh",
+                                            &validator_status.validator_pair.identity_pubkey,
+                                            &node_1.node.label,
+                                            None,
+                                        );
+                                    }
                                     
                                     let _ = log_sender.send(LogMessage {
                                         host: node_1.node.label.clone(),
@@ -984,6 +3626,7 @@ impl EnhancedStatusApp {
                                     });
                                 }
                                 Err(e) => {
+                                    let retry_in = backoffs[idx][1].record_failure(Instant::now(), crate::ssh::jitter());
                                     node_pair.node_1.is_healthy = false;
                                     // Preserve last_success from previous state
                                     if let Some(ref current) = current_state {
@@ -997,10 +3640,22 @@ impl EnhancedStatusApp {
                                     } else {
                                         node_pair.node_1.failure_start = Some(Instant::now());
                                     }
+
+                                    ui_state.write().await.digest_counters[idx].ssh_failures += 1;
+                                    crate::history::record_failure(
+                                        "ssh",
+                                        &validator_status.validator_pair.identity_pubkey,
+                                        &node_1.node.label,
+                                        Some(&e.to_string()),
+                                    );
                                     
                                     let _ = log_sender.send(LogMessage {
                                         host: node_1.node.label.clone(),
-                                        message: format!("SSH health check failed: {}", e),
+                                        message: format!(
+                                            "SSH health check failed: {} (retrying in {}s)",
+                                            e,
+                                            retry_in.as_secs()
+                                        ),
                                         timestamp: Instant::now(),
                                         level: LogLevel::Error,
                                     });
@@ -1009,6 +3664,56 @@ impl EnhancedStatusApp {
                         }
                     }
 
+                    if let (Some((consecutive_failures, seconds_since_first, last_error)), Some(alert_mgr)) =
+                        (pending_ssh_alert, alert_manager.as_ref())
+                    {
+                        let condition = condition_key("ssh", idx, Some(0));
+                        let alert_mgr = alert_mgr.with_ack(&condition);
+                        let identity = &validator_status.validator_pair.identity_pubkey;
+                        let node_labels: Vec<&str> = validator_status
+                            .nodes_with_status
+                            .iter()
+                            .map(|n| n.node.label.as_str())
+                            .collect();
+
+                        let both_down = !node_pair.node_1.is_healthy
+                            && node_pair.node_1.failure_start.is_some();
+                        let (title, result) = if both_down {
+                            let result = alert_mgr
+                                .send_common_cause_alert(
+                                    identity,
+                                    CommonCause::SshUnreachable,
+                                    &node_labels,
+                                    &format!(
+                                        "{} consecutive failures over {} seconds: {}",
+                                        consecutive_failures, seconds_since_first, last_error
+                                    ),
+                                )
+                                .await;
+                            ("Common-Cause SSH Failure", result)
+                        } else {
+                            let result = alert_mgr
+                                .send_ssh_failure_alert(
+                                    identity,
+                                    node_labels[0],
+                                    consecutive_failures,
+                                    seconds_since_first,
+                                    &last_error,
+                                )
+                                .await;
+                            ("SSH Connection", result)
+                        };
+
+                        if result.is_ok() {
+                            ui_state
+                                .write()
+                                .await
+                                .alert_history
+                                .record(&condition, title, identity);
+                            crate::history::record_alert(title, identity);
+                        }
+                    }
+
                     new_ssh_health_data.push(node_pair);
                 }
 
@@ -1019,8 +3724,566 @@ impl EnhancedStatusApp {
             }
         });
 
-        // Telegram bot polling has been removed - bot only responds to messages now
+        // Kiosk auto-reconnect task - nobody can press 'r' on a wall display, so
+        // re-run node detection periodically, sooner while SSH is failing
+        let ui_state = Arc::clone(&self.ui_state);
+        let app_state = Arc::clone(&self.app_state);
+
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(60));
+            let mut last_refresh = Instant::now();
+
+            loop {
+                interval.tick().await;
+
+                let (kiosk, ssh_failing) = {
+                    let state = ui_state.read().await;
+                    let ssh_failing = state
+                        .ssh_health_data
+                        .iter()
+                        .any(|pair| !pair.node_0.is_healthy || !pair.node_1.is_healthy);
+                    (state.kiosk, ssh_failing)
+                };
+                if !kiosk {
+                    continue;
+                }
+
+                if ssh_failing || last_refresh.elapsed() >= Duration::from_secs(300) {
+                    last_refresh = Instant::now();
+                    refresh_all_fields(Arc::clone(&app_state), Arc::clone(&ui_state)).await;
+                }
+            }
+        });
+
+        // Daily digest task - summary at a fixed UTC time, even when nothing alerted
+        let digest = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .filter(|config| config.enabled && config.telegram.is_some())
+            .and_then(|config| config.daily_digest.as_ref().map(|d| (config.clone(), d.clone())));
+
+        if let Some((alert_config, digest)) = digest {
+            let ui_state = Arc::clone(&self.ui_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                let send_at = match chrono::NaiveTime::parse_from_str(&digest.time_utc, "%H:%M") {
+                    Ok(time) => time,
+                    Err(_) => {
+                        let _ = log_sender.send(LogMessage {
+                            host: "digest".to_string(),
+                            message: format!(
+                                "Invalid daily_digest.time_utc '{}', expected HH:MM",
+                                digest.time_utc
+                            ),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                        return;
+                    }
+                };
+                let alert_manager = AlertManager::new(alert_config);
+
+                // Don't send straight away when started after today's slot
+                let now = chrono::Utc::now();
+                let mut last_sent = if now.time() >= send_at {
+                    Some(now.date_naive())
+                } else {
+                    None
+                };
+
+                let mut interval = interval(Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+
+                    let now = chrono::Utc::now();
+                    if now.time() < send_at || last_sent == Some(now.date_naive()) {
+                        continue;
+                    }
+                    last_sent = Some(now.date_naive());
+
+                    let (uptime, counters, statuses) = {
+                        let mut state = ui_state.write().await;
+                        let validator_count = state.validator_statuses.len();
+                        let counters = std::mem::replace(
+                            &mut state.digest_counters,
+                            vec![DigestCounters::default(); validator_count],
+                        );
+                        (state.started_at.elapsed(), counters, state.validator_statuses.clone())
+                    };
+
+                    let entries: Vec<DigestEntry> = statuses
+                        .iter()
+                        .zip(counters.iter())
+                        .map(|(status, counters)| DigestEntry {
+                            validator_identity: &status.validator_pair.identity_pubkey,
+                            active_node: status
+                                .nodes_with_status
+                                .iter()
+                                .find(|n| n.status == crate::types::NodeStatus::Active)
+                                .map(|n| n.node.label.as_str()),
+                            counters,
+                        })
+                        .collect();
+
+                    let (message, level) = match alert_manager.send_daily_digest(uptime, &entries).await {
+                        Ok(()) => ("Daily digest sent".to_string(), LogLevel::Info),
+                        Err(e) => (format!("Failed to send daily digest: {}", e), LogLevel::Error),
+                    };
+                    let _ = log_sender.send(LogMessage {
+                        host: "digest".to_string(),
+                        message,
+                        timestamp: Instant::now(),
+                        level,
+                    });
+                }
+            });
+        }
+
+        // Weekly rewards summary - commission earned since the previous summary
+        let rewards_summary = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .filter(|config| config.enabled && config.telegram.is_some())
+            .and_then(|config| config.rewards_summary.as_ref().map(|r| (config.clone(), r.clone())));
+
+        if let Some((alert_config, summary)) = rewards_summary {
+            let ui_state = Arc::clone(&self.ui_state);
+            let log_sender = self.log_sender.clone();
+
+            tokio::spawn(async move {
+                use chrono::Datelike;
+
+                let schedule = summary
+                    .weekday
+                    .parse::<chrono::Weekday>()
+                    .ok()
+                    .zip(chrono::NaiveTime::parse_from_str(&summary.time_utc, "%H:%M").ok());
+                let (weekday, send_at) = match schedule {
+                    Some(schedule) => schedule,
+                    None => {
+                        let _ = log_sender.send(LogMessage {
+                            host: "rewards".to_string(),
+                            message: format!(
+                                "Invalid rewards_summary schedule '{} {}', expected e.g. Mon 09:00",
+                                summary.weekday, summary.time_utc
+                            ),
+                            timestamp: Instant::now(),
+                            level: LogLevel::Error,
+                        });
+                        return;
+                    }
+                };
+                let alert_manager = AlertManager::new(alert_config);
+
+                // Don't send straight away when started after this week's slot
+                let now = chrono::Utc::now();
+                let mut last_sent = if now.weekday() == weekday && now.time() >= send_at {
+                    Some(now.date_naive())
+                } else {
+                    None
+                };
+                let mut last_reported_epoch: Vec<Option<u64>> = Vec::new();
+
+                let mut interval = interval(Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+
+                    let now = chrono::Utc::now();
+                    if now.weekday() != weekday
+                        || now.time() < send_at
+                        || last_sent == Some(now.date_naive())
+                    {
+                        continue;
+                    }
+                    last_sent = Some(now.date_naive());
+
+                    let (statuses, mut rewards) = {
+                        let state = ui_state.read().await;
+                        (state.validator_statuses.clone(), state.epoch_rewards.clone())
+                    };
+                    last_reported_epoch.resize(rewards.len(), None);
+                    for (rewards, last) in rewards.iter_mut().zip(last_reported_epoch.iter_mut()) {
+                        rewards.retain(|r| !matches!(last, Some(epoch) if r.epoch <= *epoch));
+                        if let Some(newest) = rewards.last() {
+                            *last = Some(newest.epoch);
+                        }
+                    }
+
+                    let entries: Vec<RewardsEntry> = statuses
+                        .iter()
+                        .zip(rewards.iter())
+                        .map(|(status, rewards)| RewardsEntry {
+                            validator_identity: &status.validator_pair.identity_pubkey,
+                            rewards,
+                        })
+                        .collect();
+
+                    let (message, level) = match alert_manager.send_rewards_summary(&entries).await {
+                        Ok(()) => ("Weekly rewards summary sent".to_string(), LogLevel::Info),
+                        Err(e) => (
+                            format!("Failed to send weekly rewards summary: {}", e),
+                            LogLevel::Error,
+                        ),
+                    };
+                    let _ = log_sender.send(LogMessage {
+                        host: "rewards".to_string(),
+                        message,
+                        timestamp: Instant::now(),
+                        level,
+                    });
+                }
+            });
+        }
+
+        // Telegram bot commands (/status, /health) answered from the shared UI state
+        let telegram = self
+            .app_state
+            .config
+            .alert_config
+            .as_ref()
+            .filter(|config| config.enabled)
+            .and_then(|config| config.telegram.clone())
+            .filter(|telegram| telegram.commands_enabled);
+
+        if let Some(telegram) = telegram {
+            let bot = crate::telegram_bot::TelegramBot::new(
+                telegram,
+                Arc::clone(&self.app_state),
+                Arc::clone(&self.ui_state),
+                self.log_sender.clone(),
+            );
+            tokio::spawn(Arc::new(bot).run());
+        }
+    }
+}
+
+/// The validator or node a condition alert is judged for
+struct AlertSubject<'a> {
+    /// Tracker slot: the validator index, or validator * 2 + node
+    slot: usize,
+    validator_idx: usize,
+    /// Key the alert is acknowledged and recorded under
+    condition: String,
+    identity: &'a str,
+    /// Set for node conditions, whose recovery names the node
+    node_label: Option<&'a str>,
+    muted: bool,
+}
+
+/// Where condition alerts are logged, recorded and delivered
+struct AlertSinks<'a> {
+    ui_state: &'a RwLock<UiState>,
+    alert_manager: Option<&'a AlertManager>,
+    log_sender: &'a tokio::sync::mpsc::UnboundedSender<LogMessage>,
+}
+
+/// One check of a condition alert. A breach is logged and sent at most once
+/// per tracker cooldown, unless muted or acknowledged; a clear check resolves
+/// it and sends a recovery if an alert went out. A check with nothing to
+/// judge must not call this, or it would count as a recovery.
+async fn evaluate_alert<B, Fut>(
+    tracker: &mut AlertTracker,
+    breach: Option<B>,
+    kind: &str,
+    subject: &AlertSubject<'_>,
+    sinks: &AlertSinks<'_>,
+    describe: impl FnOnce(&B) -> String,
+    send: impl FnOnce(AlertManager, B) -> Fut,
+) where
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    if !subject.muted {
+        tracker.resume(subject.slot);
+    }
+
+    let breach = match breach {
+        Some(breach) => breach,
+        None => {
+            sinks.ui_state.write().await.alert_history.resolve(&subject.condition);
+            if tracker.resolve(subject.slot) && !subject.muted {
+                if let Some(alert_mgr) = sinks.alert_manager {
+                    let _ = alert_mgr
+                        .send_recovery_alert(kind, subject.identity, subject.node_label, None)
+                        .await;
+                }
+            }
+            return;
+        }
+    };
+    if !tracker.should_send_alert(subject.slot) {
+        return;
+    }
+
+    let _ = sinks.log_sender.send(LogMessage {
+        host: match subject.node_label {
+            Some(label) => label.to_string(),
+            None => format!("validator-{}", subject.validator_idx),
+        },
+        message: describe(&breach),
+        timestamp: Instant::now(),
+        level: LogLevel::Warning,
+    });
+    if subject.muted {
+        tracker.suppress(subject.slot);
+        return;
+    }
+    if sinks.ui_state.read().await.alert_history.is_acknowledged(&subject.condition) {
+        return;
+    }
+    if let Some(alert_mgr) = sinks.alert_manager {
+        if send(alert_mgr.with_ack(&subject.condition), breach).await.is_ok() {
+            sinks
+                .ui_state
+                .write()
+                .await
+                .alert_history
+                .record(&subject.condition, kind, subject.identity);
+            crate::history::record_alert(kind, subject.identity);
+        }
+    }
+}
+
+/// Balances read on every vote poll. The identity pays the vote fees; once
+/// it runs dry the validator stops voting without any other warning. Rewards
+/// only ever add to a vote account, so a drop is a withdrawal, and one that
+/// leaves it below the rent exemption minimum can't be undone.
+struct BalanceMonitor {
+    identity_config: Option<crate::types::IdentityBalanceAlertConfig>,
+    identity_tracker: AlertTracker,
+    vote_config: crate::types::VoteAccountBalanceAlertConfig,
+    vote_low_tracker: AlertTracker,
+    withdrawal_tracker: AlertTracker,
+    /// Vote account balance a withdrawal is measured from. It follows the
+    /// balance, but holds while a withdrawal is open so the alert stays
+    /// open until the account is topped up again.
+    vote_baseline: Vec<Option<u64>>,
+    /// The same for every vote account
+    vote_rent_minimum: Option<u64>,
+}
+
+impl BalanceMonitor {
+    fn new(app_state: &AppState) -> Self {
+        let alert_config = app_state.config.alert_config.as_ref();
+        let validators = app_state.validator_statuses.len();
+        Self {
+            identity_config: alert_config.and_then(|config| config.identity_balance.clone()),
+            identity_tracker: AlertTracker::with_cooldown(validators, 3600),
+            vote_config: alert_config
+                .and_then(|config| config.vote_account_balance.clone())
+                .unwrap_or_default(),
+            vote_low_tracker: AlertTracker::with_cooldown(validators, 3600),
+            withdrawal_tracker: AlertTracker::with_cooldown(validators, 3600),
+            vote_baseline: vec![None; validators],
+            vote_rent_minimum: None,
+        }
+    }
+
+    /// Read one validator's balances from `rpc_url` and judge their alerts.
+    /// A failed read is left to the vote data RPC failure alert.
+    async fn check(
+        &mut self,
+        app_state: &AppState,
+        idx: usize,
+        rpc_url: &str,
+        muted: bool,
+        sinks: &AlertSinks<'_>,
+    ) {
+        let validator_pair = &app_state.validator_statuses[idx].validator_pair;
+        self.check_identity(idx, validator_pair, rpc_url, muted, sinks).await;
+
+        if self.vote_rent_minimum.is_none() {
+            self.vote_rent_minimum = fetch_rent_exempt_minimum(rpc_url, VOTE_ACCOUNT_SIZE).await.ok();
+        }
+        let balance = match self.vote_rent_minimum {
+            Some(rent_exempt_lamports) => match fetch_balance(rpc_url, &validator_pair.vote_pubkey).await {
+                Ok(lamports) => VoteAccountBalance { lamports, rent_exempt_lamports },
+                Err(_) => return,
+            },
+            None => return,
+        };
+        sinks.ui_state.write().await.vote_account_balances[idx] = Some(balance);
+
+        // A lagging endpoint could show an older balance, so it is neither
+        // compared nor kept as the baseline
+        let stale = match app_state.rpc_selectors.get(idx) {
+            Some(selector) => selector.read().await.stale_by(rpc_url).is_some(),
+            None => false,
+        };
+        if !stale {
+            let withdrawal = self.vote_baseline[idx]
+                .filter(|baseline| {
+                    crate::alert::vote_account_drained(&self.vote_config, *baseline, balance.lamports)
+                })
+                .map(|baseline| (baseline, balance.lamports));
+            if withdrawal.is_none() {
+                self.vote_baseline[idx] = Some(balance.lamports);
+            }
+            let subject = AlertSubject {
+                slot: idx,
+                validator_idx: idx,
+                condition: condition_key("vote_account_withdrawal", idx, None),
+                identity: &validator_pair.identity_pubkey,
+                node_label: None,
+                muted,
+            };
+            evaluate_alert(
+                &mut self.withdrawal_tracker,
+                withdrawal,
+                "Vote Account Withdrawal",
+                &subject,
+                sinks,
+                |(baseline, lamports)| {
+                    format!(
+                        "Vote account balance dropped from ◎{:.4} to ◎{:.4}",
+                        lamports_to_sol(*baseline),
+                        lamports_to_sol(*lamports)
+                    )
+                },
+                |alert_mgr, (baseline, lamports)| async move {
+                    alert_mgr
+                        .send_vote_account_drain_alert(
+                            &validator_pair.identity_pubkey,
+                            &validator_pair.vote_pubkey,
+                            baseline,
+                            lamports,
+                        )
+                        .await
+                },
+            )
+            .await;
+        }
+
+        let subject = AlertSubject {
+            slot: idx,
+            validator_idx: idx,
+            condition: condition_key("vote_account_balance", idx, None),
+            identity: &validator_pair.identity_pubkey,
+            node_label: None,
+            muted,
+        };
+        evaluate_alert(
+            &mut self.vote_low_tracker,
+            Some(balance).filter(|balance| crate::alert::vote_account_balance_low(&self.vote_config, balance)),
+            "Vote Account Low Balance",
+            &subject,
+            sinks,
+            |balance| {
+                format!(
+                    "Vote account balance ◎{:.6} is near its rent exempt minimum ◎{:.6}",
+                    lamports_to_sol(balance.lamports),
+                    lamports_to_sol(balance.rent_exempt_lamports)
+                )
+            },
+            |alert_mgr, balance| async move {
+                alert_mgr
+                    .send_vote_account_low_balance_alert(
+                        &validator_pair.identity_pubkey,
+                        &validator_pair.vote_pubkey,
+                        &balance,
+                    )
+                    .await
+            },
+        )
+        .await;
+    }
+
+    async fn check_identity(
+        &mut self,
+        idx: usize,
+        validator_pair: &crate::types::ValidatorPair,
+        rpc_url: &str,
+        muted: bool,
+        sinks: &AlertSinks<'_>,
+    ) {
+        let lamports = match fetch_balance(rpc_url, &validator_pair.identity_pubkey).await {
+            Ok(lamports) => lamports,
+            Err(_) => return,
+        };
+        sinks.ui_state.write().await.identity_balances[idx] = Some(lamports);
+        let config = match self.identity_config.as_ref() {
+            Some(config) => config,
+            None => return,
+        };
+        let subject = AlertSubject {
+            slot: idx,
+            validator_idx: idx,
+            condition: condition_key("identity_balance", idx, None),
+            identity: &validator_pair.identity_pubkey,
+            node_label: None,
+            muted,
+        };
+        evaluate_alert(
+            &mut self.identity_tracker,
+            crate::alert::identity_balance_breach(config, lamports),
+            "Low Identity Balance",
+            &subject,
+            sinks,
+            |balance| format!("Identity balance ◎{:.3} is below ◎{:.3}", balance, config.min_sol),
+            |alert_mgr, balance| async move {
+                alert_mgr
+                    .send_low_identity_balance_alert(&validator_pair.identity_pubkey, balance, config.min_sol)
+                    .await
+            },
+        )
+        .await;
+    }
+}
+
+/// Vote data from the first node whose own RPC answers over SSH. Standby
+/// nodes go first, as their view doesn't depend on the active node's health.
+async fn fetch_vote_data_from_nodes(
+    app_state: &AppState,
+    validator_status: &crate::ValidatorStatus,
+    port: Option<u16>,
+    reference_slot: Option<u64>,
+) -> Option<(String, ValidatorVoteData, Option<String>)> {
+    let mut nodes: Vec<&crate::types::NodeWithStatus> =
+        validator_status.nodes_with_status.iter().collect();
+    nodes.sort_by_key(|node| node.status == crate::types::NodeStatus::Active);
+
+    // A healthy node can still be behind the cluster; data that can't be
+    // checked against a reference slot is kept for display only
+    let mut unverified = None;
+    for node in nodes {
+        let ssh_key = match app_state.detected_ssh_keys.get(&node.node.host) {
+            Some(key) => key,
+            None => continue,
+        };
+        if let Ok(data) = crate::local_rpc::fetch_vote_account_data(
+            &app_state.ssh_pool,
+            &node.node,
+            ssh_key,
+            port,
+            &validator_status.validator_pair.vote_pubkey,
+        )
+        .await
+        {
+            let label = node.node.label.clone();
+            let problem = match (data.vote_account_info.current_slot, reference_slot) {
+                (Some(slot), Some(reference))
+                    if reference.saturating_sub(slot) > crate::rpc_selector::MAX_SLOT_LAG =>
+                {
+                    Some(format!(
+                        "local RPC on {} is {} slots behind the RPC endpoints",
+                        label,
+                        reference - slot
+                    ))
+                }
+                (Some(_), Some(_)) => return Some((label, data, None)),
+                _ => Some(format!(
+                    "local RPC on {} could not be checked against an RPC endpoint slot",
+                    label
+                )),
+            };
+            unverified.get_or_insert((label, data, problem));
+        }
     }
+    unverified
 }
 
 #[allow(dead_code)]
@@ -1041,6 +4304,32 @@ async fn fetch_catchup_for_node(
         level: LogLevel::Info,
     });
 
+    // A configured catchup command replaces the CLI lookup below
+    if node.node.has_catchup_command() {
+        let command = format!("{} 2>&1", node.node.catchup_invocation(""));
+        let output = ssh_pool
+            .execute_command(&node.node, ssh_key, &command)
+            .await
+            .ok()?;
+        let status = if output.contains("0 slot(s)") || output.contains("has caught up") {
+            "Caught up".to_string()
+        } else if let Some(pos) = output.find(" slot(s) behind") {
+            let start = output[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
+            match output[start..pos].parse::<u64>() {
+                Ok(slots) => format!("{} slots behind", slots),
+                Err(_) => "Checking...".to_string(),
+            }
+        } else {
+            "Unknown".to_string()
+        };
+        return Some(CatchupStatus {
+            status,
+            slots_behind: catchup_slots_behind(&output),
+            last_updated: Instant::now(),
+            is_streaming: false,
+        });
+    }
+
     let solana_cli = if let Some(cli) = node.solana_cli_executable.as_ref() {
         cli.clone()
     } else if let Some(validator) = node.agave_validator_executable.as_ref() {
@@ -1073,6 +4362,7 @@ async fn fetch_catchup_for_node(
                     };
                     return Some(CatchupStatus {
                         status,
+                        slots_behind: catchup_reading(&output, true),
                         last_updated: Instant::now(),
                         is_streaming: false,
                     });
@@ -1111,6 +4401,7 @@ async fn fetch_catchup_for_node(
         });
         return Some(CatchupStatus {
             status: "CLI not found".to_string(),
+            slots_behind: None,
             last_updated: Instant::now(),
             is_streaming: false,
         });
@@ -1214,6 +4505,7 @@ async fn fetch_catchup_for_node(
 
             Some(CatchupStatus {
                 status,
+                slots_behind: catchup_slots_behind(&output),
                 last_updated: Instant::now(),
                 is_streaming: false,
             })
@@ -1243,7 +4535,10 @@ async fn stream_catchup_for_node(
 ) {
     loop {
         // Determine the catchup command based on node type
-        let catchup_command = if node.validator_type == crate::types::ValidatorType::Firedancer {
+        let catchup_command = if node.node.has_catchup_command() {
+            // A configured catchup command replaces the detected one for any client
+            format!("{} 2>&1", node.node.catchup_invocation(""))
+        } else if node.validator_type == crate::types::ValidatorType::Firedancer {
             // For Firedancer, use fdctl status
             if let Some(fdctl) = &node.fdctl_executable {
                 // Also wrap fdctl in bash -c for consistency
@@ -1302,6 +4597,7 @@ async fn stream_catchup_for_node(
                     
                     let catchup_status = CatchupStatus {
                         status,
+                        slots_behind: catchup_reading(&last_output, is_firedancer),
                         last_updated: Instant::now(),
                         is_streaming: true,
                     };
@@ -1353,8 +4649,105 @@ async fn stream_catchup_for_node(
     }
 }
 
+/// Measure catchup for a single node by comparing its own RPC's processed
+/// slot with the validator's RPC on the configured catchup interval
+async fn poll_rpc_catchup_for_node(
+    ssh_pool: Arc<AsyncSshPool>,
+    app_state: Arc<AppState>,
+    ssh_key: String,
+    ui_state: Arc<RwLock<UiState>>,
+    validator_idx: usize,
+    node_idx: usize,
+    log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
+) {
+    let port = app_state.config.local_rpc.as_ref().and_then(|config| config.port);
+    let validator_status = &app_state.validator_statuses[validator_idx];
+    let node = &validator_status.nodes_with_status[node_idx];
+    let reference_rpc = &validator_status.validator_pair.rpc;
+    let interval_seconds = app_state
+        .config
+        .catchup
+        .as_ref()
+        .map_or(0, |config| config.interval_seconds);
+    let mut interval = interval(Duration::from_secs(interval_seconds.max(1)));
+    // Log a failure when it starts, not on every poll
+    let mut failing = false;
+
+    loop {
+        interval.tick().await;
+
+        let rpc_url = best_rpc(&app_state.rpc_selectors, validator_idx, reference_rpc).await;
+        // Read both at once so the reference isn't newer by the SSH round trip
+        let (local_slot, reference_slot) = tokio::join!(
+            crate::local_rpc::fetch_slot(&ssh_pool, &node.node, &ssh_key, port),
+            crate::solana_rpc::fetch_processed_slot(&rpc_url),
+        );
+
+        let (status, slots_behind) = match (local_slot, reference_slot) {
+            (Ok(local_slot), Ok(reference_slot)) => {
+                failing = false;
+                (
+                    crate::local_rpc::catchup_status(local_slot, reference_slot),
+                    Some(reference_slot.saturating_sub(local_slot)),
+                )
+            }
+            (local_slot, reference_slot) => {
+                if !failing {
+                    let error = local_slot
+                        .err()
+                        .map(|e| format!("local RPC: {}", e))
+                        .or_else(|| reference_slot.err().map(|e| format!("{}: {}", rpc_url, e)))
+                        .unwrap_or_default();
+                    let _ = log_sender.send(LogMessage {
+                        host: node.node.host.clone(),
+                        message: format!("Catchup slot comparison failed: {}", error),
+                        timestamp: Instant::now(),
+                        level: LogLevel::Warning,
+                    });
+                }
+                failing = true;
+                ("RPC Error".to_string(), None)
+            }
+        };
+
+        let mut state = ui_state.write().await;
+        if let Some(catchup_data) = state.catchup_data.get_mut(validator_idx) {
+            let catchup_status = CatchupStatus {
+                status,
+                slots_behind,
+                last_updated: Instant::now(),
+                is_streaming: false,
+            };
+            if node_idx == 0 {
+                catchup_data.node_0 = Some(catchup_status);
+            } else {
+                catchup_data.node_1 = Some(catchup_status);
+            }
+        }
+    }
+}
+
+/// Slots behind the cluster from `solana catchup` output, 0 once caught up;
+/// None when the output doesn't say
+pub(crate) fn catchup_slots_behind(output: &str) -> Option<u64> {
+    if let Some(pos) = output.find(" slot(s) behind") {
+        let start = output[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
+        return output[start..pos].parse().ok();
+    }
+    output.contains("has caught up").then_some(0)
+}
+
+/// Slots behind from catchup output; a running Firedancer counts as caught up
+fn catchup_reading(output: &str, is_firedancer: bool) -> Option<u64> {
+    if is_firedancer {
+        output.contains("running").then_some(0)
+    } else {
+        catchup_slots_behind(output)
+    }
+}
+
 /// Parse catchup output to extract status
-fn parse_catchup_output(output: &str, is_firedancer: bool) -> String {
+pub(crate) fn parse_catchup_output(output: &str, is_firedancer: bool) -> String {
     if is_firedancer {
         // For Firedancer, check if it's running
         if output.contains("running") {
@@ -1490,6 +4883,7 @@ pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
                         &app.should_quit,
                         &app.view_state,
                         &app.app_state,
+                        &app.ssh_pool,
                         &app.switch_confirmed,
                     )
                     .await?;
@@ -1503,7 +4897,10 @@ pub async fn run_enhanced_ui(app: &mut EnhancedStatusApp) -> Result<bool> {
 
         terminal.draw(|f| match *view_state_read {
             ViewState::Status => draw_ui(f, &ui_state_read, &app.app_state),
-            ViewState::Switch => draw_switch_ui(f, &app.app_state),
+            ViewState::Switch => draw_switch_ui(f, &ui_state_read, &app.app_state),
+            ViewState::Diagnostics => draw_diagnostics_ui(f, &ui_state_read),
+            ViewState::Leaders => draw_leaders_ui(f, &ui_state_read),
+            ViewState::Logs => draw_logs_ui(f, &ui_state_read),
         })?;
 
         drop(ui_state_read);
@@ -1529,14 +4926,78 @@ async fn handle_key_event(
     should_quit: &Arc<RwLock<bool>>,
     view_state: &Arc<RwLock<ViewState>>,
     _app_state: &Arc<AppState>,
+    ssh_pool: &Arc<AsyncSshPool>,
     switch_confirmed: &Arc<RwLock<bool>>,
 ) -> Result<()> {
     // Don't hold a write lock for the entire function!
+
+    // Kiosk mode only allows moving between views (and Ctrl+C for the operator)
+    if ui_state.read().await.kiosk {
+        let in_subview = matches!(
+            *view_state.read().await,
+            ViewState::Diagnostics | ViewState::Leaders
+        );
+        let allowed = match key.code {
+            KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Char('l') | KeyCode::Char('L') => true,
+            KeyCode::Char('q') | KeyCode::Esc => in_subview,
+            KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if !allowed {
+            return Ok(());
+        }
+    }
     
+    // With typed confirmation the switch view takes text instead of 'y'
+    let switch_config = _app_state.config.switch.clone().unwrap_or_default();
+    if switch_config.typed_confirmation
+        && *view_state.read().await == ViewState::Switch
+        && !key.modifiers.contains(KeyModifiers::CONTROL)
+    {
+        match key.code {
+            KeyCode::Char(c) => {
+                ui_state.write().await.confirm_input.push(c);
+                return Ok(());
+            }
+            KeyCode::Backspace => {
+                ui_state.write().await.confirm_input.pop();
+                return Ok(());
+            }
+            KeyCode::Enter => {
+                let (matches, blocked) = {
+                    let mut state = ui_state.write().await;
+                    let input = std::mem::take(&mut state.confirm_input);
+                    (
+                        crate::commands::switch::confirmation_matches(
+                            &input,
+                            &switch_config.confirmation_phrase,
+                        ),
+                        state.switch_locks.get(state.switch_selection).cloned().flatten().is_some()
+                            || switch_lag_blocker(&state, _app_state, state.switch_selection)
+                                .is_some(),
+                    )
+                };
+                if matches && !blocked {
+                    *switch_confirmed.write().await = true;
+                    *should_quit.write().await = true;
+                }
+                return Ok(());
+            }
+            KeyCode::Esc => ui_state.write().await.confirm_input.clear(),
+            _ => {}
+        }
+    }
+
     match key.code {
         KeyCode::Char('q') | KeyCode::Esc => {
             let current_view = *view_state.read().await;
-            if current_view == ViewState::Switch {
+            if current_view == ViewState::Diagnostics || current_view == ViewState::Leaders {
+                // Diagnostics and leaders views have nothing to refresh, just go back
+                *view_state.write().await = ViewState::Status;
+            } else if current_view == ViewState::Logs {
+                stop_log_tail(ui_state).await;
+                *view_state.write().await = ViewState::Status;
+            } else if current_view == ViewState::Switch {
                 // In switch view, go back to status view
                 let mut view = view_state.write().await;
                 *view = ViewState::Status;
@@ -1560,10 +5021,81 @@ async fn handle_key_event(
             let mut view = view_state.write().await;
             *view = ViewState::Switch;
         }
-        KeyCode::Char('y') | KeyCode::Char('Y') => {
-            // Confirm and execute switch if in switch view
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            // Toggle SSH pool diagnostics view
+            let mut view = view_state.write().await;
+            *view = match *view {
+                ViewState::Diagnostics => ViewState::Status,
+                _ => ViewState::Diagnostics,
+            };
+        }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            // Toggle upcoming leader windows view
+            let mut view = view_state.write().await;
+            *view = match *view {
+                ViewState::Leaders => ViewState::Status,
+                _ => ViewState::Leaders,
+            };
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            // Open the node picker of the log view, or leave it
+            let mut view = view_state.write().await;
+            if *view == ViewState::Logs {
+                stop_log_tail(ui_state).await;
+                *view = ViewState::Status;
+            } else {
+                *view = ViewState::Logs;
+            }
+        }
+        KeyCode::Up | KeyCode::Down | KeyCode::Char('1'..='9') | KeyCode::Enter
+            if *view_state.read().await == ViewState::Logs
+                && ui_state.read().await.log_tail.is_none() =>
+        {
+            // Choose the node whose log to follow
+            if key.code == KeyCode::Enter {
+                start_log_tail(ui_state, _app_state, ssh_pool).await;
+            } else {
+                let mut ui_state_write = ui_state.write().await;
+                let node_count = log_nodes(&ui_state_write).len();
+                let current = ui_state_write.log_selection;
+                ui_state_write.log_selection = match key.code {
+                    KeyCode::Up => current.saturating_sub(1),
+                    KeyCode::Down => (current + 1).min(node_count.saturating_sub(1)),
+                    KeyCode::Char(c) => match c.to_digit(10) {
+                        Some(n) if (n as usize) <= node_count => n as usize - 1,
+                        _ => current,
+                    },
+                    _ => current,
+                };
+            }
+        }
+        KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::End
+            if *view_state.read().await == ViewState::Logs =>
+        {
+            // Scroll back through the streamed log
+            if let Some(tail) = ui_state.write().await.log_tail.as_mut() {
+                match key.code {
+                    KeyCode::Up => tail.scroll_up(1),
+                    KeyCode::Down => tail.scroll_down(1),
+                    KeyCode::PageUp => tail.scroll_up(LOG_PAGE_LINES),
+                    KeyCode::PageDown => tail.scroll_down(LOG_PAGE_LINES),
+                    _ => tail.follow(),
+                }
+            }
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Char('F') => {
+            // Confirm and execute switch if in switch view. 'F' forces a
+            // switch to a standby that is further behind than allowed.
             let current_view = *view_state.read().await;
-            if current_view == ViewState::Switch {
+            let (locked, lagging) = {
+                let state = ui_state.read().await;
+                (
+                    state.switch_locks.get(state.switch_selection).cloned().flatten().is_some(),
+                    switch_lag_blocker(&state, _app_state, state.switch_selection).is_some(),
+                )
+            };
+            let forced = key.code == KeyCode::Char('F');
+            if current_view == ViewState::Switch && !locked && (!lagging || forced) {
                 // Set switch confirmed flag and quit to perform switch
                 *switch_confirmed.write().await = true;
                 *should_quit.write().await = true;
@@ -1571,6 +5103,36 @@ async fn handle_key_event(
                 return Ok(());
             }
         }
+        KeyCode::Up | KeyCode::Down | KeyCode::Char('1'..='9')
+            if *view_state.read().await == ViewState::Switch =>
+        {
+            // Choose which validator pair to switch
+            let validator_count = _app_state.validator_statuses.len();
+            let mut ui_state_write = ui_state.write().await;
+            let current = ui_state_write.switch_selection;
+            ui_state_write.switch_selection = match key.code {
+                KeyCode::Up => current.saturating_sub(1),
+                KeyCode::Down => (current + 1).min(validator_count.saturating_sub(1)),
+                KeyCode::Char(c) => match c.to_digit(10) {
+                    Some(n) if (n as usize) <= validator_count => n as usize - 1,
+                    _ => current,
+                },
+                _ => current,
+            };
+        }
+        KeyCode::Char('w') | KeyCode::Char('W')
+            if *view_state.read().await == ViewState::Status =>
+        {
+            // Collapse or expand the warnings panel
+            let mut ui_state_write = ui_state.write().await;
+            ui_state_write.warnings_collapsed = !ui_state_write.warnings_collapsed;
+        }
+        KeyCode::Char('x') | KeyCode::Char('X')
+            if *view_state.read().await == ViewState::Status =>
+        {
+            // Dismiss the oldest warning until its condition clears
+            ui_state.write().await.sticky_warnings.dismiss_oldest();
+        }
         KeyCode::Char('r') | KeyCode::Char('R') => {
             // Refresh fields in the validator status view
             let is_status_view = matches!(*view_state.read().await, ViewState::Status);
@@ -1592,6 +5154,9 @@ async fn handle_key_event(
                     }
                 }
                 
+                // Retry unreachable nodes now instead of waiting out their backoff
+                ui_state.read().await.ssh_retry.notify_one();
+
                 // Clone what we need after setting flags
                 let app_state_clone = _app_state.clone();
                 let ui_state_clone = ui_state.clone();
@@ -1613,16 +5178,81 @@ fn draw_ui(f: &mut ratatui::Frame, ui_state: &UiState, app_state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(0),    // Validator tables take all remaining space
-            Constraint::Length(1), // Footer
+            Constraint::Min(0),                                 // Validator tables take all remaining space
+            Constraint::Length(warnings_panel_height(ui_state)), // Warnings panel, empty when none
+            Constraint::Length(1),                              // Footer
         ])
         .split(f.size());
 
     // Draw validator summaries
     draw_validator_summaries(f, chunks[0], ui_state, app_state);
 
+    // Draw unresolved warnings
+    draw_warnings_panel(f, chunks[1], ui_state);
+
     // Draw footer
-    draw_footer(f, chunks[1], ui_state);
+    draw_footer(f, chunks[2], ui_state);
+}
+
+/// Most warnings listed before the panel summarizes the rest
+const MAX_WARNING_LINES: usize = 5;
+
+fn warnings_panel_height(ui_state: &UiState) -> u16 {
+    match ui_state.sticky_warnings.visible().count() {
+        0 => 0,
+        _ if ui_state.warnings_collapsed => 1,
+        count => count.min(MAX_WARNING_LINES + 1) as u16 + 2, // Borders
+    }
+}
+
+fn draw_warnings_panel(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState) {
+    let visible: Vec<_> = ui_state.sticky_warnings.visible().collect();
+    if visible.is_empty() {
+        return;
+    }
+
+    let title = format!(" ⚠️  Unresolved Warnings ({}) ", visible.len());
+    if ui_state.warnings_collapsed {
+        let summary = Paragraph::new(format!("{}- w: expand", title))
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(summary, area);
+        return;
+    }
+
+    let mut lines: Vec<Line> = visible
+        .iter()
+        .take(MAX_WARNING_LINES)
+        .map(|warning| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", warning.first_seen.format("%H:%M:%S")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(warning.message.clone(), Style::default().fg(Color::Yellow)),
+            ])
+        })
+        .collect();
+    if visible.len() > MAX_WARNING_LINES {
+        lines.push(Line::from(Span::styled(
+            format!("... and {} more", visible.len() - MAX_WARNING_LINES),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let dismissed = ui_state.sticky_warnings.dismissed_count();
+    let hint = if dismissed > 0 {
+        format!(" w: collapse | x: dismiss oldest | {} dismissed ", dismissed)
+    } else {
+        " w: collapse | x: dismiss oldest ".to_string()
+    };
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(title)
+            .title_bottom(hint),
+    );
+    f.render_widget(panel, area);
 }
 
 #[allow(dead_code)]
@@ -1659,6 +5289,27 @@ fn draw_validator_summaries(
         let prev_slot = ui_state.previous_last_slots.get(idx).and_then(|&v| v);
         let inc_time = ui_state.increment_times.get(idx).and_then(|&v| v);
         let ssh_health_data = ui_state.ssh_health_data.get(idx);
+        let vote_landing = ui_state.vote_landing_stats.get(idx).and_then(|v| v.as_ref());
+        let rewards = ui_state.epoch_rewards.get(idx).map(|r| r.as_slice());
+        let epoch_info = ui_state.epoch_info.get(idx).and_then(|e| e.as_ref());
+        let block_production = ui_state.block_production.get(idx).and_then(|b| *b);
+        let recent_leader_slots = ui_state.recent_leader_slots.get(idx).and_then(|s| s.as_deref());
+        let vote_credits = ui_state.vote_credits.get(idx).and_then(|c| *c);
+        let identity_balance = ui_state.identity_balances.get(idx).and_then(|b| *b);
+        let vote_account_balance = ui_state.vote_account_balances.get(idx).and_then(|b| *b);
+        let stake = ui_state.stake.get(idx).and_then(|s| *s);
+        let leader_schedule = ui_state.leader_schedules.get(idx).and_then(|s| s.as_ref());
+        let cluster_versions = ui_state.cluster_version_stake.as_deref();
+        let host_checks = ui_state.host_checks.get(idx);
+
+        // Shared displays get a copy with hosts, paths and pubkeys masked
+        let masked;
+        let validator_status = if ui_state.kiosk {
+            masked = crate::kiosk::mask_validator_status(validator_status);
+            &masked
+        } else {
+            validator_status
+        };
 
         let field_refresh_state = ui_state.field_refresh_states.get(idx);
         draw_side_by_side_tables(
@@ -1674,6 +5325,18 @@ fn draw_validator_summaries(
             ssh_health_data,
             ui_state.last_ssh_health_refresh,
             field_refresh_state,
+            vote_landing,
+            rewards,
+            epoch_info,
+            block_production,
+            recent_leader_slots,
+            vote_credits,
+            identity_balance,
+            vote_account_balance,
+            stake,
+            leader_schedule,
+            cluster_versions,
+            host_checks,
         );
     }
 }
@@ -1691,6 +5354,18 @@ fn draw_side_by_side_tables(
     ssh_health_data: Option<&NodePairSshStatus>,
     last_ssh_health_refresh: Instant,
     field_refresh_state: Option<&NodeFieldRefreshState>,
+    vote_landing: Option<&VoteLandingStats>,
+    rewards: Option<&[EpochReward]>,
+    epoch_info: Option<&EpochInfo>,
+    block_production: Option<(u64, u64)>,
+    recent_leader_slots: Option<&[LeaderSlotOutcome]>,
+    vote_credits: Option<VoteCredits>,
+    identity_balance: Option<u64>,
+    vote_account_balance: Option<VoteAccountBalance>,
+    stake: Option<StakeSummary>,
+    leader_schedule: Option<&LeaderSchedule>,
+    cluster_versions: Option<&[(String, u64)]>,
+    host_checks: Option<&NodePairHostChecks>,
 ) {
     // Split area horizontally
     let chunks = Layout::default()
@@ -1717,6 +5392,9 @@ fn draw_side_by_side_tables(
         let node_refresh_state = field_refresh_state.map(|s| {
             if left_node_idx == 0 { &s.node_0 } else { &s.node_1 }
         });
+        let node_host_checks = host_checks.map(|h| {
+            if left_node_idx == 0 { &h.node_0 } else { &h.node_1 }
+        });
         
         draw_single_node_table(
             f,
@@ -1732,6 +5410,18 @@ fn draw_side_by_side_tables(
             ssh_health,
             last_ssh_health_refresh,
             node_refresh_state,
+            vote_landing,
+            rewards,
+            epoch_info,
+            block_production,
+            recent_leader_slots,
+            vote_credits,
+            identity_balance,
+            vote_account_balance,
+            stake,
+            leader_schedule,
+            cluster_versions,
+            node_host_checks,
             true, // is_left_table
         );
     }
@@ -1748,6 +5438,9 @@ fn draw_side_by_side_tables(
         let node_refresh_state = field_refresh_state.map(|s| {
             if right_node_idx == 0 { &s.node_0 } else { &s.node_1 }
         });
+        let node_host_checks = host_checks.map(|h| {
+            if right_node_idx == 0 { &h.node_0 } else { &h.node_1 }
+        });
         
         draw_single_node_table(
             f,
@@ -1763,6 +5456,18 @@ fn draw_side_by_side_tables(
             ssh_health,
             last_ssh_health_refresh,
             node_refresh_state,
+            vote_landing,
+            rewards,
+            epoch_info,
+            block_production,
+            recent_leader_slots,
+            vote_credits,
+            identity_balance,
+            vote_account_balance,
+            stake,
+            leader_schedule,
+            cluster_versions,
+            node_host_checks,
             false, // is_left_table
         );
     }
@@ -1782,7 +5487,19 @@ fn draw_single_node_table(
     ssh_health: Option<&SshHealthStatus>,
     last_ssh_health_refresh: Instant,
     field_refresh_state: Option<&FieldRefreshStates>,
-    _is_left_table: bool,
+    vote_landing: Option<&VoteLandingStats>,
+    rewards: Option<&[EpochReward]>,
+    epoch_info: Option<&EpochInfo>,
+    block_production: Option<(u64, u64)>,
+    recent_leader_slots: Option<&[LeaderSlotOutcome]>,
+    vote_credits: Option<VoteCredits>,
+    identity_balance: Option<u64>,
+    vote_account_balance: Option<VoteAccountBalance>,
+    stake: Option<StakeSummary>,
+    leader_schedule: Option<&LeaderSchedule>,
+    cluster_versions: Option<&[(String, u64)]>,
+    host_checks: Option<&HostCheckStatus>,
+    is_left_table: bool,
 ) {
     // Add padding around the table
     let padded_area = Rect {
@@ -1825,11 +5542,36 @@ fn draw_single_node_table(
         )),
     ]));
 
-    // Vote account info
+    // Vote account, with its balance and how close it is to rent exemption
     let vote_key = &validator_status.validator_pair.vote_pubkey;
+    let (vote_display, vote_style) = match vote_account_balance {
+        Some(balance) => {
+            let config = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|config| config.vote_account_balance.clone())
+                .unwrap_or_default();
+            let style = if crate::alert::vote_account_balance_low(&config, &balance) {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default()
+            };
+            (
+                format!(
+                    "{} · ◎{:.4} (◎{:.4} above rent)",
+                    vote_key,
+                    lamports_to_sol(balance.lamports),
+                    lamports_to_sol(balance.excess_lamports())
+                ),
+                style,
+            )
+        }
+        None => (vote_key.clone(), Style::default()),
+    };
     rows.push(Row::new(vec![
         Cell::from("Vote"),
-        Cell::from(vote_key.clone()),
+        Cell::from(vote_display).style(vote_style),
     ]));
 
     // Identity
@@ -1870,24 +5612,34 @@ fn draw_single_node_table(
         )
     };
     
+    // Older than what two thirds of the client's stake runs
+    let supermajority_behind = node.version.as_deref().and_then(|running| {
+        let target = crate::sfdp::supermajority_version(running, cluster_versions?)?;
+        crate::sfdp::version_older(running, &target)?.then_some(target)
+    });
+    let (client_display, client_style) = match supermajority_behind {
+        Some(target) => (
+            format!("{} ⚠️ cluster on {}+", client_display, target),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => (client_display, Style::default()),
+    };
+
     rows.push(Row::new(vec![
         Cell::from("Client"),
-        Cell::from(client_display),
+        Cell::from(client_display).style(client_style),
     ]));
 
     // Swap readiness
     rows.push(Row::new(vec![
         Cell::from("Swap Ready"),
-        Cell::from(if node.swap_ready.unwrap_or(false) {
-            "✅ Ready"
-        } else {
-            "❌ Not Ready"
-        })
-        .style(Style::default().fg(if node.swap_ready.unwrap_or(false) {
-            Color::Green
-        } else {
-            Color::Red
-        })),
+        Cell::from(node.swap_readiness_label()).style(Style::default().fg(
+            if node.swap_ready.unwrap_or(false) {
+                Color::Green
+            } else {
+                Color::Red
+            },
+        )),
     ]));
 
     // Sync status if available
@@ -2002,6 +5754,12 @@ fn draw_single_node_table(
     let (vote_display, vote_style) = if !is_active {
         // Non-active nodes always show "-"
         ("-".to_string(), Style::default())
+    } else if let Some(lag) = vote_data.and_then(|data| data.rpc_stale_slots) {
+        // The vote shown would be as old as the endpoint's view of the chain
+        (
+            format!("⚠️ RPC stale ({} slots behind)", lag),
+            Style::default().fg(Color::Yellow),
+        )
     } else if let Some(vote_data) = vote_data {
         // Active node with vote data
         let last_slot_info = vote_data.recent_votes.last().map(|lv| lv.slot);
@@ -2045,8 +5803,280 @@ fn draw_single_node_table(
     };
 
     rows.push(Row::new(vec![
-        Cell::from("Vote Status"),
-        Cell::from(vote_display).style(vote_style),
+        Cell::from("Vote Status"),
+        Cell::from(vote_display).style(vote_style),
+    ]));
+
+    // Vote landing rate - only meaningful for the node that is voting
+    let (landing_display, landing_style) = match vote_landing {
+        Some(stats) if is_active => {
+            let rate = stats.landing_rate();
+            let display = format!(
+                "{:.1}% landed ({}/{} slots, {} failed, {} expired)",
+                rate,
+                stats.landed,
+                stats.slot_span,
+                stats.failed,
+                stats.expired()
+            );
+            let color = if rate >= 95.0 {
+                Color::Green
+            } else if rate >= 85.0 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            (display, Style::default().fg(color))
+        }
+        None if is_active => (
+            "⏳ Sampling...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Vote Landing"),
+        Cell::from(landing_display).style(landing_style),
+    ]));
+
+    // Commission earned per epoch - belongs to the vote account, shown with the voter
+    let (rewards_display, rewards_style) = match rewards {
+        Some(rewards) if is_active && !rewards.is_empty() => {
+            let display = rewards
+                .iter()
+                .rev()
+                .map(|r| format!("E{} ◎{:.3}", r.epoch, lamports_to_sol(r.amount_lamports)))
+                .collect::<Vec<_>>()
+                .join(" · ");
+            (display, Style::default().fg(Color::Green))
+        }
+        Some(_) if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    // Vote credits this epoch against the best vote account in the cluster
+    let min_efficiency = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|config| config.vote_credits.as_ref())
+        .map(|config| config.min_efficiency_percent)
+        .unwrap_or(90.0);
+    let (credits_display, credits_style) = match vote_credits {
+        Some(credits) if is_active => match credits.efficiency_percent() {
+            Some(efficiency) => {
+                let color = if efficiency >= min_efficiency {
+                    Color::Green
+                } else if efficiency >= min_efficiency - 10.0 {
+                    Color::Yellow
+                } else {
+                    Color::Red
+                };
+                (
+                    format!(
+                        "{} ({:.2}/slot) · {:.1}% of best",
+                        credits.earned,
+                        credits.per_slot(),
+                        efficiency
+                    ),
+                    Style::default().fg(color),
+                )
+            }
+            None => (
+                "No credits yet this epoch".to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        },
+        None if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Vote Credits"),
+        Cell::from(credits_display).style(credits_style),
+    ]));
+
+    // Funded identity balance - it pays the vote fees
+    let min_balance = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|config| config.identity_balance.as_ref())
+        .map(|config| config.min_sol)
+        .unwrap_or_else(|| crate::types::IdentityBalanceAlertConfig::default().min_sol);
+    let (balance_display, balance_style) = match identity_balance {
+        Some(lamports) if is_active => {
+            let sol = lamports_to_sol(lamports);
+            let color = if sol < min_balance {
+                Color::Red
+            } else if sol < min_balance * 2.0 {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            (format!("◎{:.3}", sol), Style::default().fg(color))
+        }
+        None if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Identity Balance"),
+        Cell::from(balance_display).style(balance_style),
+    ]));
+
+    // Block production this epoch - leader slots belong to the voting identity
+    let skip_threshold = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|config| config.skip_rate.as_ref())
+        .map(|config| config.threshold_percent)
+        .unwrap_or(10.0);
+    let (blocks_display, blocks_style) = match block_production {
+        Some((leader_slots, produced)) if is_active => {
+            match crate::solana_rpc::skip_rate_percent(leader_slots, produced) {
+                Some(rate) => {
+                    let color = if rate > skip_threshold {
+                        Color::Red
+                    } else if rate > skip_threshold / 2.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    };
+                    (
+                        format!(
+                            "{} leader · {} produced · {} skipped ({:.1}%)",
+                            leader_slots,
+                            produced,
+                            leader_slots.saturating_sub(produced),
+                            rate
+                        ),
+                        Style::default().fg(color),
+                    )
+                }
+                None => (
+                    "No leader slots yet this epoch".to_string(),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            }
+        }
+        None if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Blocks"),
+        Cell::from(blocks_display).style(blocks_style),
+    ]));
+
+    // Next leader slot - switching right before it risks skipping the window
+    let min_gap = app_state
+        .config
+        .switch
+        .as_ref()
+        .map(|switch| switch.min_leader_gap_slots)
+        .unwrap_or_else(|| crate::types::SwitchConfig::default().min_leader_gap_slots);
+    let (next_leader_display, next_leader_style) = match leader_schedule {
+        Some(schedule) if is_active => match schedule.next_leader_in() {
+            Some(slots) => {
+                let eta = crate::commands::switch::format_wait(Duration::from_millis(
+                    slots * crate::commands::switch::SLOT_DURATION_MS,
+                ));
+                if slots < min_gap {
+                    (
+                        format!("⚠️ in ~{} ({} slots) - not a safe time to switch", eta, slots),
+                        Style::default().fg(Color::Yellow),
+                    )
+                } else {
+                    (
+                        format!("in ~{} ({} slots)", eta, slots),
+                        Style::default().fg(Color::Green),
+                    )
+                }
+            }
+            None => (
+                "No leader slots scheduled".to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        },
+        None if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Next Leader Slot"),
+        Cell::from(next_leader_display).style(next_leader_style),
+    ]));
+
+    // Most recent leader slot, with the recent ones as a produced/skipped strip
+    let (leader_display, leader_style) = match recent_leader_slots {
+        Some(outcomes) if is_active => match outcomes.last() {
+            Some(last) => {
+                let strip: String = outcomes
+                    .iter()
+                    .map(|o| if o.produced { '▪' } else { '×' })
+                    .collect();
+                let (status, color) = if last.produced {
+                    ("✅ produced", Color::Green)
+                } else {
+                    ("❌ skipped", Color::Red)
+                };
+                (
+                    format!("{} {} · {}", last.slot, status, strip),
+                    Style::default().fg(color),
+                )
+            }
+            None => (
+                "No leader slots yet this epoch".to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        },
+        None if is_active => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+        _ => ("-".to_string(), Style::default()),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Last Leader Slot"),
+        Cell::from(leader_display).style(leader_style),
+    ]));
+
+    // Epoch position is cluster-wide, so both nodes show it
+    let (epoch_display, epoch_style) = match epoch_info {
+        Some(info) => (format_epoch_progress(info), Style::default().fg(Color::Cyan)),
+        None => (
+            "⏳ Loading...".to_string(),
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Epoch"),
+        Cell::from(epoch_display).style(epoch_style),
+    ]));
+
+    rows.push(Row::new(vec![
+        Cell::from("Epoch Rewards"),
+        Cell::from(rewards_display).style(rewards_style),
     ]));
 
     // Section separator before SSH
@@ -2095,6 +6125,249 @@ fn draw_single_node_table(
         ),
     ]));
 
+    // OS patch level / pending reboot
+    let (os_display, os_color) = match host_checks.and_then(|h| h.os_patch.as_ref()) {
+        Some(os) if os.needs_reboot() => {
+            let display = match &os.latest_kernel {
+                Some(latest) if os.kernel_outdated() => format!(
+                    "⚠️ Reboot required ({} → {})",
+                    os.running_kernel, latest
+                ),
+                _ => "⚠️ Reboot required".to_string(),
+            };
+            (display, Color::Yellow)
+        }
+        Some(os) => match (os.pending_updates, os.security_updates) {
+            (Some(pending), security) if pending > 0 => (
+                format!(
+                    "⚠️ {} updates ({} security)",
+                    pending,
+                    security.unwrap_or(0)
+                ),
+                Color::Yellow,
+            ),
+            _ => (format!("✅ Up to date ({})", os.running_kernel), Color::Green),
+        },
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("OS Patches"),
+        Cell::from(os_display).style(Style::default().fg(os_color)),
+    ]));
+
+    // Free space on the ledger and accounts filesystems
+    let min_free_percent = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|c| c.disk_space.as_ref())
+        .map(|c| c.min_free_percent)
+        .unwrap_or(100 - crate::warnings::LOW_DISK_PERCENT);
+    let (disk_display, disk_color) = match host_checks.and_then(|h| h.storage.as_ref()) {
+        Some(storage) if !storage.disks().is_empty() => {
+            let disks = storage.disks();
+            let low = disks.iter().any(|(_, disk)| disk.free_percent() < min_free_percent);
+            let display = disks
+                .iter()
+                .map(|(kind, disk)| {
+                    format!(
+                        "{} {} ({}%)",
+                        kind,
+                        crate::commands::switch::format_bytes(disk.free_bytes),
+                        disk.free_percent()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" · ");
+            if low {
+                (format!("⚠️ {}", display), Color::Red)
+            } else {
+                (display, Color::Green)
+            }
+        }
+        Some(_) => ("-".to_string(), Color::DarkGray),
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Disk Free"),
+        Cell::from(disk_display).style(Style::default().fg(disk_color)),
+    ]));
+
+    // How long the validator process has run and restarts seen this session
+    let (uptime_display, uptime_color) = match host_checks.and_then(|h| h.process.as_ref()) {
+        Some(ProcessUptime { uptime_seconds: Some(seconds), restarts: 0 }) => {
+            (crate::host_checks::format_uptime(*seconds), Color::Green)
+        }
+        Some(ProcessUptime { uptime_seconds: Some(seconds), restarts }) => (
+            format!(
+                "⚠️ {} ({} restart{} seen)",
+                crate::host_checks::format_uptime(*seconds),
+                restarts,
+                if *restarts == 1 { "" } else { "s" }
+            ),
+            Color::Yellow,
+        ),
+        Some(ProcessUptime { uptime_seconds: None, .. }) => ("❌ Not running".to_string(), Color::Red),
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Uptime"),
+        Cell::from(uptime_display).style(Style::default().fg(uptime_color)),
+    ]));
+
+    // Clock synchronization and offset, highlighted past the alert limit
+    let time_limits = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|c| c.time_sync.clone())
+        .unwrap_or_default();
+    let (clock_display, clock_color) = match host_checks.and_then(|h| h.time.as_ref()) {
+        Some(sync) if sync.synchronized.is_none() => ("❓ No chrony or timesyncd".to_string(), Color::DarkGray),
+        Some(sync) => {
+            let mut display = "Synced".to_string();
+            if let Some(offset) = sync.offset_ms {
+                display.push_str(&format!(" · {:+.1} ms", offset));
+            }
+            if let Some(source) = &sync.source {
+                display.push_str(&format!(" · {}", source));
+            }
+            match crate::alert::time_sync_problem(&time_limits, sync) {
+                Some(problem) => (format!("⚠️ {}", problem), Color::Red),
+                None => (display, Color::Green),
+            }
+        }
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Clock"),
+        Cell::from(clock_display).style(Style::default().fg(clock_color)),
+    ]));
+
+    // CPU, memory and swap usage, highlighted above the alert limits
+    let limits = app_state
+        .config
+        .alert_config
+        .as_ref()
+        .and_then(|c| c.system_metrics.clone())
+        .unwrap_or_default();
+    let (system_display, system_color) = match host_checks.and_then(|h| h.system.as_ref()) {
+        Some(metrics) => {
+            let percent = |value: Option<f64>| {
+                value
+                    .map(|v| format!("{:.0}%", v))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            let mut display = format!("CPU {}", percent(metrics.cpu_percent));
+            if let Some(load) = metrics.load_average {
+                display.push_str(&format!(" (load {:.1}", load));
+                if let Some(cpus) = metrics.cpu_count {
+                    display.push_str(&format!("/{}", cpus));
+                }
+                display.push(')');
+            }
+            display.push_str(&format!(" · Mem {}", percent(metrics.memory_used_percent())));
+            display.push_str(&format!(
+                " · Swap {}",
+                metrics
+                    .swap_used_percent()
+                    .map(|v| format!("{:.0}%", v))
+                    .unwrap_or_else(|| "off".to_string())
+            ));
+            if crate::alert::system_metrics_breach(&limits, metrics).is_empty() {
+                (display, Color::Green)
+            } else {
+                (format!("⚠️ {}", display), Color::Yellow)
+            }
+        }
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("System"),
+        Cell::from(system_display).style(Style::default().fg(system_color)),
+    ]));
+
+    // Newest full and incremental snapshot
+    let storage = host_checks.and_then(|h| h.storage.as_ref());
+    let age = |seconds: Option<u64>| {
+        seconds
+            .map(|s| format!("{} ago", crate::commands::switch::format_wait(Duration::from_secs(s))))
+            .unwrap_or_else(|| "none".to_string())
+    };
+    let (snapshot_display, snapshot_color) = match storage {
+        Some(storage) if storage.snapshot_age_seconds().is_some() => {
+            let display = format!(
+                "full {} · incr {}",
+                age(storage.full_snapshot_age_seconds),
+                age(storage.incremental_snapshot_age_seconds)
+            );
+            let max_age_seconds = app_state
+                .config
+                .alert_config
+                .as_ref()
+                .and_then(|c| c.snapshot_age.as_ref())
+                .map(|c| c.max_age_minutes * 60);
+            let stale = node.status == crate::types::NodeStatus::Standby
+                && max_age_seconds
+                    .zip(storage.snapshot_age_seconds())
+                    .is_some_and(|(max, age)| age > max);
+            if stale {
+                (format!("⚠️ {}", display), Color::Yellow)
+            } else {
+                (display, Color::Green)
+            }
+        }
+        Some(_) if node.ledger_path.is_some() => ("No snapshots found".to_string(), Color::DarkGray),
+        Some(_) => ("-".to_string(), Color::DarkGray),
+        None => ("⏳ Checking...".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Snapshots"),
+        Cell::from(snapshot_display).style(Style::default().fg(snapshot_color)),
+    ]));
+
+    // Ledger directory size, growth and when it fills the disk at that rate
+    let (ledger_display, ledger_color) = match host_checks.and_then(|h| h.ledger_growth.as_ref()) {
+        Some(growth) => {
+            let mut display = crate::commands::switch::format_bytes(growth.bytes);
+            if let Some(rate) = growth.bytes_per_day {
+                let sign = if rate < 0.0 { "-" } else { "+" };
+                display.push_str(&format!(
+                    " · {}{}/day",
+                    sign,
+                    crate::commands::switch::format_bytes(rate.abs() as u64)
+                ));
+            }
+            match growth.days_to_full {
+                Some(days) => {
+                    display.push_str(&format!(" · full in ~{:.1}d", days));
+                    let color = if days < 2.0 {
+                        Color::Red
+                    } else if days < 7.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    };
+                    (display, color)
+                }
+                None => (display, Color::Green),
+            }
+        }
+        None if node.ledger_path.is_some() => ("⏳ Measuring...".to_string(), Color::DarkGray),
+        None => ("-".to_string(), Color::DarkGray),
+    };
+
+    rows.push(Row::new(vec![
+        Cell::from("Ledger Size"),
+        Cell::from(ledger_display).style(Style::default().fg(ledger_color)),
+    ]));
+
     // Section separator before Alert Configuration
     rows.push(create_section_header_with_label("ALERTS"));
 
@@ -2163,6 +6436,17 @@ fn draw_single_node_table(
         Style::default().fg(Color::DarkGray)
     };
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .padding(ratatui::widgets::Padding::new(1, 1, 0, 0));
+    // Stake belongs to the vote account, so it heads the pair once
+    if is_left_table {
+        if let Some(stake) = stake {
+            block = block.title(stake_title(&stake));
+        }
+    }
+
     let table = Table::new(
         rows,
         vec![
@@ -2170,16 +6454,33 @@ fn draw_single_node_table(
             Constraint::Percentage(80),
         ],
     )
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(border_style)
-            .padding(ratatui::widgets::Padding::new(1, 1, 0, 0)),
-    );
+    .block(block);
 
     f.render_widget(table, padded_area);
 }
 
+/// Activated stake with any stake changing at the next epoch boundary;
+/// deactivating stake is red so a large undelegation stands out
+fn stake_title(stake: &StakeSummary) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!(" Stake ◎{:.0} ", lamports_to_sol(stake.activated_lamports)),
+        Style::default().fg(Color::Cyan),
+    )];
+    if stake.activating_lamports > 0 {
+        spans.push(Span::styled(
+            format!("+◎{:.0} activating ", lamports_to_sol(stake.activating_lamports)),
+            Style::default().fg(Color::Green),
+        ));
+    }
+    if stake.deactivating_lamports > 0 {
+        spans.push(Span::styled(
+            format!("-◎{:.0} deactivating ", lamports_to_sol(stake.deactivating_lamports)),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
 fn create_section_header_with_label(label: &'static str) -> Row<'static> {
     if label.is_empty() {
         // Empty row for spacing
@@ -2383,26 +6684,20 @@ fn draw_validator_table(
         // Swap readiness row
         rows.push(Row::new(vec![
             Cell::from("Swap Ready"),
-            Cell::from(if node_0.swap_ready.unwrap_or(false) {
-                "✅ Ready"
-            } else {
-                "❌ Not Ready"
-            })
-            .style(Style::default().fg(if node_0.swap_ready.unwrap_or(false) {
-                Color::Green
-            } else {
-                Color::Red
-            })),
-            Cell::from(if node_1.swap_ready.unwrap_or(false) {
-                "✅ Ready"
-            } else {
-                "❌ Not Ready"
-            })
-            .style(Style::default().fg(if node_1.swap_ready.unwrap_or(false) {
-                Color::Green
-            } else {
-                Color::Red
-            })),
+            Cell::from(node_0.swap_readiness_label()).style(Style::default().fg(
+                if node_0.swap_ready.unwrap_or(false) {
+                    Color::Green
+                } else {
+                    Color::Red
+                },
+            )),
+            Cell::from(node_1.swap_readiness_label()).style(Style::default().fg(
+                if node_1.swap_ready.unwrap_or(false) {
+                    Color::Green
+                } else {
+                    Color::Red
+                },
+            )),
         ]));
 
         // Sync status row if available
@@ -2636,62 +6931,491 @@ fn draw_validator_table(
         _ => "Disabled",
     };
 
-    rows.push(Row::new(vec![
-        Cell::from("Alert Status"),
-        Cell::from(alert_status),
-        Cell::from(alert_status),
-    ]));
+    rows.push(Row::new(vec![
+        Cell::from("Alert Status"),
+        Cell::from(alert_status),
+        Cell::from(alert_status),
+    ]));
+
+    let table = Table::new(
+        rows,
+        vec![
+            Constraint::Length(20), // Wider label column for better spacing
+            Constraint::Percentage(40),
+            Constraint::Percentage(40),
+        ],
+    )
+    .block(
+        Block::default()
+            .title(format!(
+                "{}Identity: {} | Vote: {} | Time: {}",
+                crate::config::active_profile()
+                    .map(|name| format!("Profile: {} | ", name))
+                    .unwrap_or_default(),
+                identity_formatted,
+                vote_formatted,
+                chrono::Local::now().format("%H:%M:%S")
+            ))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(ratatui::widgets::Padding::new(1, 1, 0, 0)),
+    );
+
+    f.render_widget(table, padded_area);
+}
+
+// Removed draw_logs function as logs are no longer displayed
+
+fn draw_footer(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState) {
+    // Check if any fields are refreshing
+    let is_refreshing = ui_state.field_refresh_states.iter().any(|state| {
+        state.node_0.status_refreshing || state.node_0.identity_refreshing || state.node_0.version_refreshing ||
+        state.node_1.status_refreshing || state.node_1.identity_refreshing || state.node_1.version_refreshing
+    });
+    
+    let refresh_indicator = if is_refreshing {
+        " | 🔄 Refreshing..."
+    } else {
+        ""
+    };
+    
+    let help_text = if ui_state.kiosk {
+        format!("🖥️  KIOSK MODE | d: Diagnostics | l: Leaders{}", refresh_indicator)
+    } else {
+        format!(
+            "q/Esc: Quit | r: Refresh (5s) | s: Switch | d: Diagnostics | l: Leaders | t: Logs | w: Warnings{}",
+            refresh_indicator
+        )
+    };
+
+    let footer = Paragraph::new(help_text)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, area);
+}
+
+/// Draw the SSH pool diagnostics view
+fn draw_diagnostics_ui(f: &mut ratatui::Frame, ui_state: &UiState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.size());
+
+    let header = Paragraph::new("🔧 SSH POOL DIAGNOSTICS")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(header, chunks[0]);
+
+    let header_row = Row::new(vec![
+        Cell::from("Host"),
+        Cell::from("Sessions"),
+        Cell::from("In Flight"),
+        Cell::from("Peak"),
+        Cell::from("Streams"),
+        Cell::from("Commands"),
+        Cell::from("Failed"),
+        Cell::from("Avg Latency"),
+        Cell::from("Last"),
+        Cell::from("Reconnects"),
+    ])
+    .style(Style::default().fg(Color::DarkGray).add_modifier(Modifier::BOLD));
+
+    let format_latency = |latency: Option<Duration>| {
+        latency
+            .map(|l| format!("{}ms", l.as_millis()))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    let rows: Vec<Row> = ui_state
+        .ssh_pool_diagnostics
+        .iter()
+        .enumerate()
+        .map(|(idx, d)| {
+            let host = if ui_state.kiosk {
+                format!("host-{}", idx + 1)
+            } else {
+                d.host.clone()
+            };
+
+            // Commands piling up means the pool is saturated and UI data goes stale
+            let in_flight_color = if d.metrics.in_flight >= 5 {
+                Color::Red
+            } else if d.metrics.in_flight >= 2 {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+
+            Row::new(vec![
+                Cell::from(host),
+                Cell::from(d.open_sessions.to_string()),
+                Cell::from(d.metrics.in_flight.to_string())
+                    .style(Style::default().fg(in_flight_color)),
+                Cell::from(d.metrics.peak_in_flight.to_string()),
+                Cell::from(d.metrics.active_streams.to_string()),
+                Cell::from(d.metrics.commands.to_string()),
+                Cell::from(d.metrics.failed_commands.to_string()).style(Style::default().fg(
+                    if d.metrics.failed_commands > 0 { Color::Yellow } else { Color::White },
+                )),
+                Cell::from(format_latency(d.metrics.average_latency())),
+                Cell::from(format_latency(d.metrics.last_latency)),
+                Cell::from(d.metrics.reconnects.to_string()).style(Style::default().fg(
+                    if d.metrics.reconnects > 0 { Color::Yellow } else { Color::White },
+                )),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        vec![
+            Constraint::Min(16),
+            Constraint::Length(9),
+            Constraint::Length(10),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(7),
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Length(11),
+        ],
+    )
+    .header(header_row)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(ratatui::widgets::Padding::new(1, 1, 0, 0)),
+    );
+    f.render_widget(table, chunks[1]);
+
+    let footer_text = if ui_state.kiosk {
+        "🖥️  KIOSK MODE | d/q/Esc: Back to status"
+    } else {
+        "d/q/Esc: Back to status | Metrics: ~/.solana-validator-switch/metrics/ssh_pool.prom"
+    };
+    let footer = Paragraph::new(footer_text)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[2]);
+}
+
+/// Leader windows listed per validator in the leaders view
+const LEADER_WINDOWS_SHOWN: usize = 8;
+
+/// Draw the upcoming leader windows view
+fn draw_leaders_ui(f: &mut ratatui::Frame, ui_state: &UiState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.size());
+
+    let header = Paragraph::new("📅 UPCOMING LEADER WINDOWS")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(header, chunks[0]);
+
+    let header_row = Row::new(vec![
+        Cell::from("Validator"),
+        Cell::from("First Slot"),
+        Cell::from("Slots"),
+        Cell::from("Starts In"),
+        Cell::from("ETA"),
+    ])
+    .style(Style::default().fg(Color::DarkGray).add_modifier(Modifier::BOLD));
+
+    let mut rows: Vec<Row> = Vec::new();
+    for (idx, validator_status) in ui_state.validator_statuses.iter().enumerate() {
+        let name = if ui_state.kiosk {
+            format!("validator-{}", idx + 1)
+        } else {
+            validator_status.validator_pair.identity_pubkey.clone()
+        };
+
+        let schedule = match ui_state.leader_schedules.get(idx).and_then(|s| s.as_ref()) {
+            Some(schedule) => schedule,
+            None => {
+                rows.push(Row::new(vec![
+                    Cell::from(name),
+                    Cell::from("⏳ Loading...").style(Style::default().fg(Color::DarkGray)),
+                ]));
+                continue;
+            }
+        };
+
+        let now = schedule.estimated_slot();
+        let upcoming: Vec<u64> = schedule.slots.iter().copied().filter(|slot| *slot >= now).collect();
+        let windows = leader_windows(&upcoming);
+        if windows.is_empty() {
+            rows.push(Row::new(vec![
+                Cell::from(name),
+                Cell::from("No leader slots scheduled").style(Style::default().fg(Color::DarkGray)),
+            ]));
+            continue;
+        }
+
+        for (position, (first_slot, count)) in windows.iter().take(LEADER_WINDOWS_SHOWN).enumerate() {
+            let slots_away = first_slot - now;
+            let eta = crate::commands::switch::format_wait(Duration::from_millis(
+                slots_away * crate::commands::switch::SLOT_DURATION_MS,
+            ));
+            // The first window is the one a switch right now has to clear
+            let style = if position == 0 {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            rows.push(
+                Row::new(vec![
+                    Cell::from(if position == 0 { name.clone() } else { String::new() }),
+                    Cell::from(first_slot.to_string()),
+                    Cell::from(count.to_string()),
+                    Cell::from(format!("{} slots", slots_away)),
+                    Cell::from(format!("~{}", eta)),
+                ])
+                .style(style),
+            );
+        }
+    }
 
     let table = Table::new(
         rows,
         vec![
-            Constraint::Length(20), // Wider label column for better spacing
-            Constraint::Percentage(40),
-            Constraint::Percentage(40),
+            Constraint::Min(20),
+            Constraint::Length(12),
+            Constraint::Length(6),
+            Constraint::Length(14),
+            Constraint::Length(10),
         ],
     )
+    .header(header_row)
     .block(
         Block::default()
-            .title(format!(
-                "Identity: {} | Vote: {} | Time: {}",
-                identity_formatted,
-                vote_formatted,
-                chrono::Local::now().format("%H:%M:%S")
-            ))
-            .title_alignment(Alignment::Center)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::DarkGray))
             .padding(ratatui::widgets::Padding::new(1, 1, 0, 0)),
     );
+    f.render_widget(table, chunks[1]);
 
-    f.render_widget(table, padded_area);
+    let footer = Paragraph::new("l/q/Esc: Back to status | Times assume 400ms slots")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[2]);
 }
 
-// Removed draw_logs function as logs are no longer displayed
+/// Lines PageUp/PageDown scroll in the log view
+const LOG_PAGE_LINES: usize = 20;
 
-fn draw_footer(f: &mut ratatui::Frame, area: Rect, ui_state: &UiState) {
-    // Check if any fields are refreshing
-    let is_refreshing = ui_state.field_refresh_states.iter().any(|state| {
-        state.node_0.status_refreshing || state.node_0.identity_refreshing || state.node_0.version_refreshing ||
-        state.node_1.status_refreshing || state.node_1.identity_refreshing || state.node_1.version_refreshing
+/// Nodes the log view can follow, in table order
+fn log_nodes(ui_state: &UiState) -> Vec<&crate::types::NodeWithStatus> {
+    ui_state
+        .validator_statuses
+        .iter()
+        .flat_map(|status| status.nodes_with_status.iter().take(2))
+        .collect()
+}
+
+/// Stream the selected node's validator log into the log view until the
+/// command ends or the view is left
+async fn start_log_tail(
+    ui_state: &Arc<RwLock<UiState>>,
+    app_state: &Arc<AppState>,
+    ssh_pool: &Arc<AsyncSshPool>,
+) {
+    let node = {
+        let state = ui_state.read().await;
+        match log_nodes(&state).get(state.log_selection) {
+            Some(node) => node.node.clone(),
+            None => return,
+        }
+    };
+
+    let mut tail = LogTail::new(&node.label);
+    let ssh_key = match app_state.detected_ssh_keys.get(&node.host) {
+        Some(key) => key.clone(),
+        None => {
+            tail.ended = Some("No SSH key detected for this node".to_string());
+            ui_state.write().await.log_tail = Some(tail);
+            return;
+        }
+    };
+    ui_state.write().await.log_tail = Some(tail);
+
+    let ui_state_clone = Arc::clone(ui_state);
+    let ssh_pool = Arc::clone(ssh_pool);
+    let task = tokio::spawn(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(1024);
+        let command = crate::log_tail::tail_command(&node, crate::log_tail::VIEW_HISTORY_LINES);
+        let stream = ssh_pool.execute_command_streaming(&node, &ssh_key, &command, tx);
+        // A busy validator logs thousands of lines a second; take the UI
+        // lock once per batch
+        let receive = async {
+            let mut batch = Vec::new();
+            while rx.recv_many(&mut batch, 256).await > 0 {
+                if let Some(tail) = ui_state_clone.write().await.log_tail.as_mut() {
+                    for line in batch.drain(..) {
+                        tail.push(&line);
+                    }
+                }
+                batch.clear();
+            }
+        };
+        let (result, _) = tokio::join!(stream, receive);
+
+        let ended = match result {
+            Ok(()) => "Log stream ended".to_string(),
+            Err(e) => format!("Log stream failed: {}", e),
+        };
+        if let Some(tail) = ui_state_clone.write().await.log_tail.as_mut() {
+            tail.ended = Some(ended);
+        }
     });
-    
-    let refresh_indicator = if is_refreshing {
-        " | 🔄 Refreshing..."
-    } else {
-        ""
+    ui_state.write().await.log_tail_task = Some(task);
+}
+
+/// Stop following the log; dropping the stream closes the remote command
+async fn stop_log_tail(ui_state: &Arc<RwLock<UiState>>) {
+    let mut state = ui_state.write().await;
+    if let Some(task) = state.log_tail_task.take() {
+        task.abort();
+    }
+    state.log_tail = None;
+}
+
+/// Draw the log view: the node picker, or the followed log
+fn draw_logs_ui(f: &mut ratatui::Frame, ui_state: &UiState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.size());
+
+    let tail = match &ui_state.log_tail {
+        Some(tail) => tail,
+        None => {
+            let header = Paragraph::new("📜 VALIDATOR LOG - SELECT NODE")
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::BOTTOM));
+            f.render_widget(header, chunks[0]);
+
+            let rows: Vec<Row> = log_nodes(ui_state)
+                .iter()
+                .enumerate()
+                .map(|(position, node)| {
+                    let role = match node.status {
+                        crate::types::NodeStatus::Active => "Active",
+                        crate::types::NodeStatus::Standby => "Standby",
+                        crate::types::NodeStatus::Unknown => "Unknown",
+                    };
+                    let style = if position == ui_state.log_selection {
+                        Style::default().fg(Color::Black).bg(Color::Cyan)
+                    } else {
+                        Style::default()
+                    };
+                    Row::new(vec![
+                        Cell::from(format!("{}", position + 1)),
+                        Cell::from(node.node.label.clone()),
+                        Cell::from(role),
+                        Cell::from(node.node.host.clone()),
+                    ])
+                    .style(style)
+                })
+                .collect();
+            let table = Table::new(
+                rows,
+                vec![
+                    Constraint::Length(3),
+                    Constraint::Min(20),
+                    Constraint::Length(10),
+                    Constraint::Min(20),
+                ],
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::DarkGray))
+                    .padding(ratatui::widgets::Padding::new(1, 1, 0, 0)),
+            );
+            f.render_widget(table, chunks[1]);
+
+            let footer = Paragraph::new("↑/↓/1-9: Select node | Enter: Follow log | t/q/Esc: Back to status")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center);
+            f.render_widget(footer, chunks[2]);
+            return;
+        }
     };
-    
-    let help_text = format!(
-        "q/Esc: Quit | r: Refresh (5s) | s: Switch{}",
-        refresh_indicator
-    );
 
-    let footer = Paragraph::new(help_text)
+    let mut title = format!("📜 VALIDATOR LOG - {}", tail.node_label);
+    if tail.scroll > 0 {
+        title.push_str(&format!(" (scrolled back {} lines)", tail.scroll));
+    }
+    let header = Paragraph::new(title)
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(header, chunks[0]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let height = block.inner(chunks[1]).height as usize;
+    let mut lines: Vec<Line> = tail
+        .visible(height.saturating_sub(usize::from(tail.ended.is_some())))
+        .map(|line| {
+            let color = if line.contains("ERROR") {
+                Color::Red
+            } else if line.contains("WARN") {
+                Color::Yellow
+            } else {
+                Color::Gray
+            };
+            Line::from(Span::styled(line.clone(), Style::default().fg(color)))
+        })
+        .collect();
+    if lines.is_empty() && tail.ended.is_none() {
+        lines.push(Line::from(Span::styled(
+            "⏳ Waiting for log lines...",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    if let Some(ended) = &tail.ended {
+        lines.push(Line::from(Span::styled(
+            format!("⚠️ {}", ended),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    f.render_widget(Paragraph::new(lines).block(block), chunks[1]);
+
+    let footer = Paragraph::new("↑/↓ PgUp/PgDn: Scroll | End: Follow | t/q/Esc: Back to status")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
-
-    f.render_widget(footer, area);
+    f.render_widget(footer, chunks[2]);
 }
 
 /// Execute emergency failover for a validator
@@ -2701,6 +7425,8 @@ async fn execute_emergency_failover(
     ssh_pool: Arc<crate::ssh::AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
     emergency_takeover_flag: Arc<RwLock<bool>>,
+    switch_config: crate::types::SwitchConfig,
+    statsd_config: Option<crate::types::StatsdConfig>,
 ) {
     // Find active and standby nodes
     let (active_node, standby_node) = match (
@@ -2729,9 +7455,11 @@ async fn execute_emergency_failover(
         ssh_pool,
         detected_ssh_keys,
         alert_manager,
+        switch_config,
+        statsd_config,
     );
 
-    if let Err(e) = emergency_failover.execute_emergency_takeover().await {
+    if let Err(e) = crate::ssh::critical(emergency_failover.execute_emergency_takeover()).await {
         eprintln!("❌ Emergency failover error: {}", e);
     }
     
@@ -2743,7 +7471,8 @@ async fn execute_emergency_failover(
 }
 
 /// Draw the switch UI
-fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState) {
+fn draw_switch_ui(f: &mut ratatui::Frame, ui_state: &UiState, app_state: &AppState) {
+    let switch_config = app_state.config.switch.clone().unwrap_or_default();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -2764,20 +7493,61 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState) {
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(header, chunks[0]);
 
-    // Content area
+    // Content area, with a pair selector when more than one is configured
+    let validator_count = app_state.validator_statuses.len();
+    let selector_height = if validator_count > 1 {
+        validator_count as u16 + 2
+    } else {
+        0
+    };
     let content_chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
-            Constraint::Length(10), // Status info
-            Constraint::Length(10), // Actions
-            Constraint::Min(0),     // Messages
+            Constraint::Length(selector_height), // Validator selection
+            Constraint::Length(10),              // Status info
+            Constraint::Length(10),              // Actions
+            Constraint::Min(0),                  // Messages
         ])
         .split(chunks[1]);
 
+    let selected = ui_state.switch_selection.min(validator_count.saturating_sub(1));
+
+    if validator_count > 1 {
+        let selector_lines: Vec<Line> = app_state
+            .validator_statuses
+            .iter()
+            .enumerate()
+            .map(|(idx, status)| {
+                let text = format!(
+                    "{} {}. {}",
+                    if idx == selected { "▶" } else { " " },
+                    idx + 1,
+                    status.validator_pair.identity_pubkey
+                );
+                if idx == selected {
+                    Line::from(text).style(
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Line::from(text)
+                }
+            })
+            .collect();
+
+        let selector_widget = Paragraph::new(selector_lines).block(
+            Block::default()
+                .title(" Validator (↑/↓ or 1-9) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        f.render_widget(selector_widget, content_chunks[0]);
+    }
+
     // Current status
-    if !app_state.validator_statuses.is_empty() {
-        let validator_status = &app_state.validator_statuses[0];
+    if let Some(validator_status) = ui_state.validator_statuses.get(selected) {
 
         let active_node = validator_status
             .nodes_with_status
@@ -2827,22 +7597,60 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Cyan)),
         );
-        f.render_widget(status_widget, content_chunks[0]);
+        f.render_widget(status_widget, content_chunks[1]);
 
         // Actions that will be performed
-        let actions_text = vec![
-            Line::from("Actions that will be performed:")
-                .style(Style::default().add_modifier(Modifier::BOLD)),
-            Line::from("  1. Switch active node to unfunded identity"),
-            Line::from("  2. Transfer tower file to standby node"),
-            Line::from("  3. Switch standby node to funded identity"),
-            Line::from(""),
-            Line::from("⚠️  Press 'y' to confirm switch or 'q' to cancel").style(
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ];
+        let lock_holder = ui_state.switch_locks.get(selected).cloned().flatten();
+        let lag_blocker = switch_lag_blocker(ui_state, app_state, selected);
+        let actions_text = if let Some(holder) = lock_holder {
+            vec![
+                Line::from(format!("🔒 Switch locked by {}", holder)).style(
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Line::from(""),
+                Line::from("Another operator is switching this validator."),
+                Line::from("Wait for their switch to finish, then check the status again."),
+            ]
+        } else if let Some(reason) = lag_blocker {
+            vec![
+                Line::from(format!("⛔ Switch blocked: {}", reason)).style(
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Line::from(""),
+                Line::from("The validator would be delinquent until the standby catches up."),
+                Line::from(if switch_config.typed_confirmation {
+                    "Wait for it to catch up, or switch with `svs switch --force`."
+                } else {
+                    "Wait for it to catch up, or press 'F' (Shift+F) to switch anyway."
+                }),
+            ]
+        } else {
+            vec![
+                Line::from("Actions that will be performed:")
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+                Line::from("  1. Switch active node to unfunded identity"),
+                Line::from("  2. Transfer tower file to standby node"),
+                Line::from("  3. Switch standby node to funded identity"),
+                Line::from(""),
+                if switch_config.typed_confirmation {
+                    Line::from(format!(
+                        "⚠️  Type {} and press Enter to confirm: {}▏",
+                        switch_config.confirmation_phrase, ui_state.confirm_input
+                    ))
+                } else {
+                    Line::from("⚠️  Press 'y' to confirm switch or 'q' to cancel")
+                }
+                .style(
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]
+        };
 
         let actions_widget = Paragraph::new(actions_text).block(
             Block::default()
@@ -2850,12 +7658,20 @@ fn draw_switch_ui(f: &mut ratatui::Frame, app_state: &AppState) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Red)),
         );
-        f.render_widget(actions_widget, content_chunks[1]);
+        f.render_widget(actions_widget, content_chunks[2]);
     }
 
     // Footer
+    let footer_text = match (switch_config.typed_confirmation, validator_count > 1) {
+        (true, true) => "↑/↓ to choose validator | Type the phrase and press Enter to confirm | Esc to cancel",
+        (true, false) => "Type the phrase and press Enter to confirm | Esc to cancel",
+        (false, true) => {
+            "↑/↓ or 1-9 to choose validator | Press 'y' to confirm switch | Press 'q' to cancel"
+        }
+        (false, false) => "Press 'y' to confirm switch | Press 'q' to cancel",
+    };
     let footer =
-        Paragraph::new("Press 'y' to confirm switch | Press 'q' to cancel")
+        Paragraph::new(footer_text)
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Center);
     f.render_widget(footer, chunks[2]);
@@ -2976,6 +7792,8 @@ async fn refresh_validator_fields(
         }
     };
     
+    let catchup_timeout_seconds = app_state.config.ssh.clone().unwrap_or_default().catchup_timeout_seconds;
+
     // Refresh each node
     for (node_idx, node_with_status) in nodes.iter().enumerate() {
         let node = node_with_status.clone();
@@ -3007,6 +7825,8 @@ async fn refresh_validator_fields(
                 ssh_pool_clone,
                 ssh_key_clone,
                 ui_state_clone,
+                false,
+                catchup_timeout_seconds,
             ).await;
         });
         
@@ -3034,7 +7854,10 @@ async fn refresh_validator_fields(
     }
 }
 
-/// Refresh node status and identity
+/// Refresh node status and identity. With `keep_on_failure` a node whose
+/// identity can't be read keeps its last known role instead of turning
+/// Unknown, so a transient SSH or RPC error doesn't flip roles.
+#[allow(clippy::too_many_arguments)]
 async fn refresh_node_status_and_identity(
     validator_idx: usize,
     node_idx: usize,
@@ -3043,6 +7866,8 @@ async fn refresh_node_status_and_identity(
     ssh_pool: Arc<crate::ssh::AsyncSshPool>,
     ssh_key: String,
     ui_state: Arc<RwLock<UiState>>,
+    keep_on_failure: bool,
+    catchup_timeout_seconds: u64,
 ) {
     // Use the same logic as startup.rs to extract identity and status
     // First, get the solana CLI path
@@ -3136,7 +7961,11 @@ async fn refresh_node_status_and_identity(
         r#"curl -s http://localhost:{} -X POST -H "Content-Type: application/json" -d '{{"jsonrpc":"2.0","id":1,"method":"getIdentity"}}' 2>&1"#,
         rpc_port
     );
-    let command = rpc_command;
+    // A configured identity command replaces the local RPC query
+    let identity_override = node.node.identity_command().map(|command| {
+        format!("timeout {} {} 2>/dev/null", catchup_timeout_seconds, command)
+    });
+    let command = identity_override.clone().unwrap_or(rpc_command);
     let use_rpc = true;
     
     
@@ -3152,26 +7981,25 @@ async fn refresh_node_status_and_identity(
             let mut extracted_sync_status = None;
             
             if use_rpc {
-                // Parse RPC response for Agave/Jito
-                match serde_json::from_str::<serde_json::Value>(&output) {
-                    Ok(json) => {
-                        if let Some(identity) = json["result"]["identity"].as_str() {
-                            extracted_identity = Some(identity.to_string());
-                            
-                            // Determine status based on identity match
-                            if identity == validator_pair.identity_pubkey {
-                                extracted_status = crate::types::NodeStatus::Active;
-                            } else {
-                                extracted_status = crate::types::NodeStatus::Standby;
-                            }
-                            
-                            // For RPC, we need to run catchup separately to get sync status
-                            // We'll do this after getting identity
-                        }
-                    }
-                    Err(_e) => {
-                        // Failed to parse RPC response
+                // Parse RPC response for Agave/Jito, or the identity command's output
+                let reported = if identity_override.is_some() {
+                    crate::types::parse_identity_output(&output)
+                } else {
+                    serde_json::from_str::<serde_json::Value>(&output)
+                        .ok()
+                        .and_then(|json| json["result"]["identity"].as_str().map(str::to_string))
+                };
+                if let Some(identity) = reported {
+                    // Determine status based on identity match
+                    if identity == validator_pair.identity_pubkey {
+                        extracted_status = crate::types::NodeStatus::Active;
+                    } else {
+                        extracted_status = crate::types::NodeStatus::Standby;
                     }
+                    extracted_identity = Some(identity);
+
+                    // For RPC, we need to run catchup separately to get sync status
+                    // We'll do this after getting identity
                 }
             } else {
                 // Parse catchup output to extract identity and sync status
@@ -3235,7 +8063,11 @@ async fn refresh_node_status_and_identity(
     
     // If we got identity via RPC, now run catchup to get sync status
     let sync_status = if use_rpc && current_identity.is_some() {
-        let catchup_command = format!("timeout 10 {} catchup --our-localhost 2>&1", solana_cli);
+        let catchup_command = format!(
+            "timeout {} {} 2>&1",
+            catchup_timeout_seconds,
+            node.node.catchup_invocation(&solana_cli)
+        );
         
         match ssh_pool.execute_command(&node.node, &ssh_key, &catchup_command).await {
             Ok(output) => {
@@ -3275,7 +8107,11 @@ async fn refresh_node_status_and_identity(
         
         // Update the validator status in UI state
         if let Some(validator_status) = ui_state_write.validator_statuses.get_mut(validator_idx) {
-            if let Some(node_with_status) = validator_status.nodes_with_status.get_mut(node_idx) {
+            if let Some(node_with_status) = validator_status
+                .nodes_with_status
+                .get_mut(node_idx)
+                .filter(|_| current_identity.is_some() || !keep_on_failure)
+            {
                 // Update status
                 node_with_status.status = _status;
                 
@@ -3296,6 +8132,76 @@ async fn refresh_node_status_and_identity(
     }
 }
 
+/// Slots the validator's standby node was last measured behind, as streamed
+/// into the UI
+pub(crate) fn standby_slots_behind(ui_state: &UiState, validator_idx: usize) -> Option<u64> {
+    let node_idx = ui_state
+        .validator_statuses
+        .get(validator_idx)?
+        .nodes_with_status
+        .iter()
+        .position(|n| n.status == crate::types::NodeStatus::Standby)?;
+    let pair = ui_state.catchup_data.get(validator_idx)?;
+    let status = if node_idx == 0 { pair.node_0.as_ref() } else { pair.node_1.as_ref() }?;
+    status.slots_behind
+}
+
+/// Why the selected validator's standby is too far behind to switch to
+fn switch_lag_blocker(
+    ui_state: &UiState,
+    app_state: &AppState,
+    validator_idx: usize,
+) -> Option<String> {
+    crate::commands::switch::standby_lag_blocker(
+        standby_slots_behind(ui_state, validator_idx),
+        app_state.config.switch.clone().unwrap_or_default().max_standby_slots_behind,
+    )
+}
+
+/// Epoch number, percent complete, slot index and estimated time to the
+/// next boundary
+fn format_epoch_progress(info: &EpochInfo) -> String {
+    let percent = if info.slots_in_epoch > 0 {
+        info.slot_index as f64 * 100.0 / info.slots_in_epoch as f64
+    } else {
+        0.0
+    };
+    let remaining_slots = info.slots_in_epoch.saturating_sub(info.slot_index);
+    format!(
+        "E{} · {:.1}% · slot {}/{} · ~{} left",
+        info.epoch,
+        percent,
+        info.slot_index,
+        info.slots_in_epoch,
+        crate::commands::switch::format_wait(Duration::from_millis(
+            remaining_slots * crate::commands::switch::SLOT_DURATION_MS
+        ))
+    )
+}
+
+/// Describe a change of active node between two role snapshots, ignoring
+/// nodes whose role couldn't be read
+fn role_change_message(
+    before: &[crate::types::NodeWithStatus],
+    after: &[crate::types::NodeWithStatus],
+) -> Option<String> {
+    let active = |nodes: &[crate::types::NodeWithStatus]| {
+        nodes
+            .iter()
+            .find(|n| n.status == crate::types::NodeStatus::Active)
+            .map(|n| n.node.label.clone())
+    };
+    let (before, after) = (active(before), active(after));
+    match (before, after) {
+        (before, Some(after)) if before.as_ref() != Some(&after) => Some(format!(
+            "Roles changed outside svs: {} is now active (was {})",
+            after,
+            before.unwrap_or_else(|| "none".to_string())
+        )),
+        _ => None,
+    }
+}
+
 /// Refresh node version
 async fn refresh_node_version(
     validator_idx: usize,
@@ -3309,7 +8215,13 @@ async fn refresh_node_version(
     let (_validator_type, _version) = match node.validator_type {
         crate::types::ValidatorType::Firedancer => {
             if let Some(ref fdctl_exec) = node.fdctl_executable {
-                let version_cmd = format!("timeout 10 {} version 2>/dev/null", fdctl_exec);
+                let version_cmd = format!(
+                    "timeout 10 {} 2>/dev/null",
+                    node.node
+                        .version_command()
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("{} version", fdctl_exec))
+                );
                 let version_output = ssh_pool
                     .execute_command(&node.node, &ssh_key, &version_cmd)
                     .await
@@ -3333,7 +8245,10 @@ async fn refresh_node_version(
         }
         crate::types::ValidatorType::Agave | crate::types::ValidatorType::Jito => {
             if let Some(ref agave_exec) = node.agave_validator_executable {
-                let version_cmd = format!("timeout 10 {} --version 2>/dev/null", agave_exec);
+                let version_cmd = format!(
+                    "timeout 10 {} 2>/dev/null",
+                    node.node.version_invocation(agave_exec)
+                );
                 let version_output = ssh_pool
                     .execute_command(&node.node, &ssh_key, &version_cmd)
                     .await
@@ -3395,6 +8310,202 @@ async fn refresh_node_version(
     }
 }
 
+/// Entry point for kiosk mode: the status UI with sensitive details masked
+/// and every input except view navigation disabled
+pub async fn show_kiosk_status_ui(app_state: &AppState) -> Result<()> {
+    print!("\x1B[2J\x1B[1;1H");
+    std::io::stdout().flush()?;
+
+    let mut app = EnhancedStatusApp::new(Arc::new(app_state.clone())).await?;
+    app.ui_state.write().await.kiosk = true;
+    run_enhanced_ui(&mut app).await?;
+
+    Ok(())
+}
+
+/// Current node, validator and SSH pool metrics as InfluxDB line protocol
+fn influx_lines(state: &UiState, timestamp: i64) -> String {
+    let mut body = String::new();
+
+    for (idx, validator_status) in state.validator_statuses.iter().enumerate() {
+        let identity = &validator_status.validator_pair.identity_pubkey;
+        let vote_data = state.vote_data.get(idx).and_then(|data| data.as_ref());
+        body.push_str(&crate::influxdb::validator_line(
+            identity,
+            vote_data.and_then(|data| data.recent_votes.last().map(|v| v.slot)),
+            vote_data.map(|data| data.is_voting),
+            state
+                .rpc_failure_tracker
+                .get(idx)
+                .map(|tracker| tracker.consecutive_failures)
+                .unwrap_or(0),
+            timestamp,
+        ));
+
+        for (node_idx, node) in validator_status.nodes_with_status.iter().enumerate() {
+            let ssh = state.ssh_health_data.get(idx).map(|pair| {
+                if node_idx == 0 { &pair.node_0 } else { &pair.node_1 }
+            });
+            let catchup = state.catchup_data.get(idx).and_then(|pair| {
+                if node_idx == 0 { pair.node_0.as_ref() } else { pair.node_1.as_ref() }
+            });
+            let slots_behind = catchup.and_then(|c| {
+                if c.status == "Caught up" {
+                    Some(0)
+                } else {
+                    crate::alert::slots_behind(&c.status)
+                }
+            });
+            body.push_str(&crate::influxdb::node_line(
+                identity,
+                &node.node.label,
+                &node.node.host,
+                node.status == crate::types::NodeStatus::Active,
+                ssh.map(|s| s.is_healthy).unwrap_or(false),
+                slots_behind,
+                timestamp,
+            ));
+        }
+    }
+
+    body.push_str(&crate::ssh::render_pool_lines(&state.ssh_pool_diagnostics, timestamp));
+    body
+}
+
+/// Write every log message to the JSON log file and syslog, whichever are
+/// configured, on a plain thread so I/O never stalls the monitor or holds up
+/// shutdown. A failing log file is reported back through the same channel,
+/// which reaches syslog and the file once it can be written again.
+fn spawn_log_sinks(
+    log_file_config: Option<&crate::types::LogFileConfig>,
+    mut log_receiver: tokio::sync::mpsc::UnboundedReceiver<LogMessage>,
+    log_sender: tokio::sync::mpsc::WeakUnboundedSender<LogMessage>,
+) -> Result<()> {
+    let mut log_file = match log_file_config {
+        Some(config) => Some(crate::log_file::JsonLogFile::from_config(config)?),
+        None => None,
+    };
+    let syslog = crate::syslog::sink();
+    if log_file.is_none() && syslog.is_none() {
+        return Ok(());
+    }
+
+    std::thread::spawn(move || {
+        let mut file_failed = false;
+        while let Some(log) = log_receiver.blocking_recv() {
+            let log = LogMessage {
+                message: crate::redact::redact_secrets(&log.message),
+                ..log
+            };
+            if let Some(sink) = syslog {
+                sink.send_log(&log);
+            }
+            if let Some(file) = log_file.as_mut() {
+                match file.write_message(&log) {
+                    Ok(()) => file_failed = false,
+                    Err(e) if !file_failed => {
+                        file_failed = true;
+                        if let Some(sender) = log_sender.upgrade() {
+                            let _ = sender.send(LogMessage {
+                                host: "log-file".to_string(),
+                                message: format!("Failed to write log file {}: {}", file.path().display(), e),
+                                timestamp: Instant::now(),
+                                level: LogLevel::Error,
+                            });
+                        }
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Tell systemd the monitor is up and, when the unit sets `WatchdogSec`, keep
+/// pinging the watchdog only while the vote-polling and SSH-health loops
+/// complete passes, so a wedged loop gets svs restarted
+fn spawn_systemd_watchdog(
+    ui_state: Arc<RwLock<UiState>>,
+    log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
+) {
+    use crate::systemd_notify::{loops_healthy, notify, watchdog_interval};
+
+    if !notify("READY=1\nSTATUS=Monitoring") {
+        return;
+    }
+    let period = match watchdog_interval(
+        std::env::var("WATCHDOG_USEC").ok().as_deref(),
+        std::env::var("WATCHDOG_PID").ok().as_deref(),
+        std::process::id(),
+    ) {
+        Some(period) => period,
+        None => return,
+    };
+
+    tokio::spawn(async move {
+        let mut interval = interval(period);
+        let mut stalled = false;
+
+        loop {
+            interval.tick().await;
+
+            let health = {
+                let state = ui_state.read().await;
+                loops_healthy(
+                    state.last_vote_refresh.elapsed(),
+                    state.last_ssh_health_refresh.elapsed(),
+                )
+            };
+            match health {
+                Ok(()) => {
+                    notify(if stalled { "WATCHDOG=1\nSTATUS=Monitoring" } else { "WATCHDOG=1" });
+                    stalled = false;
+                }
+                Err(reason) if !stalled => {
+                    stalled = true;
+                    notify(&format!("STATUS={}", reason));
+                    let _ = log_sender.send(LogMessage {
+                        host: "systemd".to_string(),
+                        message: format!("Withholding watchdog ping: {}", reason),
+                        timestamp: Instant::now(),
+                        level: LogLevel::Error,
+                    });
+                }
+                Err(_) => {}
+            }
+        }
+    });
+}
+
+/// Entry point for headless monitoring: the same background tasks as the
+/// status UI (vote polling, SSH health, alerts, auto-failover, Telegram bot)
+/// with log messages written to stdout instead of a terminal UI
+pub async fn run_headless_monitor(app_state: &AppState) -> Result<()> {
+    let mut app = EnhancedStatusApp::new(Arc::new(app_state.clone())).await?;
+    let (log_sender, mut log_receiver) = tokio::sync::mpsc::unbounded_channel();
+    // Still hand each message to the log file and syslog, if configured
+    let file_sender = std::mem::replace(&mut app.log_sender, log_sender);
+    app.spawn_background_tasks();
+    spawn_systemd_watchdog(Arc::clone(&app.ui_state), app.log_sender.clone());
+
+    println!(
+        "👀 Monitoring {} validator(s), logging to stdout",
+        app_state.validator_statuses.len()
+    );
+
+    while let Some(log) = log_receiver.recv().await {
+        let log = LogMessage {
+            message: crate::redact::redact_secrets(&log.message),
+            ..log
+        };
+        println!("{:<5} [{}] {}", log.level.label(), log.host, log.message);
+        let _ = file_sender.send(log);
+    }
+
+    Ok(())
+}
+
 /// Entry point for the enhanced UI
 pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
     // Clear any startup output before starting the TUI
@@ -3409,13 +8520,30 @@ pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
     let switch_confirmed = run_enhanced_ui(&mut app).await?;
     
     if switch_confirmed {
-        // Execute the switch
+        // Execute the switch for the pair chosen in the switch view
         // Use the switch command with confirmation already provided
         let mut app_state_mut = app_state.clone();
-        let result = crate::commands::switch::switch_command_with_confirmation(
+        let validator_idx = {
+            let state = app.ui_state.read().await;
+            // Switch with the roles as last reconciled, not as detected at startup
+            app_state_mut.validator_statuses = state.validator_statuses.clone();
+            state.switch_selection
+        };
+        if let Some(status) = app_state_mut.validator_statuses.get(validator_idx) {
+            crate::audit::record(
+                &crate::switch_lock::lock_owner(),
+                "switch_confirmed",
+                &status.validator_pair.identity_pubkey,
+                "status UI",
+            );
+        }
+        let result = crate::commands::switch::switch_validator_with_progress(
             false,  // not a dry run
             &mut app_state_mut,
+            validator_idx,
             false,  // don't require confirmation again
+            false,
+            None,
         ).await?;
         
         if result {
@@ -3427,3 +8555,96 @@ pub async fn show_enhanced_status_ui(app_state: &AppState) -> Result<()> {
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NodeConfig, NodePaths, NodeStatus, NodeWithStatus, ValidatorType};
+
+    fn node(label: &str, status: NodeStatus) -> NodeWithStatus {
+        NodeWithStatus {
+            node: NodeConfig {
+                label: label.to_string(),
+                host: "10.0.0.1".to_string(),
+                port: 22,
+                user: "solana".to_string(),
+                paths: NodePaths {
+                    funded_identity: "/keys/funded.json".to_string(),
+                    unfunded_identity: "/keys/unfunded.json".to_string(),
+                    vote_keypair: "/keys/vote.json".to_string(),
+                    ..Default::default()
+                },
+                ssh_key_path: None,
+                password_auth: false,
+                sudo: None,
+                commands: None,
+            },
+            status,
+            validator_type: ValidatorType::Agave,
+            agave_validator_executable: None,
+            fdctl_executable: None,
+            version: None,
+            sync_status: None,
+            current_identity: None,
+            ledger_path: None,
+            tower_path: None,
+            swap_ready: None,
+            swap_issues: Vec::new(),
+            ssh_key_path: None,
+            solana_cli_executable: None,
+        }
+    }
+
+    #[test]
+    fn test_swap_readiness_label() {
+        let mut standby = node("node-b", NodeStatus::Standby);
+        assert_eq!(standby.swap_readiness_label(), "❓ Unknown");
+        standby.swap_ready = Some(true);
+        assert_eq!(standby.swap_readiness_label(), "✅ Ready");
+
+        standby.swap_ready = Some(false);
+        assert_eq!(standby.swap_readiness_label(), "❌ Not Ready");
+        standby.swap_issues = vec![
+            "vm.max_map_count is 65530, needs at least 1000000".to_string(),
+            "Tower file missing".to_string(),
+        ];
+        assert_eq!(
+            standby.swap_readiness_label(),
+            "❌ vm.max_map_count is 65530, needs at least 1000000 (+1 more)"
+        );
+    }
+
+    #[test]
+    fn test_format_epoch_progress() {
+        let info = EpochInfo {
+            epoch: 700,
+            slot_index: 108_000,
+            slots_in_epoch: 432_000,
+            absolute_slot: 302_508_000,
+            block_height: 0,
+            transaction_count: None,
+        };
+        assert_eq!(
+            format_epoch_progress(&info),
+            "E700 · 25.0% · slot 108000/432000 · ~36h 0m left"
+        );
+    }
+
+    #[test]
+    fn test_role_change_message() {
+        let before = vec![node("primary", NodeStatus::Active), node("backup", NodeStatus::Standby)];
+        let swapped = vec![node("primary", NodeStatus::Standby), node("backup", NodeStatus::Active)];
+        let unreadable = vec![node("primary", NodeStatus::Unknown), node("backup", NodeStatus::Standby)];
+
+        assert_eq!(role_change_message(&before, &before), None);
+        assert_eq!(
+            role_change_message(&before, &swapped).as_deref(),
+            Some("Roles changed outside svs: backup is now active (was primary)")
+        );
+        assert_eq!(role_change_message(&before, &unreadable), None);
+        assert_eq!(
+            role_change_message(&unreadable, &before).as_deref(),
+            Some("Roles changed outside svs: primary is now active (was none)")
+        );
+    }
+}
@@ -2,18 +2,19 @@ use crate::commands::error_handler::ProgressSpinner;
 use anyhow::{anyhow, Result};
 use colored::*;
 use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-// Check if we're in silent mode (called from Telegram)
-fn is_silent_mode() -> bool {
-    std::env::var("SVS_SILENT_MODE").unwrap_or_default() == "1"
-}
-
-// Macro for conditional printing
+// Print unless the switch reports through a progress channel instead
 macro_rules! println_if_not_silent {
-    ($($arg:tt)*) => {
-        if !is_silent_mode() {
+    ($silent:expr) => {
+        if !$silent {
+            println!();
+        }
+    };
+    ($silent:expr, $($arg:tt)*) => {
+        if !$silent {
             println!($($arg)*);
         }
     };
@@ -25,9 +26,9 @@ struct ConditionalSpinner {
 }
 
 impl ConditionalSpinner {
-    fn new(message: &str) -> Self {
+    fn new(message: &str, silent: bool) -> Self {
         Self {
-            spinner: if is_silent_mode() {
+            spinner: if silent {
                 None
             } else {
                 Some(ProgressSpinner::new(message))
@@ -42,26 +43,390 @@ impl ConditionalSpinner {
     }
 }
 
-pub async fn switch_command(dry_run: bool, app_state: &mut crate::AppState) -> Result<bool> {
-    // Clear screen and ensure clean output after menu selection
-    print!("\x1B[2J\x1B[1;1H");
-    std::io::stdout().flush()?;
+/// A pre-switch check stopped the switch before anything on the nodes
+/// changed. `svs switch --yes` exits with 2 for it, like other refusals.
+#[derive(Debug)]
+pub struct SwitchRefused(pub String);
+
+impl std::fmt::Display for SwitchRefused {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SwitchRefused {}
+
+/// `assume_yes` (`svs switch --yes` from automation) skips every prompt
+pub async fn switch_command(
+    dry_run: bool,
+    force: bool,
+    assume_yes: bool,
+    validator_idx: usize,
+    app_state: &mut crate::AppState,
+) -> Result<bool> {
+    // Clear screen and ensure clean output after menu selection, but keep
+    // the log of a non-interactive run intact
+    if !assume_yes {
+        print!("\x1B[2J\x1B[1;1H");
+        std::io::stdout().flush()?;
+    }
+
+    switch_command_with_confirmation(
+        dry_run,
+        app_state,
+        validator_idx,
+        !dry_run && !assume_yes,
+        force,
+        assume_yes,
+        None,
+    )
+    .await
+}
+
+/// Approximate slot time, used for countdowns only
+pub const SLOT_DURATION_MS: u64 = 400;
+
+/// Confirm now, wait until `slot_offset` slots into the next epoch, then
+/// switch without asking again. Planned maintenance then lands right after
+/// the leader schedule rolls over.
+pub async fn switch_at_epoch_boundary(
+    app_state: &mut crate::AppState,
+    validator_idx: usize,
+    slot_offset: u64,
+    force: bool,
+    wait_for_window: bool,
+    assume_yes: bool,
+) -> Result<bool> {
+    let validator_pair = &app_state
+        .validator_statuses
+        .get(validator_idx)
+        .ok_or_else(|| anyhow!("Validator {} is not configured", validator_idx + 1))?
+        .validator_pair;
+    let rpc_url =
+        crate::rpc_selector::best_rpc(&app_state.rpc_selectors, validator_idx, &validator_pair.rpc)
+            .await;
+
+    let epoch_info = crate::solana_rpc::fetch_epoch_info(&rpc_url).await?;
+    let target_slot = crate::solana_rpc::next_epoch_start_slot(&epoch_info, slot_offset);
+    let eta = Duration::from_millis(
+        target_slot.saturating_sub(epoch_info.absolute_slot) * SLOT_DURATION_MS,
+    );
+
+    println!("\n{}", "🗓️  Switch at Epoch Boundary".bright_cyan().bold());
+    println!("{}", "━".repeat(50).dimmed());
+    println!("  Current epoch: {} (slot {})", epoch_info.epoch, epoch_info.absolute_slot);
+    println!(
+        "  Switch at:     slot {} (epoch {} + {} slots)",
+        target_slot,
+        epoch_info.epoch + 1,
+        slot_offset
+    );
+    println!("  Estimated in:  {}", format_wait(eta).bright_yellow());
+    println!();
+
+    use inquire::Confirm;
+    let confirmed = assume_yes
+        || Confirm::new("Schedule the validator switch for this slot?")
+            .with_default(false)
+            .prompt()?;
+    if !confirmed {
+        println!("\n{}", "❌ Scheduled switch cancelled by user".red());
+        return Ok(false);
+    }
+
+    println!("{}", "⏳ Waiting for the epoch boundary (Ctrl+C to abort)...".dimmed());
+    loop {
+        let current_slot = match crate::solana_rpc::fetch_epoch_info(&rpc_url).await {
+            Ok(info) => info.absolute_slot,
+            Err(e) => {
+                println!("\r{} {}", "⚠️  RPC error while waiting:".yellow(), e);
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+        if current_slot >= target_slot {
+            break;
+        }
+
+        let remaining = Duration::from_millis((target_slot - current_slot) * SLOT_DURATION_MS);
+        print!(
+            "\r\x1B[2K  Slot {} - {} slots to go (~{})",
+            current_slot,
+            target_slot - current_slot,
+            format_wait(remaining)
+        );
+        std::io::stdout().flush()?;
+
+        // Poll more often as the boundary gets closer
+        let pause = (remaining / 2).clamp(Duration::from_secs(1), Duration::from_secs(60));
+        tokio::time::sleep(pause).await;
+    }
+
+    println!("\n{}", format!("✅ Reached slot {}, starting switch", target_slot).bright_green());
+    if wait_for_window {
+        wait_for_leader_gap(app_state, validator_idx).await?;
+    }
+    switch_command_with_confirmation(false, app_state, validator_idx, false, force, assume_yes, None)
+        .await
+}
+
+/// Confirm now, wait until the validator has no leader slot within
+/// `switch.min_leader_gap_slots`, then switch without asking again. The
+/// switch then never lands in the middle of the validator's own blocks.
+pub async fn switch_in_leader_gap(
+    app_state: &mut crate::AppState,
+    validator_idx: usize,
+    force: bool,
+    assume_yes: bool,
+) -> Result<bool> {
+    let min_gap = app_state
+        .config
+        .switch
+        .clone()
+        .unwrap_or_default()
+        .min_leader_gap_slots;
+
+    println!("\n{}", "⏱️  Switch in Leader Gap".bright_cyan().bold());
+    println!("{}", "━".repeat(50).dimmed());
+    println!(
+        "  Starts once no leader slot is due for {} slots (~{})",
+        min_gap,
+        format_wait(Duration::from_millis(min_gap * SLOT_DURATION_MS))
+    );
+    println!();
+
+    use inquire::Confirm;
+    let confirmed = assume_yes
+        || Confirm::new("Switch as soon as a safe window opens?")
+            .with_default(false)
+            .prompt()?;
+    if !confirmed {
+        println!("\n{}", "❌ Switch cancelled by user".red());
+        return Ok(false);
+    }
+
+    wait_for_leader_gap(app_state, validator_idx).await?;
+    switch_command_with_confirmation(false, app_state, validator_idx, false, force, assume_yes, None)
+        .await
+}
+
+/// Slots from `current_slot` until the next leader slot is at least
+/// `min_gap` slots away. `leader_slots` must be sorted ascending.
+pub(crate) fn slots_until_leader_gap(current_slot: u64, leader_slots: &[u64], min_gap: u64) -> u64 {
+    let mut start = current_slot;
+    for &slot in leader_slots.iter().filter(|slot| **slot >= current_slot) {
+        if slot - start >= min_gap {
+            break;
+        }
+        start = slot + 1;
+    }
+    start - current_slot
+}
+
+/// Poll the leader schedule until a gap of `switch.min_leader_gap_slots`
+/// opens ahead of the current slot
+async fn wait_for_leader_gap(app_state: &crate::AppState, validator_idx: usize) -> Result<()> {
+    let validator_pair = &app_state
+        .validator_statuses
+        .get(validator_idx)
+        .ok_or_else(|| anyhow!("Validator {} is not configured", validator_idx + 1))?
+        .validator_pair;
+    let min_gap = app_state
+        .config
+        .switch
+        .clone()
+        .unwrap_or_default()
+        .min_leader_gap_slots;
+    let rpc_url =
+        crate::rpc_selector::best_rpc(&app_state.rpc_selectors, validator_idx, &validator_pair.rpc)
+            .await;
+
+    println!("{}", "⏳ Waiting for a gap in the leader schedule (Ctrl+C to abort)...".dimmed());
+    loop {
+        let (current_slot, leader_slots) = match crate::solana_rpc::fetch_upcoming_leader_slots(
+            &rpc_url,
+            &validator_pair.identity_pubkey,
+        )
+        .await
+        {
+            Ok(schedule) => schedule,
+            Err(e) => {
+                println!("\r{} {}", "⚠️  RPC error while waiting:".yellow(), e);
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+
+        let wait_slots = slots_until_leader_gap(current_slot, &leader_slots, min_gap);
+        let next_leader = leader_slots.first().map(|slot| slot - current_slot);
+        if wait_slots == 0 {
+            let next = next_leader
+                .map(|slots| format!("next leader slot in {} slots", slots))
+                .unwrap_or_else(|| "no leader slots scheduled".to_string());
+            println!("\n{}", format!("✅ Safe window at slot {}, {}", current_slot, next).bright_green());
+            return Ok(());
+        }
+
+        let remaining = Duration::from_millis(wait_slots * SLOT_DURATION_MS);
+        print!(
+            "\r\x1B[2K  Slot {} - leader in {} slots, window opens in {} slots (~{})",
+            current_slot,
+            next_leader.unwrap_or(0),
+            wait_slots,
+            format_wait(remaining)
+        );
+        std::io::stdout().flush()?;
 
-    switch_command_with_confirmation(dry_run, app_state, !dry_run).await
+        let pause = (remaining / 2).clamp(Duration::from_secs(1), Duration::from_secs(30));
+        tokio::time::sleep(pause).await;
+    }
+}
+
+pub fn format_wait(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
 }
 
+/// Step progress reported to callers without a terminal (Telegram bot)
+pub type ProgressSender = tokio::sync::mpsc::UnboundedSender<String>;
+
+/// Pre-switch checks shared by every way of starting a switch, then the
+/// switch itself. `progress` is as for `switch_validator_with_progress`.
 pub async fn switch_command_with_confirmation(
     dry_run: bool,
     app_state: &mut crate::AppState,
+    validator_idx: usize,
+    require_confirmation: bool,
+    force: bool,
+    assume_yes: bool,
+    progress: Option<ProgressSender>,
+) -> Result<bool> {
+    let silent = progress.is_some();
+
+    // A standby that trails the cluster would leave the validator delinquent
+    // after the switch; a dry run only reports it
+    let max_slots_behind = app_state
+        .config
+        .switch
+        .clone()
+        .unwrap_or_default()
+        .max_standby_slots_behind;
+    let slots_behind = standby_slots_behind(app_state, validator_idx).await;
+    if let Some(reason) = standby_lag_blocker(slots_behind, max_slots_behind) {
+        if dry_run || force {
+            println_if_not_silent!(
+                silent,
+                "{}",
+                format!("⚠️  {}{}", reason, if force { " - continuing (--force)" } else { "" })
+                    .yellow()
+            );
+        } else {
+            if let Some(progress) = &progress {
+                let _ = progress.send(format!("🔒 Switch refused: {}", reason));
+            }
+            println_if_not_silent!(silent, "{}", format!("🔒 Switch refused: {}", reason).red().bold());
+            println_if_not_silent!(
+                silent,
+                "{}",
+                "   Wait for the standby to catch up, or pass --force to switch anyway".dimmed()
+            );
+            return Ok(false);
+        }
+    }
+
+    switch_validator_with_progress(
+        dry_run,
+        app_state,
+        validator_idx,
+        require_confirmation,
+        assume_yes,
+        progress,
+    )
+    .await
+}
+
+/// Whether the typed confirmation matches the configured phrase exactly
+/// (case-sensitive, surrounding whitespace ignored)
+pub(crate) fn confirmation_matches(input: &str, phrase: &str) -> bool {
+    !phrase.trim().is_empty() && input.trim() == phrase.trim()
+}
+
+/// Why the standby is too far behind to take over, None when it is within
+/// `max_slots_behind` of the cluster. `slots_behind` is the last measured
+/// lag, None when it couldn't be measured.
+pub(crate) fn standby_lag_blocker(slots_behind: Option<u64>, max_slots_behind: u64) -> Option<String> {
+    match slots_behind {
+        Some(slots) if slots <= max_slots_behind => None,
+        Some(slots) => Some(format!(
+            "standby is {} slots behind (limit {})",
+            slots, max_slots_behind
+        )),
+        None => Some("standby catchup status is unknown".to_string()),
+    }
+}
+
+/// One-shot catchup of a validator's standby node, run over SSH for callers
+/// without the status UI's streamed catchup data
+pub(crate) async fn standby_slots_behind(
+    app_state: &crate::AppState,
+    validator_idx: usize,
+) -> Option<u64> {
+    let standby = app_state
+        .validator_statuses
+        .get(validator_idx)?
+        .nodes_with_status
+        .iter()
+        .find(|n| n.status == crate::types::NodeStatus::Standby)?;
+    let ssh_key = app_state.detected_ssh_keys.get(&standby.node.host)?;
+    let solana_cli = match (&standby.solana_cli_executable, &standby.agave_validator_executable) {
+        (Some(cli), _) => cli.clone(),
+        (None, Some(validator)) => validator.replace("agave-validator", "solana"),
+        (None, None) => "solana".to_string(),
+    };
+
+    // catchup keeps printing until the node has caught up; the last line
+    // within the timeout is the current lag
+    let command = format!(
+        "timeout {} {} 2>&1 | tail -1",
+        app_state.config.ssh.clone().unwrap_or_default().catchup_timeout_seconds,
+        standby.node.catchup_invocation(&shell_quote(&solana_cli))
+    );
+    let output = app_state
+        .ssh_pool
+        .execute_command(&standby.node, ssh_key, &command)
+        .await
+        .ok()?;
+    crate::commands::status_ui_v2::catchup_slots_behind(&output)
+}
+
+/// Switch a specific validator, optionally reporting each step to `progress`.
+/// A caller that takes the progress (the Telegram bot, under a running UI)
+/// gets no terminal output. With `assume_yes` nothing waits for a key and a
+/// switch after which voting doesn't resume is an error.
+pub async fn switch_validator_with_progress(
+    dry_run: bool,
+    app_state: &mut crate::AppState,
+    validator_idx: usize,
     require_confirmation: bool,
+    assume_yes: bool,
+    progress: Option<ProgressSender>,
 ) -> Result<bool> {
     // Validate we have at least one validator configured
     if app_state.config.validators.is_empty() {
         return Err(anyhow!("No validators configured"));
     }
+    let silent = progress.is_some();
 
-    // For now, use the first validator
-    let validator_status = &app_state.validator_statuses[0];
+    let validator_status = app_state
+        .validator_statuses
+        .get(validator_idx)
+        .ok_or_else(|| anyhow!("Validator {} is not configured", validator_idx + 1))?;
     let validator_pair = &validator_status.validator_pair;
 
     // Find active and standby nodes with full status information
@@ -90,6 +455,7 @@ pub async fn switch_command_with_confirmation(
         };
 
     println_if_not_silent!(
+        silent,
         "\n{}",
         format!(
             "🔄 Validator Switch - {} Mode",
@@ -98,18 +464,20 @@ pub async fn switch_command_with_confirmation(
         .bright_cyan()
         .bold()
     );
-    println_if_not_silent!("{}", "━".repeat(50).dimmed());
+    println_if_not_silent!(silent, "{}", "━".repeat(50).dimmed());
 
     if dry_run {
         println_if_not_silent!(
+            silent,
             "{}",
             "ℹ️  This is a DRY RUN - showing what would be executed".yellow()
         );
         println_if_not_silent!(
+            silent,
             "{}",
-            "ℹ️  Tower file transfer will be performed to measure timing".yellow()
+            "ℹ️  Each step is probed on the nodes; nothing is changed".yellow()
         );
-        println_if_not_silent!();
+        println_if_not_silent!(silent);
     }
 
     let mut switch_manager = SwitchManager::new(
@@ -119,48 +487,239 @@ pub async fn switch_command_with_confirmation(
         app_state.ssh_pool.clone(),
         app_state.detected_ssh_keys.clone(),
     );
+    switch_manager.progress = progress;
+    switch_manager.silent = silent;
+    switch_manager.switch_config = app_state.config.switch.clone().unwrap_or_default();
+
+    // Stream the steps to the alert channels, unless the caller (Telegram
+    // bot) already relays them to the chat
+    let mut progress_relay = None;
+    if switch_manager.progress.is_none() && !dry_run {
+        if let Some((sender, relay)) = app_state.config.alert_config.as_ref().and_then(|config| {
+            crate::alert::AlertManager::new(config.clone())
+                .relay_switch_progress(&validator_pair.identity_pubkey)
+        }) {
+            switch_manager.progress = Some(sender);
+            progress_relay = Some(relay);
+        }
+    }
+
+    // Dry run probes every step on the nodes before showing the plan
+    if dry_run && !switch_manager.print_dry_run_checks().await {
+        println_if_not_silent!(
+            silent,
+            "\n{}",
+            "❌ Dry run found problems - fix them before switching".red().bold()
+        );
+        return Ok(false);
+    }
 
     // Pre-warm SSH connections to both nodes for faster switching
     if !dry_run {
-        let spinner = ConditionalSpinner::new("Pre-warming SSH connections...");
+        let spinner = ConditionalSpinner::new("Pre-warming SSH connections...", silent);
 
         // Get SSH keys for both nodes
         let active_ssh_key = app_state
             .detected_ssh_keys
             .get(&active_node_with_status.node.host)
-            .ok_or_else(|| anyhow!("No SSH key detected for active node"))?;
+            .ok_or_else(|| SwitchRefused("No SSH key detected for active node".to_string()))?;
         let standby_ssh_key = app_state
             .detected_ssh_keys
             .get(&standby_node_with_status.node.host)
-            .ok_or_else(|| anyhow!("No SSH key detected for standby node"))?;
+            .ok_or_else(|| SwitchRefused("No SSH key detected for standby node".to_string()))?;
 
         // Pre-warm both connections (they'll be reused from the pool during switch)
         {
             let pool = app_state.ssh_pool.clone();
             // Trigger connection creation for both nodes
-            let _ = pool
-                .get_session(&active_node_with_status.node, active_ssh_key)
-                .await?;
-            let _ = pool
-                .get_session(&standby_node_with_status.node, standby_ssh_key)
-                .await?;
+            for (node, ssh_key) in [
+                (&active_node_with_status.node, active_ssh_key),
+                (&standby_node_with_status.node, standby_ssh_key),
+            ] {
+                pool.get_session(node, ssh_key).await.map_err(|e| {
+                    SwitchRefused(format!("Could not connect to {}: {:#}", node.label, e))
+                })?;
+            }
         }
 
         spinner.stop_with_message("✅ SSH connections ready");
+
+        let spinner = ConditionalSpinner::new("Checking identity keypairs...", silent);
+        if let Err(e) = switch_manager.verify_identity_keypairs().await {
+            spinner.stop_with_message("❌ Identity keypair check failed");
+            return Err(SwitchRefused(format!("{:#}", e)).into());
+        }
+        spinner.stop_with_message("✅ Identity keypairs valid on both nodes");
+
+        // Skewed clocks can hide a stale tower, so check before it is moved
+        match switch_manager.measure_clock_offsets().await {
+            Ok(offsets) => match switch_manager.judge_clock_skew(offsets) {
+                Ok(None) => println_if_not_silent!(silent, "✅ Node clocks in step"),
+                Ok(Some(warning)) => println_if_not_silent!(
+                    silent,
+                    "{}",
+                    format!("⚠️  Clock skew: {}", warning).yellow()
+                ),
+                Err(reason) => {
+                    return Err(SwitchRefused(format!(
+                        "Clock skew too large, nothing was switched: {}. Fix time sync (NTP) on the nodes or raise switch.max_clock_skew_ms",
+                        reason
+                    ))
+                    .into());
+                }
+            },
+            // Not being able to read a clock shouldn't block a switch
+            Err(reason) => println_if_not_silent!(
+                silent,
+                "{}",
+                format!("⚠️  Could not compare node clocks: {}", reason).yellow()
+            ),
+        }
     }
 
-    // Execute the switch process
-    let switch_result = switch_manager
-        .execute_switch(dry_run, require_confirmation)
-        .await;
+    // Hold the switch lock on both nodes so a second operator can't switch
+    // the same validator at the same time
+    switch_manager.switch_lock = if dry_run {
+        None
+    } else {
+        let nodes: Vec<(crate::types::NodeConfig, String)> =
+            [active_node_with_status, standby_node_with_status]
+                .iter()
+                .filter_map(|n| {
+                    app_state
+                        .detected_ssh_keys
+                        .get(&n.node.host)
+                        .map(|key| (n.node.clone(), key.clone()))
+                })
+                .collect();
+        Some(
+            crate::switch_lock::SwitchLock::acquire(
+                app_state.ssh_pool.clone(),
+                &validator_pair.identity_pubkey,
+                &nodes,
+            )
+            .await
+            .map_err(|e| SwitchRefused(format!("{:#}", e)))?,
+        )
+    };
+
+    if !dry_run {
+        switch_manager.report_progress(format!(
+            "▶️ Switch started by {}: {} → {}",
+            crate::switch_lock::lock_owner(),
+            active_node_with_status.node.label,
+            standby_node_with_status.node.label
+        ));
+        crate::audit::record(
+            &crate::switch_lock::lock_owner(),
+            "switch_initiated",
+            &validator_pair.identity_pubkey,
+            &format!(
+                "{} → {}",
+                active_node_with_status.node.label, standby_node_with_status.node.label
+            ),
+        );
+    }
+
+    // Execute the switch process. Its SSH commands go ahead of routine
+    // monitoring probes on both hosts.
+    let switch_result = crate::ssh::critical(
+        switch_manager.execute_switch(dry_run, require_confirmation),
+    )
+    .await;
+
+    // A live switch only counts as successful once the new active node votes
+    let resumption = match &switch_result {
+        Ok(true) if !dry_run => Some(
+            crate::ssh::critical(verify_vote_resumption(
+                app_state,
+                validator_idx,
+                &standby_node_with_status.node,
+                &switch_manager.progress,
+                silent,
+            ))
+            .await,
+        ),
+        _ => None,
+    };
+
+    if let Some(lock) = switch_manager.switch_lock.as_mut() {
+        lock.release().await;
+    }
+
+    if let (false, Some(duration)) = (dry_run, switch_manager.identity_switch_time) {
+        crate::statsd::emit_switch(
+            app_state.config.statsd.as_ref(),
+            &validator_pair.identity_pubkey,
+            duration,
+            false,
+            matches!((&switch_result, &resumption), (Ok(true), Some(Ok(())))),
+        );
+    }
+
+    // Keep every live switch in the history database, failed ones included
+    if !dry_run && !matches!(switch_result, Ok(false)) {
+        let error = match (&switch_result, &resumption) {
+            (Err(e), _) => Some(e.to_string()),
+            (_, Some(Err(reason))) => Some(reason.clone()),
+            _ => None,
+        };
+        let route = format!(
+            "{}: {} → {}",
+            validator_pair.identity_pubkey,
+            active_node_with_status.node.label,
+            standby_node_with_status.node.label
+        );
+        match &error {
+            None => crate::syslog::event(
+                crate::syslog::Severity::Notice,
+                "switch",
+                &format!("Switch completed {}", route),
+            ),
+            Some(e) => crate::syslog::event(
+                crate::syslog::Severity::Error,
+                "switch",
+                &format!("Switch failed {}: {}", route, e),
+            ),
+        }
+        crate::audit::record(
+            &crate::switch_lock::lock_owner(),
+            if error.is_none() { "switch_completed" } else { "switch_failed" },
+            &validator_pair.identity_pubkey,
+            error.as_deref().unwrap_or(""),
+        );
+
+        crate::history::record_switch(crate::history::SwitchRecord {
+            timestamp: chrono::Utc::now().timestamp(),
+            identity: validator_pair.identity_pubkey.clone(),
+            from_node: active_node_with_status.node.label.clone(),
+            to_node: standby_node_with_status.node.label.clone(),
+            emergency: false,
+            success: matches!((&switch_result, &resumption), (Ok(true), Some(Ok(())))),
+            duration_ms: switch_manager
+                .identity_switch_time
+                .map(|time| time.as_millis() as u64),
+            error,
+        });
+    }
 
     // Send Telegram notification for switch result (only for live switches)
     if !dry_run {
         if let Some(alert_config) = &app_state.config.alert_config {
             let alert_manager = crate::alert::AlertManager::new(alert_config.clone());
 
-            match &switch_result {
-                Ok(_) => {
+            match (&switch_result, &resumption) {
+                (Ok(_), Some(Err(reason))) => {
+                    let _ = alert_manager
+                        .send_vote_resumption_alert(
+                            &validator_pair.identity_pubkey,
+                            &active_node_with_status.node.label,
+                            &standby_node_with_status.node.label,
+                            reason,
+                        )
+                        .await;
+                }
+                (Ok(_), _) => {
                     // Send success notification
                     let _ = alert_manager
                         .send_switch_result(
@@ -172,7 +731,7 @@ pub async fn switch_command_with_confirmation(
                         )
                         .await;
                 }
-                Err(e) => {
+                (Err(e), _) => {
                     // Send failure notification
                     let _ = alert_manager
                         .send_switch_result(
@@ -188,59 +747,109 @@ pub async fn switch_command_with_confirmation(
         }
     }
 
+    // A failed switch still gets its steps out to the alert channels
+    if switch_result.is_err() {
+        if let Some(relay) = progress_relay.take() {
+            switch_manager.progress = None;
+            let _ = relay.await;
+        }
+    }
+
     // Re-check the result and propagate any error
     let show_status = switch_result?;
 
+    // Don't assume set-identity stuck on the old node - keep checking it in
+    // the background and alert if it ever comes back with the funded identity
+    if !dry_run && show_status {
+        if let Some(ssh_key) = app_state
+            .detected_ssh_keys
+            .get(&active_node_with_status.node.host)
+        {
+            let rpc_url = crate::rpc_selector::best_rpc(
+                &app_state.rpc_selectors,
+                validator_idx,
+                &validator_pair.rpc,
+            )
+            .await;
+
+            crate::demotion_check::DemotionVerifier {
+                old_active: active_node_with_status.node.clone(),
+                validator_pair: validator_pair.clone(),
+                rpc_url,
+                ssh_pool: app_state.ssh_pool.clone(),
+                ssh_key: ssh_key.clone(),
+                alert_manager: app_state
+                    .config
+                    .alert_config
+                    .clone()
+                    .map(crate::alert::AlertManager::new),
+            }
+            .spawn();
+
+            let note = format!(
+                "🔍 Watching {} for {} minutes to confirm it stays demoted",
+                active_node_with_status.node.label,
+                crate::demotion_check::DEMOTION_CHECK_DURATION.as_secs() / 60
+            );
+            if let Some(progress) = &switch_manager.progress {
+                let _ = progress.send(note.clone());
+            }
+            println_if_not_silent!(silent, "\n{}", note.dimmed());
+        }
+    }
+
     // Show completion message with timing breakdown
     if !dry_run {
+        let headline = if matches!(resumption, Some(Err(_))) {
+            "⚠️  Identities swapped, but voting has not resumed".red().bold()
+        } else {
+            "✅ Validator swap completed successfully".bright_green().bold()
+        };
         if let Some(total_time) = switch_manager.identity_switch_time {
-            println_if_not_silent!("\n{}", "━".repeat(50).dimmed());
+            println_if_not_silent!(silent, "\n{}", "━".repeat(50).dimmed());
             println_if_not_silent!(
+                silent,
                 "{} {}",
-                "✅ Validator swap completed successfully in"
-                    .bright_green()
-                    .bold(),
-                format!("{}ms", total_time.as_millis())
+                headline,
+                format!("in {}ms", total_time.as_millis())
                     .bright_yellow()
                     .bold()
             );
 
             // Show timing breakdown
-            println_if_not_silent!("\n{}", "📊 Timing breakdown:".dimmed());
+            println_if_not_silent!(silent, "\n{}", "📊 Timing breakdown:".dimmed());
             if let Some(active_time) = switch_manager.active_switch_time {
                 println_if_not_silent!(
+                    silent,
                     "   Step 1 - Active → Unfunded:  {}",
                     format!("{}ms", active_time.as_millis()).bright_yellow()
                 );
             }
             if let Some(tower_time) = switch_manager.tower_transfer_time {
                 println_if_not_silent!(
+                    silent,
                     "   Step 2 - Tower transfer:     {}",
                     format!("{}ms", tower_time.as_millis()).bright_yellow()
                 );
             }
             if let Some(standby_time) = switch_manager.standby_switch_time {
                 println_if_not_silent!(
+                    silent,
                     "   Step 3 - Standby → Funded:   {}",
                     format!("{}ms", standby_time.as_millis()).bright_yellow()
                 );
             }
         } else {
-            println_if_not_silent!(
-                "\n{}",
-                "✅ Validator swap completed successfully"
-                    .bright_green()
-                    .bold()
-            );
+            println_if_not_silent!(silent, "\n{}", headline);
         }
         
         // Update the node statuses in app_state to reflect the switch
-        if !dry_run && show_status && app_state.validator_statuses.len() > 0 {
+        if !dry_run && show_status && app_state.validator_statuses.len() > validator_idx {
             // Find the indices of active and standby nodes
             let mut active_idx = None;
             let mut standby_idx = None;
             
-            for (idx, node_with_status) in app_state.validator_statuses[0].nodes_with_status.iter().enumerate() {
+            for (idx, node_with_status) in app_state.validator_statuses[validator_idx].nodes_with_status.iter().enumerate() {
                 match node_with_status.status {
                     crate::types::NodeStatus::Active => active_idx = Some(idx),
                     crate::types::NodeStatus::Standby => standby_idx = Some(idx),
@@ -250,25 +859,592 @@ pub async fn switch_command_with_confirmation(
             
             // Swap the statuses
             if let (Some(active), Some(standby)) = (active_idx, standby_idx) {
-                app_state.validator_statuses[0].nodes_with_status[active].status = crate::types::NodeStatus::Standby;
-                app_state.validator_statuses[0].nodes_with_status[standby].status = crate::types::NodeStatus::Active;
+                app_state.validator_statuses[validator_idx].nodes_with_status[active].status = crate::types::NodeStatus::Standby;
+                app_state.validator_statuses[validator_idx].nodes_with_status[standby].status = crate::types::NodeStatus::Active;
             }
         }
         
-        println_if_not_silent!();
-        println_if_not_silent!("{}", "Press any key to view status...".dimmed());
-        if !is_silent_mode() {
-            let _ = std::io::stdin().read_line(&mut String::new());
+        if !assume_yes {
+            println_if_not_silent!(silent);
+            println_if_not_silent!(silent, "{}", "Press any key to view status...".dimmed());
+            if !silent {
+                let _ = std::io::stdin().read_line(&mut String::new());
+            }
         }
     }
 
+    // Let the last steps reach the alert channels before returning
+    if let Some(relay) = progress_relay {
+        switch_manager.progress = None;
+        let _ = relay.await;
+    }
+
+    // Automation needs to tell a switch that left the validator silent
+    // apart from a good one
+    if let (true, Some(Err(reason))) = (assume_yes, &resumption) {
+        return Err(anyhow!(
+            "Identities swapped, but voting has not resumed: {}",
+            reason
+        ));
+    }
+
     Ok(show_status)
 }
 
+/// Wait for the first vote from the new active node, printing the outcome.
+/// Returns the reason when no vote landed in time.
+async fn verify_vote_resumption(
+    app_state: &crate::AppState,
+    validator_idx: usize,
+    new_active: &crate::types::NodeConfig,
+    progress: &Option<ProgressSender>,
+    silent: bool,
+) -> std::result::Result<(), String> {
+    let validator_pair = &app_state.validator_statuses[validator_idx].validator_pair;
+    let timeout = Duration::from_secs(
+        app_state
+            .config
+            .switch
+            .clone()
+            .unwrap_or_default()
+            .vote_resumption_timeout_seconds,
+    );
+    let rpc_url =
+        crate::rpc_selector::best_rpc(&app_state.rpc_selectors, validator_idx, &validator_pair.rpc)
+            .await;
+    let ssh_key = app_state
+        .detected_ssh_keys
+        .get(&new_active.host)
+        .cloned()
+        .ok_or_else(|| "no SSH key detected for the new active node".to_string())?;
+
+    // Votes for slots up to now may still come from the former active node
+    let baseline_slot = crate::solana_rpc::fetch_epoch_info(&rpc_url)
+        .await
+        .map(|info| info.absolute_slot)
+        .map_err(|e| format!("could not read the current slot: {}", e))?;
+
+    let spinner = ConditionalSpinner::new(
+        &format!(
+            "Waiting up to {}s for the first vote from {}...",
+            timeout.as_secs(),
+            new_active.label
+        ),
+        silent,
+    );
+
+    let verifier = crate::vote_resumption::VoteResumptionVerifier {
+        new_active: new_active.clone(),
+        validator_pair: validator_pair.clone(),
+        rpc_url,
+        ssh_pool: app_state.ssh_pool.clone(),
+        ssh_key,
+        timeout,
+    };
+
+    let (message, result) = match verifier.run(baseline_slot).await {
+        Ok((slot, elapsed)) => (
+            format!(
+                "✅ Voting resumed: vote for slot {} landed {:.1}s after the switch",
+                slot,
+                elapsed.as_secs_f64()
+            ),
+            Ok(()),
+        ),
+        Err(reason) => (
+            format!(
+                "🚨 NO VOTE from {} within {}s: {}",
+                new_active.label,
+                timeout.as_secs(),
+                reason
+            ),
+            Err(reason),
+        ),
+    };
+    spinner.stop_with_message(&message);
+    if let Some(progress) = progress {
+        let _ = progress.send(message.clone());
+    }
+    if result.is_err() {
+        println_if_not_silent!(
+            silent,
+            "{}",
+            "⚠️  Check the new active node now - the validator may not be voting"
+                .red()
+                .bold()
+        );
+    }
+    result
+}
+
+/// One dry-run check of a switch step
+pub(crate) struct ProbeResult {
+    pub step: &'static str,
+    pub check: String,
+    pub outcome: std::result::Result<(), String>,
+}
+
+/// Quote a value for a POSIX shell command line
+pub(crate) fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Shell snippet printing PASS when `test` succeeds and `FAIL: <reason>` otherwise
+pub(crate) fn probe_command(test: &str, failure: &str) -> String {
+    format!(
+        "if {}; then echo PASS; else echo {}; fi",
+        test,
+        shell_quote(&format!("FAIL: {}", failure))
+    )
+}
+
+/// Shell test that succeeds when `path` is a readable keypair file: a JSON
+/// array of 64 byte values as written by `solana-keygen`
+pub(crate) fn keypair_valid_test(path: &str) -> String {
+    format!(
+        "test -r {0} && tr -d ' \\t\\r\\n' < {0} | grep -qE '^\\[([0-9]{{1,3}},){{63}}[0-9]{{1,3}}\\]$'",
+        shell_quote(path)
+    )
+}
+
+/// Probe telling a missing keypair apart from one that can't be parsed
+pub(crate) fn keypair_probe_command(path: &str) -> String {
+    format!(
+        "if ! test -r {quoted}; then echo {missing}; elif {valid}; then echo PASS; else echo {invalid}; fi",
+        quoted = shell_quote(path),
+        valid = keypair_valid_test(path),
+        missing = shell_quote(&format!("FAIL: {} missing or unreadable", path)),
+        invalid = shell_quote(&format!("FAIL: {} is not a valid keypair file", path)),
+    )
+}
+
+/// Prints `funded=<pubkey>` and `unfunded=<pubkey>` for a node's identity
+/// keypairs, derived with solana-keygen (on PATH or next to the running
+/// validator) or `fdctl keys pubkey`; `keygen=missing` when neither exists
+pub(crate) fn identity_pubkeys_command(funded: &str, unfunded: &str) -> String {
+    format!(
+        "pid=$(pgrep -o -f '[b]in/fdctl|[b]in/agave-validator|[r]elease/agave-validator|[b]in/solana-validator|[r]elease/solana-validator'); \
+        exe=$(readlink /proc/$pid/exe 2>/dev/null); \
+        keygen=$(command -v solana-keygen); \
+        if [ -z \"$keygen\" ] && [ -x \"${{exe%/*}}/solana-keygen\" ]; then keygen=\"${{exe%/*}}/solana-keygen\"; fi; \
+        if [ -n \"$keygen\" ]; then pubkey() {{ \"$keygen\" pubkey \"$1\"; }}; \
+        elif [ \"${{exe##*/}}\" = fdctl ]; then pubkey() {{ \"$exe\" keys pubkey \"$1\"; }}; \
+        else echo keygen=missing; exit 0; fi; \
+        echo \"funded=$(pubkey {} 2>/dev/null)\"; \
+        echo \"unfunded=$(pubkey {} 2>/dev/null)\"",
+        shell_quote(funded),
+        shell_quote(unfunded)
+    )
+}
+
+/// Funded and unfunded identity pubkeys from `identity_pubkeys_command`
+/// output; None for a keypair that could not be read
+pub(crate) fn parse_identity_pubkeys(
+    output: &str,
+) -> std::result::Result<(Option<String>, Option<String>), String> {
+    let mut funded = None;
+    let mut unfunded = None;
+    for line in output.lines() {
+        let (key, value) = match line.trim().split_once('=') {
+            Some(pair) => pair,
+            None => continue,
+        };
+        let value = value.trim();
+        let pubkey = ((32..=44).contains(&value.len())
+            && value.chars().all(|c| c.is_ascii_alphanumeric()))
+        .then(|| value.to_string());
+        match key {
+            "keygen" => return Err("neither solana-keygen nor fdctl found to derive pubkeys".to_string()),
+            "funded" => funded = pubkey,
+            "unfunded" => unfunded = pubkey,
+            _ => {}
+        }
+    }
+    Ok((funded, unfunded))
+}
+
+/// Where a node's identity keypairs disagree with the configured identity:
+/// the funded keypair must be the identity and the unfunded one must not be
+pub(crate) fn identity_pubkey_problems(
+    identity: &str,
+    paths: &crate::types::NodePaths,
+    funded: Option<&str>,
+    unfunded: Option<&str>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(funded) = funded.filter(|funded| *funded != identity) {
+        problems.push(format!(
+            "Funded identity keypair {} is {}, not the configured identity {}",
+            paths.funded_identity, funded, identity
+        ));
+    }
+    if unfunded == Some(identity) {
+        problems.push(format!(
+            "Unfunded identity keypair {} is the configured identity {}",
+            paths.unfunded_identity, identity
+        ));
+    }
+    problems
+}
+
+/// Interpret the output of a `probe_command`
+pub(crate) fn probe_outcome(output: &str) -> std::result::Result<(), String> {
+    let output = output.trim();
+    if output.ends_with("PASS") {
+        Ok(())
+    } else if let Some(pos) = output.find("FAIL: ") {
+        Err(output[pos + 6..].to_string())
+    } else {
+        Err(format!("unexpected output: {}", output))
+    }
+}
+
+/// Offset of a node's clock from ours in milliseconds, from `date +%s%N`
+/// output read between `before_ms` and `after_ms` (local Unix milliseconds).
+/// Accurate to about half the SSH round trip.
+pub(crate) fn remote_clock_offset_ms(before_ms: i64, after_ms: i64, output: &str) -> Option<i64> {
+    let remote_ns: i64 = output.trim().parse().ok()?;
+    Some(remote_ns / 1_000_000 - (before_ms + after_ms) / 2)
+}
+
+/// Judge measured clock offsets (vs this machine) of the active and standby
+/// nodes. Skew between the nodes beyond `max_ms` stops the switch; any skew,
+/// including against this machine, beyond `warning_ms` is returned as a warning.
+pub(crate) fn clock_skew_verdict(
+    active_offset_ms: i64,
+    standby_offset_ms: i64,
+    warning_ms: u64,
+    max_ms: u64,
+) -> std::result::Result<Option<String>, String> {
+    let between_nodes = (active_offset_ms - standby_offset_ms).unsigned_abs();
+    let summary = format!(
+        "nodes differ by {}ms (active {:+}ms, standby {:+}ms vs this machine)",
+        between_nodes, active_offset_ms, standby_offset_ms
+    );
+    if max_ms > 0 && between_nodes > max_ms {
+        return Err(format!("{}, limit {}ms", summary, max_ms));
+    }
+    let worst = between_nodes
+        .max(active_offset_ms.unsigned_abs())
+        .max(standby_offset_ms.unsigned_abs());
+    if worst > warning_ms {
+        Ok(Some(summary))
+    } else {
+        Ok(None)
+    }
+}
+
+/// `--config` argument of a running fdctl process
+pub(crate) fn fdctl_config_path(process_info: &str) -> Option<String> {
+    process_info
+        .lines()
+        .find(|line| line.contains("fdctl") && line.contains("--config"))
+        .and_then(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            parts
+                .windows(2)
+                .find(|w| w[0] == "--config")
+                .map(|w| w[1].to_string())
+        })
+}
+
+/// The config fdctl runs with: declared in the node's paths, else read from
+/// its process line
+pub(crate) fn fdctl_config_for(node: &crate::types::NodeConfig, process_info: &str) -> Option<String> {
+    node.paths
+        .fdctl_config
+        .clone()
+        .or_else(|| fdctl_config_path(process_info))
+}
+
+/// Shell command that copies the tower files in `ledger_path` into
+/// `tower-backups/<timestamp>` and keeps only the newest `retention` backups.
+/// None when backups are disabled.
+pub(crate) fn tower_backup_command(
+    ledger_path: &str,
+    timestamp: &str,
+    retention: usize,
+) -> Option<String> {
+    if retention == 0 {
+        return None;
+    }
+    let backups = format!("{}/tower-backups", ledger_path);
+    let dir = format!("{}/{}", backups, timestamp);
+    // Timestamps sort chronologically, so the oldest backups come last after `sort -r`
+    Some(format!(
+        "ls {ledger}/tower-*.bin >/dev/null 2>&1 || exit 0; \
+         mkdir -p {dir} && cp -p {ledger}/tower-*.bin {dir}/ && \
+         ls -1d {backups}/*/ | sort -r | tail -n +{keep} | xargs -r rm -rf",
+        ledger = shell_quote(ledger_path),
+        dir = shell_quote(&dir),
+        backups = shell_quote(&backups),
+        keep = retention + 1,
+    ))
+}
+
+/// Tower upload progress is reported each time another quarter is sent
+const TOWER_PROGRESS_STEP_PERCENT: u64 = 25;
+
+/// `512 B`, `12.3 KB`, `1.5 MB`, `431.2 GB`
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if (bytes as f64) < KB * KB {
+        format!("{:.1} KB", bytes as f64 / KB)
+    } else if (bytes as f64) < KB * KB * KB {
+        format!("{:.1} MB", bytes as f64 / KB / KB)
+    } else {
+        format!("{:.1} GB", bytes as f64 / KB / KB / KB)
+    }
+}
+
+/// Reports an upload once per `TOWER_PROGRESS_STEP_PERCENT` instead of per chunk
+pub(crate) struct TransferProgress {
+    reported_percent: u64,
+}
+
+impl TransferProgress {
+    pub(crate) fn new() -> Self {
+        Self { reported_percent: 0 }
+    }
+
+    /// Progress line when `sent` of `total` bytes crosses the next step
+    pub(crate) fn update(&mut self, sent: u64, total: u64) -> Option<String> {
+        let percent = (sent * 100).checked_div(total).unwrap_or(100);
+        let step = percent / TOWER_PROGRESS_STEP_PERCENT * TOWER_PROGRESS_STEP_PERCENT;
+        if step <= self.reported_percent {
+            return None;
+        }
+        self.reported_percent = step;
+        Some(format!(
+            "{} / {} ({}%)",
+            format_bytes(sent),
+            format_bytes(total),
+            percent
+        ))
+    }
+}
+
+/// Read command for a compressed tower transfer: the first line of output is
+/// the SHA-256 of the uncompressed file, the rest is the compressed file in base64
+pub(crate) fn compressed_tower_read_command(tower_path: &str, tool: &str) -> String {
+    format!(
+        "sha256sum {path} | cut -d' ' -f1 && {tool} -c {path} | base64 -w0",
+        path = shell_quote(tower_path),
+        tool = tool,
+    )
+}
+
+/// Split the output of `compressed_tower_read_command` into checksum and data
+pub(crate) fn parse_compressed_tower(output: &str) -> Result<(String, String)> {
+    let (checksum, data) = output
+        .trim()
+        .split_once('\n')
+        .ok_or_else(|| anyhow!("Unexpected output while reading compressed tower file"))?;
+    let checksum = checksum.trim();
+    if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("Invalid tower checksum: {}", checksum));
+    }
+    Ok((checksum.to_string(), data.trim().to_string()))
+}
+
+/// Decompress the uploaded tower file and move it into place only if its
+/// checksum matches; prints PASS or FAIL like `probe_command`
+pub(crate) fn install_compressed_tower_command(
+    compressed_path: &str,
+    dest_path: &str,
+    tool: &str,
+    checksum: &str,
+) -> String {
+    let staged = format!("{}.svs-staged", dest_path);
+    format!(
+        "{}; rm -f {} {}",
+        probe_command(
+            &format!(
+                "{tool} -d -c {src} > {staged} && [ \"$(sha256sum < {staged} | cut -d' ' -f1)\" = {sum} ] && mv {staged} {dest}",
+                tool = tool,
+                src = shell_quote(compressed_path),
+                staged = shell_quote(&staged),
+                sum = shell_quote(checksum),
+                dest = shell_quote(dest_path),
+            ),
+            "decompressed tower file failed checksum verification",
+        ),
+        shell_quote(compressed_path),
+        shell_quote(&staged),
+    )
+}
+
+/// Set by Ctrl+C or Esc while an interactive switch runs
+static ABORT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+fn request_abort() {
+    if !ABORT_REQUESTED.swap(true, Ordering::SeqCst) {
+        eprintln!("\n{}", "⏹️  Abort requested - stopping before the next step".yellow());
+    }
+}
+
+/// Drive `switch` to completion while Ctrl+C or Esc only request an abort.
+/// The step in progress always finishes; the switch checks for the request
+/// at its next step boundary.
+async fn run_abortable<T>(switch: impl std::future::Future<Output = T>) -> T {
+    let mut esc = EscWatcher::start();
+    tokio::pin!(switch);
+    loop {
+        tokio::select! {
+            result = &mut switch => return result,
+            _ = tokio::signal::ctrl_c() => request_abort(),
+            Some(()) = esc.pressed.recv() => request_abort(),
+        }
+    }
+}
+
+/// Reports Esc presses from a background reader. For its lifetime stdin
+/// leaves line mode so a single key is seen without Enter; output and the
+/// Ctrl+C signal are left as they are.
+struct EscWatcher {
+    pressed: tokio::sync::mpsc::UnboundedReceiver<()>,
+    stop: Arc<AtomicBool>,
+    reader: Option<std::thread::JoinHandle<()>>,
+    #[cfg(unix)]
+    saved_termios: Option<libc::termios>,
+}
+
+impl EscWatcher {
+    fn start() -> Self {
+        use std::io::IsTerminal;
+
+        let (tx, pressed) = tokio::sync::mpsc::unbounded_channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        if !std::io::stdin().is_terminal() {
+            return Self {
+                pressed,
+                stop,
+                reader: None,
+                #[cfg(unix)]
+                saved_termios: None,
+            };
+        }
+
+        #[cfg(unix)]
+        let saved_termios = disable_line_mode();
+        let reader = {
+            let stop = stop.clone();
+            std::thread::spawn(move || {
+                use crossterm::event::{self, Event, KeyCode};
+                while !stop.load(Ordering::SeqCst) {
+                    match event::poll(Duration::from_millis(100)) {
+                        Ok(true) => {
+                            if let Ok(Event::Key(key)) = event::read() {
+                                if key.code == KeyCode::Esc && tx.send(()).is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                        Ok(false) => {}
+                        Err(_) => break,
+                    }
+                }
+            })
+        };
+
+        Self {
+            pressed,
+            stop,
+            reader: Some(reader),
+            #[cfg(unix)]
+            saved_termios,
+        }
+    }
+}
+
+impl Drop for EscWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // The reader has to be gone before line mode returns, or it would
+        // swallow the next prompt's input
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+        #[cfg(unix)]
+        if let Some(saved) = self.saved_termios.take() {
+            // SAFETY: restores the settings tcgetattr returned for the same fd
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &saved);
+            }
+        }
+    }
+}
+
+/// Turn off canonical input and echo on stdin, returning the previous
+/// settings to restore
+#[cfg(unix)]
+fn disable_line_mode() -> Option<libc::termios> {
+    // SAFETY: tcgetattr fully initialises the zeroed struct before it is read,
+    // and tcsetattr only receives a copy of it
+    unsafe {
+        let mut saved: libc::termios = std::mem::zeroed();
+        if libc::tcgetattr(libc::STDIN_FILENO, &mut saved) != 0 {
+            return None;
+        }
+        let mut keys = saved;
+        keys.c_lflag &= !(libc::ICANON | libc::ECHO);
+        keys.c_cc[libc::VMIN] = 1;
+        keys.c_cc[libc::VTIME] = 0;
+        (libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &keys) == 0).then_some(saved)
+    }
+}
+
+/// What an abort after `completed_steps` left behind and how to recover
+pub(crate) fn abort_report(
+    completed_steps: usize,
+    active_label: &str,
+    standby_label: &str,
+    funded_identity_path: &str,
+    rolled_back: bool,
+) -> Vec<String> {
+    let step = |n: usize, text: &str| {
+        format!("{} Step {}: {}", if completed_steps >= n { "✅" } else { "⏭️ " }, n, text)
+    };
+    let mut lines = vec![
+        step(1, &format!("{} → unfunded identity", active_label)),
+        step(2, &format!("tower file copied to {}", standby_label)),
+        step(3, &format!("{} → funded identity", standby_label)),
+    ];
+    lines.push(match completed_steps {
+        0 => format!("Nothing changed - {} is still the active node", active_label),
+        1 | 2 if rolled_back => format!(
+            "Rolled back - {} is on the funded identity again and keeps voting",
+            active_label
+        ),
+        1 | 2 => format!(
+            "No node is voting. Recover by running set-identity with {} on {} (its tower is current), or start a new switch",
+            funded_identity_path, active_label
+        ),
+        _ => format!(
+            "The identity swap completed; only the catchup check was skipped. Confirm {} is voting",
+            standby_label
+        ),
+    });
+    lines
+}
+
+/// Run one switch step, naming the step in the error if it outlasts `seconds`
+pub(crate) async fn with_step_timeout<T>(
+    step: &str,
+    seconds: u64,
+    future: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    match tokio::time::timeout(Duration::from_secs(seconds), future).await {
+        Ok(result) => result,
+        Err(_) => Err(anyhow!("{} timed out after {}s", step, seconds)),
+    }
+}
+
 pub(crate) struct SwitchManager {
     active_node_with_status: crate::types::NodeWithStatus,
     standby_node_with_status: crate::types::NodeWithStatus,
-    #[allow(dead_code)]
     validator_pair: crate::types::ValidatorPair,
     ssh_pool: Arc<crate::ssh::AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
@@ -277,6 +1453,10 @@ pub(crate) struct SwitchManager {
     identity_switch_time: Option<Duration>,
     active_switch_time: Option<Duration>,
     standby_switch_time: Option<Duration>,
+    progress: Option<ProgressSender>,
+    switch_lock: Option<crate::switch_lock::SwitchLock>, // Touched at every step
+    pub(crate) silent: bool, // No terminal output, steps go to `progress` only
+    pub(crate) switch_config: crate::types::SwitchConfig,
 }
 
 impl SwitchManager {
@@ -298,7 +1478,348 @@ impl SwitchManager {
             identity_switch_time: None,
             active_switch_time: None,
             standby_switch_time: None,
+            progress: None,
+            switch_lock: None,
+            silent: false,
+            switch_config: crate::types::SwitchConfig::default(),
+        }
+    }
+
+    fn report_progress(&self, message: String) {
+        if let Some(progress) = &self.progress {
+            let _ = progress.send(message);
+        }
+    }
+
+    /// Run a probe on a node and interpret its PASS/FAIL output
+    async fn probe(
+        &self,
+        node: &crate::types::NodeWithStatus,
+        command: &str,
+    ) -> std::result::Result<(), String> {
+        let ssh_key = self
+            .get_ssh_key_for_node(&node.node.host)
+            .map_err(|e| e.to_string())?;
+        match self.ssh_pool.execute_privileged(&node.node, &ssh_key, command).await {
+            Ok(output) => probe_outcome(&output),
+            Err(e) => Err(format!("SSH error: {}", e)),
+        }
+    }
+
+    /// Check that the client running on `node` accepts the set-identity
+    /// command the switch will send
+    async fn probe_set_identity(
+        &self,
+        node: &crate::types::NodeWithStatus,
+    ) -> std::result::Result<(), String> {
+        // A configured set-identity command can't be checked without running it
+        if node.node.set_identity_command("", false).is_some() {
+            return Ok(());
+        }
+        let ssh_key = self
+            .get_ssh_key_for_node(&node.node.host)
+            .map_err(|e| e.to_string())?;
+        let process_info = self
+            .ssh_pool
+            .execute_command(
+                &node.node,
+                &ssh_key,
+                "ps aux | grep -E 'solana-validator|agave|fdctl|firedancer' | grep -v grep",
+            )
+            .await
+            .map_err(|e| format!("SSH error: {}", e))?;
+
+        if process_info.contains("fdctl") || process_info.contains("firedancer") {
+            let fdctl = node
+                .fdctl_executable
+                .as_ref()
+                .ok_or("fdctl executable not detected")?;
+            let config = fdctl_config_for(&node.node, &process_info)
+                .ok_or("fdctl is not running with --config")?;
+            self.probe(
+                node,
+                &probe_command(
+                    &format!(
+                        "test -x {fdctl} && test -r {config} && {fdctl} --version >/dev/null 2>&1",
+                        fdctl = shell_quote(fdctl),
+                        config = shell_quote(&config)
+                    ),
+                    &format!("{} or its config {} is not usable", fdctl, config),
+                ),
+            )
+            .await
+        } else if process_info.contains("agave-validator") {
+            let agave = node
+                .agave_validator_executable
+                .as_ref()
+                .ok_or("agave-validator executable not detected")?;
+            let ledger = node.ledger_path.as_ref().ok_or("ledger path not detected")?;
+            self.probe(
+                node,
+                &probe_command(
+                    &format!(
+                        "{} -l {} set-identity --help 2>&1 | grep -q -- --require-tower",
+                        shell_quote(agave),
+                        shell_quote(ledger)
+                    ),
+                    &format!("{} does not support set-identity --require-tower", agave),
+                ),
+            )
+            .await
+        } else {
+            Err("no running agave-validator or fdctl process found".to_string())
+        }
+    }
+
+    /// Offset of `node`'s clock from this machine in milliseconds
+    async fn clock_offset_ms(
+        &self,
+        node: &crate::types::NodeWithStatus,
+    ) -> std::result::Result<i64, String> {
+        let ssh_key = self
+            .get_ssh_key_for_node(&node.node.host)
+            .map_err(|e| e.to_string())?;
+        let before = chrono::Utc::now().timestamp_millis();
+        let output = self
+            .ssh_pool
+            .execute_command(&node.node, &ssh_key, "date +%s%N")
+            .await
+            .map_err(|e| format!("SSH error: {}", e))?;
+        let after = chrono::Utc::now().timestamp_millis();
+        remote_clock_offset_ms(before, after, &output)
+            .ok_or_else(|| format!("unexpected `date` output: {}", output.trim()))
+    }
+
+    /// Clock offsets of the active and standby nodes from this machine
+    pub(crate) async fn measure_clock_offsets(&self) -> std::result::Result<(i64, i64), String> {
+        let active = self.clock_offset_ms(&self.active_node_with_status).await?;
+        let standby = self.clock_offset_ms(&self.standby_node_with_status).await?;
+        Ok((active, standby))
+    }
+
+    /// `clock_skew_verdict` with the configured thresholds
+    fn judge_clock_skew(
+        &self,
+        (active, standby): (i64, i64),
+    ) -> std::result::Result<Option<String>, String> {
+        clock_skew_verdict(
+            active,
+            standby,
+            self.switch_config.clock_skew_warning_ms,
+            self.switch_config.max_clock_skew_ms,
+        )
+    }
+
+    /// Mismatches between `node`'s identity keypairs and the configured
+    /// identity. Errors when the pubkeys can't be derived on the node.
+    async fn identity_pubkey_mismatches(
+        &self,
+        node: &crate::types::NodeWithStatus,
+    ) -> std::result::Result<Vec<String>, String> {
+        let ssh_key = self
+            .get_ssh_key_for_node(&node.node.host)
+            .map_err(|e| e.to_string())?;
+        let command = identity_pubkeys_command(
+            &node.node.paths.funded_identity,
+            &node.node.paths.unfunded_identity,
+        );
+        let output = self
+            .ssh_pool
+            .execute_privileged(&node.node, &ssh_key, &command)
+            .await
+            .map_err(|e| format!("SSH error: {}", e))?;
+        let (funded, unfunded) = parse_identity_pubkeys(&output)?;
+        Ok(identity_pubkey_problems(
+            &self.validator_pair.identity_pubkey,
+            &node.node.paths,
+            funded.as_deref(),
+            unfunded.as_deref(),
+        ))
+    }
+
+    /// Confirm the identity keypairs the switch relies on exist and parse:
+    /// the unfunded keypair on both nodes and the funded one on the standby.
+    /// A missing keypair would otherwise only fail mid-switch. Where the
+    /// pubkeys can be derived, each node's funded keypair must also be the
+    /// configured identity and its unfunded one must not be.
+    pub(crate) async fn verify_identity_keypairs(&self) -> Result<()> {
+        let active = &self.active_node_with_status;
+        let standby = &self.standby_node_with_status;
+        let checks = [
+            (active, &active.node.paths.unfunded_identity),
+            (standby, &standby.node.paths.unfunded_identity),
+            (standby, &standby.node.paths.funded_identity),
+        ];
+
+        let mut problems = Vec::new();
+        for (node, path) in checks {
+            if let Err(reason) = self.probe(node, &keypair_probe_command(path)).await {
+                problems.push(format!("{}: {}", node.node.label, reason));
+            }
+        }
+        if problems.is_empty() {
+            for node in [active, standby] {
+                // Nodes without solana-keygen or fdctl can't be checked
+                if let Ok(mismatches) = self.identity_pubkey_mismatches(node).await {
+                    problems.extend(
+                        mismatches
+                            .into_iter()
+                            .map(|mismatch| format!("{}: {}", node.node.label, mismatch)),
+                    );
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Identity keypair check failed, nothing was switched:\n  {}",
+                problems.join("\n  ")
+            ))
+        }
+    }
+
+    /// Probe every switch step without changing anything and print a
+    /// pass/fail report. Returns true when every check passed.
+    pub(crate) async fn print_dry_run_checks(&self) -> bool {
+        let active = &self.active_node_with_status;
+        let standby = &self.standby_node_with_status;
+        let mut results = Vec::new();
+
+        results.push(ProbeResult {
+            step: "1. Active → unfunded identity",
+            check: format!("Unfunded identity keypair valid on {}", active.node.label),
+            outcome: self
+                .probe(
+                    active,
+                    &keypair_probe_command(&active.node.paths.unfunded_identity),
+                )
+                .await,
+        });
+        results.push(ProbeResult {
+            step: "1. Active → unfunded identity",
+            check: format!("set-identity command valid on {}", active.node.label),
+            outcome: self.probe_set_identity(active).await,
+        });
+
+        let tower_outcome = match &active.tower_path {
+            Some(tower) => {
+                self.probe(
+                    active,
+                    &probe_command(
+                        &format!("test -f {0} && test -r {0}", shell_quote(tower)),
+                        &format!("{} missing or unreadable", tower),
+                    ),
+                )
+                .await
+            }
+            None => Err("tower path not detected for active node".to_string()),
+        };
+        results.push(ProbeResult {
+            step: "2. Tower transfer",
+            check: format!("Tower file readable on {}", active.node.label),
+            outcome: tower_outcome,
+        });
+
+        let ledger_outcome = match &standby.ledger_path {
+            Some(ledger) => {
+                self.probe(
+                    standby,
+                    &probe_command(
+                        &format!("test -d {0} && test -w {0}", shell_quote(ledger)),
+                        &format!("{} missing or not writable", ledger),
+                    ),
+                )
+                .await
+            }
+            None => Err("ledger path not detected for standby node".to_string()),
+        };
+        results.push(ProbeResult {
+            step: "2. Tower transfer",
+            check: format!("Ledger directory writable on {}", standby.node.label),
+            outcome: ledger_outcome,
+        });
+        results.push(ProbeResult {
+            step: "2. Tower transfer",
+            check: "Node clocks in step".to_string(),
+            outcome: match self.measure_clock_offsets().await {
+                Ok(offsets) => self.judge_clock_skew(offsets).map(|_| ()),
+                Err(e) => Err(e),
+            },
+        });
+
+        results.push(ProbeResult {
+            step: "3. Standby → funded identity",
+            check: format!("Funded identity keypair valid on {}", standby.node.label),
+            outcome: self
+                .probe(
+                    standby,
+                    &keypair_probe_command(&standby.node.paths.funded_identity),
+                )
+                .await,
+        });
+        results.push(ProbeResult {
+            step: "3. Standby → funded identity",
+            check: format!(
+                "Unfunded identity keypair valid on {} (to step down later)",
+                standby.node.label
+            ),
+            outcome: self
+                .probe(
+                    standby,
+                    &keypair_probe_command(&standby.node.paths.unfunded_identity),
+                )
+                .await,
+        });
+        results.push(ProbeResult {
+            step: "3. Standby → funded identity",
+            check: format!("set-identity command valid on {}", standby.node.label),
+            outcome: self.probe_set_identity(standby).await,
+        });
+        for node in [active, standby] {
+            results.push(ProbeResult {
+                step: "3. Standby → funded identity",
+                check: format!("Identity keypairs match the configured identity on {}", node.node.label),
+                outcome: self
+                    .identity_pubkey_mismatches(node)
+                    .await
+                    .and_then(|mismatches| {
+                        if mismatches.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(mismatches.join("; "))
+                        }
+                    }),
+            });
+        }
+
+        println_if_not_silent!(self.silent, "{}", "📋 Step checks".bright_cyan().bold());
+        let mut current_step = "";
+        for result in &results {
+            if result.step != current_step {
+                current_step = result.step;
+                println_if_not_silent!(self.silent, "  {}", current_step.bold());
+            }
+            match &result.outcome {
+                Ok(()) => println_if_not_silent!(self.silent, "    ✅ {}", result.check),
+                Err(reason) => println_if_not_silent!(
+                    self.silent,
+                    "    ❌ {} - {}",
+                    result.check,
+                    reason.red()
+                ),
+            }
         }
+
+        let failed = results.iter().filter(|r| r.outcome.is_err()).count();
+        println_if_not_silent!(
+            self.silent,
+            "  {}/{} checks passed\n",
+            results.len() - failed,
+            results.len()
+        );
+        failed == 0
     }
 
     fn get_ssh_key_for_node(&self, host: &str) -> Result<String> {
@@ -318,6 +1839,10 @@ impl SwitchManager {
             );
             println!("{}", "━".repeat(50).dimmed());
             println!();
+            if let Some(profile) = crate::config::active_profile() {
+                println!("  {}", format!("Profile: {}", profile).bright_cyan().bold());
+                println!();
+            }
             println!(
                 "  {} → {}",
                 format!(
@@ -354,12 +1879,28 @@ impl SwitchManager {
             println!("  {}", "Estimated time: ~10 seconds".dimmed());
             println!();
 
-            // Use inquire for confirmation
-            use inquire::Confirm;
-            let confirmed = Confirm::new("Do you want to proceed with the validator switch?")
-                .with_default(false)
+            // Use inquire for confirmation; a typed phrase guards against a
+            // stray keypress when configured
+            let confirmed = if self.switch_config.typed_confirmation {
+                let phrase = &self.switch_config.confirmation_phrase;
+                let input = inquire::Text::new(&format!(
+                    "Type {} to proceed with the validator switch:",
+                    phrase
+                ))
                 .prompt()?;
+                confirmation_matches(&input, phrase)
+            } else {
+                inquire::Confirm::new("Do you want to proceed with the validator switch?")
+                    .with_default(false)
+                    .prompt()?
+            };
 
+            crate::audit::record(
+                &crate::switch_lock::lock_owner(),
+                if confirmed { "switch_confirmed" } else { "switch_cancelled" },
+                &self.validator_pair.identity_pubkey,
+                "terminal",
+            );
             if !confirmed {
                 println!("\n{}", "❌ Validator switch cancelled by user".red());
                 return Ok(false);
@@ -369,21 +1910,50 @@ impl SwitchManager {
             std::io::stdout().flush()?;
         }
 
+        // Ctrl+C or Esc stops the switch between steps so no step is left
+        // half done
+        ABORT_REQUESTED.store(false, Ordering::SeqCst);
+        if !dry_run && !self.silent {
+            run_abortable(self.run_switch_steps(dry_run)).await
+        } else {
+            self.run_switch_steps(dry_run).await
+        }
+    }
+
+    async fn run_switch_steps(&mut self, dry_run: bool) -> Result<bool> {
         // Start timing the entire switch operation
         let total_switch_start = Instant::now();
 
+        self.check_abort(0).await?;
+        self.touch_switch_lock();
+
         // Step 1: Switch active node to unfunded identity
         println_if_not_silent!(
+            self.silent,
             "\n{}",
             "🔄 Step 1: Switch Active Node to Unfunded Identity"
                 .bright_blue()
                 .bold()
         );
+        self.report_progress(format!(
+            "🔄 Step 1: Switching {} to unfunded identity",
+            self.active_node_with_status.node.label
+        ));
         let active_switch_start = Instant::now();
-        self.switch_primary_to_unfunded(dry_run).await?;
+        with_step_timeout(
+            "Step 1 (set-identity on active node)",
+            self.switch_config.active_identity_timeout_seconds,
+            self.switch_primary_to_unfunded(dry_run),
+        )
+        .await?;
         self.active_switch_time = Some(active_switch_start.elapsed());
+        self.report_progress(format!(
+            "   ✓ Completed in {}ms",
+            active_switch_start.elapsed().as_millis()
+        ));
         if !dry_run {
             println_if_not_silent!(
+                self.silent,
                 "   ✓ Completed in {}",
                 format!("{}ms", self.active_switch_time.unwrap().as_millis())
                     .bright_yellow()
@@ -391,26 +1961,57 @@ impl SwitchManager {
             );
         }
 
+        self.check_abort(1).await?;
+        self.touch_switch_lock();
+
         // Step 2: Transfer tower file
         println_if_not_silent!(
+            self.silent,
             "\n{}",
             "📤 Step 2: Transfer Tower File".bright_blue().bold()
         );
-        self.transfer_tower_file(dry_run).await?;
+        self.report_progress("📤 Step 2: Transferring tower file".to_string());
+        with_step_timeout(
+            "Step 2 (tower transfer)",
+            self.switch_config.tower_transfer_timeout_seconds,
+            self.transfer_tower_file(dry_run),
+        )
+        .await?;
         // Note: tower_transfer_time is set inside transfer_tower_file method
+        if let Some(tower_time) = self.tower_transfer_time {
+            self.report_progress(format!("   ✓ Completed in {}ms", tower_time.as_millis()));
+        }
+
+        self.check_abort(2).await?;
+        self.touch_switch_lock();
 
         // Step 3: Switch standby node to funded identity
         println_if_not_silent!(
+            self.silent,
             "\n{}",
             "🚀 Step 3: Switch Standby Node to Funded Identity"
                 .bright_blue()
                 .bold()
         );
+        self.report_progress(format!(
+            "🚀 Step 3: Switching {} to funded identity",
+            self.standby_node_with_status.node.label
+        ));
         let standby_switch_start = Instant::now();
-        self.switch_backup_to_funded(dry_run).await?;
+        with_step_timeout(
+            "Step 3 (set-identity on standby node)",
+            self.switch_config.standby_identity_timeout_seconds,
+            self.switch_backup_to_funded(dry_run),
+        )
+        .await?;
         self.standby_switch_time = Some(standby_switch_start.elapsed());
+        self.report_progress(format!(
+            "   ✓ Completed in {}ms",
+            standby_switch_start.elapsed().as_millis()
+        ));
         if !dry_run {
             println_if_not_silent!(
+                self.silent,
                 "   ✓ Completed in {}",
                 format!("{}ms", self.standby_switch_time.unwrap().as_millis())
                     .bright_yellow()
@@ -423,13 +2024,21 @@ impl SwitchManager {
             self.identity_switch_time = Some(total_switch_start.elapsed());
         }
 
+        self.check_abort(3).await?;
+        self.touch_switch_lock();
+
         // Step 4: Verify new active node catchup (former standby)
         println_if_not_silent!(
+            self.silent,
             "\n{}",
             "✅ Step 4: Verify New Active Node (Former Standby)"
                 .bright_blue()
                 .bold()
         );
+        self.report_progress(format!(
+            "✅ Step 4: Verifying {} catchup",
+            self.standby_node_with_status.node.label
+        ));
         self.verify_backup_catchup(dry_run).await?;
 
         // Summary
@@ -439,6 +2048,38 @@ impl SwitchManager {
     }
 
     pub(crate) async fn switch_primary_to_unfunded(&mut self, dry_run: bool) -> Result<()> {
+        let identity = self.active_node_with_status.node.paths.unfunded_identity.clone();
+        self.set_active_identity(
+            &identity,
+            dry_run,
+            "Switching active validator to unfunded identity...",
+            "✅ Active validator switched to unfunded identity",
+        )
+        .await
+    }
+
+    /// Point the active node at `identity`, used both for the forward switch
+    /// and for rolling back an aborted one
+    async fn set_active_identity(
+        &self,
+        identity: &str,
+        dry_run: bool,
+        spinner_message: &str,
+        done_message: &str,
+    ) -> Result<()> {
+        let node = &self.active_node_with_status.node;
+        if let Some(command) = node.set_identity_command(identity, false) {
+            return self
+                .run_custom_set_identity(
+                    &self.active_node_with_status.node,
+                    &command,
+                    dry_run,
+                    spinner_message,
+                    done_message,
+                )
+                .await;
+        }
+
         // Detect validator type to use appropriate command
         let process_info = {
             let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
@@ -461,17 +2102,10 @@ impl SwitchManager {
                 .as_ref()
                 .ok_or_else(|| anyhow!("Firedancer fdctl executable path not found"))?;
 
-            // Extract config path from the process info (e.g., "fdctl run --config /path/to/config.toml")
-            let config_path = if let Some(config_match) = process_info
-                .lines()
-                .find(|line| line.contains("fdctl") && line.contains("--config"))
-                .and_then(|line| {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    parts
-                        .windows(2)
-                        .find(|w| w[0] == "--config")
-                        .map(|w| w[1].to_string())
-                }) {
+            // Declared config path, else from the process info (e.g., "fdctl run --config /path/to/config.toml")
+            let config_path = if let Some(config_match) =
+                fdctl_config_for(&self.active_node_with_status.node, &process_info)
+            {
                 config_match
             } else {
                 return Err(anyhow!("Firedancer config path not found in running process. Please ensure fdctl is running with --config parameter"));
@@ -483,7 +2117,7 @@ impl SwitchManager {
                     "{} set-identity --config \"{}\" \"{}\"",
                     fdctl_path,
                     config_path,
-                    self.active_node_with_status.node.paths.unfunded_identity
+                    identity
                 ),
             )
         } else if process_info.contains("agave-validator") {
@@ -507,7 +2141,7 @@ impl SwitchManager {
                     "{} -l \"{}\" set-identity \"{}\"",
                     agave_path,
                     ledger_path,
-                    self.active_node_with_status.node.paths.unfunded_identity
+                    identity
                 ),
             )
         } else {
@@ -522,14 +2156,15 @@ impl SwitchManager {
                 "Using Solana validator restart",
                 format!("{} exit && solana-validator --identity {} --vote-account {} --ledger {} --limit-ledger-size 100000000 --log - &", 
                     "solana-validator",  // Using validator binary directly instead of solana CLI
-                    self.active_node_with_status.node.paths.unfunded_identity,
+                    identity,
                     self.active_node_with_status.node.paths.vote_keypair,
                     ledger_path)
             )
         };
 
-        println_if_not_silent!("{}", subtitle.dimmed());
+        println_if_not_silent!(self.silent, "{}", subtitle.dimmed());
         println_if_not_silent!(
+            self.silent,
             "ssh {}@{} '{}'",
             self.active_node_with_status.node.user,
             self.active_node_with_status.node.host,
@@ -537,8 +2172,7 @@ impl SwitchManager {
         );
 
         if !dry_run {
-            let spinner =
-                ConditionalSpinner::new("Switching active validator to unfunded identity...");
+            let spinner = ConditionalSpinner::new(spinner_message, self.silent);
             {
                 let ssh_key = self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
                 let pool = self.ssh_pool.clone();
@@ -551,20 +2185,14 @@ impl SwitchManager {
                         .fdctl_executable
                         .as_ref()
                         .unwrap();
-                    let config_path = process_info
-                        .lines()
-                        .find(|line| line.contains("fdctl") && line.contains("--config"))
-                        .and_then(|line| {
-                            let parts: Vec<&str> = line.split_whitespace().collect();
-                            parts.windows(2).find(|w| w[0] == "--config").map(|w| w[1])
-                        })
-                        .unwrap();
+                    let config_path =
+                        fdctl_config_for(&self.active_node_with_status.node, &process_info).unwrap();
 
                     let args = vec![
                         "set-identity",
                         "--config",
-                        config_path,
-                        &self.active_node_with_status.node.paths.unfunded_identity,
+                        &config_path,
+                        identity,
                     ];
 
                     pool.execute_command_with_args(
@@ -587,7 +2215,7 @@ impl SwitchManager {
                         "-l",
                         ledger_path,
                         "set-identity",
-                        &self.active_node_with_status.node.paths.unfunded_identity,
+                        identity,
                     ];
 
                     pool.execute_command_with_args(
@@ -602,7 +2230,30 @@ impl SwitchManager {
                 }
             }
             // No sleep - move immediately to next step!
-            spinner.stop_with_message("✅ Active validator switched to unfunded identity");
+            spinner.stop_with_message(done_message);
+        }
+
+        Ok(())
+    }
+
+    /// Run the node's configured set-identity command instead of the
+    /// detected fdctl or agave-validator invocation
+    async fn run_custom_set_identity(
+        &self,
+        node: &crate::types::NodeConfig,
+        command: &str,
+        dry_run: bool,
+        spinner_message: &str,
+        done_message: &str,
+    ) -> Result<()> {
+        println_if_not_silent!(self.silent, "{}", "Using configured set-identity command".dimmed());
+        println_if_not_silent!(self.silent, "ssh {}@{} '{}'", node.user, node.host, command);
+
+        if !dry_run {
+            let spinner = ConditionalSpinner::new(spinner_message, self.silent);
+            let ssh_key = self.get_ssh_key_for_node(&node.host)?;
+            self.ssh_pool.execute_command(node, &ssh_key, command).await?;
+            spinner.stop_with_message(done_message);
         }
 
         Ok(())
@@ -649,6 +2300,7 @@ impl SwitchManager {
         let dest_path = format!("{}/{}", standby_ledger_path, tower_filename);
 
         println_if_not_silent!(
+            self.silent,
             "  📤 {}@{} → {}@{}",
             self.active_node_with_status.node.user,
             self.active_node_with_status.node.host,
@@ -660,52 +2312,111 @@ impl SwitchManager {
 
         // Execute the streaming transfer using base64 encoding
         let encoded_data = if !dry_run {
-            let spinner = ConditionalSpinner::new("Reading tower file...");
+            let compression = self.switch_config.tower_compression;
+            let spinner = ConditionalSpinner::new("Reading tower file...", self.silent);
             let ssh_key_active =
                 self.get_ssh_key_for_node(&self.active_node_with_status.node.host)?;
-            let data = {
+            let read_result = {
                 let pool = self.ssh_pool.clone();
-                let base64_args = vec![tower_path.as_str()];
-                match pool
-                    .execute_command_with_args(
-                        &self.active_node_with_status.node,
-                        &ssh_key_active,
-                        "base64",
-                        &base64_args,
-                    )
-                    .await
-                {
-                    Ok(data) => data,
-                    Err(e) => {
-                        spinner.stop_with_message(&format!("❌ Failed to read tower file: {}", e));
-                        return Err(anyhow!("Failed to read tower file: {}", e));
+                match compression.tool() {
+                    None => {
+                        let base64_args = vec![tower_path.as_str()];
+                        pool.execute_command_with_args(
+                            &self.active_node_with_status.node,
+                            &ssh_key_active,
+                            "base64",
+                            &base64_args,
+                        )
+                        .await
+                        .map(|data| (None, data))
                     }
+                    Some(tool) => pool
+                        .execute_command(
+                            &self.active_node_with_status.node,
+                            &ssh_key_active,
+                            &compressed_tower_read_command(tower_path, tool),
+                        )
+                        .await
+                        .and_then(|output| parse_compressed_tower(&output))
+                        .map(|(checksum, data)| (Some(checksum), data)),
+                }
+            };
+            let (checksum, data) = match read_result {
+                Ok(result) => result,
+                Err(e) => {
+                    spinner.stop_with_message(&format!("❌ Failed to read tower file: {}", e));
+                    return Err(anyhow!("Failed to read tower file: {}", e));
                 }
             };
             spinner.stop_with_message("");
+            println_if_not_silent!(
+                self.silent,
+                "  📥 Read {} from {}",
+                format_bytes(data.len() as u64),
+                self.active_node_with_status.node.label
+            );
 
-            let spinner = ConditionalSpinner::new("Transferring tower file...");
             let ssh_key_standby =
                 self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
-            {
-                let pool = self.ssh_pool.clone();
-                match pool
-                    .transfer_base64_to_file(
+
+            // Keep the standby's current tower files so a bad switch can be recovered by hand
+            let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+            if let Some(backup_cmd) = tower_backup_command(
+                standby_ledger_path,
+                &timestamp,
+                self.switch_config.tower_backup_retention,
+            ) {
+                if let Err(e) = self
+                    .ssh_pool
+                    .execute_command(&self.standby_node_with_status.node, &ssh_key_standby, &backup_cmd)
+                    .await
+                {
+                    println_if_not_silent!(
+                        self.silent,
+                        "  {}",
+                        format!("⚠️  Tower backup on standby failed: {}", e).yellow()
+                    );
+                }
+            }
+
+            // Compressed transfers land next to the tower file and are only
+            // moved into place once the checksum matches
+            let upload_path = match compression.tool() {
+                None => dest_path.clone(),
+                Some(_) => format!("{}.svs-transfer", dest_path),
+            };
+            // Progress lines replace the spinner so a slow link shows movement
+            let mut progress = TransferProgress::new();
+            let mut write_result = self
+                .ssh_pool
+                .transfer_base64_to_file(
+                    &self.standby_node_with_status.node,
+                    &ssh_key_standby,
+                    &upload_path,
+                    &data,
+                    |sent, total| {
+                        if let Some(line) = progress.update(sent, total) {
+                            println_if_not_silent!(self.silent, "  📤 {}", line);
+                            self.report_progress(format!("   ↳ {}", line));
+                        }
+                    },
+                )
+                .await;
+            if let (Ok(()), Some(tool), Some(checksum)) = (&write_result, compression.tool(), &checksum) {
+                write_result = self
+                    .ssh_pool
+                    .execute_command(
                         &self.standby_node_with_status.node,
                         &ssh_key_standby,
-                        &dest_path,
-                        &data,
+                        &install_compressed_tower_command(&upload_path, &dest_path, tool, checksum),
                     )
                     .await
-                {
-                    Ok(_) => {}
-                    Err(e) => {
-                        spinner.stop_with_message(&format!("❌ Failed to write tower file: {}", e));
-                        return Err(anyhow!("Failed to write tower file: {}", e));
-                    }
-                }
+                    .and_then(|output| probe_outcome(&output).map_err(|e| anyhow!(e)));
+            }
+            if let Err(e) = write_result {
+                println_if_not_silent!(self.silent, "{}", format!("❌ Failed to write tower file: {}", e));
+                return Err(anyhow!("Failed to write tower file: {}", e));
             }
-            spinner.stop_with_message("");
             data
         } else {
             // For dry run, just use a dummy value
@@ -720,6 +2431,7 @@ impl SwitchManager {
         let speed_mbps = (file_size as f64 / 1024.0 / 1024.0) / transfer_duration.as_secs_f64();
 
         println_if_not_silent!(
+            self.silent,
             "  ✅ Transferred in {} ({:.2} MB/s)",
             format!("{}ms", transfer_duration.as_millis())
                 .bright_green()
@@ -751,6 +2463,19 @@ impl SwitchManager {
     }
 
     pub(crate) async fn switch_backup_to_funded(&mut self, dry_run: bool) -> Result<()> {
+        let node = &self.standby_node_with_status.node;
+        if let Some(command) = node.set_identity_command(&node.paths.funded_identity, true) {
+            return self
+                .run_custom_set_identity(
+                    &self.standby_node_with_status.node,
+                    &command,
+                    dry_run,
+                    "Switching standby validator to funded identity...",
+                    "✅ Standby validator switched to funded identity",
+                )
+                .await;
+        }
+
         // Detect validator type to use appropriate command
         let process_info = {
             let ssh_key = self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
@@ -773,17 +2498,10 @@ impl SwitchManager {
                 .as_ref()
                 .ok_or_else(|| anyhow!("Firedancer fdctl executable path not found"))?;
 
-            // Extract config path from the process info (e.g., "fdctl run --config /path/to/config.toml")
-            let config_path = if let Some(config_match) = process_info
-                .lines()
-                .find(|line| line.contains("fdctl") && line.contains("--config"))
-                .and_then(|line| {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    parts
-                        .windows(2)
-                        .find(|w| w[0] == "--config")
-                        .map(|w| w[1].to_string())
-                }) {
+            // Declared config path, else from the process info (e.g., "fdctl run --config /path/to/config.toml")
+            let config_path = if let Some(config_match) =
+                fdctl_config_for(&self.standby_node_with_status.node, &process_info)
+            {
                 config_match
             } else {
                 return Err(anyhow!("Firedancer config path not found in running process. Please ensure fdctl is running with --config parameter"));
@@ -840,8 +2558,9 @@ impl SwitchManager {
             )
         };
 
-        println_if_not_silent!("{}", subtitle.dimmed());
+        println_if_not_silent!(self.silent, "{}", subtitle.dimmed());
         println_if_not_silent!(
+            self.silent,
             "ssh {}@{} '{}'",
             self.standby_node_with_status.node.user,
             self.standby_node_with_status.node.host,
@@ -849,8 +2568,10 @@ impl SwitchManager {
         );
 
         if !dry_run {
-            let spinner =
-                ConditionalSpinner::new("Switching standby validator to funded identity...");
+            let spinner = ConditionalSpinner::new(
+                "Switching standby validator to funded identity...",
+                self.silent,
+            );
             {
                 let ssh_key =
                     self.get_ssh_key_for_node(&self.standby_node_with_status.node.host)?;
@@ -864,19 +2585,13 @@ impl SwitchManager {
                         .fdctl_executable
                         .as_ref()
                         .unwrap();
-                    let config_path = process_info
-                        .lines()
-                        .find(|line| line.contains("fdctl") && line.contains("--config"))
-                        .and_then(|line| {
-                            let parts: Vec<&str> = line.split_whitespace().collect();
-                            parts.windows(2).find(|w| w[0] == "--config").map(|w| w[1])
-                        })
-                        .unwrap();
+                    let config_path =
+                        fdctl_config_for(&self.standby_node_with_status.node, &process_info).unwrap();
 
                     let args = vec![
                         "set-identity",
                         "--config",
-                        config_path,
+                        &config_path,
                         &self.standby_node_with_status.node.paths.funded_identity,
                     ];
 
@@ -931,8 +2646,9 @@ impl SwitchManager {
             .as_ref()
             .unwrap_or(&default_solana);
 
-        let catchup_cmd = format!("{} catchup --our-localhost", solana_cli);
+        let catchup_cmd = self.standby_node_with_status.node.catchup_invocation(solana_cli);
         println_if_not_silent!(
+            self.silent,
             "ssh {}@{} '{}'",
             self.standby_node_with_status.node.user,
             self.standby_node_with_status.node.host,
@@ -943,6 +2659,7 @@ impl SwitchManager {
             // No sleep - verify immediately!
             let spinner = ConditionalSpinner::new(
                 "Verifying new active validator (former standby) catchup status...",
+                self.silent,
             );
 
             let catchup_result = {
@@ -978,17 +2695,76 @@ impl SwitchManager {
         Ok(())
     }
 
+    fn touch_switch_lock(&self) {
+        if let Some(lock) = &self.switch_lock {
+            lock.touch();
+        }
+    }
+
+    /// Stop with a report of completed steps if an abort was requested. Once the
+    /// active node was demoted no node votes, so it is rolled back to the
+    /// funded identity before stopping.
+    async fn check_abort(&self, completed_steps: usize) -> Result<()> {
+        if !ABORT_REQUESTED.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        println_if_not_silent!(self.silent, "\n{}", "⏹️  Switch aborted".yellow().bold());
+        let rolled_back = match completed_steps {
+            1 | 2 => match with_step_timeout(
+                "Rollback (set-identity on active node)",
+                self.switch_config.active_identity_timeout_seconds,
+                self.restore_active_identity(),
+            )
+            .await
+            {
+                Ok(()) => true,
+                Err(e) => {
+                    println_if_not_silent!(self.silent, "   {} {}", "❌ Rollback failed:".red(), e);
+                    false
+                }
+            },
+            _ => false,
+        };
+        for line in abort_report(
+            completed_steps,
+            &self.active_node_with_status.node.label,
+            &self.standby_node_with_status.node.label,
+            &self.active_node_with_status.node.paths.funded_identity,
+            rolled_back,
+        ) {
+            println_if_not_silent!(self.silent, "   {}", line);
+        }
+        Err(anyhow!(
+            "Switch aborted by operator after {} of 3 steps{}",
+            completed_steps,
+            if rolled_back { ", active node rolled back to the funded identity" } else { "" }
+        ))
+    }
+
+    /// Put the demoted active node back on the funded identity. Its own tower
+    /// is the newest, as no other node has voted since.
+    async fn restore_active_identity(&self) -> Result<()> {
+        self.set_active_identity(
+            &self.active_node_with_status.node.paths.funded_identity,
+            false,
+            "Restoring active validator to funded identity...",
+            "✅ Active validator restored to funded identity",
+        )
+        .await
+    }
+
     fn print_summary(&self, dry_run: bool) {
-        println_if_not_silent!();
+        println_if_not_silent!(self.silent);
         if dry_run {
-            println_if_not_silent!("✅ Dry run completed successfully");
-            println_if_not_silent!();
-            println_if_not_silent!("{}", "Press any key to continue...".dimmed());
-            if !is_silent_mode() {
+            println_if_not_silent!(self.silent, "✅ Dry run completed successfully");
+            println_if_not_silent!(self.silent);
+            println_if_not_silent!(self.silent, "{}", "Press any key to continue...".dimmed());
+            if !self.silent {
                 let _ = std::io::stdin().read_line(&mut String::new());
             }
         } else {
-            println_if_not_silent!("✅ Validator identity switch completed successfully");
+            println_if_not_silent!(self.silent, "✅ Validator identity switch completed successfully");
         }
     }
 }
@@ -212,4 +212,272 @@ mod scenario_tests {
             thread::sleep(Duration::from_millis(50));
         } // Should clean up automatically
     }
+
+    #[test]
+    fn test_dry_run_probe_helpers() {
+        use crate::commands::switch::{fdctl_config_path, probe_command, probe_outcome, shell_quote};
+
+        assert_eq!(shell_quote("/mnt/ledger"), "'/mnt/ledger'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+
+        assert_eq!(
+            probe_command("test -r '/k.json'", "/k.json missing"),
+            "if test -r '/k.json'; then echo PASS; else echo 'FAIL: /k.json missing'; fi"
+        );
+        assert_eq!(probe_outcome("PASS\n"), Ok(()));
+        assert_eq!(
+            probe_outcome("FAIL: /k.json missing\n"),
+            Err("/k.json missing".to_string())
+        );
+        assert!(probe_outcome("").is_err());
+
+        let ps = "sol 123 fdctl run --config /home/sol/config.toml";
+        assert_eq!(fdctl_config_path(ps).as_deref(), Some("/home/sol/config.toml"));
+        assert_eq!(fdctl_config_path("agave-validator --ledger /mnt"), None);
+    }
+
+    #[test]
+    fn test_keypair_probe_command() {
+        use crate::commands::switch::{keypair_probe_command, keypair_valid_test};
+
+        let test = keypair_valid_test("/keys/unfunded.json");
+        assert!(test.starts_with("test -r '/keys/unfunded.json' && tr -d"));
+        assert!(test.contains("{63}"));
+
+        let probe = keypair_probe_command("/keys/unfunded.json");
+        assert!(probe.starts_with("if ! test -r '/keys/unfunded.json'; then echo 'FAIL: /keys/unfunded.json missing or unreadable';"));
+        assert!(probe.contains(&format!("elif {}; then echo PASS;", test)));
+        assert!(probe.ends_with("else echo 'FAIL: /keys/unfunded.json is not a valid keypair file'; fi"));
+    }
+
+    #[test]
+    fn test_identity_pubkeys() {
+        use crate::commands::switch::{identity_pubkey_problems, identity_pubkeys_command, parse_identity_pubkeys};
+        use crate::types::NodePaths;
+
+        let command = identity_pubkeys_command("/keys/funded.json", "/keys/unfunded.json");
+        assert!(command.contains("echo \"funded=$(pubkey '/keys/funded.json' 2>/dev/null)\""));
+        assert!(command.contains("\"$exe\" keys pubkey \"$1\""));
+
+        let identity = "7Np41oeYqPefeNQEHSv1UDhYrehxin3NStELsSKCT4K2";
+        let unfunded_pubkey = "4gkzwVK3ENbMGyAQJ5Y9TkrGnjoXxJXDwKx6hyPTaqiG";
+        let output = format!("funded={}\nunfunded={}\n", identity, unfunded_pubkey);
+        let (funded, unfunded) = parse_identity_pubkeys(&output).unwrap();
+        assert_eq!(funded.as_deref(), Some(identity));
+        assert_eq!(unfunded.as_deref(), Some(unfunded_pubkey));
+        assert!(parse_identity_pubkeys("keygen=missing\n").is_err());
+        // An unreadable keypair derives nothing
+        assert_eq!(parse_identity_pubkeys("funded=\nunfunded=\n").unwrap(), (None, None));
+
+        let paths = NodePaths {
+            funded_identity: "/keys/funded.json".to_string(),
+            unfunded_identity: "/keys/unfunded.json".to_string(),
+            vote_keypair: "/keys/vote.json".to_string(),
+            ..Default::default()
+        };
+        assert!(identity_pubkey_problems(identity, &paths, Some(identity), Some(unfunded_pubkey)).is_empty());
+        // Swapped keypair files
+        assert_eq!(
+            identity_pubkey_problems(identity, &paths, Some(unfunded_pubkey), Some(identity)),
+            vec![
+                format!(
+                    "Funded identity keypair /keys/funded.json is {}, not the configured identity {}",
+                    unfunded_pubkey, identity
+                ),
+                format!("Unfunded identity keypair /keys/unfunded.json is the configured identity {}", identity),
+            ]
+        );
+        assert!(identity_pubkey_problems(identity, &paths, None, None).is_empty());
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        use crate::commands::switch::{clock_skew_verdict, remote_clock_offset_ms};
+
+        // Remote read at 1_000_150ms while we were between 1_000_000 and 1_000_100
+        assert_eq!(remote_clock_offset_ms(1_000_000, 1_000_100, "1000150000000\n"), Some(100));
+        assert_eq!(remote_clock_offset_ms(0, 0, "1700000000N"), None);
+
+        assert_eq!(clock_skew_verdict(20, -30, 500, 2000), Ok(None));
+        assert_eq!(
+            clock_skew_verdict(400, -300, 500, 2000),
+            Ok(Some("nodes differ by 700ms (active +400ms, standby -300ms vs this machine)".to_string()))
+        );
+        // Both nodes off from this machine by the same amount only warns
+        assert!(clock_skew_verdict(5000, 5000, 500, 2000).unwrap().is_some());
+        assert_eq!(
+            clock_skew_verdict(2500, 0, 500, 2000),
+            Err("nodes differ by 2500ms (active +2500ms, standby +0ms vs this machine), limit 2000ms".to_string())
+        );
+        assert!(clock_skew_verdict(2500, 0, 500, 0).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_confirmation_matches() {
+        use crate::commands::switch::confirmation_matches;
+
+        assert!(confirmation_matches("SWITCH", "SWITCH"));
+        assert!(confirmation_matches(" SWITCH \n", "SWITCH"));
+        assert!(!confirmation_matches("switch", "SWITCH"));
+        assert!(!confirmation_matches("y", "SWITCH"));
+        assert!(confirmation_matches("mainnet-val", "mainnet-val"));
+        assert!(!confirmation_matches("", " "));
+    }
+
+    #[test]
+    fn test_standby_lag_blocker() {
+        use crate::commands::switch::standby_lag_blocker;
+
+        assert_eq!(standby_lag_blocker(Some(0), 10), None);
+        assert_eq!(standby_lag_blocker(Some(8), 10), None);
+        assert_eq!(
+            standby_lag_blocker(Some(42), 10).as_deref(),
+            Some("standby is 42 slots behind (limit 10)")
+        );
+        assert_eq!(
+            standby_lag_blocker(None, 10).as_deref(),
+            Some("standby catchup status is unknown")
+        );
+    }
+
+    #[test]
+    fn test_catchup_slots_behind() {
+        use crate::commands::status_ui_v2::catchup_slots_behind;
+
+        assert_eq!(
+            catchup_slots_behind("⠁ 120 slot(s) behind (us:344297245 them:344297365)"),
+            Some(120)
+        );
+        assert_eq!(catchup_slots_behind("⠁ 10 slot(s) behind"), Some(10));
+        assert_eq!(
+            catchup_slots_behind("7Np41oeYqPefeNQEHSv1UDhYrehxin3NStELsSKCT4K2 has caught up (us:344297365 them:344297365)"),
+            Some(0)
+        );
+        assert_eq!(catchup_slots_behind("Error: RPC request error"), None);
+    }
+
+    #[tokio::test]
+    async fn test_step_timeout_names_the_step() {
+        use crate::commands::switch::with_step_timeout;
+
+        let ok = with_step_timeout("Step 1 (set-identity on active node)", 5, async {
+            Ok::<_, anyhow::Error>(7)
+        })
+        .await;
+        assert_eq!(ok.unwrap(), 7);
+
+        let timed_out = with_step_timeout("Step 2 (tower transfer)", 0, async {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            Ok::<_, anyhow::Error>(())
+        })
+        .await;
+        assert_eq!(
+            timed_out.unwrap_err().to_string(),
+            "Step 2 (tower transfer) timed out after 0s"
+        );
+    }
+
+    #[test]
+    fn test_tower_backup_command() {
+        use crate::commands::switch::tower_backup_command;
+
+        assert_eq!(tower_backup_command("/mnt/ledger", "20250101T000000Z", 0), None);
+
+        let cmd = tower_backup_command("/mnt/ledger", "20250101T000000Z", 3).unwrap();
+        assert!(cmd.starts_with("ls '/mnt/ledger'/tower-*.bin >/dev/null 2>&1 || exit 0;"));
+        assert!(cmd.contains("mkdir -p '/mnt/ledger/tower-backups/20250101T000000Z'"));
+        assert!(cmd.contains("cp -p '/mnt/ledger'/tower-*.bin '/mnt/ledger/tower-backups/20250101T000000Z'/"));
+        assert!(cmd.ends_with("sort -r | tail -n +4 | xargs -r rm -rf"));
+    }
+
+    #[test]
+    fn test_compressed_tower_transfer_commands() {
+        use crate::commands::switch::{
+            compressed_tower_read_command, install_compressed_tower_command, parse_compressed_tower,
+        };
+        use crate::types::{SwitchConfig, TowerCompression};
+
+        let config: SwitchConfig = serde_yaml::from_str("tower_compression: zstd").unwrap();
+        assert_eq!(config.tower_compression, TowerCompression::Zstd);
+        assert_eq!(SwitchConfig::default().tower_compression.tool(), None);
+
+        assert_eq!(
+            compressed_tower_read_command("/mnt/ledger/tower-1_9-A.bin", "gzip"),
+            "sha256sum '/mnt/ledger/tower-1_9-A.bin' | cut -d' ' -f1 && gzip -c '/mnt/ledger/tower-1_9-A.bin' | base64 -w0"
+        );
+
+        let checksum = "a".repeat(64);
+        let (sum, data) = parse_compressed_tower(&format!("{}\nH4sIAAAA\n", checksum)).unwrap();
+        assert_eq!(sum, checksum);
+        assert_eq!(data, "H4sIAAAA");
+        assert!(parse_compressed_tower("not-a-checksum\nH4sI").is_err());
+
+        let install = install_compressed_tower_command("/l/t.bin.svs-transfer", "/l/t.bin", "gzip", &checksum);
+        assert!(install.starts_with("if gzip -d -c '/l/t.bin.svs-transfer' > '/l/t.bin.svs-staged' && "));
+        assert!(install.contains("&& mv '/l/t.bin.svs-staged' '/l/t.bin'; then echo PASS;"));
+        assert!(install.ends_with("rm -f '/l/t.bin.svs-transfer' '/l/t.bin.svs-staged'"));
+    }
+
+    #[test]
+    fn test_tower_transfer_progress() {
+        use crate::commands::switch::{format_bytes, TransferProgress};
+
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(12 * 1024 + 300), "12.3 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MB");
+        assert_eq!(format_bytes(2 * 1024 * 1024 * 1024), "2.0 GB");
+
+        let total = 100 * 1024;
+        let mut progress = TransferProgress::new();
+        assert_eq!(progress.update(16 * 1024, total), None);
+        assert_eq!(progress.update(32 * 1024, total).unwrap(), "32.0 KB / 100.0 KB (32%)");
+        // Still within the same quarter
+        assert_eq!(progress.update(48 * 1024, total), None);
+        assert_eq!(progress.update(64 * 1024, total).unwrap(), "64.0 KB / 100.0 KB (64%)");
+        assert_eq!(progress.update(total, total).unwrap(), "100.0 KB / 100.0 KB (100%)");
+
+        // An empty file is done at once
+        assert_eq!(TransferProgress::new().update(0, 0).unwrap(), "0 B / 0 B (100%)");
+    }
+
+    #[test]
+    fn test_abort_report_lists_completed_steps_and_recovery() {
+        use crate::commands::switch::abort_report;
+
+        let before = abort_report(0, "primary", "backup", "/keys/funded.json", false);
+        assert_eq!(before.len(), 4);
+        assert!(before[0].starts_with("⏭️  Step 1"));
+        assert!(before[3].contains("Nothing changed - primary is still the active node"));
+
+        let midway = abort_report(1, "primary", "backup", "/keys/funded.json", false);
+        assert!(midway[0].starts_with("✅ Step 1: primary → unfunded identity"));
+        assert!(midway[1].starts_with("⏭️  Step 2"));
+        assert!(midway[3].contains("set-identity with /keys/funded.json on primary"));
+
+        let rolled_back = abort_report(2, "primary", "backup", "/keys/funded.json", true);
+        assert!(rolled_back[1].starts_with("✅ Step 2"));
+        assert!(rolled_back[3].contains("Rolled back - primary is on the funded identity again"));
+
+        let swapped = abort_report(3, "primary", "backup", "/keys/funded.json", false);
+        assert!(swapped[2].starts_with("✅ Step 3"));
+        assert!(swapped[3].contains("Confirm backup is voting"));
+    }
+
+    #[test]
+    fn test_slots_until_leader_gap() {
+        use crate::commands::switch::slots_until_leader_gap;
+
+        // No upcoming leader slots, or the next block is far enough away
+        assert_eq!(slots_until_leader_gap(100, &[], 50), 0);
+        assert_eq!(slots_until_leader_gap(100, &[40, 41, 150, 151], 50), 0);
+
+        // Leader block too close: wait until just after it
+        assert_eq!(slots_until_leader_gap(100, &[120, 121, 122, 123, 400], 50), 24);
+
+        // Two blocks close together are skipped as one
+        assert_eq!(slots_until_leader_gap(100, &[104, 105, 130, 131, 400], 50), 32);
+
+        // Currently leader
+        assert_eq!(slots_until_leader_gap(100, &[100, 101, 102, 103], 50), 4);
+    }
 }
@@ -82,8 +82,12 @@ mod tests {
                 funded_identity: funded.to_string(),
                 unfunded_identity: unfunded.to_string(),
                 vote_keypair: "/home/solana/vote.json".to_string(),
+                ..Default::default()
             },
             ssh_key_path: None,
+            password_auth: false,
+            sudo: None,
+            commands: None,
         }
     }
     
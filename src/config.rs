@@ -1,9 +1,318 @@
 use anyhow::{anyhow, Result};
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{Deserialize, Deserializer, IntoDeserializer, Visitor};
+use serde_yaml::Value;
 use std::fs;
 use std::path::PathBuf;
 
 use crate::types::Config;
 
+/// Prefix of environment variables that override config keys
+const ENV_PREFIX: &str = "SVS_";
+
+/// Separates nested keys in an override, e.g. `SVS_ALERT_CONFIG__TELEGRAM__BOT_TOKEN`
+const ENV_PATH_SEPARATOR: &str = "__";
+
+/// Names the active config profile; set from `--profile` or by the operator
+pub const PROFILE_ENV: &str = "SVS_PROFILE";
+
+/// Directory under the config directory holding one file per profile
+const PROFILES_DIR: &str = "profiles";
+
+/// Profile names end up in file names, so keep them to a safe character set
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Invalid profile name '{}': use letters, digits, '-' and '_'",
+            name
+        ))
+    }
+}
+
+/// The active profile, or `None` for the default `config.yaml`
+pub fn active_profile() -> Option<String> {
+    std::env::var(PROFILE_ENV)
+        .ok()
+        .filter(|name| !name.is_empty())
+}
+
+/// Config file for `profile` inside `config_dir`
+pub fn profile_config_path(config_dir: &std::path::Path, profile: Option<&str>) -> PathBuf {
+    match profile {
+        Some(name) => config_dir.join(PROFILES_DIR).join(format!("{}.yaml", name)),
+        None => config_dir.join("config.yaml"),
+    }
+}
+
+/// One `SVS_*` override: the variable, the config path it names and its value
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvOverride {
+    pub variable: String,
+    pub path: Vec<String>,
+    pub value: String,
+}
+
+/// Overrides among `vars`. Only names with a `__` separator count, so
+/// settings like `SVS_PROFILE` are not mistaken for config keys.
+pub fn env_overrides<I>(vars: I) -> Vec<EnvOverride>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut overrides: Vec<EnvOverride> = vars
+        .into_iter()
+        .filter_map(|(variable, value)| {
+            let name = variable.strip_prefix(ENV_PREFIX)?;
+            let path: Vec<String> = name.split(ENV_PATH_SEPARATOR).map(str::to_string).collect();
+            if path.len() < 2 || path.iter().any(|segment| segment.is_empty()) {
+                return None;
+            }
+            Some(EnvOverride { variable, path, value })
+        })
+        .collect();
+    overrides.sort_by(|a, b| a.variable.cmp(&b.variable));
+    overrides
+}
+
+/// Whether a YAML key is named by an override segment: case, `_` and `-`
+/// are ignored, so `VOTE_PUBKEY` names `votePubkey`
+fn key_matches(key: &Value, segment: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+    key.as_str().map(|key| normalize(key) == normalize(segment)).unwrap_or(false)
+}
+
+/// Child of `value` named by `segment`: a map key or a list index
+fn child<'a>(value: &'a Value, segment: &str) -> Option<&'a Value> {
+    match value {
+        Value::Mapping(map) => map.iter().find(|(key, _)| key_matches(key, segment)).map(|(_, v)| v),
+        Value::Sequence(items) => items.get(segment.parse::<usize>().ok()?),
+        _ => None,
+    }
+}
+
+fn child_mut<'a>(value: &'a mut Value, segment: &str) -> Option<&'a mut Value> {
+    match value {
+        Value::Mapping(map) => map
+            .iter_mut()
+            .find(|(key, _)| key_matches(key, segment))
+            .map(|(_, v)| v),
+        Value::Sequence(items) => items.get_mut(segment.parse::<usize>().ok()?),
+        _ => None,
+    }
+}
+
+/// Set the key named by `path`, creating missing sections. The value is kept
+/// as a string and converted to the field's type when the config is read, so
+/// `-100200` stays a chat ID; a `[a, b]` value becomes a list. Keys added here
+/// are renamed to the config's spelling (`votePubkey`) by `config_from_value`.
+pub fn apply_override(root: &mut Value, path: &[String], raw: &str) -> Result<()> {
+    let mut current = root;
+    for (idx, segment) in path.iter().enumerate() {
+        if current.is_null() {
+            *current = Value::Mapping(Default::default());
+        }
+        if child(current, segment).is_none() {
+            match current {
+                Value::Mapping(map) => {
+                    map.insert(Value::String(segment.to_ascii_lowercase()), Value::Null);
+                }
+                Value::Sequence(_) => {
+                    return Err(anyhow!("no list entry {} at {}", segment, path[..idx].join(".")))
+                }
+                _ => return Err(anyhow!("{} is not a section", path[..idx].join("."))),
+            }
+        }
+        current = child_mut(current, segment).expect("key exists");
+    }
+
+    *current = match serde_yaml::from_str(raw) {
+        Ok(Value::Sequence(items)) if raw.trim_start().starts_with('[') => Value::Sequence(items),
+        _ => Value::String(raw.to_string()),
+    };
+    Ok(())
+}
+
+/// Read the config from a YAML value, with scalars converted to the type of
+/// the field they land in, the way plain scalars in the file are read by
+/// `serde_yaml::from_str`
+pub fn config_from_value(value: Value) -> Result<Config> {
+    Ok(Config::deserialize(Coercing(value))?)
+}
+
+/// Deserializer over a YAML value that reads a string as the number or bool
+/// a field asks for and a number or bool as a string. Map keys are matched to
+/// struct fields like override segments, so the field names and serde renames
+/// are the only list of key spellings.
+struct Coercing(Value);
+
+impl<'de> IntoDeserializer<'de, serde_yaml::Error> for Coercing {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! coerce_from_string {
+    ($($method:ident => $ty:ty, $visit:ident;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0 {
+                    Value::String(s) => match s.trim().parse::<$ty>() {
+                        Ok(parsed) => visitor.$visit(parsed),
+                        Err(_) => Value::String(s).$method(visitor),
+                    },
+                    other => other.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Coercing {
+    type Error = serde_yaml::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Sequence(items) => {
+                visitor.visit_seq(SeqDeserializer::new(items.into_iter().map(Coercing)))
+            }
+            Value::Mapping(map) => visitor.visit_map(MapDeserializer::new(
+                map.into_iter().map(|(key, value)| (Coercing(key), Coercing(value))),
+            )),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+                "true" => visitor.visit_bool(true),
+                "false" => visitor.visit_bool(false),
+                _ => Value::String(s).deserialize_bool(visitor),
+            },
+            other => other.deserialize_bool(visitor),
+        }
+    }
+
+    coerce_from_string! {
+        deserialize_i8 => i8, visit_i8;
+        deserialize_i16 => i16, visit_i16;
+        deserialize_i32 => i32, visit_i32;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_u8 => u8, visit_u8;
+        deserialize_u16 => u16, visit_u16;
+        deserialize_u32 => u32, visit_u32;
+        deserialize_u64 => u64, visit_u64;
+        deserialize_f32 => f32, visit_f32;
+        deserialize_f64 => f64, visit_f64;
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Number(n) => visitor.visit_string(n.to_string()),
+            Value::Bool(b) => visitor.visit_string(b.to_string()),
+            other => other.deserialize_string(visitor),
+        }
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            other => visitor.visit_some(Coercing(other)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let map = match self.0 {
+            Value::Mapping(map) => map,
+            other => return Coercing(other).deserialize_any(visitor),
+        };
+        let entries = map.into_iter().map(|(key, value)| {
+            let field = match key.as_str() {
+                Some(name) if !fields.contains(&name) => fields
+                    .iter()
+                    .find(|field| key_matches(&key, field))
+                    .map(|field| Value::from(*field)),
+                _ => None,
+            };
+            (Coercing(field.unwrap_or(key)), Coercing(value))
+        });
+        visitor.visit_map(MapDeserializer::new(entries))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 char bytes byte_buf unit unit_struct seq tuple tuple_struct map ignored_any
+    }
+}
+
+/// Put the file's value at `path` back into `value`, or drop the key when
+/// the file doesn't have it, so overrides aren't written to the config file
+fn restore_from_file(value: &mut Value, file: &Value, path: &[String]) {
+    let (last, parents) = match path.split_last() {
+        Some(split) => split,
+        None => return,
+    };
+    let mut parent = value;
+    for segment in parents {
+        parent = match child_mut(parent, segment) {
+            Some(next) => next,
+            None => return,
+        };
+    }
+    let original = path.iter().try_fold(file, |node, segment| child(node, segment)).cloned();
+    match (original, parent) {
+        (Some(original), parent) => {
+            if let Some(slot) = child_mut(parent, last) {
+                *slot = original;
+            }
+        }
+        (None, Value::Mapping(map)) => map.retain(|key, _| !key_matches(key, last)),
+        _ => {}
+    }
+}
+
 pub struct ConfigManager {
     config_path: PathBuf,
 }
@@ -19,7 +328,12 @@ impl ConfigManager {
             fs::create_dir_all(&config_dir)?;
         }
 
-        let config_path = config_dir.join("config.yaml");
+        let profile = active_profile();
+        if let Some(name) = &profile {
+            validate_profile_name(name)?;
+            fs::create_dir_all(config_dir.join(PROFILES_DIR))?;
+        }
+        let config_path = profile_config_path(&config_dir, profile.as_deref());
 
         Ok(ConfigManager { config_path })
     }
@@ -30,19 +344,43 @@ impl ConfigManager {
 
     pub fn load(&self) -> Result<Config> {
         if !self.config_path.exists() {
+            if let Some(name) = active_profile() {
+                return Err(anyhow!(
+                    "Configuration file for profile '{}' not found at {}. Run 'svs --profile {} init' first.",
+                    name,
+                    self.config_path.display(),
+                    name
+                ));
+            }
             return Err(anyhow!(
                 "Configuration file not found. Run 'svs setup' first."
             ));
         }
 
         let content = fs::read_to_string(&self.config_path)?;
-        let config: Config = serde_yaml::from_str(&content)?;
+        let mut value: Value = serde_yaml::from_str(&content)?;
+        // Errors name the variable only; its value may be a secret
+        for env in env_overrides(std::env::vars()) {
+            apply_override(&mut value, &env.path, &env.value)
+                .map_err(|e| anyhow!("{}: {}", env.variable, e))?;
+        }
+        crate::secrets::resolve_secrets(&mut value)?;
+        let mut config = config_from_value(value)?;
+        config.normalize_nodes()?;
         Ok(config)
     }
 
-    #[allow(dead_code)]
     pub fn save(&self, config: &Config) -> Result<()> {
-        let content = serde_yaml::to_string(config)?;
+        let mut value = serde_yaml::to_value(config)?;
+        let file = match fs::read_to_string(&self.config_path) {
+            Ok(content) => serde_yaml::from_str(&content).unwrap_or(Value::Null),
+            Err(_) => Value::Null,
+        };
+        for env in &env_overrides(std::env::vars()) {
+            restore_from_file(&mut value, &file, &env.path);
+        }
+        crate::secrets::restore_references(&mut value, &file);
+        let content = serde_yaml::to_string(&value)?;
         fs::write(&self.config_path, content)?;
         Ok(())
     }
@@ -51,7 +389,6 @@ impl ConfigManager {
         self.config_path.exists()
     }
 
-    #[allow(dead_code)]
     pub fn create_default() -> Config {
         use crate::types::*;
 
@@ -59,6 +396,115 @@ impl ConfigManager {
             version: "1.0.0".to_string(),
             validators: Vec::new(),
             alert_config: None,
+            sfdp_report: None,
+            switch: None,
+            health_endpoint: None,
+            influxdb: None,
+            statsd: None,
+            log_file: None,
+            syslog: None,
+            ssh: None,
+            proxy: None,
+            vote_subscription: None,
+            rpc_rate_limit: None,
+            rpc_headers: Vec::new(),
+            local_rpc: None,
+            catchup: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    const FILE: &str = r#"
+version: "1.0.0"
+validators:
+  - votePubkey: Vote111
+    identityPubkey: Id111
+    rpc: https://api.mainnet-beta.solana.com
+    nodes: []
+alert_config:
+  enabled: true
+  delinquency_threshold_seconds: 30
+"#;
+
+    #[test]
+    fn test_profile_config_path() {
+        let dir = std::path::Path::new("/home/sol/.solana-validator-switch");
+        assert_eq!(profile_config_path(dir, None), dir.join("config.yaml"));
+        assert_eq!(
+            profile_config_path(dir, Some("testnet")),
+            dir.join("profiles").join("testnet.yaml")
+        );
+
+        assert!(validate_profile_name("mainnet-beta_2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../config").is_err());
+        assert!(validate_profile_name("main net").is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_need_a_section() {
+        let overrides = env_overrides(vars(&[
+            ("SVS_PROFILE", "mainnet"),
+            ("SVS_PROXY_URL", "socks5://10.0.0.5"),
+            ("HOME", "/root"),
+            ("SVS_SWITCH__", "x"),
+            ("SVS_VALIDATORS__0__RPC", "https://rpc.example.com"),
+        ]));
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].path, ["VALIDATORS", "0", "RPC"]);
+    }
+
+    #[test]
+    fn test_apply_override() {
+        let mut value: Value = serde_yaml::from_str(FILE).unwrap();
+        let overrides = env_overrides(vars(&[
+            ("SVS_VALIDATORS__0__RPC", "https://rpc.example.com"),
+            ("SVS_VALIDATORS__0__ADDITIONAL_RPCS", "[https://a.example.com, https://b.example.com]"),
+            ("SVS_ALERT_CONFIG__DELINQUENCY_THRESHOLD_SECONDS", "45"),
+            ("SVS_ALERT_CONFIG__TELEGRAM__BOT_TOKEN", "123456:AAH"),
+            ("SVS_ALERT_CONFIG__TELEGRAM__CHAT_ID", "-100200"),
+        ]));
+        for env in &overrides {
+            apply_override(&mut value, &env.path, &env.value).unwrap();
+        }
+
+        // Values stay strings until the config is read with the field types
+        assert_eq!(value["alert_config"]["telegram"]["chat_id"], Value::from("-100200"));
+        let config = config_from_value(value.clone()).unwrap();
+        let validator = &config.validators[0];
+        assert_eq!(validator.rpc, "https://rpc.example.com");
+        assert_eq!(validator.additional_rpcs[1], "https://b.example.com");
+        let alert_config = config.alert_config.unwrap();
+        assert_eq!(alert_config.delinquency_threshold_seconds, 45);
+        let telegram = alert_config.telegram.unwrap();
+        assert_eq!(telegram.bot_token, "123456:AAH");
+        assert_eq!(telegram.chat_id, "-100200");
+
+        let mut missing = value.clone();
+        let path = vec!["VALIDATORS".to_string(), "3".to_string(), "RPC".to_string()];
+        assert!(apply_override(&mut missing, &path, "x").is_err());
+    }
+
+    #[test]
+    fn test_restore_keeps_overrides_out_of_the_file() {
+        let file: Value = serde_yaml::from_str(FILE).unwrap();
+        let mut value = file.clone();
+        let rpc = vec!["VALIDATORS".to_string(), "0".to_string(), "RPC".to_string()];
+        let token = vec!["ALERT_CONFIG".to_string(), "TELEGRAM".to_string(), "BOT_TOKEN".to_string()];
+        apply_override(&mut value, &rpc, "https://rpc.example.com").unwrap();
+        apply_override(&mut value, &token, "123456:AAH").unwrap();
+
+        restore_from_file(&mut value, &file, &rpc);
+        restore_from_file(&mut value, &file, &token);
+        assert_eq!(value["validators"][0]["rpc"], Value::from("https://api.mainnet-beta.solana.com"));
+        assert!(value["alert_config"]["telegram"].get("bot_token").is_none());
+    }
+}
@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Result};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::alert::AlertManager;
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeConfig, ValidatorPair};
+
+/// How long the former active node is watched after a switch
+pub const DEMOTION_CHECK_DURATION: Duration = Duration::from_secs(300);
+
+/// Pause before each demotion check, the first included: gossip and the old
+/// node's RPC trail its set-identity by a few seconds
+const DEMOTION_CHECK_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Consecutive reverted checks before alerting, so one stale answer doesn't
+const REVERTED_CHECKS_TO_ALERT: u32 = 2;
+
+/// Recent vote account transactions whose fee payer is checked each round
+const VOTE_PAYERS_CHECKED: usize = 5;
+
+/// Outcome of a single check of the former active node
+#[derive(Debug, Clone, PartialEq)]
+pub enum DemotionCheck {
+    /// Running the unfunded identity and not advertising the funded one
+    Demoted,
+    /// The funded identity is back on the old node
+    Reverted(String),
+    /// Not enough data to decide this round
+    Inconclusive(String),
+}
+
+/// Decide whether the old node is demoted from its local RPC identity, the
+/// gossip address the cluster has for the funded identity and the fee payers
+/// of the latest votes that landed on chain
+pub fn evaluate_demotion(
+    funded_identity: &str,
+    local_identity: Option<&str>,
+    funded_gossip_ip: Option<IpAddr>,
+    old_node_ips: &[IpAddr],
+    recent_vote_payers: &[String],
+) -> DemotionCheck {
+    if local_identity == Some(funded_identity) {
+        return DemotionCheck::Reverted(
+            "local RPC reports the funded identity".to_string(),
+        );
+    }
+
+    // The old node's votes are paid by the identity it was demoted to; any
+    // landing means it still votes next to the new active node
+    if let Some(demoted) = local_identity {
+        if recent_vote_payers.iter().any(|payer| payer == demoted) {
+            return DemotionCheck::Reverted(format!(
+                "votes paid by its demoted identity {} still land",
+                demoted
+            ));
+        }
+    }
+
+    if let Some(ip) = funded_gossip_ip {
+        if old_node_ips.contains(&ip) {
+            return DemotionCheck::Reverted(format!(
+                "cluster gossip shows the funded identity at {}",
+                ip
+            ));
+        }
+    }
+
+    match local_identity {
+        Some(_) => DemotionCheck::Demoted,
+        None => DemotionCheck::Inconclusive("local RPC did not report an identity".to_string()),
+    }
+}
+
+/// Read the identity reported by the node's local RPC
+pub async fn fetch_local_identity(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<String> {
+    let command = crate::local_rpc::local_rpc_command(
+        None,
+        &[crate::local_rpc::request("getIdentity", serde_json::json!([]))],
+    );
+    let output = ssh_pool.execute_command(node, ssh_key, &command).await?;
+    let json: serde_json::Value = serde_json::from_str(output.trim())
+        .map_err(|e| anyhow!("Invalid getIdentity response: {}", e))?;
+    json["result"]["identity"]
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| anyhow!("getIdentity returned no identity"))
+}
+
+/// Watch the former active node for `DEMOTION_CHECK_DURATION` after a switch
+/// and alert if it reverts to the funded identity or can never be verified
+pub struct DemotionVerifier {
+    pub old_active: NodeConfig,
+    pub validator_pair: ValidatorPair,
+    pub rpc_url: String,
+    pub ssh_pool: Arc<AsyncSshPool>,
+    pub ssh_key: String,
+    pub alert_manager: Option<AlertManager>,
+}
+
+impl DemotionVerifier {
+    pub fn spawn(self) {
+        tokio::spawn(async move {
+            let _ = self.run().await;
+        });
+    }
+
+    pub async fn run(self) -> DemotionCheck {
+        let old_node_ips: Vec<IpAddr> = tokio::net::lookup_host((self.old_active.host.as_str(), 0))
+            .await
+            .map(|addrs| addrs.map(|a| a.ip()).collect())
+            .unwrap_or_default();
+
+        let rounds = (DEMOTION_CHECK_DURATION.as_secs() / DEMOTION_CHECK_INTERVAL.as_secs()).max(1);
+        let mut verified = false;
+        let mut reverted_checks = 0;
+        let mut last_issue = "no check completed".to_string();
+
+        for _ in 0..rounds {
+            tokio::time::sleep(DEMOTION_CHECK_INTERVAL).await;
+
+            let local_identity =
+                fetch_local_identity(&self.ssh_pool, &self.old_active, &self.ssh_key)
+                    .await
+                    .ok();
+            let gossip_ip = crate::solana_rpc::fetch_gossip_ip(
+                &self.rpc_url,
+                &self.validator_pair.identity_pubkey,
+            )
+            .await
+            .ok()
+            .flatten();
+            let vote_payers = crate::solana_rpc::fetch_recent_vote_payers(
+                &self.rpc_url,
+                &self.validator_pair.vote_pubkey,
+                VOTE_PAYERS_CHECKED,
+            )
+            .await
+            .unwrap_or_default();
+
+            match evaluate_demotion(
+                &self.validator_pair.identity_pubkey,
+                local_identity.as_deref(),
+                gossip_ip,
+                &old_node_ips,
+                &vote_payers,
+            ) {
+                DemotionCheck::Demoted => {
+                    verified = true;
+                    reverted_checks = 0;
+                }
+                DemotionCheck::Reverted(reason) => {
+                    reverted_checks += 1;
+                    if reverted_checks >= REVERTED_CHECKS_TO_ALERT {
+                        self.alert(&format!("{} - both nodes may be voting", reason)).await;
+                        return DemotionCheck::Reverted(reason);
+                    }
+                    last_issue = reason;
+                }
+                DemotionCheck::Inconclusive(reason) => {
+                    reverted_checks = 0;
+                    last_issue = reason;
+                }
+            }
+        }
+
+        if verified {
+            DemotionCheck::Demoted
+        } else {
+            self.alert(&format!("demotion could not be verified: {}", last_issue))
+                .await;
+            DemotionCheck::Inconclusive(last_issue)
+        }
+    }
+
+    async fn alert(&self, reason: &str) {
+        if let Some(alert_manager) = &self.alert_manager {
+            let _ = alert_manager
+                .send_demotion_alert(
+                    &self.validator_pair.identity_pubkey,
+                    &self.old_active.label,
+                    reason,
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FUNDED: &str = "FundedIdentity111";
+
+    #[test]
+    fn test_evaluate_demotion_demoted() {
+        let old_ips = vec!["10.0.0.1".parse().unwrap()];
+        let result = evaluate_demotion(
+            FUNDED,
+            Some("UnfundedIdentity111"),
+            Some("10.0.0.2".parse().unwrap()),
+            &old_ips,
+            &[FUNDED.to_string()],
+        );
+        assert_eq!(result, DemotionCheck::Demoted);
+    }
+
+    #[test]
+    fn test_evaluate_demotion_reverted() {
+        let old_ips = vec!["10.0.0.1".parse().unwrap()];
+
+        let local = evaluate_demotion(FUNDED, Some(FUNDED), None, &old_ips, &[]);
+        assert!(matches!(local, DemotionCheck::Reverted(_)));
+
+        let gossip = evaluate_demotion(
+            FUNDED,
+            Some("UnfundedIdentity111"),
+            Some("10.0.0.1".parse().unwrap()),
+            &old_ips,
+            &[],
+        );
+        assert!(matches!(gossip, DemotionCheck::Reverted(_)));
+
+        let votes = evaluate_demotion(
+            FUNDED,
+            Some("UnfundedIdentity111"),
+            None,
+            &old_ips,
+            &[FUNDED.to_string(), "UnfundedIdentity111".to_string()],
+        );
+        assert!(matches!(votes, DemotionCheck::Reverted(_)));
+    }
+
+    #[test]
+    fn test_evaluate_demotion_inconclusive() {
+        let result = evaluate_demotion(FUNDED, None, None, &[], &[]);
+        assert!(matches!(result, DemotionCheck::Inconclusive(_)));
+    }
+}
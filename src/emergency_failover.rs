@@ -1,12 +1,10 @@
 use anyhow::{anyhow, Result};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::time::timeout;
-
 use crate::alert::AlertManager;
-use crate::commands::switch::SwitchManager;
+use crate::commands::switch::{with_step_timeout, SwitchManager};
 use crate::ssh::AsyncSshPool;
-use crate::types::{NodeWithStatus, ValidatorPair};
+use crate::types::{NodeWithStatus, StatsdConfig, SwitchConfig, ValidatorPair};
 
 pub struct EmergencyFailover {
     active_node: NodeWithStatus,
@@ -15,6 +13,8 @@ pub struct EmergencyFailover {
     ssh_pool: Arc<AsyncSshPool>,
     detected_ssh_keys: std::collections::HashMap<String, String>,
     alert_manager: AlertManager,
+    switch_config: SwitchConfig,
+    statsd_config: Option<StatsdConfig>,
     // Track results
     primary_switch_success: bool,
     tower_copy_success: bool,
@@ -23,6 +23,7 @@ pub struct EmergencyFailover {
 }
 
 impl EmergencyFailover {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         active_node: NodeWithStatus,
         standby_node: NodeWithStatus,
@@ -30,6 +31,8 @@ impl EmergencyFailover {
         ssh_pool: Arc<AsyncSshPool>,
         detected_ssh_keys: std::collections::HashMap<String, String>,
         alert_manager: AlertManager,
+        switch_config: SwitchConfig,
+        statsd_config: Option<StatsdConfig>,
     ) -> Self {
         Self {
             active_node,
@@ -38,6 +41,8 @@ impl EmergencyFailover {
             ssh_pool,
             detected_ssh_keys,
             alert_manager,
+            switch_config,
+            statsd_config,
             primary_switch_success: false,
             tower_copy_success: false,
             standby_switch_success: false,
@@ -54,6 +59,30 @@ impl EmergencyFailover {
             self.active_node.node.label,
             self.standby_node.node.label
         );
+        crate::audit::record(
+            "auto-failover",
+            "emergency_takeover_started",
+            &self.validator_pair.identity_pubkey,
+            &format!("{} → {}", self.active_node.node.label, self.standby_node.node.label),
+        );
+
+        // Mirror each step to the alert channels while the takeover runs
+        let (progress, relay) = match self
+            .alert_manager
+            .relay_switch_progress(&self.validator_pair.identity_pubkey)
+        {
+            Some((sender, relay)) => (Some(sender), Some(relay)),
+            None => (None, None),
+        };
+        let report = |message: String| {
+            if let Some(progress) = &progress {
+                let _ = progress.send(message);
+            }
+        };
+        report(format!(
+            "🚨 Emergency takeover started: {} → {}",
+            self.active_node.node.label, self.standby_node.node.label
+        ));
 
         // Create switch manager for the operations
         let mut switch_manager = SwitchManager::new(
@@ -63,61 +92,74 @@ impl EmergencyFailover {
             self.ssh_pool.clone(),
             self.detected_ssh_keys.clone(),
         );
+        switch_manager.switch_config = self.switch_config.clone();
+        switch_manager.silent = true;
 
         // Step 1: Try to switch primary to unfunded (optional, best-effort)
         eprintln!("📤 Switching primary to unfunded...");
-        std::env::set_var("SVS_SILENT_MODE", "1");
-        
-        let primary_result = match timeout(
-            Duration::from_secs(10), // Default 10 second timeout
-            switch_manager.switch_primary_to_unfunded(false)
-        ).await {
-            Ok(Ok(_)) => {
+
+        let primary_result = match with_step_timeout(
+            "Set-identity on active node",
+            self.switch_config.active_identity_timeout_seconds,
+            switch_manager.switch_primary_to_unfunded(false),
+        )
+        .await
+        {
+            Ok(_) => {
                 eprintln!("   ✅ Primary switched to unfunded successfully");
+                report("✅ Primary switched to unfunded".to_string());
                 Ok(())
             }
-            Ok(Err(e)) => {
+            Err(e) => {
                 eprintln!("   ⚠️  Failed to switch primary: {}", e);
+                report(format!("⚠️ Failed to switch primary: {}", e));
                 Err(e)
             }
-            Err(_) => {
-                eprintln!("   ⚠️  Switch primary timed out");
-                Err(anyhow!("Operation timed out"))
-            }
         };
         self.primary_switch_success = primary_result.is_ok();
 
         // Step 2: Try to copy tower file (optional, best-effort)
         eprintln!("📤 Copying tower file...");
-        let tower_result = match timeout(
-            Duration::from_secs(10), // Default 10 second timeout
-            switch_manager.transfer_tower_file(false)
-        ).await {
-            Ok(Ok(_)) => {
+        let tower_result = match with_step_timeout(
+            "Tower transfer",
+            self.switch_config.tower_transfer_timeout_seconds,
+            switch_manager.transfer_tower_file(false),
+        )
+        .await
+        {
+            Ok(_) => {
                 eprintln!("   ✅ Tower file copied successfully");
+                report("✅ Tower file copied".to_string());
                 Ok(())
             }
-            Ok(Err(e)) => {
+            Err(e) => {
                 eprintln!("   ⚠️  Failed to copy tower: {}", e);
+                report(format!("⚠️ Failed to copy tower: {}", e));
                 Err(e)
             }
-            Err(_) => {
-                eprintln!("   ⚠️  Tower copy timed out");
-                Err(anyhow!("Operation timed out"))
-            }
         };
         self.tower_copy_success = tower_result.is_ok();
 
         // Step 3: Switch standby to funded (REQUIRED - must succeed)
         eprintln!("🚀 Switching standby to funded identity...");
-        match switch_manager.switch_backup_to_funded(false).await {
+        match with_step_timeout(
+            "Set-identity on standby node",
+            self.switch_config.standby_identity_timeout_seconds,
+            switch_manager.switch_backup_to_funded(false),
+        )
+        .await
+        {
             Ok(_) => {
                 self.standby_switch_success = true;
                 eprintln!("   ✅ Standby switched to funded identity successfully");
+                report("✅ Standby switched to funded identity".to_string());
             }
             Err(e) => {
                 eprintln!("   ❌ CRITICAL: Failed to switch standby to funded: {}", e);
+                report(format!("❌ Failed to switch standby to funded: {}", e));
                 self.total_time = Some(start_time.elapsed());
+                finish_progress(progress, relay).await;
+                self.record_history(Some(format!("Failed to activate standby: {}", e)));
                 
                 // Send failure notification
                 let _ = self.alert_manager.send_emergency_takeover_alert(
@@ -136,6 +178,8 @@ impl EmergencyFailover {
         }
 
         self.total_time = Some(start_time.elapsed());
+        finish_progress(progress, relay).await;
+        self.record_history(None);
 
         // Send success notification
         let _ = self.alert_manager.send_emergency_takeover_alert(
@@ -157,5 +201,59 @@ impl EmergencyFailover {
         Ok(())
     }
 
+    /// Keep the takeover in the history database, failed ones included
+    fn record_history(&self, error: Option<String>) {
+        let route = format!(
+            "{}: {} → {}",
+            self.validator_pair.identity_pubkey, self.active_node.node.label, self.standby_node.node.label
+        );
+        crate::audit::record(
+            "auto-failover",
+            if error.is_none() { "emergency_takeover_completed" } else { "emergency_takeover_failed" },
+            &self.validator_pair.identity_pubkey,
+            error.as_deref().unwrap_or(""),
+        );
+        match &error {
+            None => crate::syslog::event(
+                crate::syslog::Severity::Critical,
+                "takeover",
+                &format!("Emergency takeover completed {}", route),
+            ),
+            Some(e) => crate::syslog::event(
+                crate::syslog::Severity::Critical,
+                "takeover",
+                &format!("Emergency takeover failed {}: {}", route, e),
+            ),
+        }
+        if let Some(duration) = self.total_time {
+            crate::statsd::emit_switch(
+                self.statsd_config.as_ref(),
+                &self.validator_pair.identity_pubkey,
+                duration,
+                true,
+                error.is_none(),
+            );
+        }
+        crate::history::record_switch(crate::history::SwitchRecord {
+            timestamp: chrono::Utc::now().timestamp(),
+            identity: self.validator_pair.identity_pubkey.clone(),
+            from_node: self.active_node.node.label.clone(),
+            to_node: self.standby_node.node.label.clone(),
+            emergency: true,
+            success: error.is_none(),
+            duration_ms: self.total_time.map(|time| time.as_millis() as u64),
+            error,
+        });
+    }
 }
 
+/// Close the progress channel and wait for the queued steps to be delivered
+async fn finish_progress(
+    progress: Option<tokio::sync::mpsc::UnboundedSender<String>>,
+    relay: Option<tokio::task::JoinHandle<()>>,
+) {
+    drop(progress);
+    if let Some(relay) = relay {
+        let _ = relay.await;
+    }
+}
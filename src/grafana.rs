@@ -0,0 +1,158 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::types::GrafanaConfig;
+
+/// UID of the generated dashboard, stable so re-imports overwrite it
+pub const DASHBOARD_UID: &str = "svs-overview";
+
+/// Every annotation pushed by svs carries this tag so it can be overlaid on
+/// any dashboard with a tag-filtered annotation query
+pub const ANNOTATION_TAG: &str = "svs";
+
+/// Build the Grafana dashboard for the metrics svs exports. Panels are
+/// generated from the exporter's metric list so the two never drift apart.
+pub fn dashboard_json() -> Value {
+    let panels: Vec<Value> = crate::ssh::pool_metric_names()
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, help))| {
+            json!({
+                "id": idx + 1,
+                "type": "timeseries",
+                "title": help,
+                "datasource": { "type": "prometheus", "uid": "${datasource}" },
+                "gridPos": { "h": 8, "w": 12, "x": (idx % 2) * 12, "y": (idx / 2) * 8 },
+                "targets": [{
+                    "refId": "A",
+                    "expr": format!("{}{{host=~\"$host\"}}", name),
+                    "legendFormat": "{{host}}"
+                }],
+                "fieldConfig": {
+                    "defaults": {
+                        "unit": if name.ends_with("_seconds") { "s" } else { "short" }
+                    },
+                    "overrides": []
+                },
+                "options": { "legend": { "displayMode": "list", "placement": "bottom" } }
+            })
+        })
+        .collect();
+
+    json!({
+        "uid": DASHBOARD_UID,
+        "title": "Solana Validator Switch",
+        "tags": [ANNOTATION_TAG],
+        "timezone": "browser",
+        "schemaVersion": 39,
+        "refresh": "30s",
+        "time": { "from": "now-6h", "to": "now" },
+        "annotations": {
+            "list": [{
+                "name": "Switches and failovers",
+                "datasource": { "type": "grafana", "uid": "-- Grafana --" },
+                "enable": true,
+                "iconColor": "orange",
+                "target": {
+                    "type": "tags",
+                    "tags": [ANNOTATION_TAG],
+                    "matchAny": true,
+                    "limit": 100
+                }
+            }]
+        },
+        "templating": {
+            "list": [
+                {
+                    "name": "datasource",
+                    "label": "Data source",
+                    "type": "datasource",
+                    "query": "prometheus"
+                },
+                {
+                    "name": "host",
+                    "label": "Host",
+                    "type": "query",
+                    "datasource": { "type": "prometheus", "uid": "${datasource}" },
+                    "query": "label_values(svs_ssh_open_sessions, host)",
+                    "includeAll": true,
+                    "multi": true,
+                    "current": { "text": "All", "value": "$__all" },
+                    "refresh": 2
+                }
+            ]
+        },
+        "panels": panels
+    })
+}
+
+/// Push an annotation through Grafana's HTTP API. Tagged with `svs` plus
+/// `tags`, and pinned to `dashboard_uid` when one is configured.
+pub async fn push_annotation(config: &GrafanaConfig, text: &str, tags: &[&str]) -> Result<()> {
+    let url = format!("{}/api/annotations", config.url.trim_end_matches('/'));
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+
+    let mut all_tags = vec![ANNOTATION_TAG];
+    all_tags.extend_from_slice(tags);
+
+    let mut payload = json!({
+        "time": time,
+        "tags": all_tags,
+        "text": text
+    });
+    if let Some(uid) = &config.dashboard_uid {
+        payload["dashboardUID"] = json!(uid);
+    }
+
+    let client = crate::proxy::http_client_builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let response = client
+        .post(&url)
+        .bearer_auth(&config.api_token)
+        .json(&payload)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        anyhow::bail!("Grafana API error: {}", error_text);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashboard_covers_every_metric() {
+        let dashboard = dashboard_json();
+        let exprs: Vec<String> = dashboard["panels"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["targets"][0]["expr"].as_str().unwrap().to_string())
+            .collect();
+
+        for (name, _) in crate::ssh::pool_metric_names() {
+            assert!(exprs.iter().any(|e| e.starts_with(name)), "missing panel for {}", name);
+        }
+    }
+
+    #[test]
+    fn test_shipped_dashboard_is_up_to_date() {
+        let shipped: Value =
+            serde_json::from_str(include_str!("../grafana/svs-dashboard.json")).unwrap();
+        assert_eq!(
+            shipped,
+            dashboard_json(),
+            "regenerate with `svs grafana-dashboard -o grafana/svs-dashboard.json`"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::{anyhow, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+use crate::commands::status_ui_v2::UiState;
+use crate::types::HealthEndpointConfig;
+
+/// Healthy only while the monitoring loop keeps completing passes and at
+/// least one validator's RPC poll succeeded within `max_age`
+pub fn evaluate(
+    loop_age: Duration,
+    rpc_age: Option<Duration>,
+    max_age: Duration,
+) -> std::result::Result<String, String> {
+    if loop_age > max_age {
+        return Err(format!(
+            "monitoring loop has not completed a pass for {}s",
+            loop_age.as_secs()
+        ));
+    }
+    match rpc_age {
+        None => Err("no RPC poll has succeeded yet".to_string()),
+        Some(age) if age > max_age => Err(format!(
+            "last successful RPC poll was {}s ago",
+            age.as_secs()
+        )),
+        Some(age) => Ok(format!("ok, last successful RPC poll {}s ago", age.as_secs())),
+    }
+}
+
+/// Full HTTP response for a request line such as `GET /healthz HTTP/1.1`
+pub fn http_response(request_line: &str, verdict: &std::result::Result<String, String>) -> String {
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (status, body) = match (path.split('?').next(), verdict) {
+        (Some("/healthz"), Ok(detail)) => ("200 OK", detail.as_str()),
+        (Some("/healthz"), Err(reason)) => ("503 Service Unavailable", reason.as_str()),
+        _ => ("404 Not Found", "not found"),
+    };
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}\n",
+        status,
+        body.len() + 1,
+        body
+    )
+}
+
+/// Answer liveness probes on `config.listen` until the process exits
+pub async fn serve(config: HealthEndpointConfig, ui_state: Arc<RwLock<UiState>>) -> Result<()> {
+    let listener = TcpListener::bind(&config.listen)
+        .await
+        .map_err(|e| anyhow!("Failed to listen for health checks on {}: {}", config.listen, e))?;
+    let max_age = Duration::from_secs(config.max_age_seconds);
+
+    loop {
+        let mut stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(_) => continue,
+        };
+        let ui_state = Arc::clone(&ui_state);
+
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 1024];
+            let read = match tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buffer)).await {
+                Ok(Ok(read)) => read,
+                _ => return,
+            };
+            let request = String::from_utf8_lossy(&buffer[..read]);
+
+            let verdict = {
+                let state = ui_state.read().await;
+                let rpc_age = state
+                    .rpc_failure_tracker
+                    .iter()
+                    .filter_map(|tracker| tracker.last_success_time)
+                    .max()
+                    .map(|time| time.elapsed());
+                evaluate(state.last_vote_refresh.elapsed(), rpc_age, max_age)
+            };
+
+            let response = http_response(request.lines().next().unwrap_or(""), &verdict);
+            let _ = stream.write_all(response.as_bytes()).await;
+            let _ = stream.shutdown().await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let max_age = Duration::from_secs(60);
+        let fresh = Duration::from_secs(5);
+        let stale = Duration::from_secs(90);
+
+        assert!(evaluate(fresh, Some(fresh), max_age).is_ok());
+        assert!(evaluate(stale, Some(fresh), max_age).unwrap_err().contains("monitoring loop"));
+        assert!(evaluate(fresh, Some(stale), max_age).unwrap_err().contains("90s ago"));
+        assert!(evaluate(fresh, None, max_age).unwrap_err().contains("no RPC poll"));
+    }
+
+    #[test]
+    fn test_http_response() {
+        let healthy = Ok("ok".to_string());
+        let unhealthy = Err("stalled".to_string());
+
+        let response = http_response("GET /healthz HTTP/1.1", &healthy);
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 3\r\n"));
+        assert!(response.ends_with("\r\n\r\nok\n"));
+
+        assert!(http_response("GET /healthz?verbose HTTP/1.1", &unhealthy)
+            .starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(http_response("GET /metrics HTTP/1.1", &healthy).starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(http_response("", &healthy).starts_with("HTTP/1.1 404"));
+    }
+}
@@ -0,0 +1,483 @@
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Rows older than this are dropped when the store is pruned
+pub const RETENTION_DAYS: i64 = 90;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS vote_samples (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    last_vote_slot INTEGER,
+    voting INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS catchup_samples (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    node TEXT NOT NULL,
+    status TEXT NOT NULL,
+    slots_behind INTEGER
+);
+CREATE TABLE IF NOT EXISTS failure_events (
+    kind TEXT NOT NULL,
+    identity TEXT NOT NULL,
+    node TEXT NOT NULL,
+    started_at INTEGER NOT NULL,
+    ended_at INTEGER,
+    error TEXT
+);
+CREATE TABLE IF NOT EXISTS alerts (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    title TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS switches (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    from_node TEXT NOT NULL,
+    to_node TEXT NOT NULL,
+    emergency INTEGER NOT NULL,
+    success INTEGER NOT NULL,
+    duration_ms INTEGER,
+    error TEXT
+);
+CREATE TABLE IF NOT EXISTS skipped_slots (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    UNIQUE (identity, slot)
+);
+CREATE TABLE IF NOT EXISTS ledger_sizes (
+    timestamp INTEGER NOT NULL,
+    identity TEXT NOT NULL,
+    node TEXT NOT NULL,
+    bytes INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS vote_samples_time ON vote_samples (identity, timestamp);
+CREATE INDEX IF NOT EXISTS catchup_samples_time ON catchup_samples (identity, timestamp);
+CREATE INDEX IF NOT EXISTS failure_events_open ON failure_events (kind, identity, node, ended_at);
+";
+
+/// One switch or emergency takeover, successful or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchRecord {
+    pub timestamp: i64, // Unix seconds
+    pub identity: String,
+    pub from_node: String,
+    pub to_node: String,
+    pub emergency: bool,
+    pub success: bool,
+    pub duration_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Embedded SQLite store for monitoring history, kept across restarts
+pub struct HistoryStore {
+    connection: Mutex<Connection>,
+}
+
+fn history_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".solana-validator-switch").join("history.db"))
+}
+
+/// Shared store at `~/.solana-validator-switch/history.db`, opened on first
+/// use. None when it can't be opened; history is then simply not kept.
+pub fn store() -> Option<&'static HistoryStore> {
+    static STORE: OnceLock<Option<HistoryStore>> = OnceLock::new();
+    STORE
+        .get_or_init(|| {
+            let path = history_path()?;
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).ok()?;
+            }
+            HistoryStore::open(&path).ok()
+        })
+        .as_ref()
+}
+
+impl HistoryStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::with_connection(
+            Connection::open(path)
+                .map_err(|e| anyhow!("Failed to open history database {}: {}", path.display(), e))?,
+        )
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self> {
+        // Several tasks write briefly; wait for each other instead of failing
+        connection.busy_timeout(std::time::Duration::from_secs(5))?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connection(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.connection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn record_vote_sample(
+        &self,
+        timestamp: i64,
+        identity: &str,
+        last_vote_slot: Option<u64>,
+        voting: bool,
+    ) -> Result<()> {
+        self.connection().execute(
+            "INSERT INTO vote_samples (timestamp, identity, last_vote_slot, voting) VALUES (?1, ?2, ?3, ?4)",
+            params![timestamp, identity, last_vote_slot.map(|slot| slot as i64), voting],
+        )?;
+        Ok(())
+    }
+
+    /// Catchup status as shown in the status UI; the lag is kept when it
+    /// can be read from the status
+    pub fn record_catchup(&self, timestamp: i64, identity: &str, node: &str, status: &str) -> Result<()> {
+        let slots_behind = if status == "Caught up" {
+            Some(0)
+        } else {
+            crate::alert::slots_behind(status)
+        };
+        self.connection().execute(
+            "INSERT INTO catchup_samples (timestamp, identity, node, status, slots_behind) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![timestamp, identity, node, status, slots_behind.map(|slots| slots as i64)],
+        )?;
+        Ok(())
+    }
+
+    /// Open a failure streak (`kind` is `ssh` or `rpc`) unless one is
+    /// already open. `node` is empty for validator-wide checks.
+    pub fn failure_started(
+        &self,
+        timestamp: i64,
+        kind: &str,
+        identity: &str,
+        node: &str,
+        error: &str,
+    ) -> Result<()> {
+        if self.open_failure_since(kind, identity, node)?.is_none() {
+            self.connection().execute(
+                "INSERT INTO failure_events (kind, identity, node, started_at, error) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![kind, identity, node, timestamp, error],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Close the open failure streak, if any
+    pub fn failure_ended(&self, timestamp: i64, kind: &str, identity: &str, node: &str) -> Result<()> {
+        self.connection().execute(
+            "UPDATE failure_events SET ended_at = ?1 WHERE kind = ?2 AND identity = ?3 AND node = ?4 AND ended_at IS NULL",
+            params![timestamp, kind, identity, node],
+        )?;
+        Ok(())
+    }
+
+    /// Start of the failure streak still open, so a restarted monitor keeps
+    /// counting the outage from when it began
+    pub fn open_failure_since(&self, kind: &str, identity: &str, node: &str) -> Result<Option<i64>> {
+        Ok(self
+            .connection()
+            .query_row(
+                "SELECT MIN(started_at) FROM failure_events WHERE kind = ?1 AND identity = ?2 AND node = ?3 AND ended_at IS NULL",
+                params![kind, identity, node],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .optional()?
+            .flatten())
+    }
+
+    pub fn record_alert(&self, timestamp: i64, identity: &str, title: &str) -> Result<()> {
+        self.connection().execute(
+            "INSERT INTO alerts (timestamp, identity, title) VALUES (?1, ?2, ?3)",
+            params![timestamp, identity, title],
+        )?;
+        Ok(())
+    }
+
+    /// A leader slot the validator produced no block in; recording the same
+    /// slot again is a no-op
+    pub fn record_skipped_slot(&self, timestamp: i64, identity: &str, slot: u64) -> Result<()> {
+        self.connection().execute(
+            "INSERT OR IGNORE INTO skipped_slots (timestamp, identity, slot) VALUES (?1, ?2, ?3)",
+            params![timestamp, identity, slot as i64],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_ledger_size(&self, timestamp: i64, identity: &str, node: &str, bytes: u64) -> Result<()> {
+        self.connection().execute(
+            "INSERT INTO ledger_sizes (timestamp, identity, node, bytes) VALUES (?1, ?2, ?3, ?4)",
+            params![timestamp, identity, node, bytes as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Ledger size samples of a node as (timestamp, bytes), oldest first
+    pub fn ledger_sizes_since(&self, identity: &str, node: &str, since: i64) -> Result<Vec<(i64, u64)>> {
+        let connection = self.connection();
+        let mut statement = connection.prepare(
+            "SELECT timestamp, bytes FROM ledger_sizes
+             WHERE identity = ?1 AND node = ?2 AND timestamp >= ?3 ORDER BY timestamp",
+        )?;
+        let samples = statement
+            .query_map(params![identity, node, since], |row| {
+                Ok((row.get(0)?, row.get::<_, i64>(1)? as u64))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(samples)
+    }
+
+    pub fn record_switch(&self, record: &SwitchRecord) -> Result<()> {
+        self.connection().execute(
+            "INSERT INTO switches (timestamp, identity, from_node, to_node, emergency, success, duration_ms, error)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                record.timestamp,
+                record.identity,
+                record.from_node,
+                record.to_node,
+                record.emergency,
+                record.success,
+                record.duration_ms.map(|ms| ms as i64),
+                record.error
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Switches for `identity`, newest first
+    #[cfg(test)]
+    pub fn recent_switches(&self, identity: &str, limit: usize) -> Result<Vec<SwitchRecord>> {
+        let connection = self.connection();
+        let mut statement = connection.prepare(
+            "SELECT timestamp, identity, from_node, to_node, emergency, success, duration_ms, error
+             FROM switches WHERE identity = ?1 ORDER BY timestamp DESC LIMIT ?2",
+        )?;
+        let records = statement
+            .query_map(params![identity, limit as i64], switch_from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(records)
+    }
+
+    /// Newest successful switch or takeover for `identity`
+    pub fn last_successful_switch(&self, identity: &str) -> Result<Option<SwitchRecord>> {
+        let record = self
+            .connection()
+            .query_row(
+                "SELECT timestamp, identity, from_node, to_node, emergency, success, duration_ms, error
+                 FROM switches WHERE identity = ?1 AND success ORDER BY timestamp DESC, rowid DESC LIMIT 1",
+                params![identity],
+                switch_from_row,
+            )
+            .optional()?;
+        Ok(record)
+    }
+
+    /// Drop samples, alerts and closed failure streaks before `cutoff`.
+    /// Switches are kept.
+    pub fn prune(&self, cutoff: i64) -> Result<()> {
+        self.connection().execute_batch(&format!(
+            "DELETE FROM vote_samples WHERE timestamp < {cutoff};
+             DELETE FROM catchup_samples WHERE timestamp < {cutoff};
+             DELETE FROM alerts WHERE timestamp < {cutoff};
+             DELETE FROM skipped_slots WHERE timestamp < {cutoff};
+             DELETE FROM ledger_sizes WHERE timestamp < {cutoff};
+             DELETE FROM failure_events WHERE ended_at < {cutoff};"
+        ))?;
+        Ok(())
+    }
+}
+
+fn switch_from_row(row: &rusqlite::Row) -> rusqlite::Result<SwitchRecord> {
+    Ok(SwitchRecord {
+        timestamp: row.get(0)?,
+        identity: row.get(1)?,
+        from_node: row.get(2)?,
+        to_node: row.get(3)?,
+        emergency: row.get(4)?,
+        success: row.get(5)?,
+        duration_ms: row.get::<_, Option<i64>>(6)?.map(|ms| ms as u64),
+        error: row.get(7)?,
+    })
+}
+
+/// Record a switch in the shared store, ignoring a store that can't be used
+pub fn record_switch(mut record: SwitchRecord) {
+    record.error = record.error.map(|error| crate::redact::redact_secrets(&error));
+    if let Some(store) = store() {
+        let _ = store.record_switch(&record);
+    }
+}
+
+/// Newest successful switch or takeover of `identity`, None without a store
+pub fn last_successful_switch(identity: &str) -> Option<SwitchRecord> {
+    store().and_then(|store| store.last_successful_switch(identity).ok().flatten())
+}
+
+/// Record a sent alert in the shared store
+pub fn record_alert(title: &str, identity: &str) {
+    if let Some(store) = store() {
+        let _ = store.record_alert(chrono::Utc::now().timestamp(), identity, title);
+    }
+}
+
+/// Record a skipped leader slot in the shared store
+pub fn record_skipped_slot(identity: &str, slot: u64) {
+    if let Some(store) = store() {
+        let _ = store.record_skipped_slot(chrono::Utc::now().timestamp(), identity, slot);
+    }
+}
+
+/// Record a node's ledger size in the shared store
+pub fn record_ledger_size(identity: &str, node: &str, bytes: u64) {
+    if let Some(store) = store() {
+        let _ = store.record_ledger_size(chrono::Utc::now().timestamp(), identity, node, bytes);
+    }
+}
+
+/// Ledger size samples of a node since `since`, empty without a store
+pub fn ledger_sizes_since(identity: &str, node: &str, since: i64) -> Vec<(i64, u64)> {
+    store()
+        .and_then(|store| store.ledger_sizes_since(identity, node, since).ok())
+        .unwrap_or_default()
+}
+
+/// Record that a check started or stopped failing in the shared store
+pub fn record_failure(kind: &str, identity: &str, node: &str, error: Option<&str>) {
+    if let Some(store) = store() {
+        let now = chrono::Utc::now().timestamp();
+        let _ = match error {
+            Some(error) => store.failure_started(now, kind, identity, node, &crate::redact::redact_secrets(error)),
+            None => store.failure_ended(now, kind, identity, node),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_streaks() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        assert_eq!(store.open_failure_since("rpc", "Id111", "").unwrap(), None);
+
+        store.failure_started(100, "rpc", "Id111", "", "timeout").unwrap();
+        // Later failures in the same streak keep the original start
+        store.failure_started(105, "rpc", "Id111", "", "timeout").unwrap();
+        assert_eq!(store.open_failure_since("rpc", "Id111", "").unwrap(), Some(100));
+        assert_eq!(store.open_failure_since("ssh", "Id111", "node-a").unwrap(), None);
+
+        store.failure_ended(130, "rpc", "Id111", "").unwrap();
+        assert_eq!(store.open_failure_since("rpc", "Id111", "").unwrap(), None);
+
+        store.failure_started(200, "rpc", "Id111", "", "refused").unwrap();
+        assert_eq!(store.open_failure_since("rpc", "Id111", "").unwrap(), Some(200));
+
+        // Closed streaks past the cutoff are pruned, open ones are kept
+        store.prune(150).unwrap();
+        let remaining: i64 = store
+            .connection()
+            .query_row("SELECT COUNT(*) FROM failure_events", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 1);
+    }
+
+    #[test]
+    fn test_switch_records() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        let record = SwitchRecord {
+            timestamp: 100,
+            identity: "Id111".to_string(),
+            from_node: "node-a".to_string(),
+            to_node: "node-b".to_string(),
+            emergency: false,
+            success: true,
+            duration_ms: Some(850),
+            error: None,
+        };
+        store.record_switch(&record).unwrap();
+        store
+            .record_switch(&SwitchRecord {
+                timestamp: 200,
+                emergency: true,
+                success: false,
+                duration_ms: None,
+                error: Some("standby unreachable".to_string()),
+                ..record.clone()
+            })
+            .unwrap();
+
+        let switches = store.recent_switches("Id111", 10).unwrap();
+        assert_eq!(switches.len(), 2);
+        assert_eq!(switches[0].error.as_deref(), Some("standby unreachable"));
+        assert_eq!(switches[1], record);
+
+        // The failed attempt after it doesn't hide the last real switch
+        assert_eq!(store.last_successful_switch("Id111").unwrap(), Some(record));
+        assert_eq!(store.last_successful_switch("Id222").unwrap(), None);
+    }
+
+    #[test]
+    fn test_skipped_slots() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        store.record_skipped_slot(100, "Id111", 302_401_000).unwrap();
+        // Seen again on the next check
+        store.record_skipped_slot(220, "Id111", 302_401_000).unwrap();
+        store.record_skipped_slot(220, "Id111", 302_401_001).unwrap();
+
+        let count = |store: &HistoryStore| -> i64 {
+            store
+                .connection()
+                .query_row("SELECT COUNT(*) FROM skipped_slots", [], |row| row.get(0))
+                .unwrap()
+        };
+        assert_eq!(count(&store), 2);
+        store.prune(150).unwrap();
+        assert_eq!(count(&store), 1);
+    }
+
+    #[test]
+    fn test_ledger_sizes() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        store.record_ledger_size(100, "Id111", "node-a", 1_000).unwrap();
+        store.record_ledger_size(200, "Id111", "node-a", 1_500).unwrap();
+        store.record_ledger_size(200, "Id111", "node-b", 9_000).unwrap();
+
+        assert_eq!(
+            store.ledger_sizes_since("Id111", "node-a", 0).unwrap(),
+            vec![(100, 1_000), (200, 1_500)]
+        );
+        assert_eq!(store.ledger_sizes_since("Id111", "node-a", 150).unwrap(), vec![(200, 1_500)]);
+        store.prune(150).unwrap();
+        assert_eq!(store.ledger_sizes_since("Id111", "node-a", 0).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_catchup_lag() {
+        let store = HistoryStore::open_in_memory().unwrap();
+        store.record_catchup(100, "Id111", "node-a", "Caught up").unwrap();
+        store.record_catchup(100, "Id111", "node-b", "42 slots behind").unwrap();
+        store.record_catchup(100, "Id111", "node-b", "RPC Error").unwrap();
+
+        let lags: Vec<Option<i64>> = {
+            let connection = store.connection();
+            let mut statement = connection
+                .prepare("SELECT slots_behind FROM catchup_samples ORDER BY rowid")
+                .unwrap();
+            let rows = statement.query_map([], |row| row.get(0)).unwrap();
+            rows.map(|row| row.unwrap()).collect()
+        };
+        assert_eq!(lags, vec![Some(0), Some(42), None]);
+    }
+}
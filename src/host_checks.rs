@@ -0,0 +1,1146 @@
+use anyhow::{anyhow, Result};
+
+use crate::commands::switch::shell_quote;
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeConfig, NodePaths, ValidatorType};
+
+/// OS patch level and reboot-required state of a node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OsPatchStatus {
+    pub running_kernel: String,
+    pub latest_kernel: Option<String>, // Newest kernel installed in /boot
+    pub reboot_required: bool,         // Distro reboot-required marker is present
+    pub reboot_packages: Vec<String>,  // Packages that requested the reboot
+    pub pending_updates: Option<u32>,  // Only available where apt-check exists
+    pub security_updates: Option<u32>,
+}
+
+impl OsPatchStatus {
+    /// A newer kernel is installed than the one running
+    pub fn kernel_outdated(&self) -> bool {
+        match &self.latest_kernel {
+            Some(latest) => !latest.is_empty() && latest != &self.running_kernel,
+            None => false,
+        }
+    }
+
+    pub fn needs_reboot(&self) -> bool {
+        self.reboot_required || self.kernel_outdated()
+    }
+}
+
+/// Single batched command so the check costs one SSH round trip. Covers the
+/// Debian/Ubuntu marker files, RHEL's needs-restarting and a kernel comparison
+/// that works on any distro.
+const OS_PATCH_COMMAND: &str = "echo \"kernel=$(uname -r)\"; \
+    [ -f /var/run/reboot-required ] && echo reboot_required=1; \
+    [ -f /var/run/reboot-required.pkgs ] && sed 's/^/reboot_pkg=/' /var/run/reboot-required.pkgs; \
+    if command -v needs-restarting >/dev/null 2>&1; then needs-restarting -r >/dev/null 2>&1 || echo reboot_required=1; fi; \
+    echo \"latest_kernel=$(ls -1 /boot/vmlinuz-* 2>/dev/null | sed 's|/boot/vmlinuz-||' | sort -V | tail -n1)\"; \
+    if [ -x /usr/lib/update-notifier/apt-check ]; then echo \"updates=$(/usr/lib/update-notifier/apt-check 2>&1)\"; fi; \
+    true";
+
+pub async fn check_os_patch_status(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<OsPatchStatus> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, OS_PATCH_COMMAND)
+        .await?;
+    Ok(parse_os_patch_status(&output))
+}
+
+pub fn parse_os_patch_status(output: &str) -> OsPatchStatus {
+    let mut status = OsPatchStatus::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(kernel) = line.strip_prefix("kernel=") {
+            status.running_kernel = kernel.to_string();
+        } else if line == "reboot_required=1" {
+            status.reboot_required = true;
+        } else if let Some(pkg) = line.strip_prefix("reboot_pkg=") {
+            if !pkg.is_empty() && !status.reboot_packages.iter().any(|p| p == pkg) {
+                status.reboot_packages.push(pkg.to_string());
+            }
+        } else if let Some(kernel) = line.strip_prefix("latest_kernel=") {
+            if !kernel.is_empty() {
+                status.latest_kernel = Some(kernel.to_string());
+            }
+        } else if let Some(updates) = line.strip_prefix("updates=") {
+            // apt-check prints "<pending>;<security>"
+            let mut parts = updates.split(';');
+            status.pending_updates = parts.next().and_then(|p| p.trim().parse().ok());
+            status.security_updates = parts.next().and_then(|p| p.trim().parse().ok());
+        }
+    }
+
+    status
+}
+
+/// Usage of the filesystem holding a validator directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskUsage {
+    pub mount: String,
+    pub used_percent: u8,
+    pub free_bytes: u64,
+}
+
+impl DiskUsage {
+    pub fn free_percent(&self) -> u8 {
+        100 - self.used_percent.min(100)
+    }
+}
+
+/// Tower file freshness, snapshot ages and ledger/accounts disk usage of a node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageStatus {
+    pub tower_age_seconds: Option<u64>, // Seconds since the tower file was last written
+    pub ledger_disk: Option<DiskUsage>,
+    pub accounts_disk: Option<DiskUsage>,
+    pub full_snapshot_age_seconds: Option<u64>,
+    pub incremental_snapshot_age_seconds: Option<u64>,
+}
+
+impl StorageStatus {
+    /// Age of the newest snapshot, full or incremental - what a restart
+    /// would boot from
+    pub fn snapshot_age_seconds(&self) -> Option<u64> {
+        [self.full_snapshot_age_seconds, self.incremental_snapshot_age_seconds]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+
+    /// Each distinct filesystem as ("ledger" or "accounts", usage); accounts
+    /// on the ledger's filesystem are not listed twice
+    pub fn disks(&self) -> Vec<(&'static str, &DiskUsage)> {
+        let mut disks = Vec::new();
+        if let Some(ledger) = &self.ledger_disk {
+            disks.push(("ledger", ledger));
+        }
+        if let Some(accounts) = &self.accounts_disk {
+            if self.ledger_disk.as_ref().is_none_or(|ledger| ledger.mount != accounts.mount) {
+                disks.push(("accounts", accounts));
+            }
+        }
+        disks
+    }
+}
+
+/// Prints `<key>=<mount> <used %> <free KB>` for the filesystem holding `$path`
+fn df_command(key: &str, path: &str) -> String {
+    format!(
+        " df -Pk {} 2>/dev/null | awk 'NR==2 {{sub(\"%\", \"\", $5); print \"{}=\" $6 \" \" $5 \" \" $4}}';",
+        path, key
+    )
+}
+
+/// Sets `$<var>` to the first path `flag` gives the running validator,
+/// or `fallback` when it isn't given or doesn't exist
+fn validator_path_detection(var: &str, flag: &str, fallback: &str) -> String {
+    format!(
+        " {var}=$(ps -eo args | grep -E 'agave-validator|solana-validator' | grep -v grep | grep -o -- '{flag} [^ ]*' | head -1 | awk '{{print $2}}');\
+        {var}=${{{var}%%,*}}; [ -e \"${var}\" ] || {var}={fallback};"
+    )
+}
+
+/// Prints `<key>=<mtime>` for the newest file matching `pattern` in `$dir`
+/// or the `remote` directory downloaded snapshots are kept in
+fn newest_mtime_command(key: &str, dir: &str, pattern: &str) -> String {
+    format!(
+        " echo \"{key}=$(ls -t \"${dir}\"/{pattern} \"${dir}\"/remote/{pattern} 2>/dev/null | head -1 | xargs -r stat -c %Y)\";"
+    )
+}
+
+fn storage_command(tower_path: Option<&str>, ledger_path: Option<&str>) -> String {
+    let mut command = "echo \"now=$(date +%s)\";".to_string();
+    if let Some(tower) = tower_path {
+        command.push_str(&format!(
+            " echo \"tower_mtime=$(stat -c %Y {} 2>/dev/null)\";",
+            shell_quote(tower)
+        ));
+    }
+    if let Some(ledger) = ledger_path {
+        command.push_str(&df_command("ledger_disk", &shell_quote(ledger)));
+        // Accounts default to <ledger>/accounts, usually a separate mount
+        let default_accounts = format!("{}/accounts", ledger.trim_end_matches('/'));
+        command.push_str(&validator_path_detection("accounts", "--accounts", &shell_quote(&default_accounts)));
+        command.push_str(&df_command("accounts_disk", "\"$accounts\""));
+
+        // Snapshot archives default to the ledger directory
+        command.push_str(&validator_path_detection("snapshots", "--snapshots", &shell_quote(ledger)));
+        command.push_str(&validator_path_detection(
+            "full_snapshots",
+            "--full-snapshot-archive-path",
+            "\"$snapshots\"",
+        ));
+        command.push_str(&validator_path_detection(
+            "incremental_snapshots",
+            "--incremental-snapshot-archive-path",
+            "\"$snapshots\"",
+        ));
+        command.push_str(&newest_mtime_command("full_snapshot_mtime", "full_snapshots", "snapshot-*.tar*"));
+        command.push_str(&newest_mtime_command(
+            "incremental_snapshot_mtime",
+            "incremental_snapshots",
+            "incremental-snapshot-*.tar*",
+        ));
+    }
+    command.push_str(" true");
+    command
+}
+
+/// `<mount> <used %> <free KB>`
+fn parse_disk_usage(value: &str) -> Option<DiskUsage> {
+    let mut parts = value.trim().rsplitn(3, ' ');
+    let free_kb: u64 = parts.next()?.parse().ok()?;
+    let used_percent: u8 = parts.next()?.parse().ok()?;
+    let mount = parts.next()?.to_string();
+    Some(DiskUsage {
+        mount,
+        used_percent,
+        free_bytes: free_kb * 1024,
+    })
+}
+
+pub async fn check_storage_status(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    tower_path: Option<&str>,
+    ledger_path: Option<&str>,
+) -> Result<StorageStatus> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, &storage_command(tower_path, ledger_path))
+        .await?;
+    Ok(parse_storage_status(&output))
+}
+
+pub fn parse_storage_status(output: &str) -> StorageStatus {
+    let mut now = None;
+    let mut tower_mtime = None;
+    let mut full_snapshot_mtime = None;
+    let mut incremental_snapshot_mtime = None;
+    let mut status = StorageStatus::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("now=") {
+            now = value.parse::<u64>().ok();
+        } else if let Some(value) = line.strip_prefix("tower_mtime=") {
+            tower_mtime = value.parse::<u64>().ok();
+        } else if let Some(value) = line.strip_prefix("ledger_disk=") {
+            status.ledger_disk = parse_disk_usage(value);
+        } else if let Some(value) = line.strip_prefix("accounts_disk=") {
+            status.accounts_disk = parse_disk_usage(value);
+        } else if let Some(value) = line.strip_prefix("full_snapshot_mtime=") {
+            full_snapshot_mtime = value.parse::<u64>().ok();
+        } else if let Some(value) = line.strip_prefix("incremental_snapshot_mtime=") {
+            incremental_snapshot_mtime = value.parse::<u64>().ok();
+        }
+    }
+
+    if let Some(now) = now {
+        let age = |mtime: Option<u64>| mtime.map(|mtime| now.saturating_sub(mtime));
+        status.tower_age_seconds = age(tower_mtime);
+        status.full_snapshot_age_seconds = age(full_snapshot_mtime);
+        status.incremental_snapshot_age_seconds = age(incremental_snapshot_mtime);
+    }
+
+    status
+}
+
+/// CPU, memory and swap usage of a node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemMetrics {
+    pub cpu_percent: Option<f64>, // Busy share of all cores over a one second sample
+    pub cpu_count: Option<u32>,
+    pub load_average: Option<f64>, // 1 minute
+    pub memory_total_bytes: u64,
+    pub memory_available_bytes: u64,
+    pub swap_total_bytes: u64,
+    pub swap_free_bytes: u64,
+}
+
+impl SystemMetrics {
+    pub fn memory_used_percent(&self) -> Option<f64> {
+        used_percent(self.memory_total_bytes, self.memory_available_bytes)
+    }
+
+    /// None when the node has no swap
+    pub fn swap_used_percent(&self) -> Option<f64> {
+        used_percent(self.swap_total_bytes, self.swap_free_bytes)
+    }
+}
+
+fn used_percent(total: u64, free: u64) -> Option<f64> {
+    (total > 0).then(|| total.saturating_sub(free) as f64 * 100.0 / total as f64)
+}
+
+/// Everything comes from /proc. The aggregate cpu line of /proc/stat is read
+/// twice a second apart, since its counters only mean something as a delta.
+const SYSTEM_METRICS_COMMAND: &str = "echo \"cpus=$(nproc)\"; \
+    echo \"loadavg=$(cat /proc/loadavg)\"; \
+    echo \"cpu=$(head -n1 /proc/stat)\"; sleep 1; echo \"cpu=$(head -n1 /proc/stat)\"; \
+    grep -E '^(MemTotal|MemAvailable|SwapTotal|SwapFree):' /proc/meminfo; \
+    true";
+
+pub async fn check_system_metrics(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<SystemMetrics> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, SYSTEM_METRICS_COMMAND)
+        .await?;
+    Ok(parse_system_metrics(&output))
+}
+
+/// (idle, total) jiffies of a `/proc/stat` cpu line. Guest time is already
+/// counted in user time, so only the first eight counters are summed.
+fn cpu_times(line: &str) -> Option<(u64, u64)> {
+    let counters: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .take(8)
+        .map(|value| value.parse().ok())
+        .collect::<Option<_>>()?;
+    if counters.len() < 5 {
+        return None;
+    }
+    // idle + iowait
+    Some((counters[3] + counters[4], counters.iter().sum()))
+}
+
+pub fn parse_system_metrics(output: &str) -> SystemMetrics {
+    let mut metrics = SystemMetrics::default();
+    let mut cpu_samples = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("cpus=") {
+            metrics.cpu_count = value.parse().ok();
+        } else if let Some(value) = line.strip_prefix("loadavg=") {
+            metrics.load_average = value.split_whitespace().next().and_then(|load| load.parse().ok());
+        } else if let Some(value) = line.strip_prefix("cpu=") {
+            cpu_samples.extend(cpu_times(value));
+        } else if let Some((key, value)) = line.split_once(':') {
+            // /proc/meminfo reports kB
+            let bytes = match value.trim().trim_end_matches("kB").trim().parse::<u64>() {
+                Ok(kb) => kb * 1024,
+                Err(_) => continue,
+            };
+            match key {
+                "MemTotal" => metrics.memory_total_bytes = bytes,
+                "MemAvailable" => metrics.memory_available_bytes = bytes,
+                "SwapTotal" => metrics.swap_total_bytes = bytes,
+                "SwapFree" => metrics.swap_free_bytes = bytes,
+                _ => {}
+            }
+        }
+    }
+
+    if let [(idle_before, total_before), (idle_after, total_after)] = cpu_samples[..] {
+        let total = total_after.saturating_sub(total_before);
+        let idle = idle_after.saturating_sub(idle_before);
+        if total > 0 {
+            metrics.cpu_percent = Some(total.saturating_sub(idle) as f64 * 100.0 / total as f64);
+        }
+    }
+
+    metrics
+}
+
+/// Where `fdctl configure` mounts hugetlbfs unless the config says otherwise
+const DEFAULT_HUGETLBFS_MOUNT: &str = "/mnt/.fd";
+
+/// Host tuning a Firedancer node needs: reserved hugepages, the performance
+/// CPU governor and tile affinity that fits the machine's CPUs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FiredancerTuning {
+    pub governors: Vec<String>, // Distinct scaling governors; empty without cpufreq
+    pub hugepages: u64,         // Reserved pages of all sizes
+    pub hugetlbfs_mounts: Vec<String>,
+    pub online_cpus: Option<Vec<u32>>,
+    pub hugetlbfs_mount_path: Option<String>, // From the fdctl config
+    pub affinity: Option<String>,
+    pub agave_affinity: Option<String>,
+}
+
+impl FiredancerTuning {
+    /// Misconfigurations that would hurt the node once it votes
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        if let Some(governor) = self.governors.iter().find(|g| g.as_str() != "performance") {
+            issues.push(format!("CPU governor is {}, not performance", governor));
+        }
+
+        if self.hugepages == 0 {
+            issues.push("No hugepages reserved (fdctl configure init hugetlbfs)".to_string());
+        }
+        let mount_path = self
+            .hugetlbfs_mount_path
+            .as_deref()
+            .unwrap_or(DEFAULT_HUGETLBFS_MOUNT)
+            .trim_end_matches('/');
+        if !self.hugetlbfs_mounts.iter().any(|mount| mount.starts_with(mount_path)) {
+            issues.push(format!("hugetlbfs is not mounted under {}", mount_path));
+        }
+
+        let tiles = self.affinity.as_deref().and_then(parse_cpu_list);
+        let agave = self.agave_affinity.as_deref().and_then(parse_cpu_list);
+        if let Some(online) = &self.online_cpus {
+            for (name, affinity, cpus) in [
+                ("Tile affinity", &self.affinity, &tiles),
+                ("Agave affinity", &self.agave_affinity, &agave),
+            ] {
+                if let (Some(affinity), Some(cpus)) = (affinity, cpus) {
+                    if cpus.iter().any(|cpu| !online.contains(cpu)) {
+                        issues.push(format!("{} {} includes CPUs that are not online", name, affinity));
+                    }
+                }
+            }
+        }
+        if let (Some(tiles), Some(agave)) = (&tiles, &agave) {
+            if tiles.iter().any(|cpu| agave.contains(cpu)) {
+                issues.push("Tile affinity and agave_affinity share CPUs".to_string());
+            }
+        }
+
+        issues
+    }
+}
+
+/// CPUs of a Linux or fdctl CPU list such as `0-3,8-15/2`. Floating tile
+/// counts (`f1`) name no CPU and are skipped; `auto` has no fixed list.
+pub fn parse_cpu_list(list: &str) -> Option<Vec<u32>> {
+    let list = list.trim();
+    if list.is_empty() || list == "auto" {
+        return None;
+    }
+
+    let mut cpus = Vec::new();
+    for part in list.split(',').map(str::trim) {
+        if part.starts_with('f') {
+            continue;
+        }
+        let (range, stride) = match part.split_once('/') {
+            Some((range, stride)) => (range, stride.parse::<usize>().ok()?.max(1)),
+            None => (part, 1),
+        };
+        let (first, last) = match range.split_once('-') {
+            Some((first, last)) => (first.parse::<u32>().ok()?, last.parse::<u32>().ok()?),
+            None => {
+                let cpu = range.parse::<u32>().ok()?;
+                (cpu, cpu)
+            }
+        };
+        cpus.extend((first..=last).step_by(stride));
+    }
+    Some(cpus)
+}
+
+/// Prints `config=<section> <line>` for every assignment in the fdctl config
+/// plus the CPU, governor and hugepage state of the host
+fn firedancer_tuning_command(fdctl_config: Option<&str>) -> String {
+    let mut command = String::new();
+    if let Some(config) = fdctl_config {
+        command.push_str(&format!(
+            "awk '/^[[:space:]]*\\[/ {{section=$1; next}} /=/ {{print \"config=\" section \" \" $0}}' {} 2>/dev/null; ",
+            shell_quote(config)
+        ));
+    }
+    command.push_str(
+        "echo \"online=$(cat /sys/devices/system/cpu/online 2>/dev/null)\"; \
+        cat /sys/devices/system/cpu/cpu*/cpufreq/scaling_governor 2>/dev/null | sort -u | sed 's/^/governor=/'; \
+        for f in /sys/kernel/mm/hugepages/hugepages-*/nr_hugepages; do [ -r \"$f\" ] && echo \"hugepages=$(cat \"$f\")\"; done; \
+        awk '$3 == \"hugetlbfs\" {print \"hugetlbfs=\" $2}' /proc/mounts; \
+        true",
+    );
+    command
+}
+
+pub async fn check_firedancer_tuning(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    fdctl_config: Option<&str>,
+) -> Result<FiredancerTuning> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, &firedancer_tuning_command(fdctl_config))
+        .await?;
+    Ok(parse_firedancer_tuning(&output))
+}
+
+pub fn parse_firedancer_tuning(output: &str) -> FiredancerTuning {
+    let mut tuning = FiredancerTuning::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(assignment) = line.strip_prefix("config=") {
+            let (section, assignment) = assignment.split_once(' ').unwrap_or(("", assignment));
+            let (key, value) = match assignment.split_once('=') {
+                Some((key, value)) if !key.trim_start().starts_with('#') => (key.trim(), value),
+                _ => continue,
+            };
+            // Drop a trailing comment and the TOML string quotes
+            let value = value.split('#').next().unwrap_or("").trim().trim_matches('"').to_string();
+            match (section, key) {
+                ("[layout]", "affinity") => tuning.affinity = Some(value),
+                ("[layout]", "agave_affinity") => tuning.agave_affinity = Some(value),
+                ("[hugetlbfs]", "mount_path") => tuning.hugetlbfs_mount_path = Some(value),
+                _ => {}
+            }
+        } else if let Some(value) = line.strip_prefix("online=") {
+            tuning.online_cpus = parse_cpu_list(value);
+        } else if let Some(value) = line.strip_prefix("governor=") {
+            if !value.is_empty() {
+                tuning.governors.push(value.to_string());
+            }
+        } else if let Some(value) = line.strip_prefix("hugepages=") {
+            tuning.hugepages += value.parse::<u64>().unwrap_or(0);
+        } else if let Some(value) = line.strip_prefix("hugetlbfs=") {
+            tuning.hugetlbfs_mounts.push(value.to_string());
+        }
+    }
+
+    tuning
+}
+
+/// Open files limit the Agave tuning guide asks for (`LimitNOFILE`)
+const MIN_OPEN_FILES: u64 = 1_000_000;
+
+/// Sysctls every validator needs, with their minimum
+const SYSCTL_MINIMUMS: &[(&str, u64)] = &[("vm.max_map_count", 1_000_000)];
+
+/// UDP buffer sysctls Agave needs. Firedancer sends over XDP and sets its
+/// own with `fdctl configure`.
+const AGAVE_SYSCTL_MINIMUMS: &[(&str, u64)] = &[
+    ("net.core.rmem_default", 134_217_728),
+    ("net.core.rmem_max", 134_217_728),
+    ("net.core.wmem_default", 134_217_728),
+    ("net.core.wmem_max", 134_217_728),
+];
+
+/// Kernel and process limits a validator needs to keep up on mainnet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemLimits {
+    pub open_files: Option<u64>,     // Soft limit of the running validator; None when not running
+    pub sysctls: Vec<(String, u64)>, // Only the readable ones
+}
+
+impl SystemLimits {
+    /// Limits below what the validator needs, one message per check
+    pub fn issues(&self, validator_type: &ValidatorType) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        if let Some(open_files) = self.open_files.filter(|limit| *limit < MIN_OPEN_FILES) {
+            issues.push(format!(
+                "Validator open files limit is {}, needs at least {} (LimitNOFILE)",
+                open_files, MIN_OPEN_FILES
+            ));
+        }
+
+        let agave = if *validator_type == ValidatorType::Firedancer {
+            &[][..]
+        } else {
+            AGAVE_SYSCTL_MINIMUMS
+        };
+        for (key, minimum) in SYSCTL_MINIMUMS.iter().chain(agave) {
+            let value = self.sysctls.iter().find(|(name, _)| name == key).map(|(_, value)| *value);
+            if let Some(value) = value.filter(|value| value < minimum) {
+                issues.push(format!("{} is {}, needs at least {}", key, value, minimum));
+            }
+        }
+
+        issues
+    }
+}
+
+/// Prints `nofile=<limit>` for the running validator and `sysctl=<key>
+/// <value>` for every checked sysctl
+fn system_limits_command() -> String {
+    let keys: Vec<&str> = SYSCTL_MINIMUMS
+        .iter()
+        .chain(AGAVE_SYSCTL_MINIMUMS)
+        .map(|(key, _)| *key)
+        .collect();
+    format!(
+        "pid=$(pgrep -o -f '[b]in/fdctl|[b]in/agave-validator|[r]elease/agave-validator|[b]in/solana-validator|[r]elease/solana-validator'); \
+        [ -n \"$pid\" ] && awk '/^Max open files/ {{print \"nofile=\" $4}}' /proc/$pid/limits; \
+        for key in {}; do echo \"sysctl=$key $(cat /proc/sys/$(echo $key | tr . /) 2>/dev/null)\"; done",
+        keys.join(" ")
+    )
+}
+
+pub async fn check_system_limits(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<SystemLimits> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, &system_limits_command())
+        .await?;
+    Ok(parse_system_limits(&output))
+}
+
+pub fn parse_system_limits(output: &str) -> SystemLimits {
+    let mut limits = SystemLimits::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("nofile=") {
+            limits.open_files = match value {
+                "unlimited" => Some(u64::MAX),
+                value => value.parse().ok(),
+            };
+        } else if let Some(sysctl) = line.strip_prefix("sysctl=") {
+            let mut parts = sysctl.split_whitespace();
+            if let (Some(key), Some(Ok(value))) = (parts.next(), parts.next().map(str::parse::<u64>)) {
+                limits.sysctls.push((key.to_string(), value));
+            }
+        }
+    }
+
+    limits
+}
+
+/// Prints `owner=<user>`: the user the validator runs as, else the SSH
+/// user. fdctl starts as root and drops to its configured user, which its
+/// newest process runs as.
+const KEYPAIR_OWNER_DETECTION: &str = "pid=$(pgrep -n -f '[b]in/fdctl' || pgrep -o -f '[b]in/agave-validator|[r]elease/agave-validator|[b]in/solana-validator|[r]elease/solana-validator'); \
+    [ -n \"$pid\" ] && user=$(stat -c %U /proc/$pid 2>/dev/null); \
+    echo \"owner=${user:-${SUDO_USER:-$(id -un)}}\"";
+
+/// Owner and mode of one keypair file on a node
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeypairFile {
+    pub name: String, // funded, unfunded or vote
+    pub path: String,
+    pub owner: String,
+    pub mode: u32,
+}
+
+impl KeypairFile {
+    fn label(&self) -> &str {
+        match self.name.as_str() {
+            "funded" => "Funded identity keypair",
+            "unfunded" => "Unfunded identity keypair",
+            "vote" => "Vote keypair",
+            other => other,
+        }
+    }
+}
+
+/// Ownership and permissions of a node's keypair files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeypairPermissions {
+    pub expected_owner: Option<String>,
+    pub files: Vec<KeypairFile>, // Only the ones that exist
+}
+
+impl KeypairPermissions {
+    /// Keypairs every user on the host can read
+    pub fn critical_issues(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .filter(|file| file.mode & 0o004 != 0)
+            .map(|file| {
+                format!(
+                    "CRITICAL: {} {} is world-readable (mode {:o})",
+                    file.label(),
+                    file.path,
+                    file.mode
+                )
+            })
+            .collect()
+    }
+
+    /// Keypairs with group access or owned by another user than the validator's
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        for file in &self.files {
+            if file.mode & 0o004 == 0 && file.mode & 0o077 != 0 {
+                issues.push(format!(
+                    "{} {} has mode {:o}, should be 600",
+                    file.label(),
+                    file.path,
+                    file.mode
+                ));
+            }
+            if let Some(expected) = self.expected_owner.as_ref().filter(|owner| **owner != file.owner) {
+                issues.push(format!(
+                    "{} {} is owned by {}, expected {}",
+                    file.label(),
+                    file.path,
+                    file.owner,
+                    expected
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+/// Prints the expected owner and `key=<name> <owner> <octal mode> <path>`
+/// for every keypair file that exists
+fn keypair_permissions_command(paths: &NodePaths) -> String {
+    let mut command = KEYPAIR_OWNER_DETECTION.to_string();
+    for (name, path) in [
+        ("funded", &paths.funded_identity),
+        ("unfunded", &paths.unfunded_identity),
+        ("vote", &paths.vote_keypair),
+    ] {
+        if !path.is_empty() {
+            command.push_str(&format!(
+                "; stat -Lc 'key={} %U %a %n' {} 2>/dev/null",
+                name,
+                shell_quote(path)
+            ));
+        }
+    }
+    command.push_str("; true");
+    command
+}
+
+pub async fn check_keypair_permissions(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<KeypairPermissions> {
+    let output = ssh_pool
+        .execute_privileged(node, ssh_key, &keypair_permissions_command(&node.paths))
+        .await?;
+    Ok(parse_keypair_permissions(&output))
+}
+
+pub fn parse_keypair_permissions(output: &str) -> KeypairPermissions {
+    let mut permissions = KeypairPermissions::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(owner) = line.strip_prefix("owner=") {
+            permissions.expected_owner = Some(owner.to_string()).filter(|owner| !owner.is_empty());
+        } else if let Some(file) = line.strip_prefix("key=") {
+            let mut parts = file.splitn(4, ' ');
+            if let (Some(name), Some(owner), Some(Ok(mode)), Some(path)) = (
+                parts.next(),
+                parts.next(),
+                parts.next().map(|mode| u32::from_str_radix(mode, 8)),
+                parts.next(),
+            ) {
+                permissions.files.push(KeypairFile {
+                    name: name.to_string(),
+                    path: path.to_string(),
+                    owner: owner.to_string(),
+                    mode,
+                });
+            }
+        }
+    }
+
+    permissions
+}
+
+/// chrony's tracking report when chrony runs, plus systemd's view of NTP
+/// synchronization for hosts on systemd-timesyncd
+const TIME_SYNC_COMMAND: &str = "chronyc -n tracking 2>/dev/null | sed 's/^/chrony=/'; \
+    echo \"ntp_synchronized=$(timedatectl show -p NTPSynchronized --value 2>/dev/null)\"";
+
+/// Whether a node's clock is synchronized and how far off it is
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeSync {
+    pub synchronized: Option<bool>, // None when neither chrony nor timedatectl answers
+    pub offset_ms: Option<f64>,     // From chrony; positive when the clock is fast
+    pub source: Option<String>,     // chrony's reference server
+}
+
+pub async fn check_time_sync(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<TimeSync> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, TIME_SYNC_COMMAND)
+        .await?;
+    Ok(parse_time_sync(&output))
+}
+
+pub fn parse_time_sync(output: &str) -> TimeSync {
+    let mut sync = TimeSync::default();
+    let mut chrony_synchronized = None;
+    let mut timedatectl_synchronized = None;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(report) = line.strip_prefix("chrony=") {
+            let (key, value) = match report.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+            match key {
+                "Leap status" => chrony_synchronized = Some(value != "Not synchronised"),
+                // "0.000012345 seconds fast of NTP time"
+                "System time" => {
+                    let mut words = value.split_whitespace();
+                    let seconds = words.next().and_then(|s| s.parse::<f64>().ok());
+                    let sign = if words.nth(1) == Some("slow") { -1.0 } else { 1.0 };
+                    sync.offset_ms = seconds.map(|s| sign * s * 1000.0);
+                }
+                // "A9FE A97B (169.254.169.123)"
+                "Reference ID" => {
+                    sync.source = value
+                        .split_once('(')
+                        .map(|(_, name)| name.trim_end_matches(')').to_string())
+                        .filter(|name| !name.is_empty());
+                }
+                _ => {}
+            }
+        } else if let Some(value) = line.strip_prefix("ntp_synchronized=") {
+            timedatectl_synchronized = match value {
+                "yes" => Some(true),
+                "no" => Some(false),
+                _ => None,
+            };
+        }
+    }
+
+    // chrony knows better than timedatectl when it runs
+    sync.synchronized = chrony_synchronized.or(timedatectl_synchronized);
+    sync
+}
+
+/// Seconds since start of every validator process, from `ps`. fdctl runs
+/// several processes; the oldest is the validator's own uptime.
+const PROCESS_UPTIME_COMMAND: &str = "ps -eo etimes=,args= | grep -E 'bin/fdctl|bin/agave-validator|release/agave-validator|bin/solana-validator|release/solana-validator' | grep -v grep | awk '{print \"etimes=\" $1}'; true";
+
+/// How long the validator process on a node has been running
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessUptime {
+    pub uptime_seconds: Option<u64>, // None while no validator process runs
+    pub restarts: u32,               // Restarts seen since svs started
+}
+
+pub async fn check_process_uptime(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+) -> Result<Option<u64>> {
+    let output = ssh_pool
+        .execute_command(node, ssh_key, PROCESS_UPTIME_COMMAND)
+        .await?;
+    Ok(parse_process_uptime(&output))
+}
+
+pub fn parse_process_uptime(output: &str) -> Option<u64> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("etimes="))
+        .filter_map(|value| value.parse::<u64>().ok())
+        .max()
+}
+
+/// Uptime in days and hours, or minutes when short
+pub fn format_uptime(seconds: u64) -> String {
+    if seconds >= 86_400 {
+        format!("{}d {}h", seconds / 86_400, (seconds % 86_400) / 3600)
+    } else {
+        crate::commands::switch::format_wait(std::time::Duration::from_secs(seconds))
+    }
+}
+
+/// `du` of a large ledger can take a while, but not forever
+const LEDGER_DU_TIMEOUT_SECONDS: u64 = 600;
+
+/// Bytes used by the ledger directory, including anything stored under it
+pub async fn check_ledger_size(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    ledger_path: &str,
+) -> Result<u64> {
+    let command = format!(
+        "timeout {} du -sb {} 2>/dev/null | cut -f1",
+        LEDGER_DU_TIMEOUT_SECONDS,
+        shell_quote(ledger_path)
+    );
+    let output = ssh_pool.execute_command(node, ssh_key, &command).await?;
+    output
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("du of {} printed no size", ledger_path))
+}
+
+/// Size and growth of a ledger directory from its size history
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerGrowth {
+    pub bytes: u64,
+    pub bytes_per_day: Option<f64>, // None until the samples span an hour
+    pub days_to_full: Option<f64>,  // None while not growing or with unknown free space
+}
+
+/// Growth from (timestamp, bytes) samples, oldest first, and the free space
+/// left on the ledger's filesystem
+pub fn ledger_growth(samples: &[(i64, u64)], free_bytes: Option<u64>) -> Option<LedgerGrowth> {
+    let (first_at, first_bytes) = *samples.first()?;
+    let (last_at, bytes) = *samples.last()?;
+
+    let span = last_at - first_at;
+    let bytes_per_day = (span >= 3600)
+        .then(|| (bytes as f64 - first_bytes as f64) * 86_400.0 / span as f64);
+    let days_to_full = match (bytes_per_day, free_bytes) {
+        (Some(rate), Some(free)) if rate > 0.0 => Some(free as f64 / rate),
+        _ => None,
+    };
+
+    Some(LedgerGrowth {
+        bytes,
+        bytes_per_day,
+        days_to_full,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_os_patch_status_ubuntu_reboot_pending() {
+        let output = "kernel=6.8.0-40-generic\n\
+            reboot_required=1\n\
+            reboot_pkg=linux-image-6.8.0-45-generic\n\
+            reboot_pkg=linux-base\n\
+            latest_kernel=6.8.0-45-generic\n\
+            updates=12;3\n";
+
+        let status = parse_os_patch_status(output);
+        assert_eq!(status.running_kernel, "6.8.0-40-generic");
+        assert!(status.reboot_required);
+        assert_eq!(status.reboot_packages.len(), 2);
+        assert!(status.kernel_outdated());
+        assert!(status.needs_reboot());
+        assert_eq!(status.pending_updates, Some(12));
+        assert_eq!(status.security_updates, Some(3));
+    }
+
+    #[test]
+    fn test_parse_os_patch_status_up_to_date() {
+        let output = "kernel=5.14.0-427.el9.x86_64\nlatest_kernel=5.14.0-427.el9.x86_64\n";
+
+        let status = parse_os_patch_status(output);
+        assert!(!status.reboot_required);
+        assert!(!status.kernel_outdated());
+        assert!(!status.needs_reboot());
+        assert_eq!(status.pending_updates, None);
+    }
+
+    #[test]
+    fn test_ledger_growth() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let samples = [(0, 400 * GB), (43_200, 410 * GB), (86_400, 420 * GB)];
+        let growth = ledger_growth(&samples, Some(100 * GB)).unwrap();
+        assert_eq!(growth.bytes, 420 * GB);
+        assert_eq!(growth.bytes_per_day, Some(20.0 * GB as f64));
+        assert_eq!(growth.days_to_full, Some(5.0));
+
+        // A single sample has no rate yet, a shrinking ledger never fills up
+        assert_eq!(ledger_growth(&samples[..1], Some(GB)).unwrap().bytes_per_day, None);
+        let cleaned = [(0, 420 * GB), (86_400, 300 * GB)];
+        assert_eq!(ledger_growth(&cleaned, Some(GB)).unwrap().days_to_full, None);
+        assert!(ledger_growth(&[], None).is_none());
+    }
+
+    #[test]
+    fn test_parse_storage_status() {
+        let output = "now=1700000100\ntower_mtime=1700000040\n\
+            ledger_disk=/mnt/ledger 93 52428800\naccounts_disk=/mnt/accounts 40 1048576\n";
+        let status = parse_storage_status(output);
+        assert_eq!(status.tower_age_seconds, Some(60));
+        let ledger = status.ledger_disk.as_ref().unwrap();
+        assert_eq!(ledger.mount, "/mnt/ledger");
+        assert_eq!(ledger.used_percent, 93);
+        assert_eq!(ledger.free_percent(), 7);
+        assert_eq!(ledger.free_bytes, 50 * 1024 * 1024 * 1024);
+        assert_eq!(status.disks().len(), 2);
+
+        // Accounts on the ledger's filesystem are one disk
+        let status = parse_storage_status("ledger_disk=/ 50 100\naccounts_disk=/ 50 100\n");
+        assert_eq!(status.disks(), vec![("ledger", status.ledger_disk.as_ref().unwrap())]);
+
+        // Missing tower file leaves the age unknown
+        let status = parse_storage_status("now=1700000100\ntower_mtime=\n");
+        assert_eq!(status, StorageStatus::default());
+
+        // The newest of the full and incremental snapshot is what a restart uses
+        let status = parse_storage_status(
+            "now=1700010000\nfull_snapshot_mtime=1700000000\nincremental_snapshot_mtime=1700009940\n",
+        );
+        assert_eq!(status.full_snapshot_age_seconds, Some(10_000));
+        assert_eq!(status.snapshot_age_seconds(), Some(60));
+        let status = parse_storage_status("now=1700010000\nfull_snapshot_mtime=1700000000\nincremental_snapshot_mtime=\n");
+        assert_eq!(status.snapshot_age_seconds(), Some(10_000));
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8"), Some(vec![0, 1, 2, 3, 8]));
+        assert_eq!(parse_cpu_list("f1,2-8/3"), Some(vec![2, 5, 8]));
+        assert_eq!(parse_cpu_list("auto"), None);
+        assert_eq!(parse_cpu_list("1-x"), None);
+    }
+
+    #[test]
+    fn test_firedancer_tuning_issues() {
+        let output = "config=[layout] affinity = \"auto\"\n\
+            config=[hugetlbfs] mount_path = \"/mnt/.fd\"\n\
+            config=[ledger] path = \"/mnt/ledger\"\n\
+            online=0-31\ngovernor=performance\nhugepages=0\nhugepages=52\n\
+            hugetlbfs=/mnt/.fd/.gigantic\nhugetlbfs=/mnt/.fd/.huge\n";
+        let tuning = parse_firedancer_tuning(output);
+        assert_eq!(tuning.hugepages, 52);
+        assert_eq!(tuning.affinity.as_deref(), Some("auto"));
+        assert!(tuning.issues().is_empty());
+
+        let output = "config=[layout] affinity = \"1-40\" # tiles\n\
+            config=[layout] agave_affinity = \"30-31\"\n\
+            online=0-31\ngovernor=performance\ngovernor=powersave\nhugepages=0\n";
+        let issues = parse_firedancer_tuning(output).issues();
+        assert_eq!(
+            issues,
+            vec![
+                "CPU governor is powersave, not performance",
+                "No hugepages reserved (fdctl configure init hugetlbfs)",
+                "hugetlbfs is not mounted under /mnt/.fd",
+                "Tile affinity 1-40 includes CPUs that are not online",
+                "Tile affinity and agave_affinity share CPUs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_system_limits_issues() {
+        let output = "nofile=65536
+            sysctl=vm.max_map_count 65530
+            sysctl=net.core.rmem_default 212992
+            sysctl=net.core.rmem_max 134217728
+            sysctl=net.core.wmem_default 134217728
+            sysctl=net.core.wmem_max
+";
+        let limits = parse_system_limits(output);
+        assert_eq!(limits.open_files, Some(65536));
+        assert_eq!(limits.sysctls.len(), 4);
+        assert_eq!(
+            limits.issues(&ValidatorType::Agave),
+            vec![
+                "Validator open files limit is 65536, needs at least 1000000 (LimitNOFILE)",
+                "vm.max_map_count is 65530, needs at least 1000000",
+                "net.core.rmem_default is 212992, needs at least 134217728",
+            ]
+        );
+        // Firedancer doesn't use the kernel UDP buffers
+        assert_eq!(limits.issues(&ValidatorType::Firedancer).len(), 2);
+
+        let tuned = parse_system_limits("nofile=unlimited
+sysctl=vm.max_map_count 2000000
+");
+        assert!(tuned.issues(&ValidatorType::Agave).is_empty());
+        // Without a running validator there is no limit to check
+        assert_eq!(parse_system_limits("").open_files, None);
+    }
+
+    #[test]
+    fn test_keypair_permissions() {
+        let output = "owner=sol
+            key=funded sol 644 /home/sol/validator-keypair.json
+            key=unfunded root 600 /home/sol/unstaked identity.json
+            key=vote sol 640 /home/sol/vote-account-keypair.json
+";
+        let permissions = parse_keypair_permissions(output);
+        assert_eq!(permissions.expected_owner.as_deref(), Some("sol"));
+        assert_eq!(permissions.files[1].path, "/home/sol/unstaked identity.json");
+        assert_eq!(
+            permissions.critical_issues(),
+            vec!["CRITICAL: Funded identity keypair /home/sol/validator-keypair.json is world-readable (mode 644)"]
+        );
+        assert_eq!(
+            permissions.issues(),
+            vec![
+                "Unfunded identity keypair /home/sol/unstaked identity.json is owned by root, expected sol",
+                "Vote keypair /home/sol/vote-account-keypair.json has mode 640, should be 600",
+            ]
+        );
+
+        let locked = parse_keypair_permissions("owner=sol\nkey=funded sol 600 /k.json\nkey=vote sol 400 /v.json\n");
+        assert!(locked.critical_issues().is_empty());
+        assert!(locked.issues().is_empty());
+
+        let paths = NodePaths {
+            funded_identity: "/k.json".to_string(),
+            unfunded_identity: "/u.json".to_string(),
+            vote_keypair: String::new(),
+            ..Default::default()
+        };
+        let command = keypair_permissions_command(&paths);
+        assert!(command.contains("stat -Lc 'key=unfunded %U %a %n' '/u.json'"));
+        assert!(!command.contains("key=vote"));
+    }
+
+    #[test]
+    fn test_parse_time_sync() {
+        let output = "chrony=Reference ID    : A9FE A97B (169.254.169.123)\n\
+            chrony=Stratum         : 4\n\
+            chrony=System time     : 0.000152340 seconds slow of NTP time\n\
+            chrony=Leap status     : Normal\n\
+            ntp_synchronized=yes\n";
+        let sync = parse_time_sync(output);
+        assert_eq!(sync.synchronized, Some(true));
+        assert!((sync.offset_ms.unwrap() + 0.15234).abs() < 1e-9);
+        assert_eq!(sync.source.as_deref(), Some("169.254.169.123"));
+
+        let unsynced = parse_time_sync("chrony=Leap status     : Not synchronised\nntp_synchronized=yes\n");
+        assert_eq!(unsynced.synchronized, Some(false));
+
+        // systemd-timesyncd only
+        let timesyncd = parse_time_sync("ntp_synchronized=no\n");
+        assert_eq!(timesyncd.synchronized, Some(false));
+        assert_eq!(timesyncd.offset_ms, None);
+        assert_eq!(parse_time_sync("ntp_synchronized=\n").synchronized, None);
+    }
+
+    #[test]
+    fn test_parse_process_uptime() {
+        // The oldest fdctl process is the validator's uptime
+        assert_eq!(parse_process_uptime("etimes=86500\netimes=86460\n"), Some(86500));
+        assert_eq!(parse_process_uptime(""), None);
+        assert_eq!(format_uptime(3 * 86_400 + 5 * 3600 + 59), "3d 5h");
+        assert_eq!(format_uptime(600), "10m 0s");
+    }
+
+    #[test]
+    fn test_parse_system_metrics() {
+        let output = "cpus=32\nloadavg=24.50 20.10 18.00 3/1500 12345\n\
+            cpu=cpu  1000 0 500 8000 500 0 0 0 0 0\n\
+            cpu=cpu  1700 0 600 8150 550 0 0 0 0 0\n\
+            MemTotal:       268435456 kB\n\
+            MemAvailable:    67108864 kB\n\
+            SwapTotal:              0 kB\n\
+            SwapFree:               0 kB\n";
+        let metrics = parse_system_metrics(output);
+        assert_eq!(metrics.cpu_count, Some(32));
+        assert_eq!(metrics.load_average, Some(24.5));
+        // 1000 jiffies passed, 200 of them idle or waiting on IO
+        assert_eq!(metrics.cpu_percent, Some(80.0));
+        assert_eq!(metrics.memory_used_percent(), Some(75.0));
+        assert_eq!(metrics.swap_used_percent(), None);
+
+        // A single cpu sample has no usage
+        let metrics = parse_system_metrics("cpu=cpu  1000 0 500 8000 500 0 0 0 0 0\n");
+        assert_eq!(metrics.cpu_percent, None);
+        assert_eq!(metrics.memory_used_percent(), None);
+    }
+}
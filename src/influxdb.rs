@@ -0,0 +1,129 @@
+use anyhow::Result;
+use std::time::Duration;
+
+use crate::types::InfluxDbConfig;
+
+/// Escape a measurement, tag key or tag value for line protocol
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+/// One line-protocol point. Field values are written as given, so integers
+/// need their `i` suffix; tags with an empty value are left out.
+pub fn line(measurement: &str, tags: &[(&str, &str)], fields: &[(&str, String)], timestamp: i64) -> String {
+    let mut out = escape(measurement);
+    for (key, value) in tags.iter().filter(|(_, value)| !value.is_empty()) {
+        out.push_str(&format!(",{}={}", escape(key), escape(value)));
+    }
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}={}", escape(key), value))
+        .collect();
+    out.push_str(&format!(" {} {}\n", fields.join(","), timestamp));
+    out
+}
+
+/// `svs_node` point for one node of a validator
+pub fn node_line(
+    identity: &str,
+    label: &str,
+    host: &str,
+    active: bool,
+    ssh_healthy: bool,
+    slots_behind: Option<u64>,
+    timestamp: i64,
+) -> String {
+    let mut fields = vec![
+        ("active", active.to_string()),
+        ("ssh_healthy", ssh_healthy.to_string()),
+    ];
+    if let Some(slots) = slots_behind {
+        fields.push(("slots_behind", format!("{}i", slots)));
+    }
+    line(
+        "svs_node",
+        &[("validator", identity), ("node", label), ("host", host)],
+        &fields,
+        timestamp,
+    )
+}
+
+/// `svs_validator` point with the vote account state seen over RPC
+pub fn validator_line(
+    identity: &str,
+    last_vote_slot: Option<u64>,
+    voting: Option<bool>,
+    rpc_consecutive_failures: u32,
+    timestamp: i64,
+) -> String {
+    let mut fields = vec![(
+        "rpc_consecutive_failures",
+        format!("{}i", rpc_consecutive_failures),
+    )];
+    if let Some(slot) = last_vote_slot {
+        fields.push(("last_vote_slot", format!("{}i", slot)));
+    }
+    if let Some(voting) = voting {
+        fields.push(("voting", voting.to_string()));
+    }
+    line("svs_validator", &[("validator", identity)], &fields, timestamp)
+}
+
+/// POST a batch of points to the configured write URL (timestamps in seconds)
+pub async fn write(config: &InfluxDbConfig, body: String) -> Result<()> {
+    let client = crate::proxy::http_client_builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let mut request = client
+        .post(&config.write_url)
+        .header("Content-Type", "text/plain; charset=utf-8")
+        .body(body);
+    if let Some(token) = &config.token {
+        request = request.header("Authorization", format!("Token {}", token));
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        anyhow::bail!("InfluxDB write error: {}", error_text);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_escaping() {
+        assert_eq!(
+            line("svs_node", &[("node", "node a,1"), ("host", "")], &[("active", "true".to_string())], 100),
+            "svs_node,node=node\\ a\\,1 active=true 100\n"
+        );
+    }
+
+    #[test]
+    fn test_node_and_validator_lines() {
+        assert_eq!(
+            node_line("Id111", "node-a", "10.0.0.1", true, true, Some(3), 100),
+            "svs_node,validator=Id111,node=node-a,host=10.0.0.1 active=true,ssh_healthy=true,slots_behind=3i 100\n"
+        );
+        assert_eq!(
+            node_line("Id111", "node-b", "10.0.0.2", false, false, None, 100),
+            "svs_node,validator=Id111,node=node-b,host=10.0.0.2 active=false,ssh_healthy=false 100\n"
+        );
+        assert_eq!(
+            validator_line("Id111", Some(250), Some(true), 0, 100),
+            "svs_validator,validator=Id111 rpc_consecutive_failures=0i,last_vote_slot=250i,voting=true 100\n"
+        );
+        assert_eq!(
+            validator_line("Id111", None, None, 4, 100),
+            "svs_validator,validator=Id111 rpc_consecutive_failures=4i 100\n"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// The process holding a config's monitor lock, written into the lock file
+/// so a refused instance can say who holds it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceInfo {
+    pub pid: u32,
+    pub command: String,
+    pub started_at: String, // RFC 3339
+}
+
+impl InstanceInfo {
+    fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            command: std::env::args().collect::<Vec<_>>().join(" "),
+            started_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Lock file next to the config, so separate configs can be monitored side by side
+pub fn lock_path_for(config_path: &Path) -> PathBuf {
+    let mut name = config_path.as_os_str().to_owned();
+    name.push(".lock");
+    PathBuf::from(name)
+}
+
+/// Held for as long as this process monitors the config. The advisory lock
+/// on the file is released when it is closed, so an instance that crashed or
+/// was killed never leaves a lock behind. The file itself stays in place.
+pub struct InstanceLock {
+    _file: File,
+}
+
+impl InstanceLock {
+    pub fn acquire(path: &Path) -> Result<Self> {
+        // Not truncated before the lock is held, so a refused instance can
+        // still read the holder
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| anyhow!("Could not open lock file {}: {}", path.display(), e))?;
+
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                let mut content = String::new();
+                let _ = file.read_to_string(&mut content);
+                return Err(match serde_json::from_str::<InstanceInfo>(&content) {
+                    Ok(info) => anyhow!(
+                        "Another svs instance is already monitoring this config: pid {}, `{}`, started {}.\n\
+                         Stop it first (e.g. `systemctl stop svs` if it runs as a service).",
+                        info.pid,
+                        info.command,
+                        info.started_at
+                    ),
+                    Err(_) => anyhow!(
+                        "Another svs instance is already monitoring this config ({} is locked)",
+                        path.display()
+                    ),
+                });
+            }
+            Err(TryLockError::Error(e)) => {
+                return Err(anyhow!("Could not lock {}: {}", path.display(), e))
+            }
+        }
+
+        file.set_len(0)?;
+        file.write_all(serde_json::to_string(&InstanceInfo::current())?.as_bytes())?;
+        file.flush()?;
+        Ok(Self { _file: file })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_lock(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("svs-{}-{}.lock", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_lock_path_for() {
+        assert_eq!(
+            lock_path_for(Path::new("/home/sol/.solana-validator-switch/config.yaml")),
+            PathBuf::from("/home/sol/.solana-validator-switch/config.yaml.lock")
+        );
+    }
+
+    #[test]
+    fn test_second_instance_is_refused_until_release() {
+        let path = temp_lock("refused");
+        let _ = std::fs::remove_file(&path);
+
+        let lock = InstanceLock::acquire(&path).unwrap();
+        let err = InstanceLock::acquire(&path).err().unwrap().to_string();
+        assert!(err.contains(&format!("pid {}", std::process::id())));
+
+        drop(lock);
+        assert!(InstanceLock::acquire(&path).is_ok());
+    }
+
+    #[test]
+    fn test_unlocked_file_is_taken_over() {
+        // Left by an instance that is gone: the file exists, nobody locks it
+        let path = temp_lock("stale");
+        let stale = InstanceInfo {
+            pid: u32::MAX - 1,
+            command: "svs watch".to_string(),
+            started_at: "2025-01-01T00:00:00Z".to_string(),
+        };
+        std::fs::write(&path, serde_json::to_string(&stale).unwrap()).unwrap();
+
+        let _lock = InstanceLock::acquire(&path).unwrap();
+        let info: InstanceInfo =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(info.pid, std::process::id());
+    }
+}
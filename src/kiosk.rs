@@ -0,0 +1,95 @@
+use crate::types::NodeWithStatus;
+use crate::ValidatorStatus;
+
+/// Placeholder shown instead of hosts and file paths on shared displays
+const HIDDEN: &str = "hidden";
+
+/// Keep the first and last four characters so operators can still tell
+/// validators apart, e.g. `7Np4…Xq2Z`
+pub fn mask_pubkey(pubkey: &str) -> String {
+    let chars: Vec<char> = pubkey.chars().collect();
+    if chars.len() <= 8 {
+        return pubkey.to_string();
+    }
+    format!(
+        "{}…{}",
+        chars[..4].iter().collect::<String>(),
+        chars[chars.len() - 4..].iter().collect::<String>()
+    )
+}
+
+/// Replace absolute paths inside free text (e.g. swap readiness issues)
+pub fn mask_paths(text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            let trimmed = word.trim_start_matches(['\'', '"', '(']);
+            if trimmed.starts_with('/') || trimmed.starts_with('~') {
+                HIDDEN
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn mask_node(node: &NodeWithStatus) -> NodeWithStatus {
+    let mut node = node.clone();
+    node.node.host = HIDDEN.to_string();
+    node.node.user = HIDDEN.to_string();
+    node.current_identity = node.current_identity.as_deref().map(mask_pubkey);
+    node.ledger_path = node.ledger_path.as_ref().map(|_| HIDDEN.to_string());
+    node.tower_path = node.tower_path.as_ref().map(|_| HIDDEN.to_string());
+    node.agave_validator_executable = node
+        .agave_validator_executable
+        .as_ref()
+        .map(|_| HIDDEN.to_string());
+    node.fdctl_executable = node.fdctl_executable.as_ref().map(|_| HIDDEN.to_string());
+    node.solana_cli_executable = node
+        .solana_cli_executable
+        .as_ref()
+        .map(|_| HIDDEN.to_string());
+    node.ssh_key_path = node.ssh_key_path.as_ref().map(|_| HIDDEN.to_string());
+    node.swap_issues = node.swap_issues.iter().map(|i| mask_paths(i)).collect();
+    node
+}
+
+/// Copy of a validator status that is safe to show on a wall display: hosts
+/// and paths hidden, pubkeys shortened. Node labels and health are kept.
+pub fn mask_validator_status(status: &ValidatorStatus) -> ValidatorStatus {
+    let mut masked = status.clone();
+    masked.validator_pair.vote_pubkey = mask_pubkey(&status.validator_pair.vote_pubkey);
+    masked.validator_pair.identity_pubkey = mask_pubkey(&status.validator_pair.identity_pubkey);
+    masked.validator_pair.rpc = HIDDEN.to_string();
+    masked.validator_pair.additional_rpcs.clear();
+    masked.validator_pair.reference_rpc = None;
+    masked.nodes_with_status = status.nodes_with_status.iter().map(mask_node).collect();
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_pubkey() {
+        assert_eq!(
+            mask_pubkey("7Np41oeYqPefeNQEHSv1UDhYrehxin3NStELsSKCT4K2"),
+            "7Np4…T4K2"
+        );
+        assert_eq!(mask_pubkey("short"), "short");
+    }
+
+    #[test]
+    fn test_mask_paths() {
+        assert_eq!(
+            mask_paths("Ledger directory /mnt/ledger is not writable"),
+            "Ledger directory hidden is not writable"
+        );
+        assert_eq!(
+            mask_paths("Keypair '/home/sol/funded.json' missing"),
+            "Keypair hidden missing"
+        );
+        assert_eq!(mask_paths("Catchup failed"), "Catchup failed");
+    }
+}
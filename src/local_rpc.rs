@@ -0,0 +1,178 @@
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use solana_client::rpc_response::{RpcVoteAccountInfo, RpcVoteAccountStatus};
+
+use crate::solana_rpc::{build_vote_data, ValidatorVoteData};
+use crate::ssh::AsyncSshPool;
+use crate::types::NodeConfig;
+
+/// Sets `$port` to the node's RPC port: the running agave/solana-validator
+/// `--rpc-port`, the `[rpc]` port of the running Firedancer config, or 8899
+const RPC_PORT_DETECTION: &str = "port=$(ps aux | grep -E 'agave-validator|solana-validator' | grep -v grep | grep -o -- '--rpc-port [0-9]*' | head -1 | awk '{print $2}'); \
+    config=$(ps aux | grep 'bin/fdctl' | grep -v grep | grep -o -- '--config [^ ]*' | head -1 | awk '{print $2}'); \
+    if [ -n \"$config\" ]; then port=$(grep -A 5 '\\[rpc\\]' \"$config\" | grep 'port' | grep -o '[0-9]\\+' | head -1); fi; \
+    port=${port:-8899}";
+
+/// Shell command posting each JSON-RPC body to the node's own RPC port and
+/// printing one response per line. `port` skips the port detection.
+pub fn local_rpc_command(port: Option<u16>, bodies: &[String]) -> String {
+    let mut command = match port {
+        Some(port) => format!("port={}", port),
+        None => RPC_PORT_DETECTION.to_string(),
+    };
+    for body in bodies {
+        command.push_str(&format!(
+            "; curl -s --max-time 5 http://localhost:$port -X POST -H 'Content-Type: application/json' -d '{}'; echo",
+            body
+        ));
+    }
+    command
+}
+
+/// JSON-RPC 2.0 request body
+pub fn request(method: &str, params: serde_json::Value) -> String {
+    serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params}).to_string()
+}
+
+/// The `result` of a JSON-RPC response line
+fn result_of(line: Option<&str>, method: &str) -> Result<serde_json::Value> {
+    let line = line
+        .filter(|line| !line.trim().is_empty())
+        .ok_or_else(|| anyhow!("Local RPC gave no {} response", method))?;
+    let mut response: serde_json::Value = serde_json::from_str(line.trim())
+        .map_err(|e| anyhow!("Invalid {} response from local RPC: {}", method, e))?;
+    if let Some(error) = response.get("error") {
+        return Err(anyhow!("Local RPC {} failed: {}", method, error));
+    }
+    Ok(response["result"].take())
+}
+
+/// Parse the getHealth, getVoteAccounts, getAccountInfo and getSlot responses
+/// printed by `local_rpc_command` into vote data. A node reporting itself
+/// unhealthy is an error, its view of the vote account can't be trusted.
+pub fn parse_vote_responses(vote_pubkey: &str, output: &str) -> Result<ValidatorVoteData> {
+    let mut lines = output.lines();
+
+    result_of(lines.next(), "getHealth")?;
+
+    let accounts: RpcVoteAccountStatus =
+        serde_json::from_value(result_of(lines.next(), "getVoteAccounts")?)
+            .map_err(|e| anyhow!("Invalid getVoteAccounts result from local RPC: {}", e))?;
+    let vote_info: RpcVoteAccountInfo = accounts
+        .current
+        .into_iter()
+        .chain(accounts.delinquent)
+        .find(|account| account.vote_pubkey == vote_pubkey)
+        .ok_or_else(|| anyhow!("Local RPC does not know vote account {}", vote_pubkey))?;
+
+    let account = result_of(lines.next(), "getAccountInfo")?;
+    let encoded = account["value"]["data"][0]
+        .as_str()
+        .ok_or_else(|| anyhow!("Local RPC returned no data for vote account {}", vote_pubkey))?;
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| anyhow!("Invalid vote account data from local RPC: {}", e))?;
+
+    let current_slot = result_of(lines.next(), "getSlot")?
+        .as_u64()
+        .ok_or_else(|| anyhow!("Invalid getSlot result from local RPC"))?;
+
+    build_vote_data(vote_pubkey, &vote_info, &data, current_slot)
+}
+
+/// Parse the getSlot response printed by `local_rpc_command`
+pub fn parse_slot_response(output: &str) -> Result<u64> {
+    result_of(output.lines().next(), "getSlot")?
+        .as_u64()
+        .ok_or_else(|| anyhow!("Invalid getSlot result from local RPC"))
+}
+
+/// Catchup status from the node's own slot and the reference RPC's, worded
+/// like the streamed `solana catchup` status so both read the same
+pub fn catchup_status(local_slot: u64, reference_slot: u64) -> String {
+    match reference_slot.saturating_sub(local_slot) {
+        0 => "Caught up".to_string(),
+        behind => format!("{} slots behind", behind),
+    }
+}
+
+/// Fetch the node's processed slot from its own RPC port over SSH
+pub async fn fetch_slot(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    port: Option<u16>,
+) -> Result<u64> {
+    let command = local_rpc_command(
+        port,
+        &[request("getSlot", serde_json::json!([{"commitment": "processed"}]))],
+    );
+    let output = ssh_pool.execute_command(node, ssh_key, &command).await?;
+    parse_slot_response(&output)
+}
+
+/// Fetch vote data from a node's own RPC port over SSH, for when the
+/// external RPC endpoints are failing
+pub async fn fetch_vote_account_data(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    port: Option<u16>,
+    vote_pubkey: &str,
+) -> Result<ValidatorVoteData> {
+    let command = local_rpc_command(
+        port,
+        &[
+            request("getHealth", serde_json::json!([])),
+            request("getVoteAccounts", serde_json::json!([{"votePubkey": vote_pubkey}])),
+            request("getAccountInfo", serde_json::json!([vote_pubkey, {"encoding": "base64"}])),
+            request("getSlot", serde_json::json!([])),
+        ],
+    );
+    let output = ssh_pool.execute_command(node, ssh_key, &command).await?;
+    parse_vote_responses(vote_pubkey, &output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_rpc_command() {
+        let command = local_rpc_command(Some(8899), &[request("getSlot", serde_json::json!([]))]);
+        assert!(command.starts_with("port=8899; curl -s --max-time 5 http://localhost:$port"));
+        assert!(command.contains(r#""method":"getSlot""#));
+        assert!(command.ends_with("; echo"));
+        assert!(local_rpc_command(None, &[]).ends_with("port=${port:-8899}"));
+    }
+
+    #[test]
+    fn test_parse_vote_responses_errors() {
+        let vote = "Vote111111111111111111111111111111111111111";
+        let healthy = r#"{"jsonrpc":"2.0","result":"ok","id":1}"#;
+        let missing = r#"{"jsonrpc":"2.0","result":{"current":[],"delinquent":[]},"id":1}"#;
+        assert!(parse_vote_responses(vote, &format!("{}\n{}", healthy, missing))
+            .unwrap_err()
+            .to_string()
+            .contains("does not know"));
+
+        // An unhealthy node's vote data is not used
+        let unhealthy = r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Node is behind"},"id":1}"#;
+        let error = parse_vote_responses(vote, unhealthy).unwrap_err().to_string();
+        assert!(error.contains("getHealth") && error.contains("Node is behind"));
+
+        assert!(parse_vote_responses(vote, "").is_err());
+    }
+
+    #[test]
+    fn test_slot_catchup_status() {
+        let slot = parse_slot_response(r#"{"jsonrpc":"2.0","result":345678901,"id":1}"#).unwrap();
+        assert_eq!(slot, 345678901);
+        assert!(parse_slot_response("").is_err());
+
+        assert_eq!(catchup_status(slot, slot), "Caught up");
+        // A node a slot ahead of a lagging reference is caught up too
+        assert_eq!(catchup_status(slot + 1, slot), "Caught up");
+        assert_eq!(catchup_status(slot - 250, slot), "250 slots behind");
+    }
+}
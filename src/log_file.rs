@@ -0,0 +1,154 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::commands::status_ui_v2::LogMessage;
+use crate::types::LogFileConfig;
+
+/// One JSON line for a log message logged at `time`
+pub fn format_entry(log: &LogMessage, time: DateTime<Utc>) -> String {
+    serde_json::json!({
+        "timestamp": time.to_rfc3339_opts(SecondsFormat::Millis, true),
+        "unix_ms": time.timestamp_millis(),
+        "level": log.level.label().to_lowercase(),
+        "host": log.host,
+        "message": log.message,
+    })
+    .to_string()
+}
+
+/// Path of the `n`th rotated file, e.g. `svs.log.2`
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+/// Append-only JSON lines file, rotated to `<path>.1` ... `<path>.<max_files>`
+/// once it would grow past `max_bytes`
+pub struct JsonLogFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl JsonLogFile {
+    pub fn open(path: &Path, max_bytes: u64, max_files: usize) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| anyhow!("Failed to open log file {}: {}", path.display(), e))?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            max_bytes,
+            max_files,
+            file,
+            size,
+        })
+    }
+
+    pub fn write_line(&mut self, line: &str) -> Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.size > 0 && self.size + len > self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.size += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        if self.max_files > 0 {
+            for n in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    std::fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+
+    /// Open the file named in the config, expanding a leading `~/`
+    pub fn from_config(config: &LogFileConfig) -> Result<Self> {
+        let path = match config.path.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()
+                .ok_or_else(|| anyhow!("Could not determine home directory"))?
+                .join(rest),
+            None => PathBuf::from(&config.path),
+        };
+        Self::open(&path, config.max_size_mb.max(1) * 1024 * 1024, config.max_files)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a monitor log message with the wall-clock time it was logged
+    pub fn write_message(&mut self, log: &LogMessage) -> Result<()> {
+        let time = Utc::now()
+            - chrono::Duration::from_std(log.timestamp.elapsed()).unwrap_or_else(|_| chrono::Duration::zero());
+        self.write_line(&format_entry(log, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::status_ui_v2::LogLevel;
+    use std::time::Instant;
+
+    #[test]
+    fn test_format_entry() {
+        let log = LogMessage {
+            host: "node-a".to_string(),
+            message: "SSH \"check\" failed".to_string(),
+            timestamp: Instant::now(),
+            level: LogLevel::Warning,
+        };
+        let time = DateTime::parse_from_rfc3339("2026-01-02T03:04:05.678Z").unwrap().with_timezone(&Utc);
+        let entry: serde_json::Value = serde_json::from_str(&format_entry(&log, time)).unwrap();
+
+        assert_eq!(entry["timestamp"], "2026-01-02T03:04:05.678Z");
+        assert_eq!(entry["unix_ms"], 1767323045678i64);
+        assert_eq!(entry["level"], "warn");
+        assert_eq!(entry["host"], "node-a");
+        assert_eq!(entry["message"], "SSH \"check\" failed");
+    }
+
+    #[test]
+    fn test_rotation() {
+        let dir = std::env::temp_dir().join(format!("svs-log-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("svs.log");
+
+        let mut log_file = JsonLogFile::open(&path, 10, 2).unwrap();
+        for line in ["first", "second", "third", "fourth"] {
+            log_file.write_line(line).unwrap();
+        }
+
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(path.clone()), "fourth\n");
+        assert_eq!(read(rotated_path(&path, 1)), "third\n");
+        assert_eq!(read(rotated_path(&path, 2)), "second\n");
+        assert!(!rotated_path(&path, 3).exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
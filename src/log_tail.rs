@@ -0,0 +1,181 @@
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::collections::VecDeque;
+
+use crate::types::NodeConfig;
+
+/// Lines kept for scrolling back
+const SCROLLBACK_LINES: usize = 5000;
+
+/// Lines of history the log view starts with
+pub const VIEW_HISTORY_LINES: usize = 200;
+
+/// Longest log line quoted in an alert
+const SNIPPET_CHARS: usize = 300;
+
+/// Follows the log of the running validator: the `--log`/`-o` file of
+/// agave/solana-validator, the `[log] path` of the fdctl config, else the
+/// journal of the systemd unit the validator runs in. The patterns are
+/// bracketed so pgrep doesn't find this shell.
+const LOG_DETECTION: &str = "pid=$(pgrep -o -f '[b]in/fdctl|[b]in/agave-validator|[r]elease/agave-validator|[b]in/solana-validator|[r]elease/solana-validator'); \
+    if [ -z \"$pid\" ]; then echo 'No running validator process found; set commands.logs for this node'; exit 1; fi; \
+    args=$(tr '\\0' ' ' < /proc/$pid/cmdline); \
+    log=$(echo \"$args\" | grep -oE -- '(--log| -o) [^ ]+' | head -1 | awk '{print $2}'); \
+    config=$(echo \"$args\" | grep -oE -- '--config [^ ]+' | head -1 | awk '{print $2}'); \
+    if [ -z \"$log\" ] && [ -n \"$config\" ]; then log=$(awk '/^\\[log\\]/ {s=1; next} /^\\[/ {s=0} s && $1 == \"path\" {gsub(/\"/, \"\", $3); print $3}' \"$config\" 2>/dev/null | head -1); fi; \
+    if [ -n \"$log\" ] && [ \"$log\" != \"-\" ]; then exec tail -n {lines} -F \"$log\"; fi; \
+    unit=$(ps -o unit= -p \"$pid\" | tr -d ' '); \
+    exec journalctl -u \"$unit\" -n {lines} -f -o cat";
+
+/// Command streaming the node's validator log, starting with
+/// `history_lines` earlier lines: the configured `logs` command, else the
+/// detected log file or journal
+pub fn tail_command(node: &NodeConfig, history_lines: usize) -> String {
+    node.logs_command()
+        .map(str::to_string)
+        .unwrap_or_else(|| LOG_DETECTION.replace("{lines}", &history_lines.to_string()))
+}
+
+/// Compile the configured alert patterns, naming the first invalid one
+pub fn compile_patterns(patterns: &[String]) -> Result<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern).map_err(|e| anyhow!("Invalid log pattern '{}': {}", pattern, e))
+        })
+        .collect()
+}
+
+/// The first pattern a streamed line matches. Lines the tail command itself
+/// printed on stderr are not validator log lines.
+pub fn matching_pattern<'a>(patterns: &'a [Regex], line: &str) -> Option<&'a Regex> {
+    if line.starts_with("[ERROR] ") {
+        return None;
+    }
+    patterns.iter().find(|pattern| pattern.is_match(line))
+}
+
+/// A log line shortened for an alert
+pub fn snippet(line: &str) -> String {
+    let line = line.trim();
+    if line.chars().count() <= SNIPPET_CHARS {
+        return line.to_string();
+    }
+    let mut short: String = line.chars().take(SNIPPET_CHARS).collect();
+    short.push('…');
+    short
+}
+
+/// Streamed log lines of one node and how far the view is scrolled back
+#[derive(Debug, Clone, Default)]
+pub struct LogTail {
+    pub node_label: String,
+    pub lines: VecDeque<String>,
+    pub scroll: usize,          // Lines above the newest; 0 follows the log
+    pub ended: Option<String>, // Why the stream stopped
+}
+
+impl LogTail {
+    pub fn new(node_label: &str) -> Self {
+        Self {
+            node_label: node_label.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&mut self, line: &str) {
+        if self.lines.len() == SCROLLBACK_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line.trim_end_matches(['\r', '\n']).to_string());
+        // Keep a scrolled back view on the same lines while new ones arrive
+        if self.scroll > 0 {
+            self.scroll = (self.scroll + 1).min(self.lines.len().saturating_sub(1));
+        }
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.lines.len().saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    pub fn follow(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// The `height` lines ending `scroll` lines above the newest
+    pub fn visible(&self, height: usize) -> impl Iterator<Item = &String> {
+        let end = self.lines.len().saturating_sub(self.scroll);
+        self.lines.range(end.saturating_sub(height)..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_command() {
+        let mut node: NodeConfig = serde_yaml::from_str(
+            r#"
+label: node-a
+host: 10.0.0.4
+paths: { fundedIdentity: /f.json, unfundedIdentity: /u.json, voteKeypair: /v.json }
+"#,
+        )
+        .unwrap();
+        let command = tail_command(&node, VIEW_HISTORY_LINES);
+        assert!(command.contains("tail -n 200 -F \"$log\""));
+        assert!(command.contains("journalctl -u \"$unit\" -n 200 -f -o cat"));
+        assert!(tail_command(&node, 0).contains("tail -n 0 -F"));
+
+        node.commands = Some(crate::types::NodeCommands {
+            logs: Some("docker logs -f --tail 200 agave".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(tail_command(&node, 0), "docker logs -f --tail 200 agave");
+    }
+
+    #[test]
+    fn test_log_patterns() {
+        let patterns = compile_patterns(&["panicked".to_string(), "(?i)too many open files".to_string()]).unwrap();
+        let line = "[2025-01-10T12:00:00Z ERROR solana_core] thread 'solReplay' panicked at 'blockstore error'";
+        assert_eq!(matching_pattern(&patterns, line).unwrap().as_str(), "panicked");
+        assert_eq!(
+            matching_pattern(&patterns, "accept failed: Too Many Open Files").unwrap().as_str(),
+            "(?i)too many open files"
+        );
+        assert!(matching_pattern(&patterns, "[ERROR] tail: panicked.log has been replaced").is_none());
+        assert!(matching_pattern(&patterns, "new root 345678901").is_none());
+
+        assert!(compile_patterns(&["(unclosed".to_string()]).unwrap_err().to_string().contains("(unclosed"));
+
+        assert_eq!(snippet("  short  "), "short");
+        let long = "x".repeat(400);
+        assert_eq!(snippet(&long).chars().count(), SNIPPET_CHARS + 1);
+    }
+
+    #[test]
+    fn test_log_tail_scrollback() {
+        let mut tail = LogTail::new("node-a");
+        for i in 0..10 {
+            tail.push(&format!("line {}\n", i));
+        }
+        let shown: Vec<&String> = tail.visible(3).collect();
+        assert_eq!(shown, ["line 7", "line 8", "line 9"]);
+
+        // Scrolled back, new lines don't move the view
+        tail.scroll_up(5);
+        tail.push("line 10");
+        let shown: Vec<&String> = tail.visible(2).collect();
+        assert_eq!(shown, ["line 3", "line 4"]);
+
+        tail.scroll_up(100);
+        assert_eq!(tail.visible(2).next().unwrap(), "line 0");
+        tail.follow();
+        assert_eq!(tail.visible(1).next().unwrap(), "line 10");
+    }
+}
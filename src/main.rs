@@ -24,19 +24,45 @@ mod alert_integration_tests;
 mod status_ui_alert_tests;
 #[cfg(test)]
 mod auto_failover_tests;
+mod audit;
 mod commands;
 mod config;
+mod demotion_check;
+mod grafana;
+mod health_endpoint;
+mod history;
+mod influxdb;
+mod kiosk;
+mod local_rpc;
+mod log_file;
+mod log_tail;
 mod emergency_failover;
+mod host_checks;
+mod instance_lock;
+mod proxy;
+mod redact;
+mod rpc_limiter;
+mod rpc_selector;
+mod secrets;
+mod sfdp;
 mod solana_rpc;
 mod ssh;
 mod ssh_key_detector;
 mod startup;
 mod startup_checks;
 mod startup_logger;
+mod statsd;
+mod switch_lock;
+mod syslog;
+mod systemd_notify;
+mod telegram_bot;
 mod types;
 mod validator_metadata;
+mod vote_resumption;
+mod vote_subscription;
+mod warnings;
 
-use commands::{status_command, switch_command, test_alert_command};
+use commands::{onboard_command, status_command, switch_command, test_alert_command};
 use ssh::AsyncSshPool;
 
 #[derive(Parser)]
@@ -44,22 +70,152 @@ use ssh::AsyncSshPool;
 #[command(about = "Solana Validator Switch - Interactive CLI for validator management")]
 #[command(version = env!("CARGO_PKG_VERSION"))]
 struct Cli {
+    /// Use ~/.solana-validator-switch/profiles/<PROFILE>.yaml instead of
+    /// config.yaml (also read from SVS_PROFILE)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Create a config file with a guided wizard that probes the nodes
+    Init,
     /// Check current validator status
-    Status,
+    Status {
+        /// Read-only dashboard for shared displays: hides hosts, paths and
+        /// full pubkeys, disables switch controls and retries until nodes are reachable
+        #[arg(long)]
+        kiosk: bool,
+    },
+    /// Monitor validators without the terminal UI, logging to stdout
+    Watch,
+    /// Install `svs watch` as a systemd unit, launchd agent or scheduled task
+    InstallService {
+        /// Service name
+        #[arg(long, default_value = "svs")]
+        name: String,
+        /// User the service runs as (defaults to the invoking user)
+        #[arg(long)]
+        user: Option<String>,
+        /// Print the generated definition instead of installing it
+        #[arg(long)]
+        print: bool,
+    },
     /// Switch between primary and backup validators
     Switch {
         /// Preview switch without executing
         #[arg(short, long)]
         dry_run: bool,
+        /// Wait for the next epoch rollover, then switch automatically
+        #[arg(long, conflicts_with = "dry_run")]
+        at_epoch_boundary: bool,
+        /// Slots after the first slot of the next epoch to wait before switching
+        #[arg(long, default_value_t = 50, requires = "at_epoch_boundary")]
+        slot_offset: u64,
+        /// Wait until no leader slot is due for switch.min_leader_gap_slots, then switch
+        #[arg(long, conflicts_with = "dry_run")]
+        wait_for_window: bool,
+        /// Switch even if the standby is further behind than switch.max_standby_slots_behind
+        #[arg(long)]
+        force: bool,
+        /// Validator to switch, numbered from 1 in config order
+        #[arg(long, default_value_t = 1)]
+        validator: usize,
+        /// Don't prompt: run the checks, switch and exit with 0 (switched),
+        /// 1 (failed or not voting afterwards) or 2 (refused or not started)
+        #[arg(short, long, conflicts_with = "dry_run")]
+        yes: bool,
     },
     /// Test alert configuration
     TestAlert,
+    /// Send a simulated delinquency through alerts and shadow-mode auto-failover
+    Drill {
+        /// Validator to simulate, numbered from 1 in config order
+        #[arg(long, default_value_t = 1)]
+        validator: usize,
+    },
+    /// Guided first switch that verifies every prerequisite
+    Onboard,
+    /// Switch back to the node that was active before the last switch or takeover
+    Failback {
+        /// Validator to fail back, numbered from 1 in config order
+        #[arg(long, default_value_t = 1)]
+        validator: usize,
+    },
+    /// Export or compare configuration files
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Delegation program report: uptime, skip rate, delinquency and version currency
+    SfdpReport {
+        /// Report window in days
+        #[arg(long, default_value_t = 30)]
+        window_days: u64,
+        /// Output format: csv or json
+        #[arg(long, default_value = "csv")]
+        format: String,
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Print a Grafana dashboard for the exported metrics
+    GrafanaDashboard {
+        /// Write the dashboard JSON to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Check the hash chain of the operator audit log
+    VerifyAudit {
+        /// Audit log to check (defaults to ~/.solana-validator-switch/audit.log)
+        path: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print a config snapshot suitable for sharing
+    Export {
+        /// Mask secrets, hosts and private paths
+        #[arg(short, long)]
+        redacted: bool,
+        /// Write the snapshot to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Compare two config files
+    Diff {
+        /// Path to the first config file
+        left: String,
+        /// Path to the second config file
+        right: String,
+        /// Mask secrets, hosts and private paths in the output
+        #[arg(short, long)]
+        redacted: bool,
+    },
+    /// Update nodes from an Ansible inventory or `terraform output -json`
+    ImportInventory {
+        /// Inventory file (Ansible YAML, `ansible-inventory --list` JSON or Terraform output JSON)
+        path: String,
+        /// Inventory format: ansible or terraform (detected when omitted)
+        #[arg(short, long)]
+        format: Option<String>,
+        /// Save the changes to the config file (otherwise only show them)
+        #[arg(short, long)]
+        write: bool,
+    },
+    /// Print the configured nodes as an Ansible inventory or Terraform variables
+    ExportInventory {
+        /// Output format: ansible or terraform
+        #[arg(short, long, default_value = "ansible")]
+        format: String,
+        /// Write the inventory to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 /// Application state that persists throughout the CLI session
@@ -70,6 +226,7 @@ pub struct AppState {
     pub validator_statuses: Vec<ValidatorStatus>,
     pub metadata_cache: Arc<tokio::sync::Mutex<validator_metadata::MetadataCache>>,
     pub detected_ssh_keys: std::collections::HashMap<String, String>, // host -> key_path mapping
+    pub rpc_selectors: Vec<Arc<tokio::sync::RwLock<rpc_selector::RpcSelector>>>, // per validator, same order as validator_statuses
 }
 
 #[derive(Debug, Clone)]
@@ -80,21 +237,125 @@ pub struct ValidatorStatus {
 }
 
 impl AppState {
-    async fn new() -> Result<Option<Self>> {
+    async fn new(assume_yes: bool) -> Result<Option<Self>> {
         // Use the comprehensive startup checklist
-        startup::run_startup_checklist().await
+        startup::run_startup_checklist(assume_yes).await
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // ssh runs svs as its ProxyCommand when a proxy is configured
+    if let Some((host, port)) = proxy::proxy_command_target() {
+        return proxy::run_proxy_command(&host, port).await;
+    }
+
+    // ssh runs svs as its password helper for nodes with passwordAuth
+    if ssh::is_askpass() {
+        let prompt = std::env::args().nth(1).unwrap_or_default();
+        match ssh::askpass_reply(&prompt) {
+            Some(password) => {
+                println!("{}", password);
+                return Ok(());
+            }
+            None => std::process::exit(1),
+        }
+    }
+
     let cli = Cli::parse();
 
+    // Every ConfigManager, and ssh helpers run as child processes, pick the
+    // profile up from the environment
+    if let Some(profile) = &cli.profile {
+        config::validate_profile_name(profile)?;
+        std::env::set_var(config::PROFILE_ENV, profile);
+    }
+
+    // Config utilities only work on files and don't need SSH connections
+    if let Some(Commands::Config { action }) = &cli.command {
+        return match action {
+            ConfigAction::Export { redacted, output } => {
+                commands::config::config_export_command(*redacted, output.as_deref())
+            }
+            ConfigAction::Diff {
+                left,
+                right,
+                redacted,
+            } => commands::config::config_diff_command(left, right, *redacted),
+            ConfigAction::ImportInventory {
+                path,
+                format,
+                write,
+            } => commands::inventory::inventory_import_command(path, format.as_deref(), *write),
+            ConfigAction::ExportInventory { format, output } => {
+                commands::inventory::inventory_export_command(format, output.as_deref())
+            }
+        };
+    }
+
+    if let Some(Commands::GrafanaDashboard { output }) = &cli.command {
+        let dashboard = serde_json::to_string_pretty(&grafana::dashboard_json())? + "\n";
+        match output {
+            Some(path) => {
+                std::fs::write(path, dashboard)?;
+                println!("✅ Dashboard written to {}", path);
+            }
+            None => print!("{}", dashboard),
+        }
+        return Ok(());
+    }
+
+    if let Some(Commands::VerifyAudit { path }) = &cli.command {
+        return audit::verify_audit_command(path.as_deref());
+    }
+
+    if let Some(Commands::Init) = &cli.command {
+        return commands::init::init_command().await;
+    }
+
+    if let Some(Commands::InstallService { name, user, print }) = &cli.command {
+        return commands::service::install_service_command(name, user.clone(), *print);
+    }
+
+    // Only one process may monitor a config, otherwise alerts and
+    // auto-failover would fire twice
+    let _instance_lock = match &cli.command {
+        Some(Commands::Status { .. }) | Some(Commands::Watch) | None => {
+            let config_manager = config::ConfigManager::new()?;
+            Some(instance_lock::InstanceLock::acquire(
+                &instance_lock::lock_path_for(config_manager.get_config_path()),
+            )?)
+        }
+        _ => None,
+    };
+
+    // Kiosk displays run unattended, so keep retrying startup instead of
+    // exiting when a node is unreachable
+    if let Some(Commands::Status { kiosk: true }) = &cli.command {
+        loop {
+            if let Ok(Some(state)) = AppState::new(false).await {
+                return commands::status::kiosk_command(&state).await;
+            }
+            println!("⏳ Kiosk mode: retrying startup in 30 seconds...");
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+        }
+    }
+
+    // `svs switch --yes` runs unattended, so nothing may wait for a key
+    let assume_yes = matches!(&cli.command, Some(Commands::Switch { yes: true, .. }));
+
     // Initialize app state with persistent SSH connections
-    let app_state = AppState::new().await?;
+    let app_state = AppState::new(assume_yes).await?;
+
+    // Ship log messages and switch events to syslog when configured
+    if let Some(syslog_config) = app_state.as_ref().and_then(|state| state.config.syslog.as_ref()) {
+        if let Err(e) = syslog::init(syslog_config) {
+            eprintln!("⚠️  Syslog disabled: {}", e);
+        }
+    }
 
     match cli.command {
-        Some(Commands::Status) => {
+        Some(Commands::Status { .. }) => {
             if let Some(state) = app_state.as_ref() {
                 status_command(state).await?;
             } else {
@@ -102,9 +363,49 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Some(Commands::Switch { dry_run }) => {
+        Some(Commands::Switch {
+            dry_run,
+            at_epoch_boundary,
+            slot_offset,
+            wait_for_window,
+            force,
+            validator,
+            yes,
+        }) => {
             if let Some(mut state) = app_state {
-                let show_status = switch_command(dry_run, &mut state).await?;
+                let validator_idx = validator
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow::anyhow!("Validators are numbered from 1"))?;
+                let result = if at_epoch_boundary {
+                    commands::switch::switch_at_epoch_boundary(
+                        &mut state,
+                        validator_idx,
+                        slot_offset,
+                        force,
+                        wait_for_window,
+                        yes,
+                    )
+                    .await
+                } else if wait_for_window {
+                    commands::switch::switch_in_leader_gap(&mut state, validator_idx, force, yes)
+                        .await
+                } else {
+                    switch_command(dry_run, force, yes, validator_idx, &mut state).await
+                };
+                if yes {
+                    // Refused by a pre-switch check or not started: 2, failed: 1
+                    let code = match &result {
+                        Ok(true) => 0,
+                        Ok(false) => 2,
+                        Err(e) if e.downcast_ref::<commands::switch::SwitchRefused>().is_some() => 2,
+                        Err(_) => 1,
+                    };
+                    if let Err(e) = &result {
+                        eprintln!("Error: {:?}", e);
+                    }
+                    std::process::exit(code);
+                }
+                let show_status = result?;
                 if show_status && !dry_run {
                     status_command(&state).await?;
                 }
@@ -113,6 +414,33 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
+        Some(Commands::Watch) => {
+            if let Some(state) = app_state.as_ref() {
+                commands::status::watch_command(state).await?;
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::SfdpReport {
+            window_days,
+            format,
+            output,
+        }) => {
+            if let Some(state) = app_state.as_ref() {
+                let report = sfdp::render(&sfdp::build_report(state, window_days).await, &format)?;
+                match output {
+                    Some(path) => {
+                        std::fs::write(&path, report)?;
+                        println!("✅ Report written to {}", path);
+                    }
+                    None => print!("{}", report),
+                }
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
         Some(Commands::TestAlert) => {
             if let Some(state) = app_state.as_ref() {
                 test_alert_command(state).await?;
@@ -121,6 +449,41 @@ async fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
+        Some(Commands::Drill { validator }) => {
+            if let Some(state) = app_state.as_ref() {
+                commands::drill::drill_command(state, validator).await?;
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Onboard) => {
+            if let Some(mut state) = app_state {
+                let show_status = onboard_command(&mut state).await?;
+                if show_status {
+                    status_command(&state).await?;
+                }
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Failback { validator }) => {
+            if let Some(mut state) = app_state {
+                let show_status = commands::failback::failback_command(&mut state, validator).await?;
+                if show_status {
+                    status_command(&state).await?;
+                }
+            } else {
+                // Startup validation already showed detailed error messages
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Init)
+        | Some(Commands::Config { .. })
+        | Some(Commands::GrafanaDashboard { .. })
+        | Some(Commands::VerifyAudit { .. })
+        | Some(Commands::InstallService { .. }) => unreachable!(),
         None => {
             // Interactive main menu only if app state is valid
             if let Some(state) = app_state {
@@ -200,6 +563,7 @@ async fn show_switch_menu(app_state: &mut AppState) -> Result<()> {
         let mut options = vec![
             "🔄 Switch - Switch between primary and backup validators",
             "🧪 Dry Run - Preview switch without executing",
+            "🧭 Guided First Switch - Verify prerequisites step by step",
         ];
 
         options.push("⬅️  Back to main menu");
@@ -210,7 +574,7 @@ async fn show_switch_menu(app_state: &mut AppState) -> Result<()> {
 
         match index {
             0 => {
-                let show_status = switch_command(false, app_state).await?;
+                let show_status = switch_command(false, false, false, 0, app_state).await?;
                 if show_status {
                     status_command(app_state).await?;
                 }
@@ -218,10 +582,17 @@ async fn show_switch_menu(app_state: &mut AppState) -> Result<()> {
                 break;
             }
             1 => {
-                let _ = switch_command(true, app_state).await?;
+                let _ = switch_command(true, false, false, 0, app_state).await?;
                 // Dry run doesn't show status
             }
-            2 => break, // Back to main menu
+            2 => {
+                let show_status = onboard_command(app_state).await?;
+                if show_status {
+                    status_command(app_state).await?;
+                    break;
+                }
+            }
+            3 => break, // Back to main menu
             _ => unreachable!(),
         }
     }
@@ -0,0 +1,206 @@
+use solana_client::client_error::ClientError;
+use solana_client::rpc_client::RpcClient;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::types::RpcRateLimitConfig;
+
+/// Tries per request while the endpoint keeps answering 429
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Pause after the first 429; doubles with every consecutive one
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// 429 backoff of one endpoint
+#[derive(Debug, Clone, Copy)]
+struct Backoff {
+    paused_until: Instant,
+    streak: u32,
+}
+
+/// Shared request budget. Requests are spaced `interval` apart; requests to
+/// an endpoint that answered 429 also wait for its backoff, while the other
+/// endpoints carry on.
+struct Limiter {
+    interval: Duration,
+    max_backoff: Duration,
+    next_slot: Option<Instant>,
+    backoffs: HashMap<String, Backoff>,
+}
+
+impl Limiter {
+    fn new(config: &RpcRateLimitConfig) -> Self {
+        let interval = if config.requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / config.requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            interval,
+            max_backoff: Duration::from_secs(config.max_backoff_seconds),
+            next_slot: None,
+            backoffs: HashMap::new(),
+        }
+    }
+
+    /// How long `url` is still backing off from a 429
+    fn pause_remaining(&self, url: &str, now: Instant) -> Option<Duration> {
+        self.backoffs
+            .get(url)
+            .map(|backoff| backoff.paused_until.saturating_duration_since(now))
+            .filter(|pause| !pause.is_zero())
+    }
+
+    /// Reserve the next free request slot and return how long to wait for it
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let start = self.next_slot.map_or(now, |slot| slot.max(now));
+        self.next_slot = Some(start + self.interval);
+        start - now
+    }
+
+    fn record_throttled(&mut self, url: &str, now: Instant) -> Duration {
+        let streak = self.backoffs.get(url).map_or(0, |backoff| backoff.streak);
+        let backoff = backoff_delay(streak, self.max_backoff);
+        self.backoffs.insert(
+            url.to_string(),
+            Backoff {
+                paused_until: now + backoff,
+                streak: streak.saturating_add(1),
+            },
+        );
+        backoff
+    }
+
+    fn record_ok(&mut self, url: &str) {
+        self.backoffs.remove(url);
+    }
+}
+
+static LIMITER: OnceLock<Mutex<Limiter>> = OnceLock::new();
+
+/// Apply the configured budget; without a call the defaults are used
+pub fn init(config: &RpcRateLimitConfig) {
+    let _ = LIMITER.set(Mutex::new(Limiter::new(config)));
+}
+
+fn limiter() -> &'static Mutex<Limiter> {
+    LIMITER.get_or_init(|| Mutex::new(Limiter::new(&RpcRateLimitConfig::default())))
+}
+
+/// Backoff after `streak` earlier consecutive 429s, doubling up to the cap
+pub fn backoff_delay(streak: u32, max_backoff: Duration) -> Duration {
+    INITIAL_BACKOFF
+        .saturating_mul(2u32.saturating_pow(streak.min(16)))
+        .min(max_backoff)
+}
+
+/// Whether an RPC error is the endpoint throttling us rather than failing
+pub fn is_rate_limited(error: &str) -> bool {
+    error.to_ascii_lowercase().contains("too many requests")
+}
+
+/// Run a blocking RpcClient request. On the multi-threaded runtime the
+/// worker hands its other tasks off first, so a slow endpoint doesn't stall
+/// the UI, the bot and everything else scheduled on the same thread.
+fn blocking<T>(request: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(request)
+        }
+        _ => request(),
+    }
+}
+
+/// Run a blocking RPC request on `rpc_client` within the shared budget. A
+/// 429 pauses requests to that endpoint for the backoff and the request is
+/// retried, so a throttling public endpoint slows its own queries down
+/// instead of failing them. Errors are boxed, `ClientError` is large.
+pub async fn call<T>(
+    rpc_client: &RpcClient,
+    mut request: impl FnMut(&RpcClient) -> Result<T, Box<ClientError>>,
+) -> Result<T, Box<ClientError>> {
+    let url = rpc_client.url();
+    let mut attempt = 1;
+    loop {
+        let pause = limiter().lock().unwrap().pause_remaining(&url, Instant::now());
+        if let Some(pause) = pause {
+            tokio::time::sleep(pause).await;
+        }
+        let wait = limiter().lock().unwrap().reserve(Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+
+        match blocking(|| request(rpc_client)) {
+            Ok(value) => {
+                limiter().lock().unwrap().record_ok(&url);
+                return Ok(value);
+            }
+            Err(e) if is_rate_limited(&e.to_string()) => {
+                limiter().lock().unwrap().record_throttled(&url, Instant::now());
+                if attempt >= MAX_ATTEMPTS {
+                    return Err(e);
+                }
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_limiter(requests_per_second: f64) -> Limiter {
+        Limiter::new(&RpcRateLimitConfig {
+            requests_per_second,
+            max_backoff_seconds: 8,
+        })
+    }
+
+    #[test]
+    fn test_reserve_spaces_requests() {
+        let mut l = test_limiter(4.0);
+        let now = Instant::now();
+        assert_eq!(l.reserve(now), Duration::ZERO);
+        assert_eq!(l.reserve(now), Duration::from_millis(250));
+        assert_eq!(l.reserve(now), Duration::from_millis(500));
+        // Unused budget does not accumulate into a burst
+        let later = now + Duration::from_secs(10);
+        assert_eq!(l.reserve(later), Duration::ZERO);
+        assert_eq!(l.reserve(later), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_throttling_pauses_and_backs_off() {
+        let mut l = test_limiter(0.0);
+        let now = Instant::now();
+        let throttled = "https://api.mainnet-beta.solana.com";
+        let healthy = "https://rpc.example.com";
+        assert_eq!(l.reserve(now), Duration::ZERO);
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(1));
+        assert_eq!(l.pause_remaining(throttled, now), Some(Duration::from_secs(1)));
+        // Other endpoints and the shared budget are not held up
+        assert_eq!(l.pause_remaining(healthy, now), None);
+        assert_eq!(l.reserve(now), Duration::ZERO);
+        assert_eq!(l.pause_remaining(throttled, now + Duration::from_secs(1)), None);
+
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(2));
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(4));
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(8));
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(8));
+        l.record_ok(throttled);
+        assert_eq!(l.record_throttled(throttled, now), Duration::from_secs(1));
+        assert_eq!(l.record_throttled(healthy, now), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_is_rate_limited() {
+        assert!(is_rate_limited(
+            "HTTP status client error (429 Too Many Requests) for url (https://api.mainnet-beta.solana.com/)"
+        ));
+        assert!(!is_rate_limited("error sending request: operation timed out"));
+    }
+}
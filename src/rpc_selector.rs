@@ -0,0 +1,245 @@
+use anyhow::{anyhow, Result};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Endpoints lagging the freshest endpoint by more than this many slots are
+/// treated as unhealthy even if they respond quickly, and shown as stale
+pub const MAX_SLOT_LAG: u64 = 50;
+
+/// Weight given to the newest latency sample in the moving average
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+#[derive(Debug, Clone)]
+pub struct EndpointHealth {
+    pub url: String,
+    pub latency: Option<Duration>, // Smoothed response latency
+    pub last_slot: Option<u64>,    // Last slot reported by this endpoint
+    pub consecutive_failures: u32,
+    pub last_checked: Option<Instant>,
+    pub reference_only: bool, // Probed for its slot, never picked for queries
+}
+
+/// Tracks health of every RPC endpoint for a validator and picks the best one per query
+#[derive(Debug, Clone)]
+pub struct RpcSelector {
+    endpoints: Vec<EndpointHealth>,
+}
+
+impl RpcSelector {
+    pub fn new(urls: Vec<String>) -> Self {
+        Self {
+            endpoints: urls
+                .into_iter()
+                .map(|url| EndpointHealth {
+                    url,
+                    latency: None,
+                    last_slot: None,
+                    consecutive_failures: 0,
+                    last_checked: None,
+                    reference_only: false,
+                })
+                .collect(),
+        }
+    }
+
+    /// Add an endpoint that only serves as the slot reference for staleness
+    pub fn with_reference(mut self, url: Option<String>) -> Self {
+        if let Some(url) = url.filter(|url| !self.endpoints.iter().any(|e| &e.url == url)) {
+            self.endpoints.push(EndpointHealth {
+                url,
+                latency: None,
+                last_slot: None,
+                consecutive_failures: 0,
+                last_checked: None,
+                reference_only: true,
+            });
+        }
+        self
+    }
+
+    pub fn endpoints(&self) -> &[EndpointHealth] {
+        &self.endpoints
+    }
+
+    /// Return the healthiest endpoint: responsive, caught up with the freshest
+    /// endpoint and with the lowest latency. Falls back to the endpoint with the
+    /// fewest failures when none is healthy, keeping config order on ties.
+    pub fn best(&self) -> String {
+        let freshest_slot = self.freshest_slot();
+
+        let is_fresh = |e: &EndpointHealth| match (e.last_slot, freshest_slot) {
+            (Some(slot), Some(max)) => max.saturating_sub(slot) <= MAX_SLOT_LAG,
+            _ => true,
+        };
+
+        let candidates = || self.endpoints.iter().filter(|e| !e.reference_only);
+        let healthy = candidates()
+            .filter(|e| e.consecutive_failures == 0 && is_fresh(e))
+            // Unmeasured endpoints sort after measured ones
+            .min_by_key(|e| e.latency.unwrap_or(Duration::MAX));
+
+        healthy
+            .or_else(|| candidates().min_by_key(|e| e.consecutive_failures))
+            .map(|e| e.url.clone())
+            .unwrap_or_default()
+    }
+
+    /// Highest slot any endpoint, the reference included, last reported
+    pub fn freshest_slot(&self) -> Option<u64> {
+        self.endpoints.iter().filter_map(|e| e.last_slot).max()
+    }
+
+    /// Slots `url` trails the freshest endpoint by, once both reported a slot
+    pub fn slot_lag(&self, url: &str) -> Option<u64> {
+        let freshest_slot = self.freshest_slot()?;
+        let slot = self.endpoints.iter().find(|e| e.url == url)?.last_slot?;
+        Some(freshest_slot.saturating_sub(slot))
+    }
+
+    /// Slots behind when `url` lags by more than `MAX_SLOT_LAG`
+    pub fn stale_by(&self, url: &str) -> Option<u64> {
+        self.slot_lag(url).filter(|lag| *lag > MAX_SLOT_LAG)
+    }
+
+    pub fn record_success(&mut self, url: &str, latency: Duration, slot: Option<u64>) {
+        if let Some(endpoint) = self.endpoints.iter_mut().find(|e| e.url == url) {
+            endpoint.latency = Some(match endpoint.latency {
+                Some(previous) => Duration::from_secs_f64(
+                    previous.as_secs_f64() * (1.0 - LATENCY_SMOOTHING)
+                        + latency.as_secs_f64() * LATENCY_SMOOTHING,
+                ),
+                None => latency,
+            });
+            if slot.is_some() {
+                endpoint.last_slot = slot;
+            }
+            endpoint.consecutive_failures = 0;
+            endpoint.last_checked = Some(Instant::now());
+        }
+    }
+
+    pub fn record_failure(&mut self, url: &str) {
+        if let Some(endpoint) = self.endpoints.iter_mut().find(|e| e.url == url) {
+            endpoint.consecutive_failures += 1;
+            endpoint.last_checked = Some(Instant::now());
+        }
+    }
+}
+
+/// Healthiest endpoint for a validator, or the fallback when it has no selector
+pub async fn best_rpc(selectors: &[Arc<RwLock<RpcSelector>>], validator_idx: usize, fallback: &str) -> String {
+    match selectors.get(validator_idx) {
+        Some(selector) => {
+            let best = selector.read().await.best();
+            if best.is_empty() {
+                fallback.to_string()
+            } else {
+                best
+            }
+        }
+        None => fallback.to_string(),
+    }
+}
+
+/// Probe every endpoint of every selector and record the results
+pub async fn probe_all(selectors: &[Arc<RwLock<RpcSelector>>]) {
+    for selector in selectors {
+        let urls: Vec<String> = selector
+            .read()
+            .await
+            .endpoints()
+            .iter()
+            .map(|e| e.url.clone())
+            .collect();
+
+        // A single endpoint has nothing to choose between
+        if urls.len() < 2 {
+            continue;
+        }
+
+        for url in urls {
+            let result = probe_endpoint(&url).await;
+            let mut selector = selector.write().await;
+            match result {
+                Ok((latency, slot)) => selector.record_success(&url, latency, Some(slot)),
+                Err(_) => selector.record_failure(&url),
+            }
+        }
+    }
+}
+
+/// Probe an endpoint with getSlot, returning the response latency and slot
+pub async fn probe_endpoint(url: &str) -> Result<(Duration, u64)> {
+    let rpc_client = crate::solana_rpc::new_rpc_client(url, Duration::from_secs(3))?;
+    let start = Instant::now();
+    let slot = crate::rpc_limiter::call(&rpc_client, |client| client.get_slot().map_err(Box::new))
+        .await
+        .map_err(|e| anyhow!("Failed to probe {}: {}", url, e))?;
+    Ok((start.elapsed(), slot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector() -> RpcSelector {
+        RpcSelector::new(vec![
+            "https://a.example".to_string(),
+            "https://b.example".to_string(),
+            "https://c.example".to_string(),
+        ])
+    }
+
+    #[test]
+    fn test_best_prefers_lowest_latency() {
+        let mut s = selector();
+        s.record_success("https://a.example", Duration::from_millis(300), Some(1000));
+        s.record_success("https://b.example", Duration::from_millis(50), Some(1000));
+        s.record_success("https://c.example", Duration::from_millis(100), Some(1000));
+        assert_eq!(s.best(), "https://b.example");
+    }
+
+    #[test]
+    fn test_best_skips_failing_and_stale_endpoints() {
+        let mut s = selector();
+        s.record_success("https://a.example", Duration::from_millis(300), Some(1000));
+        s.record_success("https://b.example", Duration::from_millis(50), Some(1000));
+        s.record_success("https://c.example", Duration::from_millis(10), Some(900));
+        s.record_failure("https://b.example");
+        // c is fastest but 100 slots behind, b is failing
+        assert_eq!(s.best(), "https://a.example");
+    }
+
+    #[test]
+    fn test_best_falls_back_to_fewest_failures() {
+        let mut s = selector();
+        s.record_failure("https://a.example");
+        s.record_failure("https://a.example");
+        s.record_failure("https://b.example");
+        s.record_failure("https://c.example");
+        s.record_failure("https://c.example");
+        assert_eq!(s.best(), "https://b.example");
+    }
+
+    #[test]
+    fn test_reference_is_compared_but_never_picked() {
+        let mut s = RpcSelector::new(vec!["https://a.example".to_string()])
+            .with_reference(Some("https://ref.example".to_string()));
+        s.record_success("https://a.example", Duration::from_millis(300), Some(900));
+        s.record_success("https://ref.example", Duration::from_millis(10), Some(1000));
+        assert_eq!(s.best(), "https://a.example");
+        assert_eq!(s.freshest_slot(), Some(1000));
+        assert_eq!(s.slot_lag("https://a.example"), Some(100));
+        assert_eq!(s.stale_by("https://a.example"), Some(100));
+        assert_eq!(s.stale_by("https://ref.example"), None);
+
+        s.record_success("https://a.example", Duration::from_millis(300), Some(990));
+        assert_eq!(s.stale_by("https://a.example"), None);
+    }
+
+    #[test]
+    fn test_unmeasured_endpoints_keep_config_order() {
+        assert_eq!(selector().best(), "https://a.example");
+    }
+}
@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Result};
+use serde_yaml::Value;
+use std::process::Command;
+
+/// `env:NAME` reads the secret from an environment variable
+const ENV_PREFIX: &str = "env:";
+
+/// `exec:command` runs the command with `sh -c` and uses its stdout
+const EXEC_PREFIX: &str = "exec:";
+
+/// Path segment matching every item of a list or every value of a map
+const ANY: &str = "*";
+
+/// Config values that may hold a secret reference instead of the secret
+const SECRET_PATHS: &[&[&str]] = &[
+    &["alert_config", "telegram", "bot_token"],
+    &["alert_config", "telegram", "chat_id"],
+    &["alert_config", "grafana", "api_token"],
+    &["alert_config", "heartbeat", "url"],
+    &["influxdb", "token"],
+    &["proxy", "url"],
+    &["rpc_headers", ANY, "headers", ANY],
+];
+
+/// Whether `value` points at a secret instead of being one
+pub fn is_reference(value: &str) -> bool {
+    value.starts_with(ENV_PREFIX) || value.starts_with(EXEC_PREFIX)
+}
+
+/// The secret `value` refers to, or `value` itself when it isn't a reference.
+/// Errors never include the secret or the command's output.
+pub fn resolve(value: &str) -> Result<String> {
+    if let Some(name) = value.strip_prefix(ENV_PREFIX) {
+        let name = name.trim();
+        return match std::env::var(name) {
+            Ok(secret) if !secret.is_empty() => Ok(secret),
+            _ => Err(anyhow!("environment variable {} is not set", name)),
+        };
+    }
+
+    if let Some(command) = value.strip_prefix(EXEC_PREFIX) {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command.trim())
+            .output()
+            .map_err(|e| anyhow!("failed to run secret command: {}", e))?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "secret command exited with {}",
+                output
+                    .status
+                    .code()
+                    .map(|code| code.to_string())
+                    .unwrap_or_else(|| "a signal".to_string())
+            ));
+        }
+        let secret = String::from_utf8(output.stdout)
+            .map_err(|_| anyhow!("secret command printed invalid UTF-8"))?;
+        let secret = secret.trim_end_matches(['\r', '\n']).to_string();
+        if secret.is_empty() {
+            return Err(anyhow!("secret command printed nothing"));
+        }
+        return Ok(secret);
+    }
+
+    Ok(value.to_string())
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+/// Every concrete path in `root` that `path` matches
+fn expand(root: &Value, path: &[&str]) -> Vec<Vec<Step>> {
+    let (first, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return vec![Vec::new()],
+    };
+    let children: Vec<(Step, &Value)> = match (*first, root) {
+        (ANY, Value::Sequence(items)) => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (Step::Index(index), item))
+            .collect(),
+        (ANY, Value::Mapping(map)) => map
+            .iter()
+            .filter_map(|(key, item)| key.as_str().map(|key| (Step::Key(key.to_string()), item)))
+            .collect(),
+        (ANY, _) => Vec::new(),
+        (key, node) => node
+            .get(key)
+            .map(|item| vec![(Step::Key(key.to_string()), item)])
+            .unwrap_or_default(),
+    };
+
+    children
+        .into_iter()
+        .flat_map(|(step, child)| {
+            expand(child, rest).into_iter().map(move |mut tail| {
+                tail.insert(0, step.clone());
+                tail
+            })
+        })
+        .collect()
+}
+
+fn display(steps: &[Step]) -> String {
+    steps
+        .iter()
+        .map(|step| match step {
+            Step::Key(key) => key.clone(),
+            Step::Index(index) => index.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn value_at<'a>(root: &'a Value, steps: &[Step]) -> Option<&'a Value> {
+    steps.iter().try_fold(root, |node, step| match step {
+        Step::Key(key) => node.get(key.as_str()),
+        Step::Index(index) => node.get(*index),
+    })
+}
+
+fn value_at_mut<'a>(root: &'a mut Value, steps: &[Step]) -> Option<&'a mut Value> {
+    steps.iter().try_fold(root, |node, step| match step {
+        Step::Key(key) => node.get_mut(key.as_str()),
+        Step::Index(index) => node.get_mut(*index),
+    })
+}
+
+/// Replace every secret reference in a loaded config with its secret
+pub fn resolve_secrets(root: &mut Value) -> Result<()> {
+    for path in SECRET_PATHS {
+        for steps in expand(root, path) {
+            if let Some(Value::String(value)) = value_at_mut(root, &steps) {
+                if is_reference(value) {
+                    *value = resolve(value).map_err(|e| anyhow!("{}: {}", display(&steps), e))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Put the references from the config file back before saving, so resolved
+/// secrets are never written to disk
+pub fn restore_references(root: &mut Value, file: &Value) {
+    for path in SECRET_PATHS {
+        for steps in expand(file, path) {
+            let reference = match value_at(file, &steps) {
+                Some(Value::String(reference)) if is_reference(reference) => reference.clone(),
+                _ => continue,
+            };
+            if let Some(slot) = value_at_mut(root, &steps) {
+                *slot = Value::String(reference);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        std::env::set_var("SVS_TEST_SECRET_TOKEN", "123456:AAH");
+        assert_eq!(resolve("env:SVS_TEST_SECRET_TOKEN").unwrap(), "123456:AAH");
+        assert!(resolve("env:SVS_TEST_SECRET_UNSET").is_err());
+
+        assert_eq!(resolve("exec:printf 'abc\\n'").unwrap(), "abc");
+        assert!(resolve("exec:exit 3").unwrap_err().to_string().contains("3"));
+        assert!(resolve("exec:true").is_err());
+
+        assert_eq!(resolve("plain-token").unwrap(), "plain-token");
+    }
+
+    #[test]
+    fn test_resolve_and_restore_secrets() {
+        std::env::set_var("SVS_TEST_SECRET_GRAFANA", "glsa_abc");
+        let file: Value = serde_yaml::from_str(
+            r#"
+alert_config:
+  telegram:
+    bot_token: "exec:echo 123456:AAH"
+    chat_id: "-100200"
+  grafana:
+    url: https://grafana.example.com
+    api_token: env:SVS_TEST_SECRET_GRAFANA
+rpc_headers:
+  - url: https://rpc.example.com
+    headers:
+      x-api-key: env:SVS_TEST_SECRET_GRAFANA
+      x-region: eu
+"#,
+        )
+        .unwrap();
+
+        let mut value = file.clone();
+        resolve_secrets(&mut value).unwrap();
+        assert_eq!(value["alert_config"]["telegram"]["bot_token"], Value::from("123456:AAH"));
+        assert_eq!(value["alert_config"]["telegram"]["chat_id"], Value::from("-100200"));
+        assert_eq!(value["alert_config"]["grafana"]["api_token"], Value::from("glsa_abc"));
+        assert_eq!(value["rpc_headers"][0]["headers"]["x-api-key"], Value::from("glsa_abc"));
+        assert_eq!(value["rpc_headers"][0]["headers"]["x-region"], Value::from("eu"));
+
+        restore_references(&mut value, &file);
+        assert_eq!(value, file);
+    }
+}
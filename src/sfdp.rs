@@ -0,0 +1,381 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::AppState;
+
+/// Seconds between voting samples; each sample stands for one minute
+pub const SAMPLE_INTERVAL_SECONDS: u64 = 60;
+
+/// Samples older than this are dropped when the file is pruned
+const SAMPLE_RETENTION_DAYS: i64 = 90;
+
+/// One observation of a validator taken by the monitor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SfdpSample {
+    pub timestamp: i64, // Unix seconds
+    pub identity: String,
+    pub voting: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Delegation program metrics for one validator over one window
+#[derive(Debug, Clone, Serialize)]
+pub struct SfdpReportRow {
+    pub identity: String,
+    pub vote_pubkey: String,
+    pub window_days: u64,
+    pub observed_minutes: u64,
+    pub uptime_percent: Option<f64>,
+    pub delinquent_minutes: u64,
+    pub epoch: Option<u64>,
+    pub leader_slots: Option<u64>,
+    pub blocks_produced: Option<u64>,
+    pub skip_rate_percent: Option<f64>,
+    pub version: Option<String>,
+    pub latest_cluster_version: Option<String>,
+    pub version_current: Option<bool>,
+}
+
+fn metrics_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".solana-validator-switch").join("metrics"))
+}
+
+fn samples_path() -> Option<PathBuf> {
+    metrics_dir().map(|dir| dir.join("sfdp_samples.jsonl"))
+}
+
+pub fn append_samples(samples: &[SfdpSample]) -> Result<()> {
+    let path = samples_path().ok_or_else(|| anyhow!("Could not find home directory"))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    for sample in samples {
+        writeln!(file, "{}", serde_json::to_string(sample)?)?;
+    }
+    Ok(())
+}
+
+/// Samples taken at or after `since`, skipping unreadable lines
+pub fn load_samples(since: i64) -> Vec<SfdpSample> {
+    let content = match samples_path().and_then(|path| std::fs::read_to_string(path).ok()) {
+        Some(content) => content,
+        None => return Vec::new(),
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<SfdpSample>(line).ok())
+        .filter(|sample| sample.timestamp >= since)
+        .collect()
+}
+
+/// Rewrite the sample file without samples past the retention period
+pub fn prune_samples() -> Result<()> {
+    let path = match samples_path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok(()),
+    };
+    let cutoff = chrono::Utc::now().timestamp() - SAMPLE_RETENTION_DAYS * 86_400;
+    let kept: String = load_samples(cutoff)
+        .iter()
+        .filter_map(|sample| serde_json::to_string(sample).ok())
+        .map(|line| line + "\n")
+        .collect();
+    std::fs::write(path, kept)?;
+    Ok(())
+}
+
+/// Minutes observed and minutes delinquent for `identity`
+pub fn summarize_samples(samples: &[SfdpSample], identity: &str) -> (u64, u64) {
+    samples
+        .iter()
+        .filter(|sample| sample.identity == identity)
+        .fold((0, 0), |(observed, delinquent), sample| {
+            (observed + 1, delinquent + u64::from(!sample.voting))
+        })
+}
+
+/// Numeric parts of a version string such as `Agave 2.2.14`
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let numbers = version.split_whitespace().last()?;
+    numbers
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<u64>>>()
+        .filter(|parts| parts.len() >= 2)
+}
+
+/// Newest cluster version in the same release family (major version) as
+/// `running`, so Agave and Firedancer are each compared with their own kind
+pub fn latest_version(running: &str, cluster_versions: &[String]) -> Option<String> {
+    let major = *parse_version(running)?.first()?;
+    cluster_versions
+        .iter()
+        .filter_map(|v| parse_version(v).map(|parts| (parts, v)))
+        .filter(|(parts, _)| parts[0] == major)
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, v)| v.clone())
+}
+
+/// Current when running the same minor release as the newest cluster version
+pub fn version_is_current(running: &str, latest: &str) -> Option<bool> {
+    let running = parse_version(running)?;
+    let latest = parse_version(latest)?;
+    Some(running[..2] >= latest[..2])
+}
+
+/// Highest version in `running`'s release family that two thirds of the
+/// family's stake runs or exceeds, from (version, activated stake) pairs
+pub fn supermajority_version(running: &str, stake_by_version: &[(String, u64)]) -> Option<String> {
+    let major = *parse_version(running)?.first()?;
+    let mut family: Vec<(Vec<u64>, &String, u64)> = stake_by_version
+        .iter()
+        .filter_map(|(v, stake)| parse_version(v).map(|parts| (parts, v, *stake)))
+        .filter(|(parts, _, _)| parts[0] == major)
+        .collect();
+    family.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let total: u64 = family.iter().map(|(_, _, stake)| stake).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut at_or_above = 0u64;
+    for (_, version, stake) in family {
+        at_or_above += stake;
+        if at_or_above as f64 >= total as f64 * 2.0 / 3.0 {
+            return Some(version.clone());
+        }
+    }
+    None
+}
+
+/// Whether `running` is an older release than `reference`
+pub fn version_older(running: &str, reference: &str) -> Option<bool> {
+    Some(parse_version(running)? < parse_version(reference)?)
+}
+
+/// Build report rows for every configured validator over `window_days`
+pub async fn build_report(app_state: &AppState, window_days: u64) -> Vec<SfdpReportRow> {
+    let since = chrono::Utc::now().timestamp() - (window_days as i64) * 86_400;
+    let samples = load_samples(since);
+    let mut rows = Vec::new();
+
+    for (idx, validator_status) in app_state.validator_statuses.iter().enumerate() {
+        let pair = &validator_status.validator_pair;
+        let rpc_url = crate::rpc_selector::best_rpc(&app_state.rpc_selectors, idx, &pair.rpc).await;
+
+        let (observed_minutes, delinquent_minutes) =
+            summarize_samples(&samples, &pair.identity_pubkey);
+        let uptime_percent = (observed_minutes > 0).then(|| {
+            (observed_minutes - delinquent_minutes) as f64 * 100.0 / observed_minutes as f64
+        });
+
+        let epoch = crate::solana_rpc::fetch_epoch_info(&rpc_url)
+            .await
+            .ok()
+            .map(|info| info.epoch);
+        let production = crate::solana_rpc::fetch_block_production(&rpc_url, &pair.identity_pubkey)
+            .await
+            .ok();
+        let skip_rate_percent = production.and_then(|(leader_slots, blocks)| {
+            crate::solana_rpc::skip_rate_percent(leader_slots, blocks)
+        });
+
+        // Prefer the version seen by the latest sample, else the active node
+        let version = samples
+            .iter()
+            .rev()
+            .find(|s| s.identity == pair.identity_pubkey)
+            .and_then(|s| s.version.clone())
+            .or_else(|| {
+                validator_status
+                    .nodes_with_status
+                    .iter()
+                    .find(|n| n.status == crate::types::NodeStatus::Active)
+                    .and_then(|n| n.version.clone())
+            });
+        let cluster_versions = crate::solana_rpc::fetch_cluster_versions(&rpc_url)
+            .await
+            .unwrap_or_default();
+        let latest_cluster_version = version
+            .as_deref()
+            .and_then(|v| latest_version(v, &cluster_versions));
+        let version_current = match (&version, &latest_cluster_version) {
+            (Some(running), Some(latest)) => version_is_current(running, latest),
+            _ => None,
+        };
+
+        rows.push(SfdpReportRow {
+            identity: pair.identity_pubkey.clone(),
+            vote_pubkey: pair.vote_pubkey.clone(),
+            window_days,
+            observed_minutes,
+            uptime_percent,
+            delinquent_minutes,
+            epoch,
+            leader_slots: production.map(|(slots, _)| slots),
+            blocks_produced: production.map(|(_, blocks)| blocks),
+            skip_rate_percent,
+            version,
+            latest_cluster_version,
+            version_current,
+        });
+    }
+
+    rows
+}
+
+pub fn render_csv(rows: &[SfdpReportRow]) -> String {
+    fn opt<T: ToString>(value: &Option<T>) -> String {
+        value.as_ref().map(|v| v.to_string()).unwrap_or_default()
+    }
+    fn pct(value: Option<f64>) -> String {
+        value.map(|v| format!("{:.2}", v)).unwrap_or_default()
+    }
+
+    let mut csv = "identity,vote_pubkey,window_days,observed_minutes,uptime_percent,delinquent_minutes,epoch,leader_slots,blocks_produced,skip_rate_percent,version,latest_cluster_version,version_current\n".to_string();
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            row.identity,
+            row.vote_pubkey,
+            row.window_days,
+            row.observed_minutes,
+            pct(row.uptime_percent),
+            row.delinquent_minutes,
+            opt(&row.epoch),
+            opt(&row.leader_slots),
+            opt(&row.blocks_produced),
+            pct(row.skip_rate_percent),
+            opt(&row.version),
+            opt(&row.latest_cluster_version),
+            opt(&row.version_current),
+        ));
+    }
+    csv
+}
+
+pub fn render(rows: &[SfdpReportRow], format: &str) -> Result<String> {
+    match format {
+        "csv" => Ok(render_csv(rows)),
+        "json" => Ok(serde_json::to_string_pretty(rows)? + "\n"),
+        other => Err(anyhow!("Unknown report format '{}', use csv or json", other)),
+    }
+}
+
+/// Write one report file per window into the metrics directory
+pub async fn write_periodic_reports(
+    app_state: &AppState,
+    window_days: &[u64],
+    format: &str,
+) -> Result<()> {
+    let dir = metrics_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
+    std::fs::create_dir_all(&dir)?;
+    for days in window_days {
+        let rows = build_report(app_state, *days).await;
+        std::fs::write(
+            dir.join(format!("sfdp_report_{}d.{}", days, format)),
+            render(&rows, format)?,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(identity: &str, voting: bool) -> SfdpSample {
+        SfdpSample {
+            timestamp: 0,
+            identity: identity.to_string(),
+            voting,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_summarize_samples() {
+        let samples = vec![
+            sample("A", true),
+            sample("A", false),
+            sample("A", true),
+            sample("B", false),
+        ];
+        assert_eq!(summarize_samples(&samples, "A"), (3, 1));
+        assert_eq!(summarize_samples(&samples, "C"), (0, 0));
+    }
+
+    #[test]
+    fn test_version_currency() {
+        let cluster = vec![
+            "2.1.21".to_string(),
+            "2.2.14".to_string(),
+            "0.503.20214".to_string(),
+            "unknown".to_string(),
+        ];
+        assert_eq!(latest_version("Agave 2.1.5", &cluster).as_deref(), Some("2.2.14"));
+        assert_eq!(
+            latest_version("Firedancer 0.502.20113", &cluster).as_deref(),
+            Some("0.503.20214")
+        );
+        assert_eq!(version_is_current("Jito 2.2.3", "2.2.14"), Some(true));
+        assert_eq!(version_is_current("Agave 2.1.5", "2.2.14"), Some(false));
+        assert_eq!(version_is_current("unknown", "2.2.14"), None);
+    }
+
+    #[test]
+    fn test_supermajority_version() {
+        let cluster = vec![
+            ("2.2.14".to_string(), 500),
+            ("2.2.12".to_string(), 200),
+            ("2.1.21".to_string(), 300),
+            ("0.505.20216".to_string(), 400),
+            ("unknown".to_string(), 900),
+        ];
+        // 2.2.14 alone is half the Agave stake, 2.2.12 and newer is 70%
+        assert_eq!(supermajority_version("Agave 2.1.21", &cluster).as_deref(), Some("2.2.12"));
+        assert_eq!(
+            supermajority_version("Firedancer 0.503.20214", &cluster).as_deref(),
+            Some("0.505.20216")
+        );
+        assert_eq!(supermajority_version("Agave 3.0.1", &cluster), None);
+
+        assert_eq!(version_older("Agave 2.1.21", "2.2.12"), Some(true));
+        assert_eq!(version_older("Jito 2.2.14", "2.2.12"), Some(false));
+        assert_eq!(version_older("unknown", "2.2.12"), None);
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let rows = vec![SfdpReportRow {
+            identity: "Id111".to_string(),
+            vote_pubkey: "Vote111".to_string(),
+            window_days: 7,
+            observed_minutes: 100,
+            uptime_percent: Some(99.0),
+            delinquent_minutes: 1,
+            epoch: Some(700),
+            leader_slots: None,
+            blocks_produced: None,
+            skip_rate_percent: None,
+            version: Some("Agave 2.2.14".to_string()),
+            latest_cluster_version: Some("2.2.14".to_string()),
+            version_current: Some(true),
+        }];
+        let csv = render_csv(&rows);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap().split(',').count(), 13);
+        assert_eq!(
+            lines.next().unwrap(),
+            "Id111,Vote111,7,100,99.00,1,700,,,,Agave 2.2.14,2.2.14,true"
+        );
+        assert!(render(&rows, "xml").is_err());
+    }
+}
@@ -1,8 +1,57 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
-use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_client::{RpcClient, RpcClientConfig};
+use solana_client::rpc_response::RpcVoteAccountInfo;
+use solana_rpc_client::http_sender::HttpSender;
 use solana_sdk::pubkey::Pubkey;
+use std::collections::BTreeMap;
 use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::types::RpcHeadersConfig;
+
+static RPC_HEADERS: OnceLock<Vec<RpcHeadersConfig>> = OnceLock::new();
+
+/// Register the configured per-endpoint headers once at startup
+pub fn init_headers(config: &[RpcHeadersConfig]) {
+    let _ = RPC_HEADERS.set(config.to_vec());
+}
+
+/// Headers for `url`; the entry with the longest matching URL prefix wins
+pub fn headers_for<'a>(config: &'a [RpcHeadersConfig], url: &str) -> Option<&'a BTreeMap<String, String>> {
+    config
+        .iter()
+        .filter(|entry| !entry.url.is_empty() && url.starts_with(&entry.url))
+        .max_by_key(|entry| entry.url.len())
+        .map(|entry| &entry.headers)
+}
+
+/// RPC client for `url` that sends the headers configured for its endpoint
+/// and goes through the proxy when one is configured
+pub fn new_rpc_client(url: &str, timeout: Duration) -> Result<RpcClient> {
+    let mut header_map = HttpSender::default_headers();
+    for (name, value) in RPC_HEADERS.get().and_then(|config| headers_for(config, url)).into_iter().flatten() {
+        // Errors leave out the value, which is usually a credential
+        let header_name = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| anyhow!("Invalid RPC header name '{}'", name))?;
+        let mut header_value = reqwest::header::HeaderValue::from_str(value)
+            .map_err(|_| anyhow!("Invalid value for RPC header '{}'", name))?;
+        header_value.set_sensitive(true);
+        header_map.insert(header_name, header_value);
+    }
+
+    let client = crate::proxy::http_client_builder()
+        .default_headers(header_map)
+        .timeout(timeout)
+        .pool_idle_timeout(timeout)
+        .build()
+        .map_err(|e| anyhow!("Failed to build RPC client: {}", e))?;
+    Ok(RpcClient::new_sender(
+        HttpSender::new_with_client(url.to_string(), client),
+        RpcClientConfig::default(),
+    ))
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoteAccountInfo {
@@ -30,14 +79,13 @@ pub struct ValidatorVoteData {
     pub vote_account_info: VoteAccountInfo,
     pub recent_votes: Vec<RecentVote>,
     pub is_voting: bool,
+    pub rpc_stale_slots: Option<u64>, // Serving endpoint is this far behind the others
 }
 
 pub async fn fetch_vote_account_data(
     rpc_url: &str,
     vote_pubkey_str: &str,
 ) -> Result<ValidatorVoteData> {
-    use std::time::Duration;
-
     // Validate RPC URL
     if rpc_url.is_empty() {
         return Err(anyhow!("RPC URL is empty"));
@@ -47,14 +95,16 @@ pub async fn fetch_vote_account_data(
     // eprintln!("Using RPC URL: {}", rpc_url);
     // eprintln!("Looking for vote account: {}", vote_pubkey_str);
 
-    let rpc_client = RpcClient::new_with_timeout(rpc_url.to_string(), Duration::from_secs(3));
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(3))?;
     let vote_pubkey =
         Pubkey::from_str(vote_pubkey_str).map_err(|e| anyhow!("Invalid vote pubkey: {}", e))?;
 
     // Get vote account info
-    let vote_account = rpc_client
-        .get_vote_accounts()
-        .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+    let vote_account = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_vote_accounts().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
 
     // Find our specific vote account in current or delinquent
     let vote_info = vote_account
@@ -68,19 +118,35 @@ pub async fn fetch_vote_account_data(
         })?;
 
     // Get detailed vote account data
-    let account_data = rpc_client
-        .get_account(&vote_pubkey)
-        .map_err(|e| anyhow!("Failed to get vote account data: {}", e))?;
+    let account_data = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_account(&vote_pubkey).map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote account data: {}", e))?;
+
+    let current_slot = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_slot().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get current slot: {}", e))?;
+
+    build_vote_data(vote_pubkey_str, vote_info, &account_data.data, current_slot)
+}
 
+/// Vote data from a vote account's entry in getVoteAccounts, its raw account
+/// data and the current slot, however they were fetched
+pub fn build_vote_data(
+    vote_pubkey_str: &str,
+    vote_info: &RpcVoteAccountInfo,
+    account_data: &[u8],
+    current_slot: u64,
+) -> Result<ValidatorVoteData> {
     // Parse vote state from account data
-    let vote_state = solana_sdk::vote::state::VoteState::deserialize(&account_data.data)
+    let vote_state = solana_sdk::vote::state::VoteState::deserialize(account_data)
         .map_err(|e| anyhow!("Failed to deserialize vote state: {}", e))?;
 
     // Get recent votes with latency
     let mut recent_votes = Vec::new();
-    let current_slot = rpc_client
-        .get_slot()
-        .map_err(|e| anyhow!("Failed to get current slot: {}", e))?;
 
     // Get the most recent votes (up to 31 as shown in the example)
     // The votes are stored in order, with most recent at the end
@@ -138,5 +204,1007 @@ pub async fn fetch_vote_account_data(
         },
         recent_votes,
         is_voting,
+        rpc_stale_slots: None,
+    })
+}
+
+/// An endpoint further than this many slots behind a second source can't
+/// tell a stalled validator from its own lag
+const MAX_TRUSTED_SLOT_LAG: u64 = 50;
+
+/// JSON-RPC "method not found"; some providers don't expose getHealth
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Why vote data from `rpc_url` can't be trusted to show that a validator
+/// stopped voting, or None when it can: the endpoint must pass getHealth and
+/// be within `MAX_TRUSTED_SLOT_LAG` slots of the first of `references` that
+/// answers
+pub async fn endpoint_distrust(rpc_url: &str, references: &[String]) -> Option<String> {
+    use solana_client::client_error::ClientErrorKind;
+    use solana_client::rpc_request::RpcError;
+
+    let rpc_client = match new_rpc_client(rpc_url, Duration::from_secs(3)) {
+        Ok(client) => client,
+        Err(e) => return Some(e.to_string()),
+    };
+
+    let health =
+        crate::rpc_limiter::call(&rpc_client, |client| client.get_health().map_err(Box::new)).await;
+    if let Err(e) = health {
+        let unsupported = matches!(
+            e.kind(),
+            ClientErrorKind::RpcError(RpcError::RpcResponseError { code: METHOD_NOT_FOUND, .. })
+        );
+        if !unsupported {
+            return Some(format!("{} is unhealthy: {}", rpc_url, e));
+        }
+    }
+
+    let slot = crate::rpc_limiter::call(&rpc_client, |client| client.get_slot().map_err(Box::new)).await;
+    let slot = match slot {
+        Ok(slot) => slot,
+        Err(e) => return Some(format!("{} did not return a slot: {}", rpc_url, e)),
+    };
+
+    for reference in references.iter().filter(|url| url.as_str() != rpc_url) {
+        let reference_slot = match new_rpc_client(reference, Duration::from_secs(3)) {
+            Ok(client) => crate::rpc_limiter::call(&client, |client| client.get_slot().map_err(Box::new))
+                .await
+                .ok(),
+            Err(_) => None,
+        };
+        if let Some(reference_slot) = reference_slot {
+            let lag = reference_slot.saturating_sub(slot);
+            return (lag > MAX_TRUSTED_SLOT_LAG)
+                .then(|| format!("{} is {} slots behind {}", rpc_url, lag, reference));
+        }
+    }
+
+    // No second source answered; the health check alone has to do
+    None
+}
+
+/// Cluster-wide signals that tell a cluster outage from a node problem
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClusterHealth {
+    pub block_age_seconds: u64, // Age of the newest confirmed block; grows while the cluster is halted
+    pub delinquent_stake_percent: f64,
+}
+
+/// Percentage of activated stake on delinquent vote accounts
+pub fn delinquent_stake_percent(current_stake: u64, delinquent_stake: u64) -> f64 {
+    let total = current_stake + delinquent_stake;
+    if total == 0 {
+        return 0.0;
+    }
+    delinquent_stake as f64 / total as f64 * 100.0
+}
+
+/// Fetch how far the cluster has progressed and how much stake is delinquent
+pub async fn fetch_cluster_health(rpc_url: &str) -> Result<ClusterHealth> {
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let slot = crate::rpc_limiter::call(&rpc_client, |client| {
+        client
+            .get_slot_with_commitment(CommitmentConfig::confirmed())
+            .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get slot: {}", e))?;
+    let block_time = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_block_time(slot).map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get block time: {}", e))?;
+    let accounts = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_vote_accounts().map_err(Box::new)
     })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(block_time);
+    let stake = |accounts: &[RpcVoteAccountInfo]| -> u64 {
+        accounts.iter().map(|account| account.activated_stake).sum()
+    };
+
+    Ok(ClusterHealth {
+        block_age_seconds: now.saturating_sub(block_time).max(0) as u64,
+        delinquent_stake_percent: delinquent_stake_percent(
+            stake(&accounts.current),
+            stake(&accounts.delinquent),
+        ),
+    })
+}
+
+/// Vote landing statistics sampled from recent vote transaction signatures
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteLandingStats {
+    pub sampled: usize,     // Number of signatures sampled
+    pub landed: usize,      // Vote transactions that executed successfully
+    pub failed: usize,      // Vote transactions that landed with an error
+    pub slot_span: u64,     // Slots covered by the sample (expected vote count)
+}
+
+impl VoteLandingStats {
+    /// Votes that should exist for the sampled slot range but never landed
+    pub fn expired(&self) -> u64 {
+        self.slot_span
+            .saturating_sub(self.landed as u64)
+            .saturating_sub(self.failed as u64)
+    }
+
+    /// Percentage of expected votes that landed successfully
+    pub fn landing_rate(&self) -> f64 {
+        if self.slot_span == 0 {
+            return 0.0;
+        }
+        (self.landed as f64 / self.slot_span as f64 * 100.0).min(100.0)
+    }
+}
+
+/// Compute landing stats from (slot, had_error) pairs of vote transactions
+pub fn compute_vote_landing_stats(samples: &[(u64, bool)]) -> Option<VoteLandingStats> {
+    let min_slot = samples.iter().map(|(slot, _)| *slot).min()?;
+    let max_slot = samples.iter().map(|(slot, _)| *slot).max()?;
+    let failed = samples.iter().filter(|(_, had_error)| *had_error).count();
+
+    Some(VoteLandingStats {
+        sampled: samples.len(),
+        landed: samples.len() - failed,
+        failed,
+        slot_span: max_slot - min_slot + 1,
+    })
+}
+
+/// Sample recent vote transactions for a vote account and compute the landing rate
+pub async fn fetch_vote_landing_stats(
+    rpc_url: &str,
+    vote_pubkey_str: &str,
+    sample_size: usize,
+) -> Result<VoteLandingStats> {
+    use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(5))?;
+    let vote_pubkey =
+        Pubkey::from_str(vote_pubkey_str).map_err(|e| anyhow!("Invalid vote pubkey: {}", e))?;
+
+    let signatures = crate::rpc_limiter::call(&rpc_client, |client| {
+        client
+            .get_signatures_for_address_with_config(
+                &vote_pubkey,
+                GetConfirmedSignaturesForAddress2Config {
+                    before: None,
+                    until: None,
+                    limit: Some(sample_size),
+                    commitment: None,
+                },
+            )
+            .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote signatures: {}", e))?;
+
+    let samples: Vec<(u64, bool)> = signatures
+        .iter()
+        .map(|status| (status.slot, status.err.is_some()))
+        .collect();
+
+    compute_vote_landing_stats(&samples)
+        .ok_or_else(|| anyhow!("No recent vote transactions found for {}", vote_pubkey_str))
+}
+
+/// Gossip IP the cluster currently sees for an identity, or None if the
+/// identity is not in gossip
+pub async fn fetch_gossip_ip(rpc_url: &str, identity: &str) -> Result<Option<std::net::IpAddr>> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let nodes = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_cluster_nodes().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get cluster nodes: {}", e))?;
+
+    Ok(nodes
+        .into_iter()
+        .find(|node| node.pubkey == identity)
+        .and_then(|node| node.gossip)
+        .map(|addr| addr.ip()))
+}
+
+/// Fee payers of the latest successful transactions on the vote account,
+/// newest first. A vote is paid for by the identity of the node that cast it.
+pub async fn fetch_recent_vote_payers(
+    rpc_url: &str,
+    vote_pubkey: &str,
+    limit: usize,
+) -> Result<Vec<String>> {
+    use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+    use solana_client::rpc_config::RpcTransactionConfig;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::signature::Signature;
+    use solana_transaction_status::UiTransactionEncoding;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let vote_pubkey = Pubkey::from_str(vote_pubkey)
+        .map_err(|e| anyhow!("Invalid vote pubkey {}: {}", vote_pubkey, e))?;
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let signatures = crate::rpc_limiter::call(&rpc_client, |client| {
+        client
+            .get_signatures_for_address_with_config(
+                &vote_pubkey,
+                GetConfirmedSignaturesForAddress2Config {
+                    limit: Some(limit),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    ..Default::default()
+                },
+            )
+            .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote account signatures: {}", e))?;
+
+    let mut payers = Vec::new();
+    for entry in signatures.into_iter().filter(|entry| entry.err.is_none()) {
+        let signature = Signature::from_str(&entry.signature)
+            .map_err(|e| anyhow!("Invalid signature {}: {}", entry.signature, e))?;
+        let transaction = crate::rpc_limiter::call(&rpc_client, |client| {
+            client
+                .get_transaction_with_config(
+                    &signature,
+                    RpcTransactionConfig {
+                        encoding: Some(UiTransactionEncoding::Base64),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                        max_supported_transaction_version: Some(0),
+                    },
+                )
+                .map_err(Box::new)
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to get vote transaction: {}", e))?;
+
+        if let Some(payer) = transaction
+            .transaction
+            .transaction
+            .decode()
+            .and_then(|tx| tx.message.static_account_keys().first().copied())
+        {
+            payers.push(payer.to_string());
+        }
+    }
+    Ok(payers)
+}
+
+/// Leader slots and blocks produced by `identity` in the current epoch
+pub async fn fetch_block_production(rpc_url: &str, identity: &str) -> Result<(u64, u64)> {
+    use solana_client::rpc_config::RpcBlockProductionConfig;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let production = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_block_production_with_config(RpcBlockProductionConfig {
+            identity: Some(identity.to_string()),
+            ..Default::default()
+        })
+        .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get block production: {}", e))?;
+
+    Ok(production
+        .value
+        .by_identity
+        .get(identity)
+        .map(|(leader_slots, blocks)| (*leader_slots as u64, *blocks as u64))
+        .unwrap_or((0, 0)))
+}
+
+/// Share of elapsed leader slots without a block, or None before the first one
+pub fn skip_rate_percent(leader_slots: u64, blocks_produced: u64) -> Option<f64> {
+    (leader_slots > 0).then(|| {
+        leader_slots.saturating_sub(blocks_produced) as f64 * 100.0 / leader_slots as f64
+    })
+}
+
+/// Software versions advertised in gossip, one per cluster node that reports one
+pub async fn fetch_cluster_versions(rpc_url: &str) -> Result<Vec<String>> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let nodes = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_cluster_nodes().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get cluster nodes: {}", e))?;
+
+    Ok(nodes.into_iter().filter_map(|node| node.version).collect())
+}
+
+/// Gossip version and activated stake of every voting node, for the
+/// stake-weighted version distribution
+pub async fn fetch_cluster_version_stake(rpc_url: &str) -> Result<Vec<(String, u64)>> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let nodes = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_cluster_nodes().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get cluster nodes: {}", e))?;
+    let accounts = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_vote_accounts().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+
+    let versions: std::collections::HashMap<String, String> = nodes
+        .into_iter()
+        .filter_map(|node| node.version.map(|version| (node.pubkey, version)))
+        .collect();
+
+    Ok(accounts
+        .current
+        .iter()
+        .chain(accounts.delinquent.iter())
+        .filter_map(|account| {
+            versions
+                .get(&account.node_pubkey)
+                .map(|version| (version.clone(), account.activated_stake))
+        })
+        .collect())
+}
+
+/// Processed slot of an RPC endpoint, the tip node catchup is measured against
+pub async fn fetch_processed_slot(rpc_url: &str) -> Result<u64> {
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(5))?;
+    crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_slot_with_commitment(CommitmentConfig::processed()).map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get slot: {}", e))
+}
+
+/// SOL balance of an account in lamports
+pub async fn fetch_balance(rpc_url: &str, pubkey: &str) -> Result<u64> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let pubkey = Pubkey::from_str(pubkey).map_err(|e| anyhow!("Invalid pubkey {}: {}", pubkey, e))?;
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(5))?;
+    crate::rpc_limiter::call(&rpc_client, |client| client.get_balance(&pubkey).map_err(Box::new))
+        .await
+        .map_err(|e| anyhow!("Failed to get balance: {}", e))
+}
+
+/// Lamports an account of `data_len` bytes needs to be rent exempt
+pub async fn fetch_rent_exempt_minimum(rpc_url: &str, data_len: usize) -> Result<u64> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(5))?;
+    crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_minimum_balance_for_rent_exemption(data_len).map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get rent exemption minimum: {}", e))
+}
+
+/// Current epoch position as reported by getEpochInfo
+pub async fn fetch_epoch_info(rpc_url: &str) -> Result<solana_sdk::epoch_info::EpochInfo> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    crate::rpc_limiter::call(&rpc_client, |client| client.get_epoch_info().map_err(Box::new))
+        .await
+        .map_err(|e| anyhow!("Failed to get epoch info: {}", e))
+}
+
+/// Current slot and the leader slots of `identity` from there to the end of
+/// the next epoch, as absolute slots in ascending order
+pub async fn fetch_upcoming_leader_slots(rpc_url: &str, identity: &str) -> Result<(u64, Vec<u64>)> {
+    use solana_client::rpc_config::RpcLeaderScheduleConfig;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let epoch_info = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_epoch_info().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+
+    // The next epoch's schedule is already fixed, so a wait near the end of
+    // an epoch still sees the leader slots right after the boundary
+    let epoch_start = epoch_info.absolute_slot - epoch_info.slot_index;
+    let mut slots = Vec::new();
+    for first_slot in [epoch_start, epoch_start + epoch_info.slots_in_epoch] {
+        let schedule = crate::rpc_limiter::call(&rpc_client, |client| {
+            client
+                .get_leader_schedule_with_config(
+                    Some(first_slot),
+                    RpcLeaderScheduleConfig {
+                        identity: Some(identity.to_string()),
+                        commitment: None,
+                    },
+                )
+                .map_err(Box::new)
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to get leader schedule: {}", e))?;
+        if let Some(indices) = schedule.as_ref().and_then(|s| s.get(identity)) {
+            slots.extend(indices.iter().map(|index| first_slot + *index as u64));
+        }
+    }
+
+    slots.retain(|slot| *slot >= epoch_info.absolute_slot);
+    slots.sort_unstable();
+    Ok((epoch_info.absolute_slot, slots))
+}
+
+/// Vote credits earned so far this epoch by one vote account, next to the
+/// most any vote account earned
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoteCredits {
+    pub epoch: u64,
+    pub slots_elapsed: u64,
+    pub earned: u64,
+    pub cluster_max: u64,
+}
+
+impl VoteCredits {
+    /// Credits per elapsed slot
+    pub fn per_slot(&self) -> f64 {
+        if self.slots_elapsed == 0 {
+            return 0.0;
+        }
+        self.earned as f64 / self.slots_elapsed as f64
+    }
+
+    /// Earned credits as a share of the cluster's best, or None before
+    /// anyone earned any
+    pub fn efficiency_percent(&self) -> Option<f64> {
+        (self.cluster_max > 0).then(|| self.earned as f64 * 100.0 / self.cluster_max as f64)
+    }
+}
+
+/// Size of a vote account, which fixes its rent exemption minimum
+pub const VOTE_ACCOUNT_SIZE: usize = solana_sdk::vote::state::VoteState::size_of();
+
+/// A vote account's balance next to the minimum keeping it rent exempt
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoteAccountBalance {
+    pub lamports: u64,
+    pub rent_exempt_lamports: u64,
+}
+
+impl VoteAccountBalance {
+    /// Lamports above the rent exemption minimum, the withdrawable part
+    pub fn excess_lamports(&self) -> u64 {
+        self.lamports.saturating_sub(self.rent_exempt_lamports)
+    }
+
+    pub fn is_rent_exempt(&self) -> bool {
+        self.lamports >= self.rent_exempt_lamports
+    }
+}
+
+/// Credits earned in `epoch` from a vote account's (epoch, credits,
+/// previous credits) history
+pub fn credits_in_epoch(epoch_credits: &[(u64, u64, u64)], epoch: u64) -> u64 {
+    epoch_credits
+        .iter()
+        .find(|(e, _, _)| *e == epoch)
+        .map(|(_, credits, previous)| credits.saturating_sub(*previous))
+        .unwrap_or(0)
+}
+
+/// Current-epoch vote credits of `vote_pubkey` and the cluster maximum
+pub async fn fetch_vote_credits(rpc_url: &str, vote_pubkey: &str) -> Result<VoteCredits> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let epoch_info = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_epoch_info().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+    let accounts = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_vote_accounts().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+
+    let all = || accounts.current.iter().chain(accounts.delinquent.iter());
+    let account = all()
+        .find(|account| account.vote_pubkey == vote_pubkey)
+        .ok_or_else(|| anyhow!("Vote account {} not found", vote_pubkey))?;
+
+    Ok(VoteCredits {
+        epoch: epoch_info.epoch,
+        slots_elapsed: epoch_info.slot_index,
+        earned: credits_in_epoch(&account.epoch_credits, epoch_info.epoch),
+        cluster_max: all()
+            .map(|account| credits_in_epoch(&account.epoch_credits, epoch_info.epoch))
+            .max()
+            .unwrap_or(0),
+    })
+}
+
+/// One of the validator's leader slots and whether it produced a block there
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeaderSlotOutcome {
+    pub slot: u64,
+    pub produced: bool,
+}
+
+/// Pair leader slots with the blocks confirmed over the same range
+pub fn leader_slot_outcomes(leader_slots: &[u64], blocks: &[u64]) -> Vec<LeaderSlotOutcome> {
+    leader_slots
+        .iter()
+        .map(|slot| LeaderSlotOutcome {
+            slot: *slot,
+            produced: blocks.binary_search(slot).is_ok(),
+        })
+        .collect()
+}
+
+/// The last `count` finalized leader slots of `identity` this epoch, oldest
+/// first, with whether a block landed in each
+pub async fn fetch_recent_leader_slots(
+    rpc_url: &str,
+    identity: &str,
+    count: usize,
+) -> Result<Vec<LeaderSlotOutcome>> {
+    use solana_client::rpc_config::RpcLeaderScheduleConfig;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    // Epoch info and blocks both use the finalized commitment, so a slot
+    // counted here as skipped can no longer get a block
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(10))?;
+    let epoch_info = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_epoch_info().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+    let epoch_start = epoch_info.absolute_slot - epoch_info.slot_index;
+
+    let schedule = crate::rpc_limiter::call(&rpc_client, |client| {
+        client
+            .get_leader_schedule_with_config(
+                Some(epoch_start),
+                RpcLeaderScheduleConfig {
+                    identity: Some(identity.to_string()),
+                    commitment: None,
+                },
+            )
+            .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get leader schedule: {}", e))?;
+
+    let mut leader_slots: Vec<u64> = schedule
+        .as_ref()
+        .and_then(|s| s.get(identity))
+        .map(|indices| indices.iter().map(|index| epoch_start + *index as u64).collect())
+        .unwrap_or_default();
+    leader_slots.retain(|slot| *slot <= epoch_info.absolute_slot);
+    leader_slots.sort_unstable();
+    let leader_slots = &leader_slots[leader_slots.len().saturating_sub(count)..];
+
+    let (first, last) = match (leader_slots.first(), leader_slots.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return Ok(Vec::new()),
+    };
+    let blocks = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_blocks(first, Some(last)).map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get blocks: {}", e))?;
+
+    Ok(leader_slot_outcomes(leader_slots, &blocks))
+}
+
+/// Consecutive runs of leader slots as (first slot, number of slots).
+/// `leader_slots` must be sorted ascending.
+pub fn leader_windows(leader_slots: &[u64]) -> Vec<(u64, u64)> {
+    let mut windows: Vec<(u64, u64)> = Vec::new();
+    for &slot in leader_slots {
+        match windows.last_mut() {
+            Some((first, count)) if *first + *count == slot => *count += 1,
+            _ => windows.push((slot, 1)),
+        }
+    }
+    windows
+}
+
+/// Slot `slot_offset` slots into the next epoch
+pub fn next_epoch_start_slot(epoch_info: &solana_sdk::epoch_info::EpochInfo, slot_offset: u64) -> u64 {
+    epoch_info.absolute_slot - epoch_info.slot_index + epoch_info.slots_in_epoch + slot_offset
+}
+
+/// Inflation reward credited to a vote account for one epoch. For a vote
+/// account this is the operator's commission on its delegators' rewards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochReward {
+    pub epoch: u64,
+    pub amount_lamports: u64,
+    pub post_balance_lamports: u64,
+    pub commission: Option<u8>,
+}
+
+impl EpochReward {
+    /// Total staking rewards the commission was taken from, estimated as
+    /// amount / commission. None when the commission is zero or unknown.
+    pub fn estimated_total_rewards_lamports(&self) -> Option<u64> {
+        match self.commission {
+            Some(commission) if commission > 0 => {
+                Some(self.amount_lamports * 100 / commission as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / 1_000_000_000.0
+}
+
+/// Size of a stake account
+const STAKE_ACCOUNT_SIZE: u64 = 200;
+
+/// Offset of the delegation's voter pubkey in a stake account
+const STAKE_VOTER_OFFSET: usize = 124;
+
+/// Offset of the delegated stake, followed by the activation and
+/// deactivation epochs (little-endian u64 each)
+const STAKE_AMOUNT_OFFSET: usize = 156;
+
+/// Stake delegated to a vote account: effective now, and what was delegated
+/// or undelegated this epoch and takes effect over the coming epochs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StakeSummary {
+    pub epoch: u64,
+    pub activated_lamports: u64,
+    pub activating_lamports: u64,
+    pub deactivating_lamports: u64,
+}
+
+/// Activating and deactivating lamports among (stake, activation epoch,
+/// deactivation epoch) delegations. Warmup and cooldown can spread a large
+/// change over several epochs; only delegations changed this epoch count.
+pub fn pending_stake(epoch: u64, delegations: &[(u64, u64, u64)]) -> (u64, u64) {
+    delegations
+        .iter()
+        .fold((0, 0), |(activating, deactivating), (stake, activation, deactivation)| {
+            if *deactivation == u64::MAX {
+                if *activation == epoch {
+                    return (activating + stake, deactivating);
+                }
+            } else if *deactivation == epoch && *activation != epoch {
+                return (activating, deactivating + stake);
+            }
+            (activating, deactivating)
+        })
+}
+
+/// Stake, activation epoch and deactivation epoch from the delegation slice
+/// of a stake account
+fn parse_delegation(data: &[u8]) -> Option<(u64, u64, u64)> {
+    let field = |index: usize| -> Option<u64> {
+        let bytes = data.get(index * 8..index * 8 + 8)?;
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    };
+    Some((field(0)?, field(1)?, field(2)?))
+}
+
+/// Activated stake of `vote_pubkey` and the stake pending activation or
+/// deactivation at the next epoch boundary
+pub async fn fetch_stake_summary(rpc_url: &str, vote_pubkey: &str) -> Result<StakeSummary> {
+    use base64::Engine;
+    use solana_client::rpc_config::RpcGetVoteAccountsConfig;
+    use solana_client::rpc_request::RpcRequest;
+
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    // getProgramAccounts over the stake program is slow on busy endpoints
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(30))?;
+    let epoch_info = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_epoch_info().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?;
+
+    let accounts = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_vote_accounts_with_config(RpcGetVoteAccountsConfig {
+            vote_pubkey: Some(vote_pubkey.to_string()),
+            keep_unstaked_delinquents: Some(true),
+            ..Default::default()
+        })
+        .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get vote accounts: {}", e))?;
+    let activated_lamports = accounts
+        .current
+        .iter()
+        .chain(accounts.delinquent.iter())
+        .find(|account| account.vote_pubkey == vote_pubkey)
+        .map(|account| account.activated_stake)
+        .ok_or_else(|| anyhow!("Vote account {} not found", vote_pubkey))?;
+
+    // Only the delegation fields of stake accounts delegated to this vote account
+    let params = serde_json::json!([
+        solana_sdk::stake::program::id().to_string(),
+        {
+            "encoding": "base64",
+            "dataSlice": {"offset": STAKE_AMOUNT_OFFSET, "length": 24},
+            "filters": [
+                {"dataSize": STAKE_ACCOUNT_SIZE},
+                {"memcmp": {"offset": STAKE_VOTER_OFFSET, "bytes": vote_pubkey}},
+            ],
+        }
+    ]);
+    let stake_accounts: serde_json::Value = crate::rpc_limiter::call(&rpc_client, |client| {
+        client
+            .send(RpcRequest::GetProgramAccounts, params.clone())
+            .map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get stake accounts: {}", e))?;
+
+    let delegations: Vec<(u64, u64, u64)> = stake_accounts
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item["account"]["data"][0].as_str())
+                .filter_map(|encoded| base64::engine::general_purpose::STANDARD.decode(encoded).ok())
+                .filter_map(|data| parse_delegation(&data))
+                .collect()
+        })
+        .unwrap_or_default();
+    let (activating_lamports, deactivating_lamports) = pending_stake(epoch_info.epoch, &delegations);
+
+    Ok(StakeSummary {
+        epoch: epoch_info.epoch,
+        activated_lamports,
+        activating_lamports,
+        deactivating_lamports,
+    })
+}
+
+/// Rewards credited to `vote_pubkey` for the last `epochs` completed epochs,
+/// oldest first. Epochs without a reward (e.g. no stake yet) are skipped.
+pub async fn fetch_epoch_rewards(
+    rpc_url: &str,
+    vote_pubkey_str: &str,
+    epochs: u64,
+) -> Result<Vec<EpochReward>> {
+    if rpc_url.is_empty() {
+        return Err(anyhow!("RPC URL is empty"));
+    }
+
+    let rpc_client = new_rpc_client(rpc_url, Duration::from_secs(30))?;
+    let vote_pubkey =
+        Pubkey::from_str(vote_pubkey_str).map_err(|e| anyhow!("Invalid vote pubkey: {}", e))?;
+    let current_epoch = crate::rpc_limiter::call(&rpc_client, |client| {
+        client.get_epoch_info().map_err(Box::new)
+    })
+    .await
+    .map_err(|e| anyhow!("Failed to get epoch info: {}", e))?
+    .epoch;
+
+    let mut rewards = Vec::new();
+    for epoch in current_epoch.saturating_sub(epochs)..current_epoch {
+        let reward = crate::rpc_limiter::call(&rpc_client, |client| {
+            client.get_inflation_reward(&[vote_pubkey], Some(epoch)).map_err(Box::new)
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to get inflation reward for epoch {}: {}", epoch, e))?
+        .into_iter()
+        .next()
+        .flatten();
+        if let Some(reward) = reward {
+            rewards.push(EpochReward {
+                epoch: reward.epoch,
+                amount_lamports: reward.amount,
+                post_balance_lamports: reward.post_balance,
+                commission: reward.commission,
+            });
+        }
+    }
+
+    Ok(rewards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_for_longest_prefix() {
+        let entry = |url: &str, key: &str| RpcHeadersConfig {
+            url: url.to_string(),
+            headers: BTreeMap::from([("x-api-key".to_string(), key.to_string())]),
+        };
+        let config = vec![
+            entry("https://rpc.example.com", "general"),
+            entry("https://rpc.example.com/mainnet", "mainnet"),
+        ];
+
+        let key = |url: &str| headers_for(&config, url).map(|h| h["x-api-key"].clone());
+        assert_eq!(key("https://rpc.example.com/mainnet/v1").as_deref(), Some("mainnet"));
+        assert_eq!(key("https://rpc.example.com/devnet").as_deref(), Some("general"));
+        assert_eq!(key("https://api.mainnet-beta.solana.com"), None);
+    }
+
+    #[test]
+    fn test_vote_landing_stats_counts_failed_and_expired() {
+        // 10 slots spanned, 7 landed, 1 failed -> 2 expired
+        let samples = vec![
+            (100, false),
+            (101, false),
+            (102, true),
+            (103, false),
+            (105, false),
+            (106, false),
+            (108, false),
+            (109, false),
+        ];
+
+        let stats = compute_vote_landing_stats(&samples).unwrap();
+        assert_eq!(stats.sampled, 8);
+        assert_eq!(stats.landed, 7);
+        assert_eq!(stats.failed, 1);
+        assert_eq!(stats.slot_span, 10);
+        assert_eq!(stats.expired(), 2);
+        assert!((stats.landing_rate() - 70.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_vote_landing_stats_empty_sample() {
+        assert!(compute_vote_landing_stats(&[]).is_none());
+    }
+
+    #[test]
+    fn test_skip_rate_percent() {
+        assert_eq!(skip_rate_percent(0, 0), None);
+        assert_eq!(skip_rate_percent(40, 40), Some(0.0));
+        assert_eq!(skip_rate_percent(40, 30), Some(25.0));
+    }
+
+    #[test]
+    fn test_pending_stake() {
+        let delegations = [
+            (100, 690, u64::MAX), // Active
+            (20, 700, u64::MAX),  // Activating
+            (30, 650, 700),       // Deactivating
+            (40, 650, 699),       // Deactivated last epoch
+            (50, 700, 700),       // Delegated and undelegated this epoch
+        ];
+        assert_eq!(pending_stake(700, &delegations), (20, 30));
+
+        let mut data = Vec::new();
+        for value in [5_000_000_000u64, 700, u64::MAX] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(parse_delegation(&data), Some((5_000_000_000, 700, u64::MAX)));
+        assert_eq!(parse_delegation(&data[..16]), None);
+    }
+
+    #[test]
+    fn test_vote_credits() {
+        let history = [(699, 6_500_000, 0), (700, 6_600_000, 6_500_000)];
+        assert_eq!(credits_in_epoch(&history, 700), 100_000);
+        assert_eq!(credits_in_epoch(&history, 701), 0);
+
+        let credits = VoteCredits {
+            epoch: 700,
+            slots_elapsed: 8_000,
+            earned: 100_000,
+            cluster_max: 125_000,
+        };
+        assert!((credits.per_slot() - 12.5).abs() < f64::EPSILON);
+        assert_eq!(credits.efficiency_percent(), Some(80.0));
+        assert_eq!(VoteCredits { cluster_max: 0, ..credits }.efficiency_percent(), None);
+    }
+
+    #[test]
+    fn test_leader_slot_outcomes() {
+        let outcomes = leader_slot_outcomes(&[100, 101, 102, 103], &[99, 100, 102, 103, 104]);
+        let produced: Vec<bool> = outcomes.iter().map(|o| o.produced).collect();
+        assert_eq!(produced, vec![true, false, true, true]);
+        assert_eq!(outcomes[1].slot, 101);
+        assert!(leader_slot_outcomes(&[], &[100]).is_empty());
+    }
+
+    #[test]
+    fn test_delinquent_stake_percent() {
+        assert_eq!(delinquent_stake_percent(900, 100), 10.0);
+        assert_eq!(delinquent_stake_percent(1_000, 0), 0.0);
+        assert_eq!(delinquent_stake_percent(0, 0), 0.0);
+    }
+
+    #[test]
+    fn test_leader_windows() {
+        assert_eq!(
+            leader_windows(&[100, 101, 102, 103, 200, 201, 202, 203, 204]),
+            vec![(100, 4), (200, 5)]
+        );
+        assert_eq!(leader_windows(&[7]), vec![(7, 1)]);
+        assert!(leader_windows(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_next_epoch_start_slot() {
+        let epoch_info = solana_sdk::epoch_info::EpochInfo {
+            epoch: 700,
+            slot_index: 1_000,
+            slots_in_epoch: 432_000,
+            absolute_slot: 302_401_000,
+            block_height: 0,
+            transaction_count: None,
+        };
+        assert_eq!(next_epoch_start_slot(&epoch_info, 0), 302_832_000);
+        assert_eq!(next_epoch_start_slot(&epoch_info, 50), 302_832_050);
+    }
+
+    #[test]
+    fn test_estimated_total_rewards() {
+        let mut reward = EpochReward {
+            epoch: 700,
+            amount_lamports: 500_000_000,
+            post_balance_lamports: 2_000_000_000,
+            commission: Some(5),
+        };
+        assert_eq!(reward.estimated_total_rewards_lamports(), Some(10_000_000_000));
+        reward.commission = Some(0);
+        assert_eq!(reward.estimated_total_rewards_lamports(), None);
+        reward.commission = None;
+        assert_eq!(reward.estimated_total_rewards_lamports(), None);
+        assert!((lamports_to_sol(1_500_000_000) - 1.5).abs() < f64::EPSILON);
+    }
 }
@@ -1,16 +1,284 @@
-use crate::types::NodeConfig;
+use crate::commands::switch::shell_quote;
+use crate::types::{NodeConfig, SudoConfig};
 use anyhow::{anyhow, Result};
 use openssh::{Session, SessionBuilder, Stdio};
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
+
+/// Stands in for a key path for nodes logged into with a password
+pub const PASSWORD_AUTH: &str = "password";
+
+/// Set on the ssh process only: the password svs, started as its
+/// SSH_ASKPASS, answers with. It never reaches svs's own environment or disk.
+const ASKPASS_PASSWORD_ENV: &str = "SVS_ASKPASS_PASSWORD";
+
+/// Upload chunk size; progress is reported after each chunk
+const TRANSFER_CHUNK_BYTES: usize = 16 * 1024;
 
 /// SSH session pool with async support and connection reuse
 pub struct AsyncSshPool {
     sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     config: PoolConfig,
+    metrics: Arc<Mutex<HashMap<String, HostMetrics>>>, // host -> counters
+    limiter: Arc<HostLimiter>,
+    passwords: Arc<RwLock<HashMap<String, String>>>, // user@host:port -> password, memory only
+    sudo_passwords: Arc<RwLock<HashMap<String, String>>>, // user@host:port -> sudo password, memory only
+    connect_gates: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>, // connection key -> held while connecting
+    askpass_dirs: Arc<Mutex<HashMap<String, AskpassDir>>>, // connection key -> password master's directory
+}
+
+/// Private directory of a password session's control master and its log.
+/// Dropping it stops the master and removes the directory.
+struct AskpassDir {
+    dir: std::path::PathBuf,
+}
+
+impl AskpassDir {
+    fn create() -> Result<Self> {
+        static NEXT_DIR: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+        let dir = std::env::temp_dir().join(format!(
+            "svs-ssh-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
+        }
+        Ok(Self { dir })
+    }
+
+    fn control(&self) -> std::path::PathBuf {
+        self.dir.join("master")
+    }
+
+    fn log(&self) -> std::path::PathBuf {
+        self.dir.join("log")
+    }
+
+}
+
+impl Drop for AskpassDir {
+    fn drop(&mut self) {
+        let control = self.control();
+        if control.exists() {
+            let _ = std::process::Command::new("ssh")
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .arg("-S")
+                .arg(&control)
+                .args(["-O", "exit", "svs"])
+                .status();
+        }
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Scheduling class of a remote command. Critical commands (switch, failover)
+/// start immediately and hold back new routine commands until they finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandPriority {
+    Routine,
+    Critical,
+}
+
+tokio::task_local! {
+    static COMMAND_PRIORITY: CommandPriority;
+}
+
+/// Run `future` with every SSH command it issues treated as critical
+pub async fn critical<F: std::future::Future>(future: F) -> F::Output {
+    COMMAND_PRIORITY.scope(CommandPriority::Critical, future).await
+}
+
+fn current_priority() -> CommandPriority {
+    COMMAND_PRIORITY
+        .try_with(|priority| *priority)
+        .unwrap_or(CommandPriority::Routine)
+}
+
+/// Commands admitted on one host
+#[derive(Debug, Default)]
+struct HostSlots {
+    routine_running: usize,
+    critical_running: usize,
+    released: Arc<Notify>,
+}
+
+impl HostSlots {
+    fn admits(&self, priority: CommandPriority, max_routine: usize) -> bool {
+        match priority {
+            CommandPriority::Critical => true,
+            CommandPriority::Routine => {
+                self.critical_running == 0 && self.routine_running < max_routine
+            }
+        }
+    }
+}
+
+/// Per-host admission control so UI refreshes, catchup and health probes
+/// can't flood a node, and never delay a switch
+struct HostLimiter {
+    max_routine: usize,
+    hosts: Mutex<HashMap<String, HostSlots>>,
+}
+
+impl HostLimiter {
+    fn new(max_routine: usize) -> Self {
+        Self {
+            max_routine: max_routine.max(1),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a slot if one is free, otherwise return what to wait on
+    fn try_admit(&self, host: &str, priority: CommandPriority) -> std::result::Result<(), Arc<Notify>> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let slots = hosts.entry(host.to_string()).or_default();
+        if !slots.admits(priority, self.max_routine) {
+            return Err(Arc::clone(&slots.released));
+        }
+        match priority {
+            CommandPriority::Critical => slots.critical_running += 1,
+            CommandPriority::Routine => slots.routine_running += 1,
+        }
+        Ok(())
+    }
+
+    async fn acquire(self: &Arc<Self>, host: &str) -> HostPermit {
+        let priority = current_priority();
+        loop {
+            let released = match self.try_admit(host, priority) {
+                Ok(()) => break,
+                Err(released) => released,
+            };
+            // Register before re-checking so a release in between isn't missed
+            let notified = released.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.try_admit(host, priority).is_ok() {
+                break;
+            }
+            notified.await;
+        }
+
+        HostPermit {
+            limiter: Arc::clone(self),
+            host: host.to_string(),
+            priority,
+        }
+    }
+}
+
+/// A running command's slot, freed on drop
+struct HostPermit {
+    limiter: Arc<HostLimiter>,
+    host: String,
+    priority: CommandPriority,
+}
+
+impl Drop for HostPermit {
+    fn drop(&mut self) {
+        let mut hosts = self.limiter.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(slots) = hosts.get_mut(&self.host) {
+            match self.priority {
+                CommandPriority::Critical => {
+                    slots.critical_running = slots.critical_running.saturating_sub(1)
+                }
+                CommandPriority::Routine => {
+                    slots.routine_running = slots.routine_running.saturating_sub(1)
+                }
+            }
+            slots.released.notify_waiters();
+        }
+    }
+}
+
+/// Running counters for one host, used to diagnose pool saturation
+#[derive(Debug, Clone, Default)]
+pub struct HostMetrics {
+    pub in_flight: usize,        // Commands waiting for a session or running
+    pub peak_in_flight: usize,   // Highest in-flight count seen
+    pub active_streams: usize,   // Long-running streaming commands (catchup)
+    pub commands: u64,           // Completed commands
+    pub failed_commands: u64,    // Completed commands that returned an error
+    pub total_latency: Duration, // Sum of completed command latencies
+    pub last_latency: Option<Duration>,
+    pub sessions_created: u64,   // First connections
+    pub reconnects: u64,         // Sessions recreated after going stale
+}
+
+impl HostMetrics {
+    pub fn average_latency(&self) -> Option<Duration> {
+        if self.commands == 0 {
+            None
+        } else {
+            Some(self.total_latency / self.commands as u32)
+        }
+    }
+}
+
+/// Tracks one command from start to finish; records latency on drop so every
+/// early return is accounted for
+struct CommandTimer {
+    metrics: Arc<Mutex<HashMap<String, HostMetrics>>>,
+    host: String,
+    started: Instant,
+    streaming: bool,
+    failed: bool,
+}
+
+impl CommandTimer {
+    fn start(metrics: &Arc<Mutex<HashMap<String, HostMetrics>>>, host: &str, streaming: bool) -> Self {
+        if let Ok(mut metrics) = metrics.lock() {
+            let entry = metrics.entry(host.to_string()).or_default();
+            if streaming {
+                entry.active_streams += 1;
+            } else {
+                entry.in_flight += 1;
+                entry.peak_in_flight = entry.peak_in_flight.max(entry.in_flight);
+            }
+        }
+        Self {
+            metrics: Arc::clone(metrics),
+            host: host.to_string(),
+            started: Instant::now(),
+            streaming,
+            failed: true,
+        }
+    }
+
+    fn finish<T>(mut self, result: Result<T>) -> Result<T> {
+        self.failed = result.is_err();
+        result
+    }
+}
+
+impl Drop for CommandTimer {
+    fn drop(&mut self) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            let entry = metrics.entry(self.host.clone()).or_default();
+            if self.streaming {
+                entry.active_streams = entry.active_streams.saturating_sub(1);
+                return;
+            }
+            let latency = self.started.elapsed();
+            entry.in_flight = entry.in_flight.saturating_sub(1);
+            entry.commands += 1;
+            entry.total_latency += latency;
+            entry.last_latency = Some(latency);
+            if self.failed {
+                entry.failed_commands += 1;
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -18,6 +286,7 @@ pub struct PoolConfig {
     pub connect_timeout: Duration,
     pub max_idle_time: Duration,
     pub multiplex: bool,
+    pub max_concurrent_per_host: usize, // Routine commands running at once on one host
 }
 
 impl Default for PoolConfig {
@@ -26,6 +295,7 @@ impl Default for PoolConfig {
             connect_timeout: Duration::from_secs(10),
             max_idle_time: Duration::from_secs(300),
             multiplex: true, // Enable connection multiplexing by default
+            max_concurrent_per_host: 4,
         }
     }
 }
@@ -38,33 +308,67 @@ impl AsyncSshPool {
     pub fn with_config(config: PoolConfig) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
+            limiter: Arc::new(HostLimiter::new(config.max_concurrent_per_host)),
             config,
+            metrics: Arc::new(Mutex::new(HashMap::new())),
+            passwords: Arc::new(RwLock::new(HashMap::new())),
+            sudo_passwords: Arc::new(RwLock::new(HashMap::new())),
+            connect_gates: Arc::new(Mutex::new(HashMap::new())),
+            askpass_dirs: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Remember the password for a node with `passwordAuth`; it is only kept
+    /// in memory and handed to ssh when a session is (re)connected
+    pub async fn set_password(&self, node: &NodeConfig, password: String) {
+        let key = format!("{}@{}:{}", node.user, node.host, node.port);
+        self.passwords.write().await.insert(key, password);
+    }
+
+    /// Remember the sudo password for a node with `sudo.askpass`; like SSH
+    /// passwords it is only kept in memory
+    pub async fn set_sudo_password(&self, node: &NodeConfig, password: String) {
+        let key = format!("{}@{}:{}", node.user, node.host, node.port);
+        self.sudo_passwords.write().await.insert(key, password);
+    }
+
     fn get_connection_key(node: &NodeConfig, ssh_key_path: &str) -> String {
         format!("{}@{}:{}:{}", node.user, node.host, node.port, ssh_key_path)
     }
 
-    /// Get or create an SSH session for a node
+    /// Get or create an SSH session for a node. Commands on a host share its
+    /// one master connection, each running on its own channel.
     pub async fn get_session(&self, node: &NodeConfig, ssh_key_path: &str) -> Result<Arc<Session>> {
         let key = Self::get_connection_key(node, ssh_key_path);
+        if let Some(session) = self.alive_session(&key).await {
+            return Ok(session);
+        }
 
-        // Try to get existing session
-        {
-            let sessions = self.sessions.read().await;
-            if let Some(session) = sessions.get(&key) {
-                // Check if session is still alive
-                if self.is_session_alive(session).await {
-                    return Ok(Arc::clone(session));
-                }
-            }
+        // Callers arriving while a connection is being made wait for it and
+        // share it instead of opening their own
+        let gate = {
+            let mut gates = self.connect_gates.lock().unwrap_or_else(|e| e.into_inner());
+            Arc::clone(gates.entry(key.clone()).or_default())
+        };
+        let _connecting = gate.lock().await;
+        if let Some(session) = self.alive_session(&key).await {
+            return Ok(session);
         }
+        let is_reconnect = self.sessions.read().await.contains_key(&key);
 
         // Create new session
         let session = self.create_session(node, ssh_key_path).await?;
         let session_arc = Arc::new(session);
 
+        if let Ok(mut metrics) = self.metrics.lock() {
+            let entry = metrics.entry(node.host.clone()).or_default();
+            if is_reconnect {
+                entry.reconnects += 1;
+            } else {
+                entry.sessions_created += 1;
+            }
+        }
+
         // Store session
         {
             let mut sessions = self.sessions.write().await;
@@ -74,7 +378,18 @@ impl AsyncSshPool {
         Ok(session_arc)
     }
 
+    /// Cached session for `key` if its master connection is still up. The
+    /// check is a message on the local control socket, not a remote command.
+    async fn alive_session(&self, key: &str) -> Option<Arc<Session>> {
+        let session = self.sessions.read().await.get(key).cloned()?;
+        session.check().await.ok().map(|_| session)
+    }
+
     async fn create_session(&self, node: &NodeConfig, ssh_key_path: &str) -> Result<Session> {
+        if ssh_key_path == PASSWORD_AUTH {
+            return self.create_password_session(node).await;
+        }
+
         // Expand the SSH key path
         let expanded_path = if ssh_key_path.starts_with("~") {
             let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
@@ -97,6 +412,9 @@ impl AsyncSshPool {
             .port(node.port)
             .keyfile(&expanded_path)
             .connect_timeout(self.config.connect_timeout);
+        if let Some(config_file) = crate::proxy::ssh_config_file() {
+            builder.config_file(config_file);
+        }
 
         // Enable multiplexing if configured
         if self.config.multiplex {
@@ -110,20 +428,71 @@ impl AsyncSshPool {
             }
         }
 
+        // Native multiplexing talks to the master's control socket directly
+        // rather than spawning a local ssh client per command
         let session = builder
-            .connect(&node.host)
+            .connect_mux(&node.host)
             .await
             .map_err(|e| anyhow!("Failed to connect to {}@{}: {}", node.user, node.host, e))?;
 
         Ok(session)
     }
 
-    async fn is_session_alive(&self, session: &Session) -> bool {
-        // Simple check by running a lightweight command
-        match session.command("true").output().await {
-            Ok(output) => output.status.success(),
-            Err(_) => false,
+    /// The openssh builder always runs ssh in batch mode, which rules out
+    /// passwords, so start the control master here with svs itself as
+    /// SSH_ASKPASS and attach to its socket
+    async fn create_password_session(&self, node: &NodeConfig) -> Result<Session> {
+        let key = format!("{}@{}:{}", node.user, node.host, node.port);
+        let password = self
+            .passwords
+            .read()
+            .await
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow!("No password entered for {}@{}", node.user, node.host))?;
+
+        let dir = AskpassDir::create()?;
+        let control = dir.control();
+        let log = dir.log();
+
+        let persist = if self.config.multiplex {
+            self.config.max_idle_time.as_secs()
+        } else {
+            0
+        };
+        let status = tokio::process::Command::new("ssh")
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .arg("-E")
+            .arg(&log)
+            .arg("-S")
+            .arg(&control)
+            .args(crate::proxy::ssh_option_args())
+            .args(password_master_args(node, self.config.connect_timeout, persist))
+            .env("SSH_ASKPASS", std::env::current_exe()?)
+            .env("SSH_ASKPASS_REQUIRE", "force")
+            .env(ASKPASS_PASSWORD_ENV, &password)
+            .status()
+            .await
+            .map_err(|e| anyhow!("Failed to start ssh for {}@{}: {}", node.user, node.host, e))?;
+
+        if !status.success() {
+            let output = std::fs::read_to_string(&log).unwrap_or_default();
+            return Err(anyhow!(
+                "Failed to connect to {}@{} with password: {}",
+                node.user,
+                node.host,
+                output.trim()
+            ));
         }
+
+        // Replacing a dead master's directory stops and removes it
+        self.askpass_dirs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, dir);
+        Ok(Session::resume_mux(control.into_boxed_path(), Some(log.into_boxed_path())))
     }
 
     /// Execute a command with arguments and return the output
@@ -133,6 +502,21 @@ impl AsyncSshPool {
         ssh_key_path: &str,
         command: &str,
         args: &[&str],
+    ) -> Result<String> {
+        let timer = CommandTimer::start(&self.metrics, &node.host, false);
+        let _permit = self.limiter.acquire(&node.host).await;
+        timer.finish(
+            self.execute_command_with_args_inner(node, ssh_key_path, command, args)
+                .await,
+        )
+    }
+
+    async fn execute_command_with_args_inner(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        command: &str,
+        args: &[&str],
     ) -> Result<String> {
         let session = self.get_session(node, ssh_key_path).await?;
 
@@ -170,6 +554,17 @@ impl AsyncSshPool {
         node: &NodeConfig,
         ssh_key_path: &str,
         command: &str,
+    ) -> Result<String> {
+        let timer = CommandTimer::start(&self.metrics, &node.host, false);
+        let _permit = self.limiter.acquire(&node.host).await;
+        timer.finish(self.execute_command_inner(node, ssh_key_path, command).await)
+    }
+
+    async fn execute_command_inner(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        command: &str,
     ) -> Result<String> {
         let session = self.get_session(node, ssh_key_path).await?;
 
@@ -222,6 +617,38 @@ impl AsyncSshPool {
         Ok(String::new())
     }
 
+    /// Run a command through the node's `sudo` settings, or as the SSH user
+    /// when it has none. With `askpass` the password entered at startup is
+    /// written to sudo's stdin.
+    pub async fn execute_privileged(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        command: &str,
+    ) -> Result<String> {
+        let sudo = match &node.sudo {
+            Some(sudo) => sudo,
+            None => return self.execute_command(node, ssh_key_path, command).await,
+        };
+        let wrapped = sudo_command(command, sudo);
+        if !sudo.askpass {
+            return self
+                .execute_command_with_args(node, ssh_key_path, "bash", &["-c", &wrapped])
+                .await;
+        }
+
+        let key = format!("{}@{}:{}", node.user, node.host, node.port);
+        let password = self
+            .sudo_passwords
+            .read()
+            .await
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow!("No sudo password entered for {}", node.host))?;
+        self.execute_command_with_input(node, ssh_key_path, &wrapped, &format!("{}\n", password))
+            .await
+    }
+
     /// Execute a command with early exit based on output
     pub async fn execute_command_with_early_exit<F>(
         &self,
@@ -230,6 +657,24 @@ impl AsyncSshPool {
         command: &str,
         check_fn: F,
     ) -> Result<String>
+    where
+        F: Fn(&str) -> bool + Send + 'static,
+    {
+        let timer = CommandTimer::start(&self.metrics, &node.host, false);
+        let _permit = self.limiter.acquire(&node.host).await;
+        timer.finish(
+            self.execute_command_with_early_exit_inner(node, ssh_key_path, command, check_fn)
+                .await,
+        )
+    }
+
+    async fn execute_command_with_early_exit_inner<F>(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        command: &str,
+        check_fn: F,
+    ) -> Result<String>
     where
         F: Fn(&str) -> bool + Send + 'static,
     {
@@ -306,6 +751,7 @@ impl AsyncSshPool {
         command: &str,
         tx: tokio::sync::mpsc::Sender<String>,
     ) -> Result<()> {
+        let _stream = CommandTimer::start(&self.metrics, &node.host, true);
         let session = self.get_session(node, ssh_key_path).await?;
 
         // Check if command needs shell features
@@ -410,29 +856,29 @@ impl AsyncSshPool {
         command: &str,
         input: &str,
     ) -> Result<String> {
-        let session = self.get_session(node, ssh_key_path).await?;
-
-        // Check if command needs shell features
-        let needs_shell = command.contains('|')
-            || command.contains('>')
-            || command.contains('<')
-            || command.contains('&')
-            || command.contains(';')
-            || command.contains('$')
-            || command.contains('`')
-            || command.contains("||")
-            || command.contains("&&")
-            || command.contains("2>&1");
+        let timer = CommandTimer::start(&self.metrics, &node.host, false);
+        let _permit = self.limiter.acquire(&node.host).await;
+        timer.finish(
+            self.execute_command_with_input_inner(node, ssh_key_path, command, input)
+                .await,
+        )
+    }
 
-        let shell_command = if needs_shell {
-            format!("bash -c '{}'", command.replace("'", "'\\'''"))
-        } else {
-            command.to_string()
-        };
+    async fn execute_command_with_input_inner(
+        &self,
+        node: &NodeConfig,
+        ssh_key_path: &str,
+        command: &str,
+        input: &str,
+    ) -> Result<String> {
+        let session = self.get_session(node, ssh_key_path).await?;
 
-        // Create command with input via pipe
+        // Run through bash so quoting and shell features in the command work;
+        // the program name given to the session is escaped as a single word
         let mut child = session
-            .command(&shell_command)
+            .command("bash")
+            .arg("-c")
+            .arg(command)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -461,14 +907,20 @@ impl AsyncSshPool {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
-    /// Optimized tower transfer using base64 -d streaming + dd
-    pub async fn transfer_base64_to_file(
+    /// Optimized tower transfer using base64 -d streaming + dd. Calls
+    /// `on_progress(sent, total)` as the decoded bytes are written.
+    pub async fn transfer_base64_to_file<F>(
         &self,
         node: &NodeConfig,
         ssh_key_path: &str,
         remote_path: &str,
         base64_data: &str,
-    ) -> Result<()> {
+        mut on_progress: F,
+    ) -> Result<()>
+    where
+        F: FnMut(u64, u64),
+    {
+        let _permit = self.limiter.acquire(&node.host).await;
         let session = self.get_session(node, ssh_key_path).await?;
 
         // Start base64 -d on remote, writing to stdout
@@ -518,7 +970,13 @@ impl AsyncSshPool {
         // Write decoded content to dd stdin
         if let Some(mut dd_stdin) = dd_child.stdin().take() {
             use tokio::io::AsyncWriteExt;
-            dd_stdin.write_all(&decoded).await?;
+            let total = decoded.len() as u64;
+            let mut sent = 0;
+            for chunk in decoded.chunks(TRANSFER_CHUNK_BYTES) {
+                dd_stdin.write_all(chunk).await?;
+                sent += chunk.len() as u64;
+                on_progress(sent, total);
+            }
             dd_stdin.flush().await?;
             drop(dd_stdin);
         }
@@ -540,6 +998,7 @@ impl AsyncSshPool {
         local_path: &str,
         remote_path: &str,
     ) -> Result<()> {
+        let _permit = self.limiter.acquire(&node.host).await;
         let session = self.get_session(node, ssh_key_path).await?;
 
         // Read file content
@@ -573,6 +1032,10 @@ impl AsyncSshPool {
     pub async fn clear_all_sessions(&self) {
         let mut sessions = self.sessions.write().await;
         sessions.clear();
+        self.askpass_dirs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
     }
 
     /// Get pool statistics
@@ -583,7 +1046,7 @@ impl AsyncSshPool {
         // Count alive sessions
         let mut alive = 0;
         for session in sessions.values() {
-            if self.is_session_alive(session).await {
+            if session.check().await.is_ok() {
                 alive += 1;
             }
         }
@@ -603,6 +1066,111 @@ pub struct PoolStats {
     pub dead_sessions: usize,
 }
 
+/// Per-host view of the pool for the diagnostics panel and metrics export
+#[derive(Debug, Clone)]
+pub struct HostPoolDiagnostics {
+    pub host: String,
+    pub open_sessions: usize,
+    pub metrics: HostMetrics,
+}
+
+impl AsyncSshPool {
+    /// Snapshot of per-host pool state. Does not probe sessions, so it is cheap
+    /// enough to call on every UI frame.
+    pub async fn diagnostics(&self) -> Vec<HostPoolDiagnostics> {
+        let mut open_sessions: HashMap<String, usize> = HashMap::new();
+        {
+            let sessions = self.sessions.read().await;
+            for key in sessions.keys() {
+                // Key format: user@host:port:key_path
+                let host = key
+                    .split_once('@')
+                    .map(|(_, rest)| rest.split(':').next().unwrap_or(rest))
+                    .unwrap_or(key);
+                *open_sessions.entry(host.to_string()).or_default() += 1;
+            }
+        }
+
+        let metrics = self
+            .metrics
+            .lock()
+            .map(|m| m.clone())
+            .unwrap_or_default();
+
+        let mut hosts: Vec<String> = metrics.keys().cloned().collect();
+        for host in open_sessions.keys() {
+            if !hosts.contains(host) {
+                hosts.push(host.clone());
+            }
+        }
+        hosts.sort();
+
+        hosts
+            .into_iter()
+            .map(|host| HostPoolDiagnostics {
+                open_sessions: open_sessions.get(&host).copied().unwrap_or(0),
+                metrics: metrics.get(&host).cloned().unwrap_or_default(),
+                host,
+            })
+            .collect()
+    }
+}
+
+/// Metric name, help text and value extractor for one exported gauge
+type PoolGauge = (&'static str, &'static str, fn(&HostPoolDiagnostics) -> f64);
+
+fn pool_gauges() -> [PoolGauge; 8] {
+    [
+        ("svs_ssh_open_sessions", "Open SSH sessions", |d| d.open_sessions as f64),
+        ("svs_ssh_in_flight_commands", "Commands waiting or running", |d| d.metrics.in_flight as f64),
+        ("svs_ssh_active_streams", "Long-running streaming commands", |d| d.metrics.active_streams as f64),
+        ("svs_ssh_commands_total", "Completed commands", |d| d.metrics.commands as f64),
+        ("svs_ssh_failed_commands_total", "Completed commands that failed", |d| d.metrics.failed_commands as f64),
+        ("svs_ssh_command_latency_avg_seconds", "Average command latency", |d| {
+            d.metrics.average_latency().map(|l| l.as_secs_f64()).unwrap_or(0.0)
+        }),
+        ("svs_ssh_sessions_created_total", "SSH sessions created", |d| d.metrics.sessions_created as f64),
+        ("svs_ssh_reconnects_total", "SSH sessions recreated after going stale", |d| d.metrics.reconnects as f64),
+    ]
+}
+
+/// Name and help text of every exported pool metric
+pub fn pool_metric_names() -> Vec<(&'static str, &'static str)> {
+    pool_gauges()
+        .iter()
+        .map(|(name, help, _)| (*name, *help))
+        .collect()
+}
+
+/// Render pool diagnostics in Prometheus text format (node_exporter textfile collector)
+pub fn render_pool_metrics(diagnostics: &[HostPoolDiagnostics]) -> String {
+    let mut out = String::new();
+
+    for (name, help, value) in pool_gauges().iter() {
+        out.push_str(&format!("# HELP {} {}\n# TYPE {} gauge\n", name, help, name));
+        for d in diagnostics {
+            out.push_str(&format!("{}{{host=\"{}\"}} {}\n", name, d.host, value(d)));
+        }
+    }
+
+    out
+}
+
+/// Render pool diagnostics as InfluxDB line protocol, one `svs_ssh` point
+/// per host with the same values as the Prometheus gauges
+pub fn render_pool_lines(diagnostics: &[HostPoolDiagnostics], timestamp: i64) -> String {
+    diagnostics
+        .iter()
+        .map(|d| {
+            let fields: Vec<(&str, String)> = pool_gauges()
+                .iter()
+                .map(|(name, _, value)| (name.trim_start_matches("svs_ssh_"), value(d).to_string()))
+                .collect();
+            crate::influxdb::line("svs_ssh", &[("host", &d.host)], &fields, timestamp)
+        })
+        .collect()
+}
+
 /// SSH command builder for complex commands
 #[allow(dead_code)]
 pub struct CommandBuilder {
@@ -676,3 +1244,289 @@ impl CommandBuilder {
         cmd
     }
 }
+
+/// `command` run by bash as the sudo target user. With `askpass` sudo reads
+/// the password from stdin without printing a prompt; otherwise it must not
+/// need one, so a missing sudoers rule fails instead of hanging.
+pub fn sudo_command(command: &str, sudo: &SudoConfig) -> String {
+    let auth = if sudo.askpass { "-S -p ''" } else { "-n" };
+    format!(
+        "sudo {} -u {} -- bash -c {}",
+        auth,
+        shell_quote(&sudo.user),
+        shell_quote(command)
+    )
+}
+
+/// Delay before the next health check of a host that failed `failures`
+/// times in a row: `base` doubled per failure up to `max`, spread by up to
+/// ±20% (`jitter` in -1.0..=1.0) so hosts recovering together aren't hit at once
+pub fn backoff_delay(base: Duration, max: Duration, failures: u32, jitter: f64) -> Duration {
+    let exponential = base.saturating_mul(1u32 << failures.saturating_sub(1).min(16)).min(max);
+    exponential.mul_f64(1.0 + 0.2 * jitter.clamp(-1.0, 1.0)).min(max)
+}
+
+/// When a host's SSH health is next checked: every `base` while healthy,
+/// with exponential backoff while it keeps failing
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    base: Duration,
+    max: Duration,
+    failures: u32,
+    next_attempt: Option<Instant>,
+}
+
+impl ReconnectBackoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            failures: 0,
+            next_attempt: None,
+        }
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.next_attempt.is_none_or(|next| now >= next)
+    }
+
+    pub fn record_success(&mut self, now: Instant) {
+        self.failures = 0;
+        self.next_attempt = Some(now + self.base);
+    }
+
+    /// Returns the delay until the next attempt
+    pub fn record_failure(&mut self, now: Instant, jitter: f64) -> Duration {
+        self.failures += 1;
+        let delay = backoff_delay(self.base, self.max, self.failures, jitter);
+        self.next_attempt = Some(now + delay);
+        delay
+    }
+
+    /// Check at the next opportunity, e.g. when the operator asks for a retry
+    pub fn retry_now(&mut self) {
+        self.next_attempt = None;
+    }
+}
+
+/// Uniform-ish value in -1.0..=1.0, different per process and call
+pub fn jitter() -> f64 {
+    use std::hash::BuildHasher;
+    let seed = std::collections::hash_map::RandomState::new().hash_one(Instant::now());
+    (seed % 2001) as f64 / 1000.0 - 1.0
+}
+
+/// Arguments starting a background control master that logs in with a
+/// password only. A persist of 0 keeps the master for as long as svs uses it.
+pub fn password_master_args(node: &NodeConfig, connect_timeout: Duration, persist_secs: u64) -> Vec<String> {
+    let persist = if persist_secs == 0 {
+        "ControlPersist=yes".to_string()
+    } else {
+        format!("ControlPersist={}s", persist_secs)
+    };
+    let mut args = vec!["-M".to_string(), "-f".to_string(), "-N".to_string()];
+    for option in [
+        persist,
+        "BatchMode=no".to_string(),
+        "StrictHostKeyChecking=accept-new".to_string(),
+        "PubkeyAuthentication=no".to_string(),
+        "PreferredAuthentications=password,keyboard-interactive".to_string(),
+        "NumberOfPasswordPrompts=1".to_string(),
+        format!("ConnectTimeout={}", connect_timeout.as_secs().max(1)),
+    ] {
+        args.push("-o".to_string());
+        args.push(option);
+    }
+    args.extend([
+        "-p".to_string(),
+        node.port.to_string(),
+        "-l".to_string(),
+        node.user.clone(),
+        node.host.clone(),
+    ]);
+    args
+}
+
+/// When svs runs as ssh's SSH_ASKPASS helper: the answer to `prompt`, or
+/// None if it isn't a password prompt (e.g. an unexpected confirmation)
+pub fn askpass_reply(prompt: &str) -> Option<String> {
+    let password = std::env::var(ASKPASS_PASSWORD_ENV).ok()?;
+    if prompt.to_lowercase().contains("password") {
+        Some(password)
+    } else {
+        None
+    }
+}
+
+/// Whether this process was started by ssh as the password helper
+pub fn is_askpass() -> bool {
+    std::env::var_os(ASKPASS_PASSWORD_ENV).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_askpass_dir_is_private_and_removed() {
+        let dir = AskpassDir::create().unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&dir.dir).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+
+        let path = dir.dir.clone();
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_render_pool_metrics() {
+        let diagnostics = vec![HostPoolDiagnostics {
+            host: "10.0.0.1".to_string(),
+            open_sessions: 1,
+            metrics: HostMetrics {
+                in_flight: 2,
+                commands: 4,
+                failed_commands: 1,
+                total_latency: Duration::from_millis(400),
+                ..Default::default()
+            },
+        }];
+
+        let out = render_pool_metrics(&diagnostics);
+        assert!(out.contains("# TYPE svs_ssh_open_sessions gauge"));
+        assert!(out.contains("svs_ssh_in_flight_commands{host=\"10.0.0.1\"} 2\n"));
+        assert!(out.contains("svs_ssh_failed_commands_total{host=\"10.0.0.1\"} 1\n"));
+        assert!(out.contains("svs_ssh_command_latency_avg_seconds{host=\"10.0.0.1\"} 0.1\n"));
+
+        let lines = render_pool_lines(&diagnostics, 100);
+        assert!(lines.starts_with("svs_ssh,host=10.0.0.1 open_sessions=1,in_flight_commands=2,"));
+        assert!(lines.contains(",command_latency_avg_seconds=0.1,"));
+        assert!(lines.ends_with(" 100\n"));
+    }
+
+    #[test]
+    fn test_critical_commands_hold_back_routine() {
+        let limiter = HostLimiter::new(2);
+        assert!(limiter.try_admit("a", CommandPriority::Routine).is_ok());
+        assert!(limiter.try_admit("a", CommandPriority::Routine).is_ok());
+        assert!(limiter.try_admit("a", CommandPriority::Routine).is_err());
+        // Other hosts have their own slots
+        assert!(limiter.try_admit("b", CommandPriority::Routine).is_ok());
+
+        // Critical commands never wait, and block new routine ones meanwhile
+        assert!(limiter.try_admit("b", CommandPriority::Critical).is_ok());
+        assert!(limiter.try_admit("a", CommandPriority::Critical).is_ok());
+        assert!(limiter.try_admit("b", CommandPriority::Routine).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_permit_release_wakes_waiter() {
+        let limiter = Arc::new(HostLimiter::new(1));
+        let held = critical(limiter.acquire("a")).await;
+        assert_eq!(held.priority, CommandPriority::Critical);
+
+        let waiter = {
+            let limiter = Arc::clone(&limiter);
+            tokio::spawn(async move { limiter.acquire("a").await.priority })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        drop(held);
+        let priority = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(priority, CommandPriority::Routine);
+    }
+
+    #[test]
+    fn test_password_master_args() {
+        let node = NodeConfig {
+            label: "node-a".to_string(),
+            host: "10.0.0.1".to_string(),
+            port: 2222,
+            user: "solana".to_string(),
+            paths: crate::types::NodePaths {
+                funded_identity: "/keys/funded.json".to_string(),
+                unfunded_identity: "/keys/unfunded.json".to_string(),
+                vote_keypair: "/keys/vote.json".to_string(),
+                ..Default::default()
+            },
+            ssh_key_path: None,
+            password_auth: true,
+            sudo: None,
+            commands: None,
+        };
+
+        let args = password_master_args(&node, Duration::from_secs(10), 300);
+        assert_eq!(&args[..3], ["-M", "-f", "-N"]);
+        assert!(args.windows(2).any(|w| w == ["-o", "BatchMode=no"]));
+        assert!(args.windows(2).any(|w| w == ["-o", "PubkeyAuthentication=no"]));
+        assert!(args.windows(2).any(|w| w == ["-o", "ControlPersist=300s"]));
+        assert!(args.ends_with(&["-p", "2222", "-l", "solana", "10.0.0.1"].map(String::from)));
+
+        let args = password_master_args(&node, Duration::from_secs(10), 0);
+        assert!(args.windows(2).any(|w| w == ["-o", "ControlPersist=yes"]));
+    }
+
+    #[test]
+    fn test_sudo_command() {
+        let sudo = SudoConfig {
+            user: "sol".to_string(),
+            askpass: false,
+        };
+        assert_eq!(
+            sudo_command("test -r '/keys/a.json' && echo ok", &sudo),
+            "sudo -n -u 'sol' -- bash -c 'test -r '\\''/keys/a.json'\\'' && echo ok'"
+        );
+
+        let sudo = SudoConfig {
+            user: "root".to_string(),
+            askpass: true,
+        };
+        assert_eq!(sudo_command("true", &sudo), "sudo -S -p '' -u 'root' -- bash -c 'true'");
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let base = Duration::from_secs(30);
+        let max = Duration::from_secs(300);
+        assert_eq!(backoff_delay(base, max, 1, 0.0), Duration::from_secs(30));
+        assert_eq!(backoff_delay(base, max, 2, 0.0), Duration::from_secs(60));
+        assert_eq!(backoff_delay(base, max, 3, 0.0), Duration::from_secs(120));
+        assert_eq!(backoff_delay(base, max, 5, 0.0), max);
+        assert_eq!(backoff_delay(base, max, 100, 0.0), max);
+        // Jitter spreads the delay but never past the cap
+        assert_eq!(backoff_delay(base, max, 2, 1.0), Duration::from_secs(72));
+        assert_eq!(backoff_delay(base, max, 2, -1.0), Duration::from_secs(48));
+        assert_eq!(backoff_delay(base, max, 5, 1.0), max);
+    }
+
+    #[test]
+    fn test_reconnect_backoff() {
+        let start = Instant::now();
+        let mut backoff = ReconnectBackoff::new(Duration::from_secs(30), Duration::from_secs(300));
+        assert!(backoff.is_due(start));
+
+        backoff.record_success(start);
+        assert!(!backoff.is_due(start + Duration::from_secs(29)));
+        assert!(backoff.is_due(start + Duration::from_secs(30)));
+
+        assert_eq!(backoff.record_failure(start, 0.0), Duration::from_secs(30));
+        assert_eq!(backoff.record_failure(start, 0.0), Duration::from_secs(60));
+        assert!(!backoff.is_due(start + Duration::from_secs(59)));
+        backoff.retry_now();
+        assert!(backoff.is_due(start));
+
+        // A success resets the schedule
+        backoff.record_success(start);
+        assert_eq!(backoff.record_failure(start, 0.0), Duration::from_secs(30));
+
+        assert!((-1.0..=1.0).contains(&jitter()));
+    }
+}
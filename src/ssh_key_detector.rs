@@ -11,9 +11,9 @@ pub struct SshKeyInfo {
 }
 
 /// Try to detect SSH key by using ssh -vv and parsing the output
-pub async fn detect_ssh_key(host: &str, user: &str) -> Result<String> {
+pub async fn detect_ssh_key(host: &str, port: u16, user: &str) -> Result<String> {
     // Always use verbose SSH to detect the actual key being used
-    extract_key_from_verbose_ssh(host, user).await
+    extract_key_from_verbose_ssh(host, port, user).await
 }
 
 /// Try an SSH connection with optional key path
@@ -27,7 +27,8 @@ async fn try_ssh_connection(host: &str, user: &str, key_path: Option<&str>) -> R
         .arg("-o")
         .arg("StrictHostKeyChecking=no")
         .arg("-o")
-        .arg("PasswordAuthentication=no");
+        .arg("PasswordAuthentication=no")
+        .args(crate::proxy::ssh_option_args());
 
     if let Some(key) = key_path {
         cmd.arg("-i").arg(key);
@@ -40,9 +41,11 @@ async fn try_ssh_connection(host: &str, user: &str, key_path: Option<&str>) -> R
 }
 
 /// Extract the working SSH key path from verbose SSH output
-async fn extract_key_from_verbose_ssh(host: &str, user: &str) -> Result<String> {
+async fn extract_key_from_verbose_ssh(host: &str, port: u16, user: &str) -> Result<String> {
     let output = Command::new("ssh")
         .arg("-vv") // Double verbose is enough
+        .arg("-p")
+        .arg(port.to_string())
         .arg("-o")
         .arg("BatchMode=yes")
         .arg("-o")
@@ -51,6 +54,7 @@ async fn extract_key_from_verbose_ssh(host: &str, user: &str) -> Result<String>
         .arg("StrictHostKeyChecking=no")
         .arg("-o")
         .arg("PasswordAuthentication=no")
+        .args(crate::proxy::ssh_option_args())
         .arg(format!("{}@{}", user, host))
         .arg("exit")
         .output()?;
@@ -253,7 +257,7 @@ pub async fn auto_detect_ssh_keys(nodes: &[(String, String)]) -> Vec<Result<SshK
     let mut results = Vec::new();
 
     for (host, user) in nodes {
-        match detect_ssh_key(host, user).await {
+        match detect_ssh_key(host, 22, user).await {
             Ok(key_path) => {
                 results.push(Ok(SshKeyInfo {
                     path: key_path,
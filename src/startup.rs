@@ -7,13 +7,16 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 
+use crate::commands::switch::{
+    identity_pubkey_problems, identity_pubkeys_command, keypair_valid_test, parse_identity_pubkeys,
+};
 use crate::config::ConfigManager;
 use crate::ssh::AsyncSshPool;
 use crate::startup_logger::StartupLogger;
 use crate::types::{Config, NodeConfig};
 
 // Default SSH key path for legacy functions
-const DEFAULT_SSH_KEY: &str = "~/.ssh/id_rsa";
+pub(crate) const DEFAULT_SSH_KEY: &str = "~/.ssh/id_rsa";
 use inquire::{validator::Validation, Text};
 
 /// Startup validation result
@@ -39,8 +42,9 @@ fn get_ssh_key_for_host(
         .ok_or_else(|| anyhow!("No SSH key detected for host: {}", host))
 }
 
-/// Comprehensive startup checklist and validation with enhanced UX
-pub async fn run_startup_checklist() -> Result<Option<crate::AppState>> {
+/// Comprehensive startup checklist and validation with enhanced UX.
+/// `assume_yes` skips the closing key press for non-interactive switches.
+pub async fn run_startup_checklist(assume_yes: bool) -> Result<Option<crate::AppState>> {
     // Create logger first
     let logger = StartupLogger::new()?;
     logger.create_latest_symlink()?;
@@ -85,6 +89,23 @@ pub async fn run_startup_checklist() -> Result<Option<crate::AppState>> {
     let mut config =
         validate_configuration_with_progress(&mut validation, &progress_bar, &logger).await?;
 
+    // Route SSH and RPC through the proxy before connecting anywhere
+    if let Some(proxy_config) = config.as_ref().and_then(|config| config.proxy.as_ref()) {
+        if let Err(e) = crate::proxy::init(proxy_config) {
+            validation.issues.push(format!("Proxy: {}", e));
+            validation.config_valid = false;
+        }
+    }
+
+    // Share one RPC request budget and the endpoint headers across every
+    // monitored validator
+    if let Some(config) = config.as_ref() {
+        if let Some(rate_limit) = config.rpc_rate_limit.as_ref() {
+            crate::rpc_limiter::init(rate_limit);
+        }
+        crate::solana_rpc::init_headers(&config.rpc_headers);
+    }
+
     // Only continue with SSH and other validation if config is valid
     let ssh_pool_and_keys = if validation.config_valid {
         progress_bar.set_position(30);
@@ -237,12 +258,24 @@ pub async fn run_startup_checklist() -> Result<Option<crate::AppState>> {
             let metadata_cache =
                 Arc::new(Mutex::new(crate::validator_metadata::MetadataCache::new()));
 
+            // One RPC selector per validator, tracking all configured endpoints
+            let rpc_selectors = validator_statuses
+                .iter()
+                .map(|status| {
+                    Arc::new(tokio::sync::RwLock::new(
+                        crate::rpc_selector::RpcSelector::new(status.validator_pair.rpc_endpoints())
+                            .with_reference(status.validator_pair.reference_rpc.clone()),
+                    ))
+                })
+                .collect();
+
             let app_state = crate::AppState {
                 ssh_pool: Arc::new(ssh_pool),
                 config,
                 validator_statuses,
                 metadata_cache,
                 detected_ssh_keys,
+                rpc_selectors,
             };
             
             // Perform auto-failover safety checks if enabled
@@ -252,7 +285,7 @@ pub async fn run_startup_checklist() -> Result<Option<crate::AppState>> {
             }
 
             // Show "press any key to continue" prompt after all checks pass
-            show_ready_prompt().await;
+            show_ready_prompt(assume_yes).await;
             
             Ok(Some(app_state))
         } else {
@@ -386,11 +419,15 @@ async fn validate_configuration_with_progress(
             println!("{}", "You can either:".dimmed());
             println!(
                 "{}",
-                "  1. Copy and edit the example config: config.example.yaml".dimmed()
+                "  1. Run `svs init` to answer a few questions and detect the rest".dimmed()
+            );
+            println!(
+                "{}",
+                "  2. Copy and edit the example config: config.example.yaml".dimmed()
             );
             println!(
                 "{}",
-                "  2. Create the file manually using the documented YAML format".dimmed()
+                "  3. Create the file manually using the documented YAML format".dimmed()
             );
             println!();
             println!("{}", "Application will exit now.".yellow());
@@ -499,7 +536,8 @@ async fn validate_configuration(validation: &mut StartupValidation) -> Result<Op
 
         println!(
             "{}",
-            "Please create your configuration file and restart the application.".yellow()
+            "Run `svs init` or create your configuration file, then restart the application."
+                .yellow()
         );
         return Ok(None);
     }
@@ -588,7 +626,11 @@ async fn validate_ssh_connections_with_progress(
 ) -> Result<(AsyncSshPool, std::collections::HashMap<String, String>)> {
     logger.log_section("SSH Connection Validation")?;
 
-    let ssh_pool = AsyncSshPool::new();
+    let ssh_config = config.ssh.clone().unwrap_or_default();
+    let ssh_pool = AsyncSshPool::with_config(crate::ssh::PoolConfig {
+        connect_timeout: Duration::from_secs(ssh_config.connect_timeout_seconds),
+        ..Default::default()
+    });
     let mut connection_issues = Vec::new();
     let mut detected_ssh_keys = std::collections::HashMap::new();
 
@@ -641,9 +683,10 @@ async fn validate_ssh_connections_with_progress(
             }
 
             // If no configured key or it failed, auto-detect
+            let mut key_issue = None;
             if !key_worked {
                 logger.log("  Auto-detecting SSH key...")?;
-                match crate::ssh_key_detector::detect_ssh_key(&node.host, &node.user).await {
+                match crate::ssh_key_detector::detect_ssh_key(&node.host, node.port, &node.user).await {
                     Ok(detected_key) => {
                         logger.log(&format!("  Detected SSH key: {}", detected_key))?;
                         // Try to connect with detected key (silently)
@@ -655,24 +698,78 @@ async fn validate_ssh_connections_with_progress(
                                 ))?;
                                 _connected_nodes += 1;
                                 detected_ssh_keys.insert(node.host.clone(), detected_key);
+                                key_worked = true;
                             }
                             Err(e) => {
                                 logger.log_error("SSH", &format!("  Connection failed: {}", e))?;
-                                connection_issues
-                                    .push(format!("Failed to connect to {}: {}", node_name, e));
+                                key_issue = Some(format!("Failed to connect to {}: {}", node_name, e));
                             }
                         }
                     }
                     Err(detect_err) => {
                         logger
                             .log_error("SSH", &format!("  Key detection failed: {}", detect_err))?;
-                        connection_issues.push(format!(
+                        key_issue = Some(format!(
                             "Failed to detect SSH key for {}: {}",
                             node_name, detect_err
                         ));
                     }
                 }
             }
+
+            // Without a working key, nodes with passwordAuth fall back to a
+            // password prompted for now and kept in memory only
+            if !key_worked && node.password_auth {
+                let prompt = format!("SSH password for {}@{}:", node.user, node.host);
+                let password = progress_bar.suspend(|| {
+                    inquire::Password::new(&prompt)
+                        .without_confirmation()
+                        .with_display_toggle_enabled()
+                        .prompt()
+                });
+                match password {
+                    Ok(password) => {
+                        ssh_pool.set_password(node, password).await;
+                        match ssh_pool.get_session(node, crate::ssh::PASSWORD_AUTH).await {
+                            Ok(_) => {
+                                logger.log_success(&format!(
+                                    "  Connected to {} with password",
+                                    node.host
+                                ))?;
+                                _connected_nodes += 1;
+                                detected_ssh_keys
+                                    .insert(node.host.clone(), crate::ssh::PASSWORD_AUTH.to_string());
+                                key_issue = None;
+                            }
+                            Err(e) => {
+                                logger.log_error("SSH", &format!("  Password login failed: {}", e))?;
+                                key_issue = Some(format!("Failed to connect to {}: {}", node_name, e));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        key_issue = Some(format!("No SSH password entered for {}: {}", node_name, e));
+                    }
+                }
+            }
+            connection_issues.extend(key_issue);
+
+            // Privileged checks on nodes with `sudo.askpass` need the sudo
+            // password, kept in memory like SSH passwords
+            if node.sudo.as_ref().is_some_and(|sudo| sudo.askpass) {
+                let prompt = format!("sudo password for {}@{}:", node.user, node.host);
+                let password = progress_bar.suspend(|| {
+                    inquire::Password::new(&prompt)
+                        .without_confirmation()
+                        .with_display_toggle_enabled()
+                        .prompt()
+                });
+                match password {
+                    Ok(password) => ssh_pool.set_sudo_password(node, password).await,
+                    Err(e) => connection_issues
+                        .push(format!("No sudo password entered for {}: {}", node_name, e)),
+                }
+            }
         }
     }
 
@@ -697,7 +794,11 @@ async fn validate_ssh_connections(
     config: &Config,
     validation: &mut StartupValidation,
 ) -> Result<AsyncSshPool> {
-    let ssh_pool = AsyncSshPool::new();
+    let ssh_config = config.ssh.clone().unwrap_or_default();
+    let ssh_pool = AsyncSshPool::with_config(crate::ssh::PoolConfig {
+        connect_timeout: Duration::from_secs(ssh_config.connect_timeout_seconds),
+        ..Default::default()
+    });
     let mut connection_issues = Vec::new();
 
     if config.validators.is_empty() {
@@ -874,6 +975,21 @@ fn validate_config_completeness(config: &Config) -> Vec<String> {
             issues.push(format!("{} RPC endpoint is empty", validator_name));
         }
 
+        if validator_pair.additional_rpcs.iter().any(|rpc| rpc.is_empty()) {
+            issues.push(format!("{} has an empty additional RPC endpoint", validator_name));
+        }
+
+        // A quorum the endpoints can never reach would silently disable failover
+        if let Some(quorum) = config.alert_config.as_ref().and_then(|c| c.auto_failover_quorum) {
+            let endpoints = validator_pair.rpc_endpoints().len();
+            if quorum == 0 || quorum > endpoints {
+                issues.push(format!(
+                    "{} auto_failover_quorum is {} but {} RPC endpoint(s) are configured",
+                    validator_name, quorum, endpoints
+                ));
+            }
+        }
+
         // Check nodes
         if validator_pair.nodes.len() != 2 {
             issues.push(format!("{} should have exactly 2 nodes", validator_name));
@@ -898,7 +1014,10 @@ fn validate_node_config(
     }
 
     if node.user.is_empty() {
-        issues.push(format!("{} user is empty", node_name));
+        issues.push(format!(
+            "{} user is empty (set user on the node or ssh.user)",
+            node_name
+        ));
     }
 
     if node.paths.funded_identity.is_empty() {
@@ -1056,7 +1175,7 @@ async fn migrate_configuration(
     Ok(config)
 }
 
-async fn show_ready_prompt() {
+async fn show_ready_prompt(assume_yes: bool) {
     // Show animated ready message
     println!(
         "{}",
@@ -1094,6 +1213,11 @@ async fn show_ready_prompt() {
     // Flush stdout to ensure the prompt appears immediately
     io::stdout().flush().unwrap();
 
+    // Non-interactive switches have nobody to press a key
+    if assume_yes {
+        return;
+    }
+
     // Skip wait for status command
     if std::env::args().any(|arg| arg == "status") {
         // For status command, just clear everything
@@ -1226,6 +1350,7 @@ async fn detect_node_statuses_with_progress(
                 validator_pair,
                 ssh_pool,
                 detected_ssh_keys.get(&node.host).cloned(),
+                config.ssh.clone().unwrap_or_default().catchup_timeout_seconds,
                 progress_bar,
                 logger,
             )
@@ -1377,7 +1502,7 @@ async fn detect_node_statuses_with_progress(
 }
 
 #[allow(dead_code)]
-async fn detect_node_status_and_executable(
+pub(crate) async fn detect_node_status_and_executable(
     node: &crate::types::NodeConfig,
     validator_pair: &crate::types::ValidatorPair,
     ssh_pool: &AsyncSshPool,
@@ -1424,58 +1549,75 @@ async fn detect_node_status_and_executable(
     let mut sync_status = None;
     let mut current_identity = None;
     let mut ledger_path = None;
-    #[allow(dead_code)]
     let mut firedancer_config_path = None;
 
+    // Executables declared in the config are trusted as-is; the process list
+    // and disk are only searched for nodes that don't declare them
+    let declared = node.paths.declares_validator();
+    if declared {
+        fdctl_executable = node.paths.fdctl.clone();
+        agave_validator_executable = node.paths.agave_validator.clone();
+        solana_cli_executable = node.paths.declared_solana_cli();
+        firedancer_config_path = node.paths.fdctl_config.clone();
+        _main_validator_executable = fdctl_executable.clone().or(agave_validator_executable.clone());
+        validator_type = if fdctl_executable.is_some() {
+            crate::types::ValidatorType::Firedancer
+        } else {
+            crate::types::ValidatorType::Agave
+        };
+    }
+
     // First, check what validator is actually running
-    let ps_cmd =
-        "ps aux | grep -E 'bin/fdctl|bin/agave-validator|release/agave-validator|bin/solana-validator|release/solana-validator' | grep -v grep";
-    if let Ok(output) = ssh_pool.execute_command(node, &ssh_key, ps_cmd).await {
-        let lines: Vec<&str> = output.lines().collect();
-        for line in lines {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-
-            // Check if this is a Firedancer process
-            if line.contains("bin/fdctl") {
-                // logger.log("Detected Firedancer validator")?;
-                validator_type = crate::types::ValidatorType::Firedancer;
-
-                // Extract fdctl executable and config path
-                for (i, part) in parts.iter().enumerate() {
-                    if part.contains("bin/fdctl") {
-                        fdctl_executable = Some(part.to_string());
-                        _main_validator_executable = Some(part.to_string());
-
-                        // For Firedancer, solana CLI is in the same directory as fdctl
-                        if let Some(fdctl_dir) = std::path::Path::new(part).parent() {
-                            let solana_path = fdctl_dir.join("solana");
-                            solana_cli_executable = Some(solana_path.to_string_lossy().to_string());
+    if !declared {
+        let ps_cmd =
+            "ps aux | grep -E 'bin/fdctl|bin/agave-validator|release/agave-validator|bin/solana-validator|release/solana-validator' | grep -v grep";
+        if let Ok(output) = ssh_pool.execute_command(node, &ssh_key, ps_cmd).await {
+            let lines: Vec<&str> = output.lines().collect();
+            for line in lines {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+
+                // Check if this is a Firedancer process
+                if line.contains("bin/fdctl") {
+                    // logger.log("Detected Firedancer validator")?;
+                    validator_type = crate::types::ValidatorType::Firedancer;
+
+                    // Extract fdctl executable and config path
+                    for (i, part) in parts.iter().enumerate() {
+                        if part.contains("bin/fdctl") {
+                            fdctl_executable = Some(part.to_string());
+                            _main_validator_executable = Some(part.to_string());
+
+                            // For Firedancer, solana CLI is in the same directory as fdctl
+                            if let Some(fdctl_dir) = std::path::Path::new(part).parent() {
+                                let solana_path = fdctl_dir.join("solana");
+                                solana_cli_executable = Some(solana_path.to_string_lossy().to_string());
+                            }
+                        } else if part == &"--config" && i + 1 < parts.len() {
+                            let _ = firedancer_config_path.insert(parts[i + 1].to_string());
                         }
-                    } else if part == &"--config" && i + 1 < parts.len() {
-                        let _ = firedancer_config_path.insert(parts[i + 1].to_string());
                     }
+                    break;
                 }
-                break;
-            }
-            // Check if this is an Agave/Jito process
-            else if line.contains("agave-validator") {
-                // logger.log("Detected Agave validator")?;
-                validator_type = crate::types::ValidatorType::Agave;
+                // Check if this is an Agave/Jito process
+                else if line.contains("agave-validator") {
+                    // logger.log("Detected Agave validator")?;
+                    validator_type = crate::types::ValidatorType::Agave;
 
-                // Extract agave executable and ledger path
-                for (i, part) in parts.iter().enumerate() {
-                    if part.contains("agave-validator")
-                        && (part.contains("bin/agave-validator")
-                            || part.contains("release/agave-validator"))
-                    {
-                        if agave_validator_executable.is_none() {
-                            agave_validator_executable = Some(part.to_string());
-                            _main_validator_executable = Some(part.to_string());
-                            // Derive solana CLI path from agave-validator path
-                            solana_cli_executable = Some(part.replace("agave-validator", "solana"));
+                    // Extract agave executable and ledger path
+                    for (i, part) in parts.iter().enumerate() {
+                        if part.contains("agave-validator")
+                            && (part.contains("bin/agave-validator")
+                                || part.contains("release/agave-validator"))
+                        {
+                            if agave_validator_executable.is_none() {
+                                agave_validator_executable = Some(part.to_string());
+                                _main_validator_executable = Some(part.to_string());
+                                // Derive solana CLI path from agave-validator path
+                                solana_cli_executable = Some(part.replace("agave-validator", "solana"));
+                            }
+                        } else if part == &"--ledger" && i + 1 < parts.len() {
+                            ledger_path = Some(parts[i + 1].to_string());
                         }
-                    } else if part == &"--ledger" && i + 1 < parts.len() {
-                        ledger_path = Some(parts[i + 1].to_string());
                     }
                 }
             }
@@ -1507,6 +1649,14 @@ async fn detect_node_status_and_executable(
         }
     }
 
+    // Declared paths win over detected ones
+    if let Some(cli) = &node.paths.solana_cli {
+        solana_cli_executable = Some(cli.clone());
+    }
+    if let Some(ledger) = &node.paths.ledger {
+        ledger_path = Some(ledger.clone());
+    }
+
     // Detect version based on validator type
     if validator_type == crate::types::ValidatorType::Firedancer {
         // For Firedancer, use fdctl executable
@@ -1515,7 +1665,7 @@ async fn detect_node_status_and_executable(
                 .execute_command(
                     node,
                     &ssh_key,
-                    &format!("timeout 5 {} --version 2>/dev/null", fdctl_exec),
+                    &format!("timeout 5 {} 2>/dev/null", node.version_invocation(fdctl_exec)),
                 )
                 .await
             {
@@ -1533,7 +1683,7 @@ async fn detect_node_status_and_executable(
             .execute_command(
                 node,
                 &ssh_key,
-                &format!("timeout 5 {} --version 2>/dev/null", agave_exec),
+                &format!("timeout 5 {} 2>/dev/null", node.version_invocation(agave_exec)),
             )
             .await
         {
@@ -1580,8 +1730,8 @@ async fn detect_node_status_and_executable(
         String::new()
     };
 
-    if !solana_cli.is_empty() {
-        let catchup_cmd = format!("timeout 10 {} catchup --our-localhost 2>&1", solana_cli);
+    if !solana_cli.is_empty() || node.has_catchup_command() {
+        let catchup_cmd = format!("timeout 10 {} 2>&1", node.catchup_invocation(&solana_cli));
         if let Ok(catchup_output) = ssh_pool
             .execute_command_with_early_exit(node, &ssh_key, &catchup_cmd, |output| {
                 output.contains("0 slot(s)") || output.contains("has caught up")
@@ -1639,9 +1789,19 @@ async fn detect_node_status_and_executable(
 
     // Initial swap readiness check - skip tower files since we don't know if it's active yet
     let (swap_ready, swap_issues) =
-        check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(true)).await;
+        check_node_swap_readiness(
+            ssh_pool,
+            node,
+            &ssh_key,
+            ledger_path.as_ref(),
+            Some(true),
+            &validator_type,
+            firedancer_config_path.as_ref(),
+            &validator_pair.identity_pubkey,
+        )
+        .await;
 
-    // Use catchup command to get the active identity
+    // Use catchup command (or the node's identity command) to get the active identity
     // Derive solana CLI from agave-validator path
     let solana_cli_path = if let Some(ref agave_exec) = agave_validator_executable {
         // Replace agave-validator with solana in the path
@@ -1651,76 +1811,26 @@ async fn detect_node_status_and_executable(
         String::new()
     };
 
-    let catchup_cmd = format!(
-        "timeout 10 {} catchup --our-localhost 2>&1",
-        solana_cli_path
-    );
+    if let Some(identity) = query_node_identity(ssh_pool, node, &ssh_key, &solana_cli_path, 10).await {
+        current_identity = Some(identity.clone());
 
-    match ssh_pool
-        .execute_command_with_early_exit(node, &ssh_key, &catchup_cmd, |output| {
-            output.contains("0 slot(s)") || output.contains("has caught up")
-        })
-        .await
-    {
-        Ok(output) => {
-            // Parse the catchup output to find identity
-            for line in output.lines() {
-                if line.contains("has caught up") {
-                    if let Some(caught_up_pos) = line.find(" has caught up") {
-                        let identity = line[..caught_up_pos].trim();
-                        // logger.log(&format!("Extracted identity from catchup (final check): {}", identity)).ok();
-                        if !identity.is_empty() {
-                            current_identity = Some(identity.to_string());
-                            // logger.log(&format!("Set current_identity to: {}", identity)).ok();
-
-                            // Check if this identity matches the validator's funded identity
-                            // logger.log(&format!("Comparing identity {} with validator identity {}", identity, validator_pair.identity_pubkey)).ok();
-                            if identity == validator_pair.identity_pubkey {
-                                // logger.log_success(&format!("Node {} is ACTIVE (identity matches)", node.label)).ok();
-                                // Recheck swap readiness for active node (with tower requirement)
-                                let (active_swap_ready, active_swap_issues) =
-                                    check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(false)).await;
-                                return Ok((
-                                    crate::types::NodeStatus::Active,
-                                    validator_type,
-                                    agave_validator_executable,
-                                    fdctl_executable,
-                                    solana_cli_executable,
-                                    version,
-                                    sync_status,
-                                    current_identity,
-                                    ledger_path,
-                                    Some(active_swap_ready),
-                                    active_swap_issues,
-                                ));
-                            } else {
-                                // logger.log(&format!("Node {} is STANDBY (identity {} does not match {})", node.label, identity, validator_pair.identity_pubkey)).ok();
-                                // Recheck swap readiness for standby node (without tower requirement)
-                                let (standby_swap_ready, standby_swap_issues) =
-                                    check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(true)).await;
-                                return Ok((
-                                    crate::types::NodeStatus::Standby,
-                                    validator_type,
-                                    agave_validator_executable,
-                                    fdctl_executable,
-                                    solana_cli_executable,
-                                    version,
-                                    sync_status,
-                                    current_identity,
-                                    ledger_path,
-                                    Some(standby_swap_ready),
-                                    standby_swap_issues,
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
-
-            // If we can't find the Identity line from either method, assume unknown
-            // logger.log_warning(&format!("Could not determine node status for {} - no matching identity found", node.label)).ok();
-            Ok((
-                crate::types::NodeStatus::Unknown,
+        // Check if this identity matches the validator's funded identity
+        if identity == validator_pair.identity_pubkey {
+            // Recheck swap readiness for active node (with tower requirement)
+            let (active_swap_ready, active_swap_issues) =
+                check_node_swap_readiness(
+                    ssh_pool,
+                    node,
+                    &ssh_key,
+                    ledger_path.as_ref(),
+                    Some(false),
+                    &validator_type,
+                    firedancer_config_path.as_ref(),
+                    &validator_pair.identity_pubkey,
+                )
+                .await;
+            return Ok((
+                crate::types::NodeStatus::Active,
                 validator_type,
                 agave_validator_executable,
                 fdctl_executable,
@@ -1729,14 +1839,25 @@ async fn detect_node_status_and_executable(
                 sync_status,
                 current_identity,
                 ledger_path,
-                Some(swap_ready),
-                swap_issues,
-            ))
-        }
-        Err(_e) => {
-            // logger.log_error("catchup command", &format!("Failed for node {}: {:?}", node.label, e)).ok();
-            Ok((
-                crate::types::NodeStatus::Unknown,
+                Some(active_swap_ready),
+                active_swap_issues,
+            ));
+        } else {
+            // Recheck swap readiness for standby node (without tower requirement)
+            let (standby_swap_ready, standby_swap_issues) =
+                check_node_swap_readiness(
+                    ssh_pool,
+                    node,
+                    &ssh_key,
+                    ledger_path.as_ref(),
+                    Some(true),
+                    &validator_type,
+                    firedancer_config_path.as_ref(),
+                    &validator_pair.identity_pubkey,
+                )
+                .await;
+            return Ok((
+                crate::types::NodeStatus::Standby,
                 validator_type,
                 agave_validator_executable,
                 fdctl_executable,
@@ -1745,20 +1866,77 @@ async fn detect_node_status_and_executable(
                 sync_status,
                 current_identity,
                 ledger_path,
-                Some(swap_ready),
-                swap_issues,
-            ))
+                Some(standby_swap_ready),
+                standby_swap_issues,
+            ));
         }
     }
+
+    // If we can't find the identity, assume unknown
+    Ok((
+        crate::types::NodeStatus::Unknown,
+        validator_type,
+        agave_validator_executable,
+        fdctl_executable,
+        solana_cli_executable,
+        version,
+        sync_status,
+        current_identity,
+        ledger_path,
+        Some(swap_ready),
+        swap_issues,
+    ))
+}
+
+/// Identity the validator on `node` runs with, from the node's configured
+/// identity command or else parsed from `solana catchup --our-localhost`
+async fn query_node_identity(
+    ssh_pool: &AsyncSshPool,
+    node: &crate::types::NodeConfig,
+    ssh_key: &str,
+    solana_cli: &str,
+    timeout_seconds: u64,
+) -> Option<String> {
+    if let Some(command) = node.identity_command() {
+        let output = ssh_pool
+            .execute_command(node, ssh_key, &format!("timeout {} {} 2>/dev/null", timeout_seconds, command))
+            .await
+            .ok()?;
+        return crate::types::parse_identity_output(&output);
+    }
+
+    if solana_cli.is_empty() && !node.has_catchup_command() {
+        return None;
+    }
+    let catchup_cmd = format!(
+        "timeout {} {} 2>&1",
+        timeout_seconds,
+        node.catchup_invocation(solana_cli)
+    );
+    let output = ssh_pool
+        .execute_command_with_early_exit(node, ssh_key, &catchup_cmd, |output| {
+            output.contains("0 slot(s)") || output.contains("has caught up")
+        })
+        .await
+        .ok()?;
+    output
+        .lines()
+        .filter_map(|line| line.find(" has caught up").map(|pos| line[..pos].trim()))
+        .find(|identity| !identity.is_empty())
+        .map(str::to_string)
 }
 
 /// Check if a node is ready for validator switching
+#[allow(clippy::too_many_arguments)]
 async fn check_node_swap_readiness(
     ssh_pool: &AsyncSshPool,
     node: &crate::types::NodeConfig,
     ssh_key_path: &str,
     ledger_path: Option<&String>,
     is_standby: Option<bool>,
+    validator_type: &crate::types::ValidatorType,
+    fdctl_config: Option<&String>,
+    identity_pubkey: &str,
 ) -> (bool, Vec<String>) {
     let mut issues = Vec::new();
     let mut all_ready = true;
@@ -1772,25 +1950,25 @@ async fn check_node_swap_readiness(
     // For standby nodes, we don't check tower files
     let file_check_cmd = if is_standby == Some(true) {
         format!(
-            "test -r {} && echo 'funded_ok' || echo 'funded_fail'; \
-             test -r {} && echo 'unfunded_ok' || echo 'unfunded_fail'; \
+            "{} && echo 'funded_ok' || echo 'funded_fail'; \
+             {} && echo 'unfunded_ok' || echo 'unfunded_fail'; \
              test -r {} && echo 'vote_ok' || echo 'vote_fail'; \
              test -d {} && test -w {} && echo 'ledger_ok' || echo 'ledger_fail'",
-            node.paths.funded_identity,
-            node.paths.unfunded_identity,
+            keypair_valid_test(&node.paths.funded_identity),
+            keypair_valid_test(&node.paths.unfunded_identity),
             node.paths.vote_keypair,
             ledger,
             ledger
         )
     } else {
         format!(
-            "test -r {} && echo 'funded_ok' || echo 'funded_fail'; \
-             test -r {} && echo 'unfunded_ok' || echo 'unfunded_fail'; \
+            "{} && echo 'funded_ok' || echo 'funded_fail'; \
+             {} && echo 'unfunded_ok' || echo 'unfunded_fail'; \
              test -r {} && echo 'vote_ok' || echo 'vote_fail'; \
              ls {}/tower-1_9-*.bin >/dev/null 2>&1 && echo 'tower_ok' || echo 'tower_fail'; \
              test -d {} && test -w {} && echo 'ledger_ok' || echo 'ledger_fail'",
-            node.paths.funded_identity,
-            node.paths.unfunded_identity,
+            keypair_valid_test(&node.paths.funded_identity),
+            keypair_valid_test(&node.paths.unfunded_identity),
             node.paths.vote_keypair,
             ledger,
             ledger,
@@ -1799,19 +1977,20 @@ async fn check_node_swap_readiness(
     };
 
     match ssh_pool
-        .execute_command(node, ssh_key_path, &file_check_cmd)
+        .execute_privileged(node, ssh_key_path, &file_check_cmd)
         .await
     {
         Ok(output) => {
             for line in output.lines() {
                 match line.trim() {
                     "funded_fail" => {
-                        issues.push("Funded identity keypair missing or not readable".to_string());
+                        issues.push("Funded identity keypair missing, unreadable or invalid".to_string());
                         all_ready = false;
                     }
                     "unfunded_fail" => {
-                        issues
-                            .push("Unfunded identity keypair missing or not readable".to_string());
+                        issues.push(
+                            "Unfunded identity keypair missing, unreadable or invalid".to_string(),
+                        );
                         all_ready = false;
                     }
                     "vote_fail" => {
@@ -1839,6 +2018,87 @@ async fn check_node_swap_readiness(
         }
     }
 
+    // A funded keypair that isn't the configured identity would make the
+    // switch vote with the wrong key. Skipped without solana-keygen or fdctl.
+    let pubkeys_cmd =
+        identity_pubkeys_command(&node.paths.funded_identity, &node.paths.unfunded_identity);
+    if let Ok(output) = ssh_pool
+        .execute_privileged(node, ssh_key_path, &pubkeys_cmd)
+        .await
+    {
+        if let Ok((funded, unfunded)) = parse_identity_pubkeys(&output) {
+            let mismatches = identity_pubkey_problems(
+                identity_pubkey,
+                &node.paths,
+                funded.as_deref(),
+                unfunded.as_deref(),
+            );
+            if !mismatches.is_empty() {
+                all_ready = false;
+                issues.extend(mismatches);
+            }
+        }
+    }
+
+    // Keys other users can read or own may leak; world-readable ones go
+    // first so the readiness summary shows them
+    match crate::host_checks::check_keypair_permissions(ssh_pool, node, ssh_key_path).await {
+        Ok(permissions) => {
+            let critical = permissions.critical_issues();
+            let findings = permissions.issues();
+            if !critical.is_empty() || !findings.is_empty() {
+                all_ready = false;
+                for (position, issue) in critical.into_iter().enumerate() {
+                    issues.insert(position, issue);
+                }
+                issues.extend(findings);
+            }
+        }
+        Err(_) => {
+            all_ready = false;
+            issues.push("Failed to check keypair permissions".to_string());
+        }
+    }
+
+    // Kernel and process limits the validator needs to keep up
+    match crate::host_checks::check_system_limits(ssh_pool, node, ssh_key_path).await {
+        Ok(limits) => {
+            let limit_issues = limits.issues(validator_type);
+            if !limit_issues.is_empty() {
+                all_ready = false;
+                issues.extend(limit_issues);
+            }
+        }
+        Err(_) => {
+            all_ready = false;
+            issues.push("Failed to check system limits".to_string());
+        }
+    }
+
+    // Firedancer only performs with its host tuning in place
+    if *validator_type == crate::types::ValidatorType::Firedancer {
+        match crate::host_checks::check_firedancer_tuning(
+            ssh_pool,
+            node,
+            ssh_key_path,
+            fdctl_config.map(|s| s.as_str()),
+        )
+        .await
+        {
+            Ok(tuning) => {
+                let tuning_issues = tuning.issues();
+                if !tuning_issues.is_empty() {
+                    all_ready = false;
+                    issues.extend(tuning_issues);
+                }
+            }
+            Err(_) => {
+                all_ready = false;
+                issues.push("Failed to check Firedancer tuning".to_string());
+            }
+        }
+    }
+
     (all_ready, issues)
 }
 
@@ -1848,6 +2108,7 @@ async fn detect_node_status_and_executable_with_progress(
     validator_pair: &crate::types::ValidatorPair,
     ssh_pool: &AsyncSshPool,
     ssh_key_path: Option<String>,
+    catchup_timeout_seconds: u64,
     progress_bar: &ProgressBar,
     logger: &StartupLogger,
 ) -> Result<(
@@ -1909,86 +2170,103 @@ async fn detect_node_status_and_executable_with_progress(
     let mut sync_status = None;
     let mut current_identity = None;
     let mut ledger_path = None;
-    #[allow(dead_code)]
     let mut firedancer_config_path = None;
 
     // Step 2: Executable Detection
     // Removed println to prevent progress bar corruption
     logger.log("Detecting validator executables...")?;
 
+    // Executables declared in the config are trusted as-is; the process list
+    // and disk are only searched for nodes that don't declare them
+    let declared = node.paths.declares_validator();
+    if declared {
+        fdctl_executable = node.paths.fdctl.clone();
+        agave_validator_executable = node.paths.agave_validator.clone();
+        solana_cli_executable = node.paths.declared_solana_cli();
+        firedancer_config_path = node.paths.fdctl_config.clone();
+        _main_validator_executable = fdctl_executable.clone().or(agave_validator_executable.clone());
+        validator_type = if fdctl_executable.is_some() {
+            crate::types::ValidatorType::Firedancer
+        } else {
+            crate::types::ValidatorType::Agave
+        };
+    }
+
     // First, check what validator is actually running
-    let ps_cmd =
-        "ps aux | grep -E 'bin/fdctl|bin/agave-validator|release/agave-validator|bin/solana-validator|release/solana-validator' | grep -v grep";
-    logger.log_ssh_command(&node.host, ps_cmd, "", None)?;
+    if !declared {
+        let ps_cmd =
+            "ps aux | grep -E 'bin/fdctl|bin/agave-validator|release/agave-validator|bin/solana-validator|release/solana-validator' | grep -v grep";
+        logger.log_ssh_command(&node.host, ps_cmd, "", None)?;
+
+        if let Ok(output) = ssh_pool.execute_command(node, &ssh_key, ps_cmd).await {
+            logger.log_ssh_command(&node.host, ps_cmd, &output, None)?;
+            let lines: Vec<&str> = output.lines().collect();
+            logger
+                .log(&format!("Found {} process lines", lines.len()))
+                .ok();
+            for line in lines {
+                logger.log(&format!("Processing line: {}", line)).ok();
+                let parts: Vec<&str> = line.split_whitespace().collect();
+
+                // Check if this is a Firedancer process
+                if line.contains("bin/fdctl") {
+                    logger.log("Detected Firedancer validator process").ok();
+                    validator_type = crate::types::ValidatorType::Firedancer;
+
+                    // Extract fdctl executable and config path
+                    for (i, part) in parts.iter().enumerate() {
+                        if part.contains("bin/fdctl") {
+                            fdctl_executable = Some(part.to_string());
+                            _main_validator_executable = Some(part.to_string());
 
-    if let Ok(output) = ssh_pool.execute_command(node, &ssh_key, ps_cmd).await {
-        logger.log_ssh_command(&node.host, ps_cmd, &output, None)?;
-        let lines: Vec<&str> = output.lines().collect();
-        logger
-            .log(&format!("Found {} process lines", lines.len()))
-            .ok();
-        for line in lines {
-            logger.log(&format!("Processing line: {}", line)).ok();
-            let parts: Vec<&str> = line.split_whitespace().collect();
-
-            // Check if this is a Firedancer process
-            if line.contains("bin/fdctl") {
-                logger.log("Detected Firedancer validator process").ok();
-                validator_type = crate::types::ValidatorType::Firedancer;
-
-                // Extract fdctl executable and config path
-                for (i, part) in parts.iter().enumerate() {
-                    if part.contains("bin/fdctl") {
-                        fdctl_executable = Some(part.to_string());
-                        _main_validator_executable = Some(part.to_string());
-
-                        // For Firedancer, solana CLI is in the same directory as fdctl
-                        if let Some(fdctl_dir) = std::path::Path::new(part).parent() {
-                            let solana_path = fdctl_dir.join("solana");
-                            solana_cli_executable = Some(solana_path.to_string_lossy().to_string());
+                            // For Firedancer, solana CLI is in the same directory as fdctl
+                            if let Some(fdctl_dir) = std::path::Path::new(part).parent() {
+                                let solana_path = fdctl_dir.join("solana");
+                                solana_cli_executable = Some(solana_path.to_string_lossy().to_string());
+                            }
+                        } else if part == &"--config" && i + 1 < parts.len() {
+                            let _ = firedancer_config_path.insert(parts[i + 1].to_string());
                         }
-                    } else if part == &"--config" && i + 1 < parts.len() {
-                        let _ = firedancer_config_path.insert(parts[i + 1].to_string());
                     }
+                    break;
                 }
-                break;
-            }
-            // Check if this is an Agave/Jito/Solana process
-            else if line.contains("agave-validator") || line.contains("solana-validator") {
-                logger
-                    .log(&format!(
-                        "Detected {} validator process",
-                        if line.contains("agave-validator") {
-                            "Agave"
-                        } else {
-                            "Solana"
-                        }
-                    ))
-                    .ok();
-                validator_type = crate::types::ValidatorType::Agave;
-
-                // Extract validator executable and ledger path
-                for (i, part) in parts.iter().enumerate() {
-                    if (part.contains("agave-validator") || part.contains("solana-validator"))
-                        && (part.contains("bin/agave-validator")
-                            || part.contains("release/agave-validator")
-                            || part.contains("bin/solana-validator")
-                            || part.contains("release/solana-validator"))
-                    {
-                        if agave_validator_executable.is_none() {
-                            agave_validator_executable = Some(part.to_string());
-                            _main_validator_executable = Some(part.to_string());
-                            // Derive solana CLI path from validator path
-                            if part.contains("agave-validator") {
-                                solana_cli_executable =
-                                    Some(part.replace("agave-validator", "solana"));
+                // Check if this is an Agave/Jito/Solana process
+                else if line.contains("agave-validator") || line.contains("solana-validator") {
+                    logger
+                        .log(&format!(
+                            "Detected {} validator process",
+                            if line.contains("agave-validator") {
+                                "Agave"
                             } else {
-                                solana_cli_executable =
-                                    Some(part.replace("solana-validator", "solana"));
+                                "Solana"
                             }
+                        ))
+                        .ok();
+                    validator_type = crate::types::ValidatorType::Agave;
+
+                    // Extract validator executable and ledger path
+                    for (i, part) in parts.iter().enumerate() {
+                        if (part.contains("agave-validator") || part.contains("solana-validator"))
+                            && (part.contains("bin/agave-validator")
+                                || part.contains("release/agave-validator")
+                                || part.contains("bin/solana-validator")
+                                || part.contains("release/solana-validator"))
+                        {
+                            if agave_validator_executable.is_none() {
+                                agave_validator_executable = Some(part.to_string());
+                                _main_validator_executable = Some(part.to_string());
+                                // Derive solana CLI path from validator path
+                                if part.contains("agave-validator") {
+                                    solana_cli_executable =
+                                        Some(part.replace("agave-validator", "solana"));
+                                } else {
+                                    solana_cli_executable =
+                                        Some(part.replace("solana-validator", "solana"));
+                                }
+                            }
+                        } else if part == &"--ledger" && i + 1 < parts.len() {
+                            ledger_path = Some(parts[i + 1].to_string());
                         }
-                    } else if part == &"--ledger" && i + 1 < parts.len() {
-                        ledger_path = Some(parts[i + 1].to_string());
                     }
                 }
             }
@@ -2071,6 +2349,14 @@ async fn detect_node_status_and_executable_with_progress(
         }
     }
 
+    // Declared paths win over detected ones
+    if let Some(cli) = &node.paths.solana_cli {
+        solana_cli_executable = Some(cli.clone());
+    }
+    if let Some(ledger) = &node.paths.ledger {
+        ledger_path = Some(ledger.clone());
+    }
+
     let validator_type_name = match validator_type {
         crate::types::ValidatorType::Firedancer => "Firedancer",
         crate::types::ValidatorType::Agave => "Agave",
@@ -2141,7 +2427,7 @@ async fn detect_node_status_and_executable_with_progress(
                 .execute_command(
                     node,
                     &ssh_key,
-                    &format!("timeout 5 {} --version 2>/dev/null", fdctl_exec),
+                    &format!("timeout 5 {} 2>/dev/null", node.version_invocation(fdctl_exec)),
                 )
                 .await
             {
@@ -2159,7 +2445,7 @@ async fn detect_node_status_and_executable_with_progress(
             .execute_command(
                 node,
                 &ssh_key,
-                &format!("timeout 5 {} --version 2>/dev/null", agave_exec),
+                &format!("timeout 5 {} 2>/dev/null", node.version_invocation(agave_exec)),
             )
             .await
         {
@@ -2221,8 +2507,12 @@ async fn detect_node_status_and_executable_with_progress(
         String::new()
     };
 
-    if !solana_cli.is_empty() {
-        let catchup_cmd = format!("timeout 10 {} catchup --our-localhost 2>&1", solana_cli);
+    if !solana_cli.is_empty() || node.has_catchup_command() {
+        let catchup_cmd = format!(
+            "timeout {} {} 2>&1",
+            catchup_timeout_seconds,
+            node.catchup_invocation(&solana_cli)
+        );
         if let Ok(catchup_output) = ssh_pool
             .execute_command_with_early_exit(node, &ssh_key, &catchup_cmd, |output| {
                 output.contains("0 slot(s)") || output.contains("has caught up")
@@ -2286,7 +2576,17 @@ async fn detect_node_status_and_executable_with_progress(
 
     // Initial check - assume standby for now (skip tower requirement)
     let (swap_ready, mut swap_issues) =
-        check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(true)).await;
+        check_node_swap_readiness(
+            ssh_pool,
+            node,
+            &ssh_key,
+            ledger_path.as_ref(),
+            Some(true),
+            &validator_type,
+            firedancer_config_path.as_ref(),
+            &validator_pair.identity_pubkey,
+        )
+        .await;
 
     if swap_ready {
         logger.log_success("Node is ready for swap")?;
@@ -2346,7 +2646,7 @@ async fn detect_node_status_and_executable_with_progress(
     });
     logger.log("Detecting active identity...")?;
 
-    // Use catchup command to get identity
+    // Use catchup command (or the node's identity command) to get identity
     let solana_cli_fallback = if let Some(ref cli) = solana_cli_executable {
         cli.clone()
     } else if let Some(ref agave_exec) = agave_validator_executable {
@@ -2355,73 +2655,76 @@ async fn detect_node_status_and_executable_with_progress(
         String::new()
     };
 
-    if !solana_cli_fallback.is_empty() {
-        let catchup_cmd = format!(
-            "timeout 10 {} catchup --our-localhost 2>&1",
-            solana_cli_fallback
-        );
-        if let Ok(catchup_output) = ssh_pool
-            .execute_command_with_early_exit(node, &ssh_key, &catchup_cmd, |output| {
-                output.contains("0 slot(s)") || output.contains("has caught up")
-            })
-            .await
-        {
-            for line in catchup_output.lines() {
-                if line.contains("has caught up") {
-                    if let Some(caught_up_pos) = line.find(" has caught up") {
-                        let identity = line[..caught_up_pos].trim();
-                        // logger.log(&format!("Extracted identity from catchup (final check): {}", identity)).ok();
-                        if !identity.is_empty() {
-                            current_identity = Some(identity.to_string());
-                            // logger.log(&format!("Set current_identity to: {}", identity)).ok();
-
-                            // Check if this identity matches the validator's funded identity
-                            // logger.log(&format!("Comparing identity {} with validator identity {}", identity, validator_pair.identity_pubkey)).ok();
-                            if identity == validator_pair.identity_pubkey {
-                                // logger.log_success(&format!("Node {} is ACTIVE (identity matches)", node.label)).ok();
-                                // Recheck swap readiness for active node (with tower requirement)
-                                let (active_swap_ready, active_swap_issues) =
-                                    check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(false)).await;
-                                return Ok((
-                                    crate::types::NodeStatus::Active,
-                                    validator_type,
-                                    agave_validator_executable,
-                                    fdctl_executable,
-                                    solana_cli_executable,
-                                    version,
-                                    sync_status,
-                                    current_identity,
-                                    ledger_path,
-                                    Some(active_swap_ready),
-                                    active_swap_issues,
-                                ));
-                            } else {
-                                // logger.log(&format!("Node {} is STANDBY (identity {} does not match {})", node.label, identity, validator_pair.identity_pubkey)).ok();
-                                // Recheck swap readiness for standby node (without tower requirement)
-                                let (standby_swap_ready, standby_swap_issues) =
-                                    check_node_swap_readiness(ssh_pool, node, &ssh_key, ledger_path.as_ref(), Some(true)).await;
-                                return Ok((
-                                    crate::types::NodeStatus::Standby,
-                                    validator_type,
-                                    agave_validator_executable,
-                                    fdctl_executable,
-                                    solana_cli_executable,
-                                    version,
-                                    sync_status,
-                                    current_identity,
-                                    ledger_path,
-                                    Some(standby_swap_ready),
-                                    standby_swap_issues,
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
+    if let Some(identity) = query_node_identity(
+        ssh_pool,
+        node,
+        &ssh_key,
+        &solana_cli_fallback,
+        catchup_timeout_seconds,
+    )
+    .await
+    {
+        current_identity = Some(identity.clone());
+
+        // Check if this identity matches the validator's funded identity
+        if identity == validator_pair.identity_pubkey {
+            // Recheck swap readiness for active node (with tower requirement)
+            let (active_swap_ready, active_swap_issues) =
+                check_node_swap_readiness(
+                    ssh_pool,
+                    node,
+                    &ssh_key,
+                    ledger_path.as_ref(),
+                    Some(false),
+                    &validator_type,
+                    firedancer_config_path.as_ref(),
+                    &validator_pair.identity_pubkey,
+                )
+                .await;
+            return Ok((
+                crate::types::NodeStatus::Active,
+                validator_type,
+                agave_validator_executable,
+                fdctl_executable,
+                solana_cli_executable,
+                version,
+                sync_status,
+                current_identity,
+                ledger_path,
+                Some(active_swap_ready),
+                active_swap_issues,
+            ));
+        } else {
+            // Recheck swap readiness for standby node (without tower requirement)
+            let (standby_swap_ready, standby_swap_issues) =
+                check_node_swap_readiness(
+                    ssh_pool,
+                    node,
+                    &ssh_key,
+                    ledger_path.as_ref(),
+                    Some(true),
+                    &validator_type,
+                    firedancer_config_path.as_ref(),
+                    &validator_pair.identity_pubkey,
+                )
+                .await;
+            return Ok((
+                crate::types::NodeStatus::Standby,
+                validator_type,
+                agave_validator_executable,
+                fdctl_executable,
+                solana_cli_executable,
+                version,
+                sync_status,
+                current_identity,
+                ledger_path,
+                Some(standby_swap_ready),
+                standby_swap_issues,
+            ));
         }
     }
 
-    // If we can't find the identity from catchup, assume unknown
+    // If we can't find the identity, assume unknown
     progress_bar.suspend(|| {
         println!("      ❌ Identity: Unable to determine");
     });
@@ -58,7 +58,7 @@ impl StartupLogger {
     /// Log a message with timestamp
     pub fn log(&self, message: &str) -> Result<()> {
         let timestamp = Local::now().format("%H:%M:%S%.3f");
-        let formatted = format!("[{}] {}\n", timestamp, message);
+        let formatted = format!("[{}] {}\n", timestamp, crate::redact::redact_secrets(message));
 
         if let Ok(mut file) = self.file.lock() {
             file.write_all(formatted.as_bytes())?;
@@ -0,0 +1,139 @@
+use anyhow::Result;
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use crate::types::StatsdConfig;
+
+/// Metric kinds used by svs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Gauge,
+    Count,
+    Timing,
+}
+
+impl Kind {
+    fn suffix(self) -> &'static str {
+        match self {
+            Kind::Gauge => "g",
+            Kind::Count => "c",
+            Kind::Timing => "ms",
+        }
+    }
+}
+
+/// Characters statsd servers split on are replaced in names and tags
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ':' | '|' | '@' | ',' | '#' | ' ' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// One datagram. With dogstatsd, tags are sent as `|#key:value`; plain
+/// statsd has no tags, so their values become extra name segments instead.
+pub fn format_metric(
+    prefix: &str,
+    name: &str,
+    value: f64,
+    kind: Kind,
+    tags: &[(&str, &str)],
+    dogstatsd: bool,
+) -> String {
+    let mut metric = if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    };
+    if !dogstatsd {
+        for (_, tag) in tags {
+            metric.push('.');
+            metric.push_str(&sanitize(tag).replace('.', "_"));
+        }
+    }
+
+    let mut out = format!("{}:{}|{}", sanitize(&metric), value, kind.suffix());
+    if dogstatsd && !tags.is_empty() {
+        let tags: Vec<String> = tags
+            .iter()
+            .map(|(key, tag)| format!("{}:{}", sanitize(key), sanitize(tag)))
+            .collect();
+        out.push_str(&format!("|#{}", tags.join(",")));
+    }
+    out
+}
+
+/// Fire-and-forget UDP emitter; a lost datagram is never an error
+pub struct StatsdClient {
+    socket: UdpSocket,
+    target: String,
+    prefix: String,
+    dogstatsd: bool,
+}
+
+impl StatsdClient {
+    pub fn new(config: &StatsdConfig) -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            target: format!("{}:{}", config.host, config.port),
+            prefix: config.prefix.clone(),
+            dogstatsd: config.dogstatsd,
+        })
+    }
+
+    pub fn send(&self, name: &str, value: f64, kind: Kind, tags: &[(&str, &str)]) {
+        let metric = format_metric(&self.prefix, name, value, kind, tags, self.dogstatsd);
+        let _ = self.socket.send_to(metric.as_bytes(), &self.target);
+    }
+}
+
+/// Emit the duration of a finished switch or takeover, if statsd is configured
+pub fn emit_switch(
+    config: Option<&StatsdConfig>,
+    identity: &str,
+    duration: Duration,
+    emergency: bool,
+    success: bool,
+) {
+    if let Some(client) = config.and_then(|config| StatsdClient::new(config).ok()) {
+        let kind = if emergency { "takeover" } else { "switch" };
+        let result = if success { "success" } else { "failure" };
+        client.send(
+            "switch_duration",
+            duration.as_millis() as f64,
+            Kind::Timing,
+            &[("validator", identity), ("kind", kind), ("result", result)],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_metric() {
+        let tags = [("validator", "Id111"), ("node", "node a")];
+        assert_eq!(
+            format_metric("svs", "slots_behind", 12.0, Kind::Gauge, &tags, true),
+            "svs.slots_behind:12|g|#validator:Id111,node:node_a"
+        );
+        assert_eq!(
+            format_metric("svs", "slots_behind", 12.0, Kind::Gauge, &tags, false),
+            "svs.slots_behind.Id111.node_a:12|g"
+        );
+        assert_eq!(
+            format_metric("", "ssh_failures", 2.0, Kind::Count, &[], true),
+            "ssh_failures:2|c"
+        );
+        assert_eq!(
+            format_metric("svs", "switch_duration", 850.0, Kind::Timing, &[("host", "10.0.0.1")], false),
+            "svs.switch_duration.10_0_0_1:850|ms"
+        );
+    }
+}
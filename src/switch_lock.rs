@@ -0,0 +1,223 @@
+use anyhow::{anyhow, Result};
+use std::sync::Arc;
+
+use crate::commands::switch::shell_quote;
+use crate::ssh::AsyncSshPool;
+use crate::types::NodeConfig;
+
+/// A lock older than this is treated as left behind by a crashed switch
+pub const LOCK_STALE_SECONDS: u64 = 600;
+
+/// Lock directory on each node, one per validator identity. `mkdir` is
+/// atomic, so two operators can't both create it.
+pub fn lock_path(identity: &str) -> String {
+    format!("/tmp/svs-switch-{}.lock", identity)
+}
+
+/// Who is switching, as shown to other operators
+pub fn lock_owner() -> String {
+    let user = std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string());
+    let host = std::fs::read_to_string("/etc/hostname")
+        .map(|h| h.trim().to_string())
+        .ok()
+        .filter(|h| !h.is_empty())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .unwrap_or_else(|| "unknown-host".to_string());
+    format!("{}@{} (pid {})", user, host, std::process::id())
+}
+
+/// Shell snippet setting `$stamp` to when the lock was last touched: the
+/// owner file, or the directory while the owner is still being written
+const LOCK_STAMP: &str =
+    "stamp=$(stat -c %Y \"$lock/owner\" 2>/dev/null || stat -c %Y \"$lock\" 2>/dev/null || echo 0)";
+
+/// Take the lock unless someone else holds a fresh one. Prints `ACQUIRED`
+/// or `HELD: <owner>`.
+///
+/// A stale lock is renamed aside before `mkdir`, so of several operators
+/// taking it over only one gets the new directory. The aside name carries
+/// the stale timestamp; a late operator who read the same stamp can't move
+/// the new lock there, as the rename onto the non-empty aside fails.
+pub fn acquire_command(path: &str, owner: &str) -> String {
+    format!(
+        "lock={path}; \
+         if mkdir \"$lock\" 2>/dev/null; then :; \
+         else {stamp}; [ $(( $(date +%s) - stamp )) -gt {stale} ] && mv -T \"$lock\" \"$lock.stale-$stamp\" 2>/dev/null && mkdir \"$lock\" 2>/dev/null; fi \
+         && echo {owner} > \"$lock/owner\" && echo ACQUIRED \
+         || echo \"HELD: $(cat \"$lock/owner\" 2>/dev/null)\"",
+        path = shell_quote(path),
+        owner = shell_quote(owner),
+        stamp = LOCK_STAMP,
+        stale = LOCK_STALE_SECONDS,
+    )
+}
+
+/// Mark the lock as still in use if we own it
+pub fn touch_command(path: &str, owner: &str) -> String {
+    format!(
+        "lock={path}; [ \"$(cat \"$lock/owner\" 2>/dev/null)\" = {owner} ] && touch \"$lock/owner\"; true",
+        path = shell_quote(path),
+        owner = shell_quote(owner),
+    )
+}
+
+/// Remove the lock only if we still own it, along with stale locks it
+/// replaced
+pub fn release_command(path: &str, owner: &str) -> String {
+    format!(
+        "lock={path}; [ \"$(cat \"$lock/owner\" 2>/dev/null)\" = {owner} ] && rm -rf \"$lock\" \"$lock\".stale-*; true",
+        path = shell_quote(path),
+        owner = shell_quote(owner),
+    )
+}
+
+/// Print the owner of a fresh lock, nothing when unlocked or stale
+pub fn holder_command(path: &str) -> String {
+    format!(
+        "lock={path}; \
+         [ -d \"$lock\" ] && {stamp} && [ $(( $(date +%s) - stamp )) -le {stale} ] && cat \"$lock/owner\" 2>/dev/null; true",
+        path = shell_quote(path),
+        stamp = LOCK_STAMP,
+        stale = LOCK_STALE_SECONDS,
+    )
+}
+
+/// Interpret the output of `acquire_command`: Ok, or the current holder
+pub fn parse_acquire(output: &str) -> std::result::Result<(), String> {
+    let output = output.trim();
+    if output.ends_with("ACQUIRED") {
+        Ok(())
+    } else if let Some(holder) = output.strip_prefix("HELD:") {
+        let holder = holder.trim();
+        Err(if holder.is_empty() {
+            "another operator".to_string()
+        } else {
+            holder.to_string()
+        })
+    } else {
+        Err(format!("unexpected output: {}", output))
+    }
+}
+
+/// Switch lock held on both nodes of a validator for the duration of a switch
+pub struct SwitchLock {
+    ssh_pool: Arc<AsyncSshPool>,
+    path: String,
+    owner: String,
+    held: Vec<(NodeConfig, String)>, // Node and SSH key
+}
+
+impl SwitchLock {
+    /// Lock every node in `nodes`, releasing any taken so far if one is held
+    /// by someone else
+    pub async fn acquire(
+        ssh_pool: Arc<AsyncSshPool>,
+        identity: &str,
+        nodes: &[(NodeConfig, String)],
+    ) -> Result<Self> {
+        let mut lock = Self {
+            ssh_pool,
+            path: lock_path(identity),
+            owner: lock_owner(),
+            held: Vec::new(),
+        };
+
+        for (node, ssh_key) in nodes {
+            let output = lock
+                .ssh_pool
+                .execute_command(node, ssh_key, &acquire_command(&lock.path, &lock.owner))
+                .await;
+            let result = match output {
+                Ok(output) => parse_acquire(&output),
+                Err(e) => Err(format!("could not check the lock: {}", e)),
+            };
+            match result {
+                Ok(()) => lock.held.push((node.clone(), ssh_key.clone())),
+                Err(holder) => {
+                    lock.release().await;
+                    return Err(anyhow!(
+                        "Switch lock on {} is held by {} - another switch may be in progress (stale locks expire after {} minutes)",
+                        node.label,
+                        holder,
+                        LOCK_STALE_SECONDS / 60
+                    ));
+                }
+            }
+        }
+
+        Ok(lock)
+    }
+
+    /// Refresh the lock on every held node without waiting for it, so a
+    /// long switch isn't taken for a crashed one
+    pub fn touch(&self) {
+        let ssh_pool = self.ssh_pool.clone();
+        let command = touch_command(&self.path, &self.owner);
+        let held = self.held.clone();
+        tokio::spawn(async move {
+            for (node, ssh_key) in held {
+                let _ = ssh_pool.execute_command(&node, &ssh_key, &command).await;
+            }
+        });
+    }
+
+    pub async fn release(&mut self) {
+        for (node, ssh_key) in self.held.drain(..) {
+            let _ = self
+                .ssh_pool
+                .execute_command(&node, &ssh_key, &release_command(&self.path, &self.owner))
+                .await;
+        }
+    }
+}
+
+/// Current holder of the switch lock on `node`, if any
+pub async fn lock_holder(
+    ssh_pool: &AsyncSshPool,
+    node: &NodeConfig,
+    ssh_key: &str,
+    identity: &str,
+) -> Option<String> {
+    ssh_pool
+        .execute_command(node, ssh_key, &holder_command(&lock_path(identity)))
+        .await
+        .ok()
+        .map(|output| output.trim().to_string())
+        .filter(|holder| !holder.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_acquire() {
+        assert_eq!(parse_acquire("ACQUIRED\n"), Ok(()));
+        assert_eq!(
+            parse_acquire("HELD: alice@ops (pid 42)\n"),
+            Err("alice@ops (pid 42)".to_string())
+        );
+        assert_eq!(parse_acquire("HELD: "), Err("another operator".to_string()));
+        assert!(parse_acquire("").is_err());
+    }
+
+    #[test]
+    fn test_lock_commands_quote_values() {
+        let path = lock_path("Id111");
+        assert_eq!(path, "/tmp/svs-switch-Id111.lock");
+
+        let acquire = acquire_command(&path, "bob@ops (pid 7)");
+        assert!(acquire.starts_with("lock='/tmp/svs-switch-Id111.lock';"));
+        assert!(acquire.contains("echo 'bob@ops (pid 7)' > \"$lock/owner\""));
+        assert!(acquire.contains("-gt 600 ]"));
+        assert!(acquire.contains("mv -T \"$lock\" \"$lock.stale-$stamp\" 2>/dev/null && mkdir \"$lock\""));
+
+        let touch = touch_command(&path, "bob@ops (pid 7)");
+        assert!(touch.contains("= 'bob@ops (pid 7)' ] && touch \"$lock/owner\""));
+
+        let release = release_command(&path, "bob@ops (pid 7)");
+        assert!(release.contains("= 'bob@ops (pid 7)' ] && rm -rf \"$lock\""));
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::net::UdpSocket;
+use std::sync::OnceLock;
+
+use crate::commands::status_ui_v2::{LogLevel, LogMessage};
+use crate::types::SyslogConfig;
+
+/// Local syslog socket used when no remote address is configured
+const LOCAL_SOCKET: &str = "/dev/log";
+
+/// RFC5424 severities svs logs at
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Critical = 2,
+    Error = 3,
+    Warning = 4,
+    Notice = 5,
+    Info = 6,
+}
+
+impl From<LogLevel> for Severity {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Info => Severity::Info,
+            LogLevel::Warning => Severity::Warning,
+            LogLevel::Error => Severity::Error,
+        }
+    }
+}
+
+/// Numeric code of a facility name such as `daemon` or `local3`
+pub fn facility_code(name: &str) -> Option<u8> {
+    let code = match name {
+        "kern" => 0,
+        "user" => 1,
+        "mail" => 2,
+        "daemon" => 3,
+        "auth" => 4,
+        "syslog" => 5,
+        "lpr" => 6,
+        "news" => 7,
+        "uucp" => 8,
+        "cron" => 9,
+        "authpriv" => 10,
+        "ftp" => 11,
+        _ => {
+            let n: u8 = name.strip_prefix("local")?.parse().ok()?;
+            if n > 7 {
+                return None;
+            }
+            16 + n
+        }
+    };
+    Some(code)
+}
+
+/// One RFC5424 message; nil values (`-`) stand in for missing fields
+#[allow(clippy::too_many_arguments)]
+pub fn format_rfc5424(
+    facility: u8,
+    severity: Severity,
+    time: DateTime<Utc>,
+    hostname: &str,
+    app_name: &str,
+    proc_id: u32,
+    msg_id: &str,
+    message: &str,
+) -> String {
+    let field = |value: &str| -> String {
+        let value: String = value.chars().filter(|c| c.is_ascii_graphic()).take(48).collect();
+        if value.is_empty() { "-".to_string() } else { value }
+    };
+    format!(
+        "<{}>1 {} {} {} {} {} - {}",
+        facility as u32 * 8 + severity as u32,
+        time.to_rfc3339_opts(SecondsFormat::Millis, true),
+        field(hostname),
+        field(app_name),
+        proc_id,
+        field(msg_id),
+        message
+    )
+}
+
+enum Transport {
+    #[cfg(unix)]
+    Local(std::os::unix::net::UnixDatagram),
+    Remote(UdpSocket, String),
+}
+
+/// Sends to the local syslog socket or a remote collector over UDP
+pub struct SyslogSink {
+    transport: Transport,
+    facility: u8,
+    hostname: String,
+    app_name: String,
+}
+
+impl SyslogSink {
+    pub fn new(config: &SyslogConfig) -> Result<Self> {
+        let facility = facility_code(&config.facility)
+            .ok_or_else(|| anyhow!("Unknown syslog facility '{}'", config.facility))?;
+        let transport = match &config.address {
+            Some(address) => {
+                let socket = UdpSocket::bind("0.0.0.0:0")?;
+                Transport::Remote(socket, address.clone())
+            }
+            #[cfg(unix)]
+            None => {
+                let socket = std::os::unix::net::UnixDatagram::unbound()?;
+                socket
+                    .connect(LOCAL_SOCKET)
+                    .map_err(|e| anyhow!("Failed to connect to {}: {}", LOCAL_SOCKET, e))?;
+                Transport::Local(socket)
+            }
+            #[cfg(not(unix))]
+            None => return Err(anyhow!("Local syslog is not supported here, set an address")),
+        };
+        let hostname = std::fs::read_to_string("/proc/sys/kernel/hostname")
+            .map(|name| name.trim().to_string())
+            .unwrap_or_default();
+
+        Ok(Self {
+            transport,
+            facility,
+            hostname,
+            app_name: config.app_name.clone(),
+        })
+    }
+
+    pub fn send(&self, severity: Severity, msg_id: &str, message: &str) {
+        let line = format_rfc5424(
+            self.facility,
+            severity,
+            Utc::now(),
+            &self.hostname,
+            &self.app_name,
+            std::process::id(),
+            msg_id,
+            message,
+        );
+        let _ = match &self.transport {
+            #[cfg(unix)]
+            Transport::Local(socket) => socket.send(line.as_bytes()),
+            Transport::Remote(socket, address) => socket.send_to(line.as_bytes(), address),
+        };
+    }
+
+    /// Forward a monitor log message, keeping the node or subsystem it came from
+    pub fn send_log(&self, log: &LogMessage) {
+        self.send(
+            log.level.into(),
+            "log",
+            &format!("[{}] {}", log.host, log.message),
+        );
+    }
+}
+
+static SINK: OnceLock<SyslogSink> = OnceLock::new();
+
+/// Open the configured sink once at startup
+pub fn init(config: &SyslogConfig) -> Result<()> {
+    let sink = SyslogSink::new(config)?;
+    let _ = SINK.set(sink);
+    Ok(())
+}
+
+/// The sink opened by `init`, if syslog is configured
+pub fn sink() -> Option<&'static SyslogSink> {
+    SINK.get()
+}
+
+/// Report an important event such as a switch; a no-op without syslog
+pub fn event(severity: Severity, msg_id: &str, message: &str) {
+    if let Some(sink) = sink() {
+        sink.send(severity, msg_id, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_facility_code() {
+        assert_eq!(facility_code("daemon"), Some(3));
+        assert_eq!(facility_code("local0"), Some(16));
+        assert_eq!(facility_code("local7"), Some(23));
+        assert_eq!(facility_code("local8"), None);
+        assert_eq!(facility_code("Daemon"), None);
+    }
+
+    #[test]
+    fn test_format_rfc5424() {
+        let time = DateTime::parse_from_rfc3339("2026-01-02T03:04:05.678Z").unwrap().with_timezone(&Utc);
+        assert_eq!(
+            format_rfc5424(3, Severity::Warning, time, "monitor-1", "svs", 42, "log", "[node-a] SSH failed"),
+            "<28>1 2026-01-02T03:04:05.678Z monitor-1 svs 42 log - [node-a] SSH failed"
+        );
+        // local0.crit, with an unknown hostname and spaces stripped from header fields
+        assert_eq!(
+            format_rfc5424(16, Severity::Critical, time, "", "my svs", 42, "takeover", "done"),
+            "<130>1 2026-01-02T03:04:05.678Z - mysvs 42 takeover - done"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+/// Longest the vote-polling loop (5s interval) may go without a pass before
+/// watchdog pings stop
+pub const VOTE_LOOP_STALL: Duration = Duration::from_secs(60);
+
+/// Longest the SSH-health loop (30s interval, slow hosts time out) may go
+/// without a pass before watchdog pings stop
+pub const SSH_LOOP_STALL: Duration = Duration::from_secs(180);
+
+/// Send a state string such as `READY=1` to systemd. Returns false when not
+/// started by systemd with `Type=notify` or the message couldn't be sent.
+#[cfg(target_os = "linux")]
+pub fn notify(state: &str) -> bool {
+    use std::os::linux::net::SocketAddrExt;
+    use std::os::unix::net::{SocketAddr, UnixDatagram};
+
+    let path = match std::env::var_os("NOTIFY_SOCKET") {
+        Some(path) => path.to_string_lossy().into_owned(),
+        None => return false,
+    };
+    let addr = match path.strip_prefix('@') {
+        Some(name) => SocketAddr::from_abstract_name(name.as_bytes()),
+        None => SocketAddr::from_pathname(&path),
+    };
+
+    match (UnixDatagram::unbound(), addr) {
+        (Ok(socket), Ok(addr)) => socket.send_to_addr(state.as_bytes(), &addr).is_ok(),
+        _ => false,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn notify(_state: &str) -> bool {
+    false
+}
+
+/// How often to ping the watchdog: half of `WATCHDOG_USEC`, as systemd
+/// recommends. None when no watchdog is configured for this process.
+pub fn watchdog_interval(usec: Option<&str>, watchdog_pid: Option<&str>, own_pid: u32) -> Option<Duration> {
+    if let Some(pid) = watchdog_pid {
+        if pid.trim().parse::<u32>().ok() != Some(own_pid) {
+            return None;
+        }
+    }
+    let usec = usec?.trim().parse::<u64>().ok().filter(|usec| *usec > 0)?;
+    Some(Duration::from_micros(usec / 2))
+}
+
+/// Whether the monitoring loops are still completing passes. The error names
+/// the loop that wedged and is reported to systemd as the service status.
+pub fn loops_healthy(vote_loop_age: Duration, ssh_loop_age: Duration) -> Result<(), String> {
+    if vote_loop_age > VOTE_LOOP_STALL {
+        return Err(format!(
+            "vote-polling loop stalled for {}s",
+            vote_loop_age.as_secs()
+        ));
+    }
+    if ssh_loop_age > SSH_LOOP_STALL {
+        return Err(format!(
+            "SSH-health loop stalled for {}s",
+            ssh_loop_age.as_secs()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watchdog_interval() {
+        assert_eq!(
+            watchdog_interval(Some("120000000"), None, 42),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            watchdog_interval(Some("120000000"), Some("42"), 42),
+            Some(Duration::from_secs(60))
+        );
+        // Watchdog meant for another process, or not configured
+        assert_eq!(watchdog_interval(Some("120000000"), Some("7"), 42), None);
+        assert_eq!(watchdog_interval(None, None, 42), None);
+        assert_eq!(watchdog_interval(Some("0"), None, 42), None);
+        assert_eq!(watchdog_interval(Some("soon"), None, 42), None);
+    }
+
+    #[test]
+    fn test_loops_healthy() {
+        let fresh = Duration::from_secs(10);
+        assert!(loops_healthy(fresh, fresh).is_ok());
+        assert!(loops_healthy(Duration::from_secs(61), fresh)
+            .unwrap_err()
+            .starts_with("vote-polling loop"));
+        assert!(loops_healthy(fresh, Duration::from_secs(181))
+            .unwrap_err()
+            .starts_with("SSH-health loop"));
+    }
+}
@@ -0,0 +1,1046 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::alert::AlertHistory;
+use crate::commands::status_ui_v2::{LogLevel, LogMessage, UiState};
+use crate::types::{NodeStatus, TelegramConfig, ValidatorPair};
+use crate::AppState;
+
+/// Long-poll timeout passed to getUpdates
+const POLL_TIMEOUT_SECONDS: u64 = 30;
+
+/// How long a /switch confirmation button stays valid
+const SWITCH_CONFIRM_TIMEOUT_SECONDS: u64 = 60;
+
+/// Longest allowed /mute, so a forgotten mute can't silence alerts for good
+const MAX_MUTE_MINUTES: u64 = 24 * 60;
+
+/// Commands understood by the bot
+#[derive(Debug, Clone, PartialEq)]
+pub enum BotCommand {
+    Status,
+    Health,
+    Switch(Option<String>),
+    Mute {
+        validator: Option<String>,
+        minutes: Option<u64>,
+    },
+    Unmute(Option<String>),
+    Alerts,
+    Help,
+}
+
+impl BotCommand {
+    /// Commands that change validator state or silence alerts and may be
+    /// restricted to admins
+    pub fn is_dangerous(&self) -> bool {
+        matches!(self, BotCommand::Switch(_) | BotCommand::Mute { .. })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BotCommand::Status => "/status",
+            BotCommand::Health => "/health",
+            BotCommand::Switch(_) => "/switch",
+            BotCommand::Mute { .. } => "/mute",
+            BotCommand::Unmute(_) => "/unmute",
+            BotCommand::Alerts => "/alerts",
+            BotCommand::Help => "/help",
+        }
+    }
+}
+
+/// Result of checking a command against the chat allowlist and admin list
+#[derive(Debug, Clone, PartialEq)]
+pub enum Authorization {
+    Allowed,
+    UnknownChat,
+    NotAdmin,
+    NoAdmins,
+}
+
+/// Commands are accepted from `chat_id` and `allowed_chat_ids`. Dangerous
+/// commands additionally need the sender (user or chat) in `admin_ids`, and
+/// are refused outright while no admins are configured.
+pub fn authorize(
+    telegram: &TelegramConfig,
+    chat_id: &str,
+    user_id: Option<&str>,
+    command: &BotCommand,
+) -> Authorization {
+    let chat_allowed =
+        chat_id == telegram.chat_id || telegram.allowed_chat_ids.iter().any(|id| id == chat_id);
+    if !chat_allowed {
+        return Authorization::UnknownChat;
+    }
+
+    if !command.is_dangerous() {
+        return Authorization::Allowed;
+    }
+
+    if telegram.admin_ids.is_empty() {
+        return Authorization::NoAdmins;
+    }
+
+    let is_admin = telegram
+        .admin_ids
+        .iter()
+        .any(|id| id == chat_id || Some(id.as_str()) == user_id);
+
+    if is_admin {
+        Authorization::Allowed
+    } else {
+        Authorization::NotAdmin
+    }
+}
+
+/// Parse a chat message into a bot command. Handles the `/cmd@botname` form
+/// Telegram uses in group chats.
+pub fn parse_command(text: &str) -> Option<BotCommand> {
+    let mut words = text.split_whitespace();
+    let first = words.next()?;
+    let command = first.strip_prefix('/')?;
+    let command = command.split('@').next().unwrap_or(command);
+
+    match command.to_lowercase().as_str() {
+        "status" => Some(BotCommand::Status),
+        "health" => Some(BotCommand::Health),
+        "switch" => Some(BotCommand::Switch(words.next().map(|w| w.to_string()))),
+        "mute" => {
+            // /mute <minutes> or /mute <validator> <minutes>
+            let args: Vec<&str> = words.take(2).collect();
+            let (validator, minutes) = match args.as_slice() {
+                [] => (None, None),
+                [minutes] => (None, minutes.parse().ok()),
+                [validator, minutes] => (Some(validator.to_string()), minutes.parse().ok()),
+                _ => unreachable!(),
+            };
+            Some(BotCommand::Mute { validator, minutes })
+        }
+        "unmute" => Some(BotCommand::Unmute(words.next().map(|w| w.to_string()))),
+        "alerts" => Some(BotCommand::Alerts),
+        "help" | "start" => Some(BotCommand::Help),
+        _ => None,
+    }
+}
+
+/// Who sent a message or pressed a button: `@username`, else the first name,
+/// else the numeric user id
+pub fn telegram_user(from: &Value, user_id: Option<&str>) -> String {
+    match from["username"].as_str() {
+        Some(username) => format!("@{}", username),
+        None => from["first_name"]
+            .as_str()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| user_id.unwrap_or("unknown").to_string()),
+    }
+}
+
+/// Resolve the `/switch` argument to a validator index. Accepts the 1-based
+/// position, or a prefix of the identity or vote pubkey. The argument may be
+/// omitted when only one validator is configured.
+pub fn resolve_validator(arg: Option<&str>, validators: &[ValidatorPair]) -> Result<usize, String> {
+    let arg = match arg {
+        Some(arg) => arg,
+        None if validators.len() == 1 => return Ok(0),
+        None => {
+            return Err(format!(
+                "Multiple validators configured, use /switch <1-{}|identity>",
+                validators.len()
+            ))
+        }
+    };
+
+    if let Ok(position) = arg.parse::<usize>() {
+        if position >= 1 && position <= validators.len() {
+            return Ok(position - 1);
+        }
+        return Err(format!("No validator #{}", position));
+    }
+
+    let matches: Vec<usize> = validators
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.identity_pubkey.starts_with(arg) || v.vote_pubkey.starts_with(arg))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    match matches.as_slice() {
+        [idx] => Ok(*idx),
+        [] => Err(format!("No validator matches `{}`", arg)),
+        _ => Err(format!("`{}` matches more than one validator", arg)),
+    }
+}
+
+/// A /switch request waiting for the inline keyboard answer
+struct PendingSwitch {
+    validator_idx: usize,
+    nonce: i64,
+    requested_at: Instant,
+}
+
+/// Two-way Telegram bot answering commands from the configured chat with data
+/// from the shared UI state
+pub struct TelegramBot {
+    telegram: TelegramConfig,
+    app_state: Arc<AppState>,
+    ui_state: Arc<RwLock<UiState>>,
+    log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
+    client: reqwest::Client,
+    pending_switch: Mutex<Option<PendingSwitch>>,
+}
+
+impl TelegramBot {
+    pub fn new(
+        telegram: TelegramConfig,
+        app_state: Arc<AppState>,
+        ui_state: Arc<RwLock<UiState>>,
+        log_sender: tokio::sync::mpsc::UnboundedSender<LogMessage>,
+    ) -> Self {
+        Self {
+            telegram,
+            app_state,
+            ui_state,
+            log_sender,
+            client: crate::proxy::http_client_builder().build().unwrap_or_default(),
+            pending_switch: Mutex::new(None),
+        }
+    }
+
+    /// Poll for updates forever. Errors are logged and retried after a pause so
+    /// a Telegram outage never takes down monitoring.
+    pub async fn run(self: Arc<Self>) {
+        // Skip messages sent while svs was not running
+        let mut offset = self.latest_update_id().await.map(|id| id + 1);
+
+        loop {
+            match self.get_updates(offset).await {
+                Ok(updates) => {
+                    for update in updates {
+                        if let Some(id) = update["update_id"].as_i64() {
+                            offset = Some(id + 1);
+                        }
+                        self.handle_update(&update).await;
+                    }
+                }
+                Err(e) => {
+                    self.log(LogLevel::Warning, format!("Telegram polling failed: {}", e));
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                }
+            }
+        }
+    }
+
+    async fn handle_update(self: &Arc<Self>, update: &Value) {
+        if update.get("callback_query").is_some() {
+            self.handle_callback(&update["callback_query"]).await;
+            return;
+        }
+
+        let message = &update["message"];
+        let chat_id = match message["chat"]["id"].as_i64() {
+            Some(id) => id.to_string(),
+            None => return,
+        };
+        let text = match message["text"].as_str() {
+            Some(text) => text,
+            None => return,
+        };
+        let user_id = message["from"]["id"].as_i64().map(|id| id.to_string());
+
+        let command = match parse_command(text) {
+            Some(command) => command,
+            None => return,
+        };
+
+        if !self
+            .check_authorization(&chat_id, user_id.as_deref(), &command)
+            .await
+        {
+            return;
+        }
+
+        let reply = match command {
+            BotCommand::Switch(arg) => {
+                let nonce = update["update_id"].as_i64().unwrap_or_default();
+                self.request_switch(&chat_id, arg.as_deref(), nonce).await;
+                return;
+            }
+            BotCommand::Mute { validator, minutes } => {
+                let user = telegram_user(&message["from"], user_id.as_deref());
+                self.mute(&user, validator.as_deref(), minutes).await
+            }
+            BotCommand::Unmute(validator) => {
+                let user = telegram_user(&message["from"], user_id.as_deref());
+                self.unmute(&user, validator.as_deref()).await
+            }
+            BotCommand::Status => format_status(&*self.ui_state.read().await),
+            BotCommand::Health => format_health(&*self.ui_state.read().await, &self.app_state),
+            BotCommand::Alerts => format_alert_history(&self.ui_state.read().await.alert_history),
+            BotCommand::Help => help_text(),
+        };
+
+        if let Err(e) = self.send_message(&chat_id, &reply).await {
+            self.log(LogLevel::Error, format!("Failed to send Telegram reply: {}", e));
+        }
+    }
+
+    /// Check and log a command attempt. Denied admin commands get a reply;
+    /// unknown chats are ignored silently.
+    async fn check_authorization(
+        &self,
+        chat_id: &str,
+        user_id: Option<&str>,
+        command: &BotCommand,
+    ) -> bool {
+        let authorization = authorize(&self.telegram, chat_id, user_id, command);
+        let sender = format!(
+            "user {} in chat {}",
+            user_id.unwrap_or("unknown"),
+            chat_id
+        );
+
+        match authorization {
+            Authorization::Allowed => {
+                self.log(
+                    LogLevel::Info,
+                    format!("Telegram {} from {}: allowed", command.name(), sender),
+                );
+                true
+            }
+            Authorization::UnknownChat => {
+                self.log(
+                    LogLevel::Warning,
+                    format!("Telegram {} from {}: denied, chat not allowed", command.name(), sender),
+                );
+                false
+            }
+            Authorization::NotAdmin => {
+                self.log(
+                    LogLevel::Warning,
+                    format!("Telegram {} from {}: denied, not an admin", command.name(), sender),
+                );
+                let _ = self
+                    .send_message(chat_id, &format!("⛔ {} is restricted to admins", command.name()))
+                    .await;
+                false
+            }
+            Authorization::NoAdmins => {
+                self.log(
+                    LogLevel::Warning,
+                    format!("Telegram {} from {}: denied, no admin_ids configured", command.name(), sender),
+                );
+                let _ = self
+                    .send_message(
+                        chat_id,
+                        &format!("⛔ {} is disabled until telegram.admin_ids is configured", command.name()),
+                    )
+                    .await;
+                false
+            }
+        }
+    }
+
+    /// Resolve a mute target: "all" or no argument means every validator
+    fn mute_target(&self, validator: Option<&str>) -> Result<Option<usize>, String> {
+        match validator {
+            None | Some("all") => Ok(None),
+            Some(arg) => resolve_validator(Some(arg), &self.app_state.config.validators).map(Some),
+        }
+    }
+
+    fn mute_target_label(&self, target: Option<usize>) -> String {
+        match target.and_then(|idx| self.app_state.config.validators.get(idx)) {
+            Some(pair) => format!("validator `{}`", pair.identity_pubkey),
+            None => "all validators".to_string(),
+        }
+    }
+
+    async fn mute(&self, user: &str, validator: Option<&str>, minutes: Option<u64>) -> String {
+        let minutes = match minutes {
+            Some(minutes) if minutes > 0 => minutes.min(MAX_MUTE_MINUTES),
+            _ => return "Usage: /mute [validator|all] <minutes>".to_string(),
+        };
+        let target = match self.mute_target(validator) {
+            Ok(target) => target,
+            Err(e) => return format!("❌ {}", e),
+        };
+
+        self.ui_state
+            .write()
+            .await
+            .alert_mutes
+            .mute(target, Duration::from_secs(minutes * 60));
+
+        let label = self.mute_target_label(target);
+        self.log(
+            LogLevel::Warning,
+            format!("Alerts muted for {} for {} minutes", label, minutes),
+        );
+        crate::audit::record(
+            &format!("telegram {}", user),
+            "mute",
+            &label,
+            &format!("{} minutes", minutes),
+        );
+        format!("🔇 Alerts muted for {} for {} minutes", label, minutes)
+    }
+
+    async fn unmute(&self, user: &str, validator: Option<&str>) -> String {
+        let target = match self.mute_target(validator) {
+            Ok(target) => target,
+            Err(e) => return format!("❌ {}", e),
+        };
+
+        self.ui_state.write().await.alert_mutes.unmute(target);
+
+        let label = self.mute_target_label(target);
+        self.log(LogLevel::Info, format!("Alerts unmuted for {}", label));
+        crate::audit::record(&format!("telegram {}", user), "unmute", &label, "");
+        format!("🔔 Alerts unmuted for {}", label)
+    }
+
+    /// Answer /switch with a summary of the planned switch and an inline
+    /// keyboard. Nothing happens until the confirm button is pressed.
+    async fn request_switch(&self, chat_id: &str, arg: Option<&str>, nonce: i64) {
+        let validator_idx = match resolve_validator(arg, &self.app_state.config.validators) {
+            Ok(idx) => idx,
+            Err(e) => {
+                let _ = self.send_message(chat_id, &format!("❌ {}", e)).await;
+                return;
+            }
+        };
+
+        let validator_status = match self.ui_state.read().await.validator_statuses.get(validator_idx) {
+            Some(status) => status.clone(),
+            None => return,
+        };
+        let active = validator_status
+            .nodes_with_status
+            .iter()
+            .find(|n| n.status == NodeStatus::Active);
+        let standby = validator_status
+            .nodes_with_status
+            .iter()
+            .find(|n| n.status == NodeStatus::Standby);
+
+        let (active, standby) = match (active, standby) {
+            (Some(active), Some(standby)) => (active, standby),
+            _ => {
+                let _ = self
+                    .send_message(chat_id, "❌ Could not determine active and standby nodes")
+                    .await;
+                return;
+            }
+        };
+
+        // Same standby catchup gate as the terminal; forcing needs the terminal
+        let lag_blocker = {
+            let state = self.ui_state.read().await;
+            crate::commands::switch::standby_lag_blocker(
+                crate::commands::status_ui_v2::standby_slots_behind(&state, validator_idx),
+                self.app_state
+                    .config
+                    .switch
+                    .clone()
+                    .unwrap_or_default()
+                    .max_standby_slots_behind,
+            )
+        };
+        if let Some(reason) = lag_blocker {
+            let _ = self
+                .send_message(
+                    chat_id,
+                    &format!(
+                        "🔒 Switch refused: {}. Wait for the standby to catch up, or switch from the terminal with `svs switch --force`.",
+                        reason
+                    ),
+                )
+                .await;
+            return;
+        }
+
+        let text = format!(
+            "⚠️ *Confirm Validator Switch*\n\n\
+            *Validator:* `{}`\n\
+            *Active:* {} → standby\n\
+            *Standby:* {} → active\n\n\
+            Confirm within {}s.",
+            validator_status.validator_pair.identity_pubkey,
+            active.node.label,
+            standby.node.label,
+            SWITCH_CONFIRM_TIMEOUT_SECONDS
+        );
+        let keyboard = json!({
+            "inline_keyboard": [[
+                { "text": "✅ Switch", "callback_data": format!("switch:{}", nonce) },
+                { "text": "❌ Cancel", "callback_data": format!("cancel:{}", nonce) }
+            ]]
+        });
+
+        *self.pending_switch.lock().await = Some(PendingSwitch {
+            validator_idx,
+            nonce,
+            requested_at: Instant::now(),
+        });
+
+        if let Err(e) = self.send_message_with_markup(chat_id, &text, Some(keyboard)).await {
+            self.log(LogLevel::Error, format!("Failed to send Telegram reply: {}", e));
+        }
+    }
+
+    async fn handle_callback(self: &Arc<Self>, callback: &Value) {
+        let callback_id = callback["id"].as_str().unwrap_or_default();
+        let chat_id = match callback["message"]["chat"]["id"].as_i64() {
+            Some(id) => id.to_string(),
+            None => return,
+        };
+        let message_id = callback["message"]["message_id"].as_i64();
+        let data = callback["data"].as_str().unwrap_or_default();
+        let user_id = callback["from"]["id"].as_i64().map(|id| id.to_string());
+
+        if let Some(condition) = data.strip_prefix("ack:") {
+            self.acknowledge(callback, &chat_id, user_id.as_deref(), condition)
+                .await;
+            return;
+        }
+
+        // Pressing the confirm button is held to the same rules as /switch
+        if !self
+            .check_authorization(&chat_id, user_id.as_deref(), &BotCommand::Switch(None))
+            .await
+        {
+            let _ = self.answer_callback(callback_id).await;
+            return;
+        }
+
+        let (action, nonce) = match data.split_once(':') {
+            Some((action, nonce)) => (action, nonce.parse::<i64>().unwrap_or_default()),
+            None => return,
+        };
+
+        // Only the latest, unexpired request can be confirmed
+        let pending = {
+            let mut pending = self.pending_switch.lock().await;
+            match pending.take() {
+                Some(p) if p.nonce == nonce => Some(p),
+                other => {
+                    *pending = other;
+                    None
+                }
+            }
+        };
+
+        let _ = self.answer_callback(callback_id).await;
+
+        let pending = match pending {
+            Some(p) if p.requested_at.elapsed().as_secs() <= SWITCH_CONFIRM_TIMEOUT_SECONDS => p,
+            _ => {
+                self.edit_message(&chat_id, message_id, "⌛ Switch request expired").await;
+                return;
+            }
+        };
+
+        let user = format!("telegram {}", telegram_user(&callback["from"], user_id.as_deref()));
+        let identity = self
+            .app_state
+            .config
+            .validators
+            .get(pending.validator_idx)
+            .map(|pair| pair.identity_pubkey.clone())
+            .unwrap_or_default();
+        if action != "switch" {
+            crate::audit::record(&user, "switch_cancelled", &identity, "");
+            self.edit_message(&chat_id, message_id, "❌ Switch cancelled").await;
+            return;
+        }
+        crate::audit::record(&user, "switch_confirmed", &identity, "");
+
+        self.edit_message(&chat_id, message_id, "🔄 Switch confirmed, starting...")
+            .await;
+
+        // The switch outlives this update; keep polling so the chat stays
+        // responsive while it runs
+        let bot = Arc::clone(self);
+        tokio::spawn(async move {
+            bot.run_switch(&chat_id, pending.validator_idx).await;
+        });
+    }
+
+    /// Handle the Acknowledge button on an alert: stop repeats for the
+    /// condition until it clears and record who acknowledged it
+    async fn acknowledge(
+        &self,
+        callback: &Value,
+        chat_id: &str,
+        user_id: Option<&str>,
+        condition: &str,
+    ) {
+        let callback_id = callback["id"].as_str().unwrap_or_default();
+        let _ = self.answer_callback(callback_id).await;
+
+        // Acknowledging is as harmless as reading the alert history
+        if !self
+            .check_authorization(chat_id, user_id, &BotCommand::Alerts)
+            .await
+        {
+            return;
+        }
+
+        let user = telegram_user(&callback["from"], user_id);
+
+        let acknowledged = self
+            .ui_state
+            .write()
+            .await
+            .alert_history
+            .acknowledge(condition, &user);
+
+        let reply = if acknowledged {
+            self.log(
+                LogLevel::Info,
+                format!("Alert {} acknowledged by {}", condition, user),
+            );
+            crate::audit::record(&format!("telegram {}", user), "acknowledge", condition, "");
+            self.remove_keyboard(chat_id, callback["message"]["message_id"].as_i64())
+                .await;
+            format!(
+                "👀 Alert acknowledged by {}. Repeat notifications are paused until the condition clears.",
+                user
+            )
+        } else {
+            "ℹ️ This alert was already acknowledged or has cleared".to_string()
+        };
+
+        if let Err(e) = self.send_message(chat_id, &reply).await {
+            self.log(LogLevel::Error, format!("Failed to send Telegram reply: {}", e));
+        }
+    }
+
+    /// Run the switch through the same code path as the CLI, relaying step
+    /// progress to the chat as it happens and the outcome when it ends
+    async fn run_switch(&self, chat_id: &str, validator_idx: usize) {
+        self.log(
+            LogLevel::Warning,
+            format!("Switch of validator #{} started from Telegram", validator_idx + 1),
+        );
+
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app_state = (*self.app_state).clone();
+        // Switch with the roles as last reconciled, not as detected at startup
+        app_state.validator_statuses = self.ui_state.read().await.validator_statuses.clone();
+
+        // Passing a progress channel keeps the switch off the terminal, which
+        // the running UI owns. progress_tx moves into the switch and is
+        // dropped when it finishes, which ends the relay loop below. The
+        // pre-switch checks, lag gate included, run again at confirm time;
+        // the confirm button stands in for the terminal's confirmation.
+        let switch = async move {
+            crate::commands::switch::switch_command_with_confirmation(
+                false,
+                &mut app_state,
+                validator_idx,
+                false,
+                false,
+                false,
+                Some(progress_tx),
+            )
+            .await
+        };
+
+        let relay = async {
+            while let Some(step) = progress_rx.recv().await {
+                let _ = self.send_message(chat_id, &step).await;
+            }
+        };
+
+        let (result, _) = tokio::join!(switch, relay);
+
+        let (reply, level) = match result {
+            Ok(true) => ("✅ Switch completed".to_string(), LogLevel::Info),
+            Ok(false) => ("⚠️ Switch was not completed".to_string(), LogLevel::Warning),
+            Err(e) => (format!("❌ Switch failed: {}", e), LogLevel::Error),
+        };
+        self.log(level, format!("Telegram switch: {}", reply));
+        let _ = self.send_message(chat_id, &reply).await;
+    }
+
+    async fn answer_callback(&self, callback_id: &str) -> Result<()> {
+        let url = format!(
+            "https://api.telegram.org/bot{}/answerCallbackQuery",
+            self.telegram.bot_token
+        );
+        self.client
+            .post(&url)
+            .json(&json!({ "callback_query_id": callback_id }))
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    /// Replace the confirmation message text, which also removes its keyboard
+    async fn edit_message(&self, chat_id: &str, message_id: Option<i64>, text: &str) {
+        let message_id = match message_id {
+            Some(id) => id,
+            None => return,
+        };
+        let url = format!(
+            "https://api.telegram.org/bot{}/editMessageText",
+            self.telegram.bot_token
+        );
+        let payload = json!({
+            "chat_id": chat_id,
+            "message_id": message_id,
+            "text": text
+        });
+        let _ = self.client.post(&url).json(&payload).send().await;
+    }
+
+    /// Drop the inline keyboard from an alert, keeping its text
+    async fn remove_keyboard(&self, chat_id: &str, message_id: Option<i64>) {
+        let message_id = match message_id {
+            Some(id) => id,
+            None => return,
+        };
+        let url = format!(
+            "https://api.telegram.org/bot{}/editMessageReplyMarkup",
+            self.telegram.bot_token
+        );
+        let payload = json!({
+            "chat_id": chat_id,
+            "message_id": message_id,
+            "reply_markup": { "inline_keyboard": [] }
+        });
+        let _ = self.client.post(&url).json(&payload).send().await;
+    }
+
+    async fn latest_update_id(&self) -> Option<i64> {
+        let url = format!(
+            "https://api.telegram.org/bot{}/getUpdates?offset=-1&timeout=0",
+            self.telegram.bot_token
+        );
+        let response: Value = self.client.get(&url).send().await.ok()?.json().await.ok()?;
+        response["result"]
+            .as_array()?
+            .last()?
+            .get("update_id")?
+            .as_i64()
+    }
+
+    async fn get_updates(&self, offset: Option<i64>) -> Result<Vec<Value>> {
+        let mut url = format!(
+            "https://api.telegram.org/bot{}/getUpdates?timeout={}",
+            self.telegram.bot_token, POLL_TIMEOUT_SECONDS
+        );
+        if let Some(offset) = offset {
+            url.push_str(&format!("&offset={}", offset));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .timeout(Duration::from_secs(POLL_TIMEOUT_SECONDS + 10))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Telegram API error: {}", error_text);
+        }
+
+        let body: Value = response.json().await?;
+        Ok(body["result"].as_array().cloned().unwrap_or_default())
+    }
+
+    async fn send_message(&self, chat_id: &str, text: &str) -> Result<()> {
+        self.send_message_with_markup(chat_id, text, None).await
+    }
+
+    async fn send_message_with_markup(
+        &self,
+        chat_id: &str,
+        text: &str,
+        reply_markup: Option<Value>,
+    ) -> Result<()> {
+        let url = format!(
+            "https://api.telegram.org/bot{}/sendMessage",
+            self.telegram.bot_token
+        );
+
+        let mut payload = json!({
+            "chat_id": chat_id,
+            "text": crate::redact::redact_secrets(text),
+            "parse_mode": "Markdown",
+            "disable_web_page_preview": true
+        });
+        if let Some(markup) = reply_markup {
+            payload["reply_markup"] = markup;
+        }
+
+        let response = self.client.post(&url).json(&payload).send().await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Telegram API error: {}", error_text);
+        }
+
+        Ok(())
+    }
+
+    fn log(&self, level: LogLevel, message: String) {
+        let _ = self.log_sender.send(LogMessage {
+            host: "telegram".to_string(),
+            message,
+            timestamp: Instant::now(),
+            level,
+        });
+    }
+}
+
+fn help_text() -> String {
+    "🤖 *SVS Bot Commands*\n\n\
+    /status - Active node, last vote slot and catchup state\n\
+    /health - SSH and RPC failure counters\n\
+    /switch <validator> - Switch active and standby nodes (asks for confirmation)\n\
+    /mute [validator|all] <minutes> - Silence alerts for a while\n\
+    /unmute [validator] - Resume alerts\n\
+    /alerts - Recent alerts and who acknowledged them"
+        .to_string()
+}
+
+/// Compact per-validator summary: active node, last vote slot, catchup state
+pub fn format_status(ui_state: &UiState) -> String {
+    let mut message = "📋 *Validator Status*\n".to_string();
+
+    for (idx, validator_status) in ui_state.validator_statuses.iter().enumerate() {
+        let pair = &validator_status.validator_pair;
+        message.push_str(&format!("\n*Validator:* `{}`\n", pair.identity_pubkey));
+
+        let active = validator_status
+            .nodes_with_status
+            .iter()
+            .find(|n| n.status == NodeStatus::Active);
+        match active {
+            Some(node) => message.push_str(&format!("*Active:* {}\n", node.node.label)),
+            None => message.push_str("*Active:* ⚠️ none detected\n"),
+        }
+
+        if let Some(remaining) = ui_state.alert_mutes.remaining(idx) {
+            message.push_str(&format!(
+                "*Alerts:* 🔇 muted for {} more minutes\n",
+                remaining.as_secs().div_ceil(60)
+            ));
+        }
+
+        match ui_state.vote_data.get(idx).and_then(|v| v.as_ref()) {
+            Some(vote_data) => {
+                let last_slot = vote_data
+                    .recent_votes
+                    .first()
+                    .map(|v| v.slot.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                let voting = if vote_data.is_voting { "✅ Voting" } else { "⚠️ Not voting" };
+                message.push_str(&format!("*Last Vote:* {} ({})\n", last_slot, voting));
+            }
+            None => message.push_str("*Last Vote:* no data yet\n"),
+        }
+
+        if let Some(catchup) = ui_state.catchup_data.get(idx) {
+            for (node_idx, status) in [&catchup.node_0, &catchup.node_1].iter().enumerate() {
+                if let (Some(status), Some(node)) =
+                    (status, validator_status.nodes_with_status.get(node_idx))
+                {
+                    message.push_str(&format!(
+                        "*Catchup ({}):* {}\n",
+                        node.node.label, status.status
+                    ));
+                }
+            }
+        }
+    }
+
+    message
+}
+
+/// Most recent alerts with their acknowledgment and resolution state
+pub fn format_alert_history(history: &AlertHistory) -> String {
+    let mut message = "📜 *Recent Alerts*\n".to_string();
+    let mut empty = true;
+
+    for record in history.recent(10) {
+        empty = false;
+        let mut state = match (&record.acknowledged_by, record.acknowledged_at) {
+            (Some(user), Some(at)) => {
+                format!("acknowledged by {} at {}", user, at.format("%H:%M UTC"))
+            }
+            _ => "not acknowledged".to_string(),
+        };
+        if let Some(resolved) = record.resolved_at {
+            state.push_str(&format!(", resolved {}", resolved.format("%H:%M UTC")));
+        }
+        message.push_str(&format!(
+            "\n{} *{}* `{}`\n  {}\n",
+            record.sent_at.format("%m-%d %H:%M"),
+            record.title,
+            record.validator_identity,
+            state
+        ));
+    }
+
+    if empty {
+        message.push_str("\nNo alerts sent since startup\n");
+    }
+
+    message
+}
+
+/// SSH and RPC failure counters per validator and node
+pub fn format_health(ui_state: &UiState, app_state: &AppState) -> String {
+    let mut message = "🩺 *Health*\n".to_string();
+
+    for (idx, validator_status) in ui_state.validator_statuses.iter().enumerate() {
+        message.push_str(&format!(
+            "\n*Validator:* `{}`\n",
+            validator_status.validator_pair.identity_pubkey
+        ));
+
+        if let Some(rpc) = ui_state.rpc_failure_tracker.get(idx) {
+            message.push_str(&format!(
+                "*RPC:* {} consecutive failures{}\n",
+                rpc.consecutive_failures,
+                rpc.seconds_since_first_failure()
+                    .map(|s| format!(" (failing for {}s)", s))
+                    .unwrap_or_default()
+            ));
+        }
+
+        if let Some(selector) = app_state.rpc_selectors.get(idx) {
+            if let Ok(selector) = selector.try_read() {
+                if selector.endpoints().len() > 1 {
+                    message.push_str(&format!("*Preferred RPC:* {}\n", selector.best()));
+                }
+            }
+        }
+
+        if let Some(health) = ui_state.validator_health.get(idx) {
+            message.push_str(&format!(
+                "*SSH failures:* {}\n",
+                health.ssh_status.consecutive_failures
+            ));
+        }
+
+        if let Some(ssh) = ui_state.ssh_health_data.get(idx) {
+            for (node_idx, status) in [&ssh.node_0, &ssh.node_1].iter().enumerate() {
+                if let Some(node) = validator_status.nodes_with_status.get(node_idx) {
+                    let state = if status.is_healthy {
+                        "✅ healthy".to_string()
+                    } else {
+                        let since = status
+                            .failure_start
+                            .map(|t| format!(" for {}s", t.elapsed().as_secs()))
+                            .unwrap_or_default();
+                        format!("❌ failing{}", since)
+                    };
+                    message.push_str(&format!("*SSH ({}):* {}\n", node.node.label, state));
+                }
+            }
+        }
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("/status"), Some(BotCommand::Status));
+        assert_eq!(parse_command("/health@svs_bot"), Some(BotCommand::Health));
+        assert_eq!(parse_command("/STATUS now"), Some(BotCommand::Status));
+        assert_eq!(parse_command("/start"), Some(BotCommand::Help));
+        assert_eq!(parse_command("/alerts"), Some(BotCommand::Alerts));
+        assert_eq!(parse_command("status"), None);
+        assert_eq!(parse_command("/unknown"), None);
+        assert_eq!(parse_command(""), None);
+        assert_eq!(parse_command("/switch"), Some(BotCommand::Switch(None)));
+        assert_eq!(
+            parse_command("/switch@svs_bot 2"),
+            Some(BotCommand::Switch(Some("2".to_string())))
+        );
+        assert_eq!(
+            parse_command("/mute 30"),
+            Some(BotCommand::Mute { validator: None, minutes: Some(30) })
+        );
+        assert_eq!(
+            parse_command("/mute 2 15"),
+            Some(BotCommand::Mute { validator: Some("2".to_string()), minutes: Some(15) })
+        );
+        assert_eq!(
+            parse_command("/mute soon"),
+            Some(BotCommand::Mute { validator: None, minutes: None })
+        );
+        assert_eq!(parse_command("/unmute"), Some(BotCommand::Unmute(None)));
+    }
+
+    fn validator(identity: &str, vote: &str) -> ValidatorPair {
+        ValidatorPair {
+            vote_pubkey: vote.to_string(),
+            identity_pubkey: identity.to_string(),
+            rpc: "https://api.mainnet-beta.solana.com".to_string(),
+            additional_rpcs: Vec::new(),
+            ws_url: None,
+            reference_rpc: None,
+            nodes: Vec::new(),
+        }
+    }
+
+    fn telegram(admin_ids: Vec<&str>) -> TelegramConfig {
+        TelegramConfig {
+            bot_token: "token".to_string(),
+            chat_id: "-100".to_string(),
+            commands_enabled: true,
+            allowed_chat_ids: vec!["-200".to_string()],
+            admin_ids: admin_ids.into_iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_authorize() {
+        let switch = BotCommand::Switch(None);
+
+        // No admins: read-only commands from any allowed chat, switch from nowhere
+        let config = telegram(vec![]);
+        assert_eq!(authorize(&config, "-100", None, &BotCommand::Status), Authorization::Allowed);
+        assert_eq!(authorize(&config, "-200", None, &BotCommand::Status), Authorization::Allowed);
+        assert_eq!(authorize(&config, "-300", None, &BotCommand::Status), Authorization::UnknownChat);
+        assert_eq!(authorize(&config, "-100", None, &switch), Authorization::NoAdmins);
+        assert_eq!(authorize(&config, "-200", None, &switch), Authorization::NoAdmins);
+        assert_eq!(authorize(&config, "-300", None, &switch), Authorization::UnknownChat);
+
+        // Admins listed: switch needs a matching user or chat ID
+        let config = telegram(vec!["42"]);
+        assert_eq!(authorize(&config, "-100", Some("7"), &switch), Authorization::NotAdmin);
+        assert_eq!(authorize(&config, "-200", Some("42"), &switch), Authorization::Allowed);
+        assert_eq!(authorize(&config, "-300", Some("42"), &switch), Authorization::UnknownChat);
+    }
+
+    #[test]
+    fn test_resolve_validator() {
+        let single = vec![validator("IdA111", "VoteA111")];
+        assert_eq!(resolve_validator(None, &single), Ok(0));
+
+        let pair = vec![validator("IdA111", "VoteA111"), validator("IdB222", "VoteB222")];
+        assert!(resolve_validator(None, &pair).is_err());
+        assert_eq!(resolve_validator(Some("2"), &pair), Ok(1));
+        assert!(resolve_validator(Some("3"), &pair).is_err());
+        assert_eq!(resolve_validator(Some("IdB"), &pair), Ok(1));
+        assert_eq!(resolve_validator(Some("VoteA"), &pair), Ok(0));
+        assert!(resolve_validator(Some("Id"), &pair).is_err());
+        assert!(resolve_validator(Some("Nope"), &pair).is_err());
+    }
+}
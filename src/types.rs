@@ -1,7 +1,16 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
 // Default functions for serde
+fn default_ssh_port() -> u16 {
+    22
+}
+
+fn default_sudo_user() -> String {
+    "root".to_string()
+}
+
 fn default_enabled() -> bool {
     true
 }
@@ -18,12 +27,489 @@ fn default_rpc_failure_threshold() -> u64 {
     1800 // 30 minutes of RPC failures before alert
 }
 
+fn default_heartbeat_interval() -> u64 {
+    60 // Ping the heartbeat URL every minute
+}
+
+fn default_health_listen() -> String {
+    "127.0.0.1:9877".to_string() // Loopback only unless configured otherwise
+}
+
+fn default_health_max_age() -> u64 {
+    60 // Seconds without a monitoring pass or RPC success before /healthz fails
+}
+
+fn default_influxdb_interval() -> u64 {
+    10 // Seconds between InfluxDB writes
+}
+
+fn default_log_file_path() -> String {
+    "~/.solana-validator-switch/svs.log".to_string()
+}
+
+fn default_log_file_max_size() -> u64 {
+    10 // MB before the log file is rotated
+}
+
+fn default_log_file_max_files() -> usize {
+    5 // Rotated log files kept
+}
+
+fn default_syslog_facility() -> String {
+    "daemon".to_string()
+}
+
+fn default_syslog_app_name() -> String {
+    "svs".to_string()
+}
+
+fn default_statsd_host() -> String {
+    "127.0.0.1".to_string() // Local statsd or Datadog agent
+}
+
+fn default_statsd_port() -> u16 {
+    8125
+}
+
+fn default_statsd_prefix() -> String {
+    "svs".to_string()
+}
+
+fn default_statsd_interval() -> u64 {
+    10 // Seconds between gauge updates
+}
+
+fn default_ssh_connect_timeout() -> u64 {
+    10 // seconds
+}
+
+fn default_ssh_health_check_timeout() -> u64 {
+    10 // seconds
+}
+
+fn default_ssh_catchup_timeout() -> u64 {
+    10 // seconds
+}
+
+fn default_vote_subscription_min_interval() -> u64 {
+    1000 // Milliseconds between vote refreshes triggered by the subscription
+}
+
+fn default_rpc_requests_per_second() -> f64 {
+    10.0 // Across all validators monitored by this instance
+}
+
+fn default_rpc_max_backoff() -> u64 {
+    60 // seconds
+}
+
+fn default_digest_time() -> String {
+    "09:00".to_string() // UTC
+}
+
+fn default_rewards_weekday() -> String {
+    "Mon".to_string()
+}
+
+fn default_skip_rate_threshold() -> f64 {
+    10.0 // percent of leader slots skipped
+}
+
+fn default_skip_rate_min_leader_slots() -> u64 {
+    20
+}
+
+fn default_min_vote_efficiency() -> f64 {
+    90.0 // percent of the cluster's best credits this epoch
+}
+
+fn default_max_snapshot_age() -> u64 {
+    240 // minutes; full snapshots are made about every 3 hours by default
+}
+
+fn default_min_free_disk() -> u8 {
+    10 // percent of the filesystem
+}
+
+fn default_max_cpu_percent() -> f64 {
+    90.0
+}
+
+fn default_max_memory_percent() -> f64 {
+    90.0
+}
+
+fn default_max_swap_percent() -> f64 {
+    20.0 // a validator that swaps falls behind quickly
+}
+
+fn default_sustained_seconds() -> u64 {
+    300 // short spikes during startup or snapshot creation are normal
+}
+
+fn default_min_identity_balance() -> f64 {
+    1.0 // SOL; voting costs about 1.1 SOL a day
+}
+
+fn default_max_clock_offset_ms() -> f64 {
+    100.0 // chrony normally keeps a node within a few milliseconds
+}
+
+fn default_log_pattern_cooldown() -> u64 {
+    30 // minutes; a failing validator repeats the same error every few seconds
+}
+
+fn default_max_block_age() -> u64 {
+    20 // seconds; the confirmed tip is normally 1-2s old
+}
+
+fn default_max_delinquent_stake() -> f64 {
+    10.0 // percent of activated stake
+}
+
+fn default_vote_resumption_timeout() -> u64 {
+    60 // Seconds to wait for the first vote after a switch
+}
+
+fn default_active_identity_timeout() -> u64 {
+    10 // Seconds for set-identity on the active node
+}
+
+fn default_tower_transfer_timeout() -> u64 {
+    10 // Seconds to copy the tower file to the standby node
+}
+
+fn default_standby_identity_timeout() -> u64 {
+    30 // Seconds for set-identity on the standby node
+}
+
+fn default_tower_backup_retention() -> usize {
+    5 // Tower backups kept on the standby node, 0 disables backups
+}
+
+fn default_clock_skew_warning() -> u64 {
+    500 // Milliseconds of clock difference that earn a warning before a switch
+}
+
+fn default_max_clock_skew() -> u64 {
+    2000 // Milliseconds between the nodes' clocks that stop a switch, 0 only warns
+}
+
+fn default_max_standby_slots_behind() -> u64 {
+    10 // Slots the standby may trail the cluster before a switch is refused
+}
+
+fn default_min_leader_gap_slots() -> u64 {
+    50 // Leader-free slots required ahead before a --wait-for-window switch starts
+}
+
+fn default_confirmation_phrase() -> String {
+    "SWITCH".to_string() // Typed to confirm a switch when typed_confirmation is on
+}
+
+fn default_sfdp_windows() -> Vec<u64> {
+    vec![7, 30] // Days
+}
+
+fn default_sfdp_format() -> String {
+    "csv".to_string()
+}
+
+fn default_sfdp_interval() -> u64 {
+    24 // Hours between report files
+}
+
+fn default_catchup_interval() -> u64 {
+    10
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub version: String,
     pub validators: Vec<ValidatorPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alert_config: Option<AlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sfdp_report: Option<SfdpReportConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch: Option<SwitchConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_endpoint: Option<HealthEndpointConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub influxdb: Option<InfluxDbConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statsd: Option<StatsdConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<LogFileConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syslog: Option<SyslogConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssh: Option<SshConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vote_subscription: Option<VoteSubscriptionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_rate_limit: Option<RpcRateLimitConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc_headers: Vec<RpcHeadersConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_rpc: Option<LocalRpcConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catchup: Option<CatchupConfig>,
+}
+
+/// How the status UI measures whether each node has caught up
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatchupConfig {
+    #[serde(default)]
+    pub method: CatchupMethod,
+    #[serde(default = "default_catchup_interval")]
+    pub interval_seconds: u64, // Between slot comparisons with `method: rpc`
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CatchupMethod {
+    /// `solana catchup --our-localhost` or `fdctl status` streamed over SSH
+    #[default]
+    Stream,
+    /// The node's own getSlot, read over SSH, compared with the validator's RPC
+    Rpc,
+}
+
+/// Querying a node's own RPC port over SSH when the external endpoints fail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalRpcConfig {
+    #[serde(default = "default_enabled")]
+    pub fallback: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>, // Detected from the running validator when unset
+}
+
+/// Extra HTTP headers, e.g. an API key, sent to every RPC endpoint whose URL
+/// starts with `url`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcHeadersConfig {
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Shared request budget for every RPC call this instance makes, and the
+/// longest pause after an endpoint answers 429 Too Many Requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRateLimitConfig {
+    #[serde(default = "default_rpc_requests_per_second")]
+    pub requests_per_second: f64,
+    #[serde(default = "default_rpc_max_backoff")]
+    pub max_backoff_seconds: u64,
+}
+
+impl Default for RpcRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: default_rpc_requests_per_second(),
+            max_backoff_seconds: default_rpc_max_backoff(),
+        }
+    }
+}
+
+/// WebSocket subscription to each vote account that refreshes vote data as
+/// soon as it changes; the 5s HTTP poll keeps running as the fallback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteSubscriptionConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_vote_subscription_min_interval")]
+    pub min_interval_ms: u64,
+}
+
+/// SOCKS5 or HTTP proxy that SSH connections and RPC requests go through
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    pub url: String, // socks5://[user:password@]host[:port] or http://...
+    #[serde(default = "default_enabled")]
+    pub ssh: bool,
+    #[serde(default = "default_enabled")]
+    pub rpc: bool,
+}
+
+impl Config {
+    /// Split `user@host:port` node addresses and give nodes without a user
+    /// the global `ssh.user`
+    pub fn normalize_nodes(&mut self) -> anyhow::Result<()> {
+        let default_user = self.ssh.as_ref().and_then(|ssh| ssh.user.clone());
+        for node in self.validators.iter_mut().flat_map(|v| v.nodes.iter_mut()) {
+            node.apply_host_address()?;
+            if node.user.is_empty() {
+                if let Some(user) = &default_user {
+                    node.user = user.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// SSH defaults and command timeouts. Set-identity and tower transfer
+/// have their own timeouts under `switch`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>, // Used by nodes that don't set their own user
+    #[serde(default = "default_ssh_connect_timeout")]
+    pub connect_timeout_seconds: u64,
+    #[serde(default = "default_ssh_health_check_timeout")]
+    pub health_check_timeout_seconds: u64,
+    #[serde(default = "default_ssh_catchup_timeout")]
+    pub catchup_timeout_seconds: u64,
+}
+
+impl Default for SshConfig {
+    fn default() -> Self {
+        Self {
+            user: None,
+            connect_timeout_seconds: default_ssh_connect_timeout(),
+            health_check_timeout_seconds: default_ssh_health_check_timeout(),
+            catchup_timeout_seconds: default_ssh_catchup_timeout(),
+        }
+    }
+}
+
+/// Tuning for the switch procedure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchConfig {
+    #[serde(default = "default_vote_resumption_timeout")]
+    pub vote_resumption_timeout_seconds: u64,
+    #[serde(default = "default_active_identity_timeout")]
+    pub active_identity_timeout_seconds: u64,
+    #[serde(default = "default_tower_transfer_timeout")]
+    pub tower_transfer_timeout_seconds: u64,
+    #[serde(default = "default_standby_identity_timeout")]
+    pub standby_identity_timeout_seconds: u64,
+    #[serde(default = "default_tower_backup_retention")]
+    pub tower_backup_retention: usize,
+    #[serde(default)]
+    pub tower_compression: TowerCompression,
+    #[serde(default = "default_clock_skew_warning")]
+    pub clock_skew_warning_ms: u64,
+    #[serde(default = "default_max_clock_skew")]
+    pub max_clock_skew_ms: u64,
+    #[serde(default = "default_max_standby_slots_behind")]
+    pub max_standby_slots_behind: u64,
+    #[serde(default)]
+    pub typed_confirmation: bool,
+    #[serde(default = "default_confirmation_phrase")]
+    pub confirmation_phrase: String,
+    #[serde(default = "default_min_leader_gap_slots")]
+    pub min_leader_gap_slots: u64,
+}
+
+/// Compression applied to the tower file while it is copied to the standby
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TowerCompression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl TowerCompression {
+    /// Compressor run on both nodes, None when the file is sent as is
+    pub fn tool(&self) -> Option<&'static str> {
+        match self {
+            TowerCompression::None => None,
+            TowerCompression::Gzip => Some("gzip"),
+            TowerCompression::Zstd => Some("zstd -q"),
+        }
+    }
+}
+
+impl Default for SwitchConfig {
+    fn default() -> Self {
+        Self {
+            vote_resumption_timeout_seconds: default_vote_resumption_timeout(),
+            active_identity_timeout_seconds: default_active_identity_timeout(),
+            tower_transfer_timeout_seconds: default_tower_transfer_timeout(),
+            standby_identity_timeout_seconds: default_standby_identity_timeout(),
+            tower_backup_retention: default_tower_backup_retention(),
+            tower_compression: TowerCompression::None,
+            clock_skew_warning_ms: default_clock_skew_warning(),
+            max_clock_skew_ms: default_max_clock_skew(),
+            max_standby_slots_behind: default_max_standby_slots_behind(),
+            typed_confirmation: false,
+            confirmation_phrase: default_confirmation_phrase(),
+            min_leader_gap_slots: default_min_leader_gap_slots(),
+        }
+    }
+}
+
+/// HTTP liveness endpoint served while the monitor runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthEndpointConfig {
+    #[serde(default = "default_health_listen")]
+    pub listen: String, // host:port
+    #[serde(default = "default_health_max_age")]
+    pub max_age_seconds: u64,
+}
+
+/// InfluxDB endpoint receiving node and SSH pool metrics in line protocol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfluxDbConfig {
+    pub write_url: String, // Full write URL, e.g. .../api/v2/write?org=o&bucket=b&precision=s
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default = "default_influxdb_interval")]
+    pub interval_seconds: u64,
+}
+
+/// Structured JSON lines file receiving every monitor log message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFileConfig {
+    #[serde(default = "default_log_file_path")]
+    pub path: String,
+    #[serde(default = "default_log_file_max_size")]
+    pub max_size_mb: u64,
+    #[serde(default = "default_log_file_max_files")]
+    pub max_files: usize,
+}
+
+/// Syslog receiving log messages and switch events in RFC5424 format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyslogConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>, // Remote collector as host:port over UDP; local /dev/log if unset
+    #[serde(default = "default_syslog_facility")]
+    pub facility: String,
+    #[serde(default = "default_syslog_app_name")]
+    pub app_name: String,
+}
+
+/// statsd or dogstatsd agent receiving key counters over UDP
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsdConfig {
+    #[serde(default = "default_statsd_host")]
+    pub host: String,
+    #[serde(default = "default_statsd_port")]
+    pub port: u16,
+    #[serde(default = "default_statsd_prefix")]
+    pub prefix: String,
+    #[serde(default)]
+    pub dogstatsd: bool, // Send tags in dogstatsd format instead of name segments
+    #[serde(default = "default_statsd_interval")]
+    pub interval_seconds: u64,
+}
+
+/// Delegation program report files written periodically by the monitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SfdpReportConfig {
+    #[serde(default = "default_sfdp_windows")]
+    pub window_days: Vec<u64>,
+    #[serde(default = "default_sfdp_format")]
+    pub format: String, // csv or json
+    #[serde(default = "default_sfdp_interval")]
+    pub interval_hours: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,12 +526,238 @@ pub struct AlertConfig {
     pub telegram: Option<TelegramConfig>,
     #[serde(default)]
     pub auto_failover_enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_failover_quorum: Option<usize>, // RPC endpoints that must confirm no votes; majority when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat: Option<HeartbeatConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grafana: Option<GrafanaConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_digest: Option<DailyDigestConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewards_summary: Option<RewardsSummaryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_rate: Option<SkipRateAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vote_credits: Option<VoteCreditsAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_health: Option<ClusterHealthConfig>, // Defaults apply when unset
+    #[serde(default)]
+    pub version_lag: bool, // Alert when a node runs below the cluster's supermajority version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_space: Option<DiskSpaceAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_age: Option<SnapshotAgeAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_metrics: Option<SystemMetricsAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_sync: Option<TimeSyncAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_balance: Option<IdentityBalanceAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vote_account_balance: Option<VoteAccountBalanceAlertConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_patterns: Option<LogPatternAlertConfig>,
+    #[serde(default)]
+    pub switch_progress: bool, // Post each switch step to Telegram as it happens
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            delinquency_threshold_seconds: default_delinquency_threshold(),
+            ssh_failure_threshold_seconds: default_ssh_failure_threshold(),
+            rpc_failure_threshold_seconds: default_rpc_failure_threshold(),
+            telegram: None,
+            auto_failover_enabled: false,
+            auto_failover_quorum: None,
+            heartbeat: None,
+            grafana: None,
+            daily_digest: None,
+            rewards_summary: None,
+            skip_rate: None,
+            vote_credits: None,
+            cluster_health: None,
+            version_lag: false,
+            disk_space: None,
+            snapshot_age: None,
+            system_metrics: None,
+            time_sync: None,
+            identity_balance: None,
+            vote_account_balance: None,
+            log_patterns: None,
+            switch_progress: false,
+        }
+    }
+}
+
+/// Scheduled health summary sent to Telegram even when nothing alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyDigestConfig {
+    #[serde(default = "default_digest_time")]
+    pub time_utc: String, // HH:MM
+}
+
+/// Weekly summary of vote account rewards and commission earnings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsSummaryConfig {
+    #[serde(default = "default_rewards_weekday")]
+    pub weekday: String, // Mon..Sun
+    #[serde(default = "default_digest_time")]
+    pub time_utc: String, // HH:MM
+}
+
+/// Alert when the validator skips too many of its leader slots this epoch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipRateAlertConfig {
+    #[serde(default = "default_skip_rate_threshold")]
+    pub threshold_percent: f64,
+    #[serde(default = "default_skip_rate_min_leader_slots")]
+    pub min_leader_slots: u64, // Early in the epoch a few skips would swing the rate
+}
+
+/// Alert when the validator earns too few vote credits compared to the best
+/// performing vote account this epoch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteCreditsAlertConfig {
+    #[serde(default = "default_min_vote_efficiency")]
+    pub min_efficiency_percent: f64,
+}
+
+/// Alert when the ledger or accounts filesystem of a node runs low on space
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskSpaceAlertConfig {
+    #[serde(default = "default_min_free_disk")]
+    pub min_free_percent: u8,
+}
+
+/// Alert when the standby's newest snapshot is too old to boot from quickly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotAgeAlertConfig {
+    #[serde(default = "default_max_snapshot_age")]
+    pub max_age_minutes: u64,
+}
+
+/// Alert when a node's CPU, memory or swap usage stays above its limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemMetricsAlertConfig {
+    #[serde(default = "default_max_cpu_percent")]
+    pub max_cpu_percent: f64,
+    #[serde(default = "default_max_memory_percent")]
+    pub max_memory_percent: f64,
+    #[serde(default = "default_max_swap_percent")]
+    pub max_swap_percent: f64,
+    #[serde(default = "default_sustained_seconds")]
+    pub sustained_seconds: u64, // How long a limit must be exceeded before alerting
+}
+
+impl Default for SystemMetricsAlertConfig {
+    fn default() -> Self {
+        Self {
+            max_cpu_percent: default_max_cpu_percent(),
+            max_memory_percent: default_max_memory_percent(),
+            max_swap_percent: default_max_swap_percent(),
+            sustained_seconds: default_sustained_seconds(),
+        }
+    }
+}
+
+/// Alert when the funded identity runs low on the SOL that pays vote fees
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityBalanceAlertConfig {
+    #[serde(default = "default_min_identity_balance")]
+    pub min_sol: f64,
+}
+
+impl Default for IdentityBalanceAlertConfig {
+    fn default() -> Self {
+        Self {
+            min_sol: default_min_identity_balance(),
+        }
+    }
+}
+
+/// Alert when the vote account nears its rent exemption minimum or is drained
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VoteAccountBalanceAlertConfig {
+    #[serde(default)]
+    pub min_sol_above_rent: f64, // 0 alerts only once below the minimum
+    #[serde(default)]
+    pub max_drop_sol: f64, // Largest drop below the last balance not alerted; 0 alerts every withdrawal
+}
+
+/// Alert when a node's clock is not synchronized or drifts too far
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeSyncAlertConfig {
+    #[serde(default = "default_max_clock_offset_ms")]
+    pub max_offset_ms: f64,
+}
+
+impl Default for TimeSyncAlertConfig {
+    fn default() -> Self {
+        Self {
+            max_offset_ms: default_max_clock_offset_ms(),
+        }
+    }
+}
+
+/// Alert when a line of a node's validator log matches one of the regex
+/// patterns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogPatternAlertConfig {
+    pub patterns: Vec<String>,
+    #[serde(default = "default_log_pattern_cooldown")]
+    pub cooldown_minutes: u64, // Per node and pattern
+}
+
+/// When the cluster itself counts as halted or degraded. A delinquent
+/// validator is then reported as a cluster problem and does not fail over.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterHealthConfig {
+    #[serde(default = "default_max_block_age")]
+    pub max_block_age_seconds: u64, // Halted when the newest confirmed block is older
+    #[serde(default = "default_max_delinquent_stake")]
+    pub max_delinquent_stake_percent: f64,
+}
+
+impl Default for ClusterHealthConfig {
+    fn default() -> Self {
+        Self {
+            max_block_age_seconds: default_max_block_age(),
+            max_delinquent_stake_percent: default_max_delinquent_stake(),
+        }
+    }
+}
+
+/// Dead-man's switch: svs pings this URL periodically so an external
+/// service (e.g. healthchecks.io) can page when the pings stop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeartbeatConfig {
+    pub url: String,
+    #[serde(default = "default_heartbeat_interval")]
+    pub interval_seconds: u64,
+}
+
+/// Grafana instance that receives switch and failover annotations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrafanaConfig {
+    pub url: String,
+    pub api_token: String, // Service account token with annotation write access
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard_uid: Option<String>, // Pin annotations to one dashboard instead of the org
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TelegramConfig {
     pub bot_token: String,
     pub chat_id: String,
+    #[serde(default)]
+    pub commands_enabled: bool, // Answer bot commands from allowed chats; off unless opted in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_chat_ids: Vec<String>, // Extra chats allowed to issue commands besides chat_id
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub admin_ids: Vec<String>, // User or chat IDs allowed to run /switch and /mute; empty = nobody
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,21 +767,197 @@ pub struct ValidatorPair {
     #[serde(rename = "identityPubkey")]
     pub identity_pubkey: String,
     pub rpc: String,
+    #[serde(rename = "additionalRpcs", default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_rpcs: Vec<String>,
+    #[serde(rename = "wsUrl", default, skip_serializing_if = "Option::is_none")]
+    pub ws_url: Option<String>, // WebSocket endpoint; derived from rpc when unset
+    #[serde(rename = "referenceRpc", default, skip_serializing_if = "Option::is_none")]
+    pub reference_rpc: Option<String>, // Only compared against for staleness, never queried for vote data
     pub nodes: Vec<NodeConfig>,
 }
 
+impl ValidatorPair {
+    /// All configured RPC endpoints, primary first
+    pub fn rpc_endpoints(&self) -> Vec<String> {
+        let mut endpoints = vec![self.rpc.clone()];
+        for rpc in &self.additional_rpcs {
+            if !endpoints.contains(rpc) {
+                endpoints.push(rpc.clone());
+            }
+        }
+        endpoints
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeConfig {
     pub label: String,
-    pub host: String,
+    pub host: String, // Also accepts user@host:port
+    #[serde(default = "default_ssh_port")]
     pub port: u16,
+    #[serde(default)]
     pub user: String,
     pub paths: NodePaths,
     #[serde(rename = "sshKeyPath", skip_serializing_if = "Option::is_none")]
     pub ssh_key_path: Option<String>,
+    #[serde(rename = "passwordAuth", default, skip_serializing_if = "std::ops::Not::not")]
+    pub password_auth: bool, // Prompt for a password when no SSH key works
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sudo: Option<SudoConfig>, // Run privileged checks through sudo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commands: Option<NodeCommands>, // Replace the commands svs runs on this node
+}
+
+/// Commands that replace the invocations svs builds for a node, for wrapper
+/// scripts, containers and non-standard installs. catchup, identity and
+/// version run under `timeout`, so wrap pipelines in `bash -c '...'`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NodeCommands {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catchup: Option<String>, // Prints `solana catchup --our-localhost` output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>, // Prints the identity pubkey the validator runs with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_identity: Option<String>, // {keypair} and {require_tower} are filled in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>, // Prints `agave-validator --version` or `fdctl --version` output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs: Option<String>, // Follows the validator log, e.g. `journalctl -u sol -f -n 200 -o cat`
 }
 
+/// How privileged commands on a node are run when the SSH user can't
+/// access the validator's files itself
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SudoConfig {
+    #[serde(default = "default_sudo_user")]
+    pub user: String, // Target user, e.g. the validator's service user
+    #[serde(default)]
+    pub askpass: bool, // Prompt for the sudo password at startup instead of requiring NOPASSWD
+}
+
+impl NodeConfig {
+    fn command_override(&self, pick: fn(&NodeCommands) -> &Option<String>) -> Option<&str> {
+        self.commands
+            .as_ref()
+            .and_then(|commands| pick(commands).as_deref())
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+    }
+
+    /// `<solana_cli> catchup --our-localhost`, or the configured catchup command
+    pub fn catchup_invocation(&self, solana_cli: &str) -> String {
+        self.command_override(|c| &c.catchup)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} catchup --our-localhost", solana_cli))
+    }
+
+    /// Whether a catchup command is configured, so no solana CLI is needed
+    pub fn has_catchup_command(&self) -> bool {
+        self.command_override(|c| &c.catchup).is_some()
+    }
+
+    /// Configured command printing the validator version
+    pub fn version_command(&self) -> Option<&str> {
+        self.command_override(|c| &c.version)
+    }
+
+    /// `<executable> --version`, or the configured version command
+    pub fn version_invocation(&self, executable: &str) -> String {
+        self.version_command()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} --version", executable))
+    }
+
+    /// Configured command following the validator log
+    pub fn logs_command(&self) -> Option<&str> {
+        self.command_override(|c| &c.logs)
+    }
+
+    /// Configured command printing the node's current identity
+    pub fn identity_command(&self) -> Option<&str> {
+        self.command_override(|c| &c.identity)
+    }
+
+    /// Configured set-identity command switching to `keypair`; the standby
+    /// step passes `require_tower`
+    pub fn set_identity_command(&self, keypair: &str, require_tower: bool) -> Option<String> {
+        self.command_override(|c| &c.set_identity).map(|command| {
+            command
+                .replace("{keypair}", keypair)
+                .replace("{require_tower}", if require_tower { "--require-tower" } else { "" })
+        })
+    }
+
+    /// Move a user or port given in `host` (`user@host:port`) into their own
+    /// fields; parts written in the host take precedence
+    pub fn apply_host_address(&mut self) -> anyhow::Result<()> {
+        let (user, host, port) = parse_ssh_address(&self.host)
+            .map_err(|e| anyhow::anyhow!("Node {}: {}", self.label, e))?;
+        self.host = host;
+        if let Some(user) = user {
+            self.user = user;
+        }
+        if let Some(port) = port {
+            self.port = port;
+        }
+        Ok(())
+    }
+}
+
+/// Split `[user@]host[:port]`. An IPv6 address needs brackets when a port is
+/// given (`[2001:db8::1]:2222`); without a port it may be written bare.
+pub fn parse_ssh_address(address: &str) -> anyhow::Result<(Option<String>, String, Option<u16>)> {
+    let address = address.trim();
+    let (user, rest) = match address.rsplit_once('@') {
+        Some((user, rest)) if !user.is_empty() => (Some(user.to_string()), rest),
+        Some(_) => anyhow::bail!("empty user in host '{}'", address),
+        None => (None, address),
+    };
+
+    let parse_port = |port: &str| {
+        port.parse::<u16>()
+            .ok()
+            .filter(|port| *port > 0)
+            .ok_or_else(|| anyhow::anyhow!("invalid SSH port '{}' in host '{}'", port, address))
+    };
+
+    let (host, port) = if let Some(bracketed) = rest.strip_prefix('[') {
+        match bracketed.split_once(']') {
+            Some((host, "")) => (host.to_string(), None),
+            Some((host, port)) => match port.strip_prefix(':') {
+                Some(port) => (host.to_string(), Some(parse_port(port)?)),
+                None => anyhow::bail!("unexpected '{}' after ']' in host '{}'", port, address),
+            },
+            None => anyhow::bail!("missing ']' in host '{}'", address),
+        }
+    } else if rest.matches(':').count() == 1 {
+        let (host, port) = rest.split_once(':').unwrap_or((rest, ""));
+        (host.to_string(), Some(parse_port(port)?))
+    } else {
+        (rest.to_string(), None)
+    };
+
+    if host.is_empty() {
+        anyhow::bail!("empty host in '{}'", address);
+    }
+    Ok((user, host, port))
+}
+
+/// The identity pubkey printed by a node's identity command: the last
+/// non-empty line, when it looks like a base58 public key
+pub fn parse_identity_output(output: &str) -> Option<String> {
+    let line = output.lines().map(str::trim).filter(|line| !line.is_empty()).last()?;
+    let is_base58 = line
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'));
+    if (32..=44).contains(&line.len()) && is_base58 {
+        Some(line.to_string())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NodePaths {
     #[serde(rename = "fundedIdentity")]
     pub funded_identity: String,
@@ -77,6 +965,40 @@ pub struct NodePaths {
     pub unfunded_identity: String,
     #[serde(rename = "voteKeypair")]
     pub vote_keypair: String,
+    // Declared paths below replace process and disk detection
+    #[serde(rename = "solanaCli", default, skip_serializing_if = "Option::is_none")]
+    pub solana_cli: Option<String>,
+    #[serde(rename = "agaveValidator", default, skip_serializing_if = "Option::is_none")]
+    pub agave_validator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fdctl: Option<String>,
+    #[serde(rename = "fdctlConfig", default, skip_serializing_if = "Option::is_none")]
+    pub fdctl_config: Option<String>, // config.toml fdctl runs with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger: Option<String>,
+}
+
+impl NodePaths {
+    /// Whether the validator executable is declared, so svs doesn't look for
+    /// it in the process list or on disk
+    pub fn declares_validator(&self) -> bool {
+        self.fdctl.is_some() || self.agave_validator.is_some()
+    }
+
+    /// The declared solana CLI, else the one next to the declared validator
+    pub fn declared_solana_cli(&self) -> Option<String> {
+        if let Some(cli) = &self.solana_cli {
+            return Some(cli.clone());
+        }
+        if let Some(fdctl) = &self.fdctl {
+            return std::path::Path::new(fdctl)
+                .parent()
+                .map(|dir| dir.join("solana").to_string_lossy().to_string());
+        }
+        self.agave_validator
+            .as_ref()
+            .map(|agave| agave.replace("agave-validator", "solana"))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -112,6 +1034,19 @@ pub struct NodeWithStatus {
     pub ssh_key_path: Option<String>,     // Detected SSH key path for this node
 }
 
+impl NodeWithStatus {
+    /// Swap readiness naming the first failed check instead of a bare "Not Ready"
+    pub fn swap_readiness_label(&self) -> String {
+        match (self.swap_ready, self.swap_issues.as_slice()) {
+            (Some(true), _) => "✅ Ready".to_string(),
+            (Some(false), []) => "❌ Not Ready".to_string(),
+            (Some(false), [issue]) => format!("❌ {}", issue),
+            (Some(false), [issue, rest @ ..]) => format!("❌ {} (+{} more)", issue, rest.len()),
+            (None, _) => "❓ Unknown".to_string(),
+        }
+    }
+}
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct ValidationResult {
@@ -165,6 +1100,12 @@ impl FailureTracker {
         self.last_error = Some(error);
     }
 
+    /// Continue a failure streak that began `age` ago, e.g. before a restart
+    pub fn resume_failure(&mut self, age: std::time::Duration) {
+        self.consecutive_failures = self.consecutive_failures.max(1);
+        self.first_failure_time = Some(Instant::now().checked_sub(age).unwrap_or_else(Instant::now));
+    }
+
     pub fn seconds_since_first_failure(&self) -> Option<u64> {
         self.first_failure_time.map(|t| t.elapsed().as_secs())
     }
@@ -186,3 +1127,127 @@ pub struct NodeHealthStatus {
     #[allow(dead_code)]
     pub last_vote_time: Option<Instant>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ssh_address() {
+        assert_eq!(parse_ssh_address("10.0.0.1").unwrap(), (None, "10.0.0.1".to_string(), None));
+        assert_eq!(
+            parse_ssh_address("solana@10.0.0.1:2222").unwrap(),
+            (Some("solana".to_string()), "10.0.0.1".to_string(), Some(2222))
+        );
+        assert_eq!(
+            parse_ssh_address("node-a.example.com:2200").unwrap(),
+            (None, "node-a.example.com".to_string(), Some(2200))
+        );
+        assert_eq!(parse_ssh_address("2001:db8::1").unwrap(), (None, "2001:db8::1".to_string(), None));
+        assert_eq!(
+            parse_ssh_address("admin@[2001:db8::1]:2222").unwrap(),
+            (Some("admin".to_string()), "2001:db8::1".to_string(), Some(2222))
+        );
+
+        assert!(parse_ssh_address("10.0.0.1:ssh").is_err());
+        assert!(parse_ssh_address("10.0.0.1:0").is_err());
+        assert!(parse_ssh_address("@10.0.0.1").is_err());
+        assert!(parse_ssh_address("[2001:db8::1").is_err());
+        assert!(parse_ssh_address("solana@:22").is_err());
+    }
+
+    #[test]
+    fn test_normalize_nodes_default_user() {
+        let mut config: Config = serde_yaml::from_str(
+            r#"
+version: "1.0.0"
+ssh:
+  user: solana
+validators:
+  - votePubkey: Vote111
+    identityPubkey: Id111
+    rpc: https://api.mainnet-beta.solana.com
+    nodes:
+      - label: hetzner
+        host: 10.0.0.1
+        user: root
+        paths: { fundedIdentity: /f.json, unfundedIdentity: /u.json, voteKeypair: /v.json }
+      - label: latitude
+        host: 10.0.0.2
+        paths: { fundedIdentity: /f.json, unfundedIdentity: /u.json, voteKeypair: /v.json }
+      - label: ovh
+        host: ubuntu@10.0.0.3:2222
+        paths: { fundedIdentity: /f.json, unfundedIdentity: /u.json, voteKeypair: /v.json }
+"#,
+        )
+        .unwrap();
+        config.normalize_nodes().unwrap();
+
+        let users: Vec<&str> = config.validators[0].nodes.iter().map(|n| n.user.as_str()).collect();
+        assert_eq!(users, ["root", "solana", "ubuntu"]);
+        assert_eq!(config.validators[0].nodes[2].port, 2222);
+    }
+
+    #[test]
+    fn test_node_command_overrides() {
+        let node: NodeConfig = serde_yaml::from_str(
+            r#"
+label: docker
+host: 10.0.0.4
+paths: { fundedIdentity: /f.json, unfundedIdentity: /u.json, voteKeypair: /v.json }
+commands:
+  catchup: docker exec agave solana catchup --our-localhost
+  identity: docker exec agave solana address
+  set_identity: docker exec agave agave-validator -l /ledger set-identity {require_tower} {keypair}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            node.catchup_invocation("/usr/bin/solana"),
+            "docker exec agave solana catchup --our-localhost"
+        );
+        assert_eq!(node.version_invocation("/usr/bin/agave-validator"), "/usr/bin/agave-validator --version");
+        assert_eq!(node.identity_command(), Some("docker exec agave solana address"));
+        assert_eq!(
+            node.set_identity_command("/f.json", true).unwrap(),
+            "docker exec agave agave-validator -l /ledger set-identity --require-tower /f.json"
+        );
+
+        let mut plain = node.clone();
+        plain.commands = None;
+        assert_eq!(plain.catchup_invocation("solana"), "solana catchup --our-localhost");
+        assert!(plain.set_identity_command("/f.json", false).is_none());
+    }
+
+    #[test]
+    fn test_declared_executables() {
+        let mut paths = NodePaths::default();
+        assert!(!paths.declares_validator());
+        assert_eq!(paths.declared_solana_cli(), None);
+
+        paths.fdctl = Some("/opt/firedancer/build/native/gcc/bin/fdctl".to_string());
+        assert!(paths.declares_validator());
+        assert_eq!(
+            paths.declared_solana_cli().as_deref(),
+            Some("/opt/firedancer/build/native/gcc/bin/solana")
+        );
+
+        paths.fdctl = None;
+        paths.agave_validator = Some("/home/sol/bin/agave-validator".to_string());
+        assert_eq!(paths.declared_solana_cli().as_deref(), Some("/home/sol/bin/solana"));
+
+        paths.solana_cli = Some("/usr/local/bin/solana".to_string());
+        assert_eq!(paths.declared_solana_cli().as_deref(), Some("/usr/local/bin/solana"));
+    }
+
+    #[test]
+    fn test_parse_identity_output() {
+        assert_eq!(
+            parse_identity_output("Identity: loading\nDvAmv4LtjpXBprtVDwJBeMmnhnxq8dz6kJTmvvyCz1Uh\n"),
+            Some("DvAmv4LtjpXBprtVDwJBeMmnhnxq8dz6kJTmvvyCz1Uh".to_string())
+        );
+        assert_eq!(parse_identity_output("Error: connection refused"), None);
+        assert_eq!(parse_identity_output(""), None);
+    }
+}
@@ -51,7 +51,7 @@ pub async fn fetch_validator_metadata(
     rpc_url: &str,
     validator_identity: &str,
 ) -> Result<Option<ValidatorMetadata>> {
-    let client = reqwest::Client::new();
+    let client = crate::proxy::http_client_builder().build()?;
 
     // Prepare the RPC request
     let payload = RpcRequest {
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::demotion_check::fetch_local_identity;
+use crate::ssh::AsyncSshPool;
+use crate::types::{NodeConfig, ValidatorPair};
+
+/// Pause between checks while waiting for the first vote
+const RESUMPTION_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Outcome of a single check of the new active node
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResumptionCheck {
+    /// A vote for a slot after the switch landed while the node runs the funded identity
+    Resumed(u64),
+    /// No proof of voting yet
+    Waiting(String),
+}
+
+/// Decide whether voting resumed. Only votes for slots after `baseline_slot`
+/// count, so in-flight votes from the former active node are ignored.
+pub fn evaluate_resumption(
+    funded_identity: &str,
+    local_identity: Option<&str>,
+    last_vote_slot: Option<u64>,
+    baseline_slot: u64,
+) -> ResumptionCheck {
+    match local_identity {
+        Some(identity) if identity != funded_identity => {
+            return ResumptionCheck::Waiting(format!(
+                "new active node reports identity {}",
+                identity
+            ))
+        }
+        None => {
+            return ResumptionCheck::Waiting(
+                "new active node did not report an identity".to_string(),
+            )
+        }
+        _ => {}
+    }
+
+    match last_vote_slot {
+        Some(slot) if slot > baseline_slot => ResumptionCheck::Resumed(slot),
+        Some(slot) => ResumptionCheck::Waiting(format!(
+            "last vote is for slot {}, none after slot {} yet",
+            slot, baseline_slot
+        )),
+        None => ResumptionCheck::Waiting("vote account could not be read".to_string()),
+    }
+}
+
+/// Poll the vote account and the new active node after a switch until a
+/// vote lands or `timeout` passes
+pub struct VoteResumptionVerifier {
+    pub new_active: NodeConfig,
+    pub validator_pair: ValidatorPair,
+    pub rpc_url: String,
+    pub ssh_pool: Arc<AsyncSshPool>,
+    pub ssh_key: String,
+    pub timeout: Duration,
+}
+
+impl VoteResumptionVerifier {
+    /// Slot and time to first vote on success, or the last reason for waiting
+    pub async fn run(&self, baseline_slot: u64) -> std::result::Result<(u64, Duration), String> {
+        let started = Instant::now();
+        let mut last_issue = "no check completed".to_string();
+
+        while started.elapsed() < self.timeout {
+            let local_identity =
+                fetch_local_identity(&self.ssh_pool, &self.new_active, &self.ssh_key)
+                    .await
+                    .ok();
+            let last_vote_slot = crate::solana_rpc::fetch_vote_account_data(
+                &self.rpc_url,
+                &self.validator_pair.vote_pubkey,
+            )
+            .await
+            .ok()
+            .and_then(|data| data.recent_votes.first().map(|vote| vote.slot));
+
+            match evaluate_resumption(
+                &self.validator_pair.identity_pubkey,
+                local_identity.as_deref(),
+                last_vote_slot,
+                baseline_slot,
+            ) {
+                ResumptionCheck::Resumed(slot) => return Ok((slot, started.elapsed())),
+                ResumptionCheck::Waiting(reason) => last_issue = reason,
+            }
+
+            tokio::time::sleep(RESUMPTION_CHECK_INTERVAL).await;
+        }
+
+        Err(last_issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FUNDED: &str = "FundedIdentity111";
+
+    #[test]
+    fn test_evaluate_resumption_resumed() {
+        let result = evaluate_resumption(FUNDED, Some(FUNDED), Some(1_001), 1_000);
+        assert_eq!(result, ResumptionCheck::Resumed(1_001));
+    }
+
+    #[test]
+    fn test_evaluate_resumption_ignores_votes_before_switch() {
+        let result = evaluate_resumption(FUNDED, Some(FUNDED), Some(1_000), 1_000);
+        assert!(matches!(result, ResumptionCheck::Waiting(_)));
+    }
+
+    #[test]
+    fn test_evaluate_resumption_requires_funded_identity() {
+        let wrong = evaluate_resumption(FUNDED, Some("Unfunded111"), Some(2_000), 1_000);
+        assert!(matches!(wrong, ResumptionCheck::Waiting(_)));
+
+        let unknown = evaluate_resumption(FUNDED, None, Some(2_000), 1_000);
+        assert!(matches!(unknown, ResumptionCheck::Waiting(_)));
+    }
+}
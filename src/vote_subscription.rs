@@ -0,0 +1,102 @@
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// Port solana-validator serves RPC on by default; PubSub is on the next one
+const DEFAULT_RPC_PORT: u16 = 8899;
+
+/// First delay before reconnecting a dropped subscription
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Longest delay between reconnect attempts
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(120);
+
+/// WebSocket URL for an RPC endpoint: `http` becomes `ws` and `https`
+/// becomes `wss`. An explicit default port 8899 moves to 8900, where
+/// solana-validator serves PubSub; hosted endpoints keep their port.
+pub fn websocket_url(rpc_url: &str) -> Option<String> {
+    let mut url = url::Url::parse(rpc_url).ok()?;
+    let scheme = match url.scheme() {
+        "http" => "ws",
+        "https" => "wss",
+        "ws" | "wss" => return Some(rpc_url.to_string()),
+        _ => return None,
+    };
+    url.set_scheme(scheme).ok()?;
+    if url.port() == Some(DEFAULT_RPC_PORT) {
+        url.set_port(Some(DEFAULT_RPC_PORT + 1)).ok()?;
+    }
+    Some(url.to_string())
+}
+
+/// Delay before reconnect attempt `attempt` (0-based), doubling up to the cap
+pub fn reconnect_delay(attempt: u32) -> Duration {
+    INITIAL_RECONNECT_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.min(16)))
+        .min(MAX_RECONNECT_DELAY)
+}
+
+/// Subscribe to `vote_pubkey` and wake `wakeup` on every account update
+/// until the connection drops. Notifications carry no data we use; the
+/// woken poll fetches the vote state over HTTP as usual.
+pub async fn watch_vote_account(ws_url: &str, vote_pubkey: &str, wakeup: &Notify) -> Result<()> {
+    let pubkey =
+        Pubkey::from_str(vote_pubkey).map_err(|e| anyhow!("Invalid vote pubkey: {}", e))?;
+    let client = PubsubClient::new(ws_url)
+        .await
+        .map_err(|e| anyhow!("Failed to connect to {}: {}", ws_url, e))?;
+    let (mut updates, unsubscribe) = client
+        .account_subscribe(
+            &pubkey,
+            Some(RpcAccountInfoConfig {
+                commitment: Some(CommitmentConfig::processed()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(|e| anyhow!("accountSubscribe failed: {}", e))?;
+
+    while updates.next().await.is_some() {
+        wakeup.notify_one();
+    }
+
+    unsubscribe().await;
+    Err(anyhow!("Subscription to {} closed", ws_url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_websocket_url() {
+        assert_eq!(
+            websocket_url("https://api.mainnet-beta.solana.com").as_deref(),
+            Some("wss://api.mainnet-beta.solana.com/")
+        );
+        assert_eq!(
+            websocket_url("http://10.0.0.5:8899").as_deref(),
+            Some("ws://10.0.0.5:8900/")
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com:443/key123").as_deref(),
+            Some("wss://rpc.example.com/key123")
+        );
+        assert_eq!(websocket_url("wss://rpc.example.com").as_deref(), Some("wss://rpc.example.com"));
+        assert_eq!(websocket_url("ftp://rpc.example.com"), None);
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        assert_eq!(reconnect_delay(0), Duration::from_secs(5));
+        assert_eq!(reconnect_delay(1), Duration::from_secs(10));
+        assert_eq!(reconnect_delay(3), Duration::from_secs(40));
+        assert_eq!(reconnect_delay(10), MAX_RECONNECT_DELAY);
+    }
+}
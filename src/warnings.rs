@@ -0,0 +1,191 @@
+use chrono::{DateTime, Utc};
+
+use crate::commands::status_ui_v2::NodePairHostChecks;
+use crate::types::NodeStatus;
+use crate::ValidatorStatus;
+
+/// Seconds without a tower write before the active node's tower counts as stale
+pub const STALE_TOWER_SECONDS: u64 = 120;
+
+/// Ledger disk usage at which a node is flagged as low on space
+pub const LOW_DISK_PERCENT: u8 = 90;
+
+/// An unresolved issue kept on screen until it clears or is dismissed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StickyWarning {
+    pub key: String,
+    pub message: String,
+    pub first_seen: DateTime<Utc>,
+    pub dismissed: bool,
+}
+
+/// Warnings shown in the status view panel, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct StickyWarnings {
+    entries: Vec<StickyWarning>,
+}
+
+impl StickyWarnings {
+    /// Match the list to the conditions currently present: new ones are
+    /// added, resolved ones dropped, and existing ones keep their first-seen
+    /// time and dismissal
+    pub fn sync(&mut self, active: Vec<(String, String)>) {
+        self.entries
+            .retain(|entry| active.iter().any(|(key, _)| key == &entry.key));
+
+        for (key, message) in active {
+            match self.entries.iter_mut().find(|entry| entry.key == key) {
+                Some(entry) => entry.message = message,
+                None => self.entries.push(StickyWarning {
+                    key,
+                    message,
+                    first_seen: Utc::now(),
+                    dismissed: false,
+                }),
+            }
+        }
+    }
+
+    pub fn visible(&self) -> impl Iterator<Item = &StickyWarning> {
+        self.entries.iter().filter(|entry| !entry.dismissed)
+    }
+
+    pub fn dismissed_count(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.dismissed).count()
+    }
+
+    /// Hide the oldest visible warning until its condition clears and returns
+    pub fn dismiss_oldest(&mut self) -> bool {
+        match self.entries.iter_mut().find(|entry| !entry.dismissed) {
+            Some(entry) => {
+                entry.dismissed = true;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Unresolved issues visible in the current monitoring state, as (key, message)
+pub fn collect_warnings(
+    statuses: &[ValidatorStatus],
+    host_checks: &[NodePairHostChecks],
+) -> Vec<(String, String)> {
+    let mut warnings = Vec::new();
+
+    for (idx, status) in statuses.iter().enumerate() {
+        let nodes = &status.nodes_with_status;
+
+        for (node_idx, node) in nodes.iter().enumerate().take(2) {
+            let label = &node.node.label;
+
+            if node.swap_ready == Some(false) {
+                let issues = if node.swap_issues.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", node.swap_issues.join(", "))
+                };
+                warnings.push((
+                    format!("swap:{}:{}", idx, node_idx),
+                    format!("{} is not swap-ready{}", label, issues),
+                ));
+            }
+
+            let checks = host_checks
+                .get(idx)
+                .map(|pair| if node_idx == 0 { &pair.node_0 } else { &pair.node_1 });
+            let storage = checks.and_then(|c| c.storage.as_ref());
+
+            if node.status == NodeStatus::Active {
+                if let Some(age) = storage.and_then(|s| s.tower_age_seconds) {
+                    if age > STALE_TOWER_SECONDS {
+                        warnings.push((
+                            format!("tower:{}:{}", idx, node_idx),
+                            format!("{} tower file not written for {}s", label, age),
+                        ));
+                    }
+                }
+            }
+
+            for (kind, disk) in storage.map(|s| s.disks()).unwrap_or_default() {
+                if disk.used_percent >= LOW_DISK_PERCENT {
+                    let key = if kind == "ledger" { "disk" } else { "accounts_disk" };
+                    warnings.push((
+                        format!("{}:{}:{}", key, idx, node_idx),
+                        format!("{} {} disk {}% full", label, kind, disk.used_percent),
+                    ));
+                }
+            }
+
+            if let Some(os_patch) = checks.and_then(|c| c.os_patch.as_ref()) {
+                if os_patch.needs_reboot() {
+                    warnings.push((
+                        format!("reboot:{}:{}", idx, node_idx),
+                        format!("{} has a reboot pending", label),
+                    ));
+                }
+            }
+        }
+
+        if let (Some(version_0), Some(version_1)) = (
+            nodes.first().and_then(|n| n.version.as_ref()),
+            nodes.get(1).and_then(|n| n.version.as_ref()),
+        ) {
+            if version_0 != version_1 {
+                warnings.push((
+                    format!("version:{}", idx),
+                    format!(
+                        "Version mismatch: {} runs {}, {} runs {}",
+                        nodes[0].node.label, version_0, nodes[1].node.label, version_1
+                    ),
+                ));
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(key: &str) -> (String, String) {
+        (key.to_string(), format!("{} message", key))
+    }
+
+    #[test]
+    fn test_sync_adds_keeps_and_resolves() {
+        let mut warnings = StickyWarnings::default();
+        warnings.sync(vec![warning("swap:0:1"), warning("version:0")]);
+        let first_seen = warnings.visible().next().unwrap().first_seen;
+
+        warnings.sync(vec![
+            ("swap:0:1".to_string(), "updated".to_string()),
+            warning("disk:0:0"),
+        ]);
+        let visible: Vec<_> = warnings.visible().collect();
+        assert_eq!(visible.len(), 2);
+        assert_eq!(visible[0].key, "swap:0:1");
+        assert_eq!(visible[0].message, "updated");
+        assert_eq!(visible[0].first_seen, first_seen);
+        assert_eq!(visible[1].key, "disk:0:0");
+    }
+
+    #[test]
+    fn test_dismissed_warning_stays_hidden_until_resolved() {
+        let mut warnings = StickyWarnings::default();
+        warnings.sync(vec![warning("tower:0:0")]);
+        assert!(warnings.dismiss_oldest());
+        assert!(!warnings.dismiss_oldest());
+
+        warnings.sync(vec![warning("tower:0:0")]);
+        assert_eq!(warnings.visible().count(), 0);
+        assert_eq!(warnings.dismissed_count(), 1);
+
+        // Once resolved, a recurrence shows again
+        warnings.sync(vec![]);
+        warnings.sync(vec![warning("tower:0:0")]);
+        assert_eq!(warnings.visible().count(), 1);
+    }
+}